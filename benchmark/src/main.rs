@@ -1,13 +1,13 @@
 use serde::{Deserialize, Serialize};
+use silent::middlewares::ETag;
 use silent::prelude::*;
 use std::env;
 use std::sync::Arc;
 
 #[derive(Clone)]
 struct AppState {
-    // 供 C 场景使用的 1KiB 静态内容与其 ETag
+    // 供 C 场景使用的 1KiB 静态内容
     blob: Arc<Vec<u8>>,
-    etag: String,
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone)]
@@ -54,49 +54,26 @@ fn build_route_b() -> Route {
 }
 
 fn build_route_c(state: AppState) -> Route {
-    Route::new("static").get(move |req: Request| {
-        let state = state.clone();
-        async move {
-            // If-None-Match 处理
-            if let Some(im) = req
-                .headers()
-                .get("if-none-match")
-                .and_then(|v| v.to_str().ok())
-                && im == state.etag
-            {
+    // ETag 中间件计算弱 ETag 并处理 If-None-Match
+    Route::new("static")
+        .hook(ETag::new())
+        .get(move |_req: Request| {
+            let state = state.clone();
+            async move {
                 let mut res = Response::empty();
-                res.set_status(StatusCode::NOT_MODIFIED);
                 res.set_header(
-                    header::HeaderName::from_static("etag"),
-                    header::HeaderValue::from_str(&state.etag).unwrap(),
+                    header::HeaderName::from_static("content-type"),
+                    header::HeaderValue::from_static("application/octet-stream"),
                 );
-                return Ok(res);
+                res.set_body(full((*state.blob).clone()));
+                Ok(res)
             }
-            let mut res = Response::empty();
-            res.set_header(
-                header::HeaderName::from_static("content-type"),
-                header::HeaderValue::from_static("application/octet-stream"),
-            );
-            res.set_header(
-                header::HeaderName::from_static("etag"),
-                header::HeaderValue::from_str(&state.etag).unwrap(),
-            );
-            res.set_body(full((*state.blob).clone()));
-            Ok(res)
-        }
-    })
+        })
 }
 
 fn make_state() -> AppState {
-    let blob = vec![b'x'; 1024];
-    // 简单 ETag（非强校验，仅用于基准）
-    use std::hash::{Hash, Hasher};
-    let mut hasher = std::collections::hash_map::DefaultHasher::new();
-    blob.hash(&mut hasher);
-    let etag = format!("\"{:x}\"", hasher.finish());
     AppState {
-        blob: Arc::new(blob),
-        etag,
+        blob: Arc::new(vec![b'x'; 1024]),
     }
 }
 
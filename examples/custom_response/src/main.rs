@@ -28,7 +28,7 @@ async fn custom_response(_req: Request) -> Result<Response> {
     Ok(res)
 }
 
-async fn custom_response2(_req: Request) -> Result<Response> {
+async fn custom_response2(_req: Request) -> Result<Html<&'static str>> {
     let html = r#"
     <!DOCTYPE html>
     <html lang="en">
@@ -40,5 +40,5 @@ async fn custom_response2(_req: Request) -> Result<Response> {
         <h1>custom response2</h1>
     </body>
     </html>"#;
-    Ok(html.into())
+    Ok(Html(html))
 }
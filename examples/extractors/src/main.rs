@@ -1,11 +1,11 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use silent::extractor::{
-    Extension, Form, FromRequest, Json, Method, Path, Query, State, TypedHeader, Uri, Version,
-    handler_from_extractor,
+    Cookies, Extension, Form, FromRequest, Json, Method, Path, Query, State, TypedHeader, Uri,
+    Version, handler_from_extractor,
 };
 use silent::headers::UserAgent;
-use silent::prelude::{Route, Server, logger};
+use silent::prelude::{Cookie, Route, Server, logger};
 use silent::{Handler, MiddleWareHandler, Next, Request, Response, Result};
 use tracing::{Level, info};
 
@@ -167,27 +167,16 @@ async fn ex_headers(TypedHeader(ua): TypedHeader<UserAgent>) -> Result<String> {
     Ok(format!("headers: user-agent={}", ua.as_str()))
 }
 
-// Cookies - 使用辅助函数从 HeaderMap 提取
-async fn ex_cookies(req: Request) -> Result<String> {
-    let cookies = req
-        .headers()
-        .get("cookie")
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or_default();
-
-    let mut session = String::new();
-    let mut user = String::new();
-
-    for part in cookies.split(';') {
-        let mut kv = part.trim().split('=');
-        if let (Some(k), Some(v)) = (kv.next(), kv.next()) {
-            if k == "session" {
-                session = v.to_string();
-            } else if k == "user" {
-                user = v.to_string();
-            }
-        }
-    }
+// Cookies - 使用 Cookies 萃取器，修改会自动写入响应
+async fn ex_cookies(cookies: Cookies) -> Result<String> {
+    let value = |name: &str| {
+        cookies
+            .get(name)
+            .map(|c| c.value().to_string())
+            .unwrap_or_default()
+    };
+    let (session, user) = (value("session"), value("user"));
+    cookies.add(Cookie::new("last_seen", "extractors"));
 
     Ok(format!("cookies: session={}, user={}", session, user))
 }
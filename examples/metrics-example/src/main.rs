@@ -1,19 +1,17 @@
 use metrics_exporter_prometheus::PrometheusBuilder;
+use silent::middlewares::HttpMetrics;
 use silent::prelude::*;
 
-fn init_metrics() {
-    PrometheusBuilder::new()
-        .with_http_listener(([0, 0, 0, 0], 9898))
-        .install()
-        .expect("install prometheus recorder");
-}
-
 #[tokio::main]
 async fn main() {
     logger::fmt().with_max_level(Level::INFO).init();
-    init_metrics();
+    let handle = PrometheusBuilder::new()
+        .install_recorder()
+        .expect("install prometheus recorder");
 
-    let route = Route::new("").get(|_req: Request| async { Ok::<_, SilentError>("ok") });
+    let route = Route::new_root()
+        .hook(HttpMetrics::new().endpoint("/metrics", move || handle.render()))
+        .append(Route::new("").get(|_req: Request| async { Ok::<_, SilentError>("ok") }));
 
     Server::new()
         .bind("127.0.0.1:8080".parse().unwrap())
@@ -20,19 +20,19 @@ async fn main() -> Result<()> {
     // 端口与绑定地址由用户显式设置
     let bind_addr: std::net::SocketAddr = format!("127.0.0.1:{quic_port}").parse().unwrap();
     let store = certificate_store()?;
-    let server_config = ServerConfig {
-        connection_limits: ConnectionLimits {
-            max_body_size: Some(256 * 1024),
-            h3_read_timeout: Some(Duration::from_secs(10)),
-            max_webtransport_frame_size: Some(16 * 1024),
-            webtransport_read_timeout: Some(Duration::from_secs(10)),
-            max_webtransport_sessions: Some(32),
-            webtransport_datagram_max_size: Some(1200),
-            webtransport_datagram_rate: Some(100),
-            webtransport_datagram_drop_metric: true,
-            ..Default::default()
-        },
-        quic_transport: Some(QuicTransportConfig {
+    let server_config = ServerConfig::new()
+        .with_connection_limits(
+            ConnectionLimits::new()
+                .with_max_body_size(256 * 1024)
+                .with_h3_read_timeout(Duration::from_secs(10))
+                .with_max_webtransport_frame_size(16 * 1024)
+                .with_webtransport_read_timeout(Duration::from_secs(10))
+                .with_max_webtransport_sessions(32)
+                .with_webtransport_datagram_max_size(1200)
+                .with_webtransport_datagram_rate(100)
+                .with_webtransport_datagram_drop_metric(true),
+        )
+        .with_quic_transport(QuicTransportConfig {
             keep_alive_interval: Some(Duration::from_secs(15)),
             max_idle_timeout: Some(Duration::from_secs(120)),
             max_bidirectional_streams: Some(256),
@@ -40,8 +40,7 @@ async fn main() -> Result<()> {
             max_datagram_recv_size: Some(128 * 1024),
             enable_datagram: true,
             alpn_protocols: Some(vec![b"h3".to_vec(), b"h3-29".to_vec()]),
-        }),
-    };
+        });
 
     // QUIC listener with HTTP fallback (自动附加 HTTP/1.1 + TLS listener)
     let listener = QuicEndpointListener::from_server_config(bind_addr, &store, &server_config)
@@ -95,25 +94,12 @@ fn build_routes(quic_port: u16) -> Route {
     api.push(Route::new("health").get(health));
 
     Route::new_root()
-        .hook(PoweredByMiddleware)
+        .with_headers([("x-powered-by", "silent-http3")])
         .append(Route::new("").get(index))
         .append(api)
         .with_quic_port(quic_port)
 }
 
-#[derive(Clone)]
-struct PoweredByMiddleware;
-
-#[async_trait::async_trait]
-impl MiddleWareHandler for PoweredByMiddleware {
-    async fn handle(&self, req: Request, next: &Next) -> silent::Result<Response> {
-        let mut resp = next.call(req).await?;
-        resp.headers_mut()
-            .insert("x-powered-by", "silent-http3".parse().unwrap());
-        Ok(resp)
-    }
-}
-
 #[derive(Clone)]
 struct ChatHandler;
 
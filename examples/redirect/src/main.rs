@@ -2,7 +2,9 @@ use silent::prelude::*;
 
 fn main() {
     logger::fmt().with_max_level(Level::INFO).init();
-    let route =
-        Route::new("").get(|_req: Request| async { Response::redirect("https://www.baidu.com") });
+    let route = Route::new("")
+        .get(|_req: Request| async { Response::redirect("https://www.baidu.com") })
+        .append(Route::new("login").post(|_req: Request| async { Ok(Redirect::see_other("/")) }))
+        .append(Route::new("old").get(|_req: Request| async { Ok(Redirect::permanent("/new")) }));
     Server::new().run(route);
 }
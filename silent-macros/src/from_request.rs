@@ -0,0 +1,67 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, parse_quote};
+
+pub(crate) fn expand(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = input.ident.clone();
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "only structs are supported",
+        ));
+    };
+
+    let fields: Vec<_> = data.fields.iter().cloned().collect();
+    let bindings: Vec<_> = (0..fields.len())
+        .map(|i| format_ident!("__field_{}", i))
+        .collect();
+    let build = match &data.fields {
+        Fields::Named(_) => {
+            let names = fields
+                .iter()
+                .map(|f| f.ident.as_ref().expect("named field"));
+            quote! { Self { #(#names: #bindings,)* } }
+        }
+        Fields::Unnamed(_) => quote! { Self(#(#bindings,)*) },
+        Fields::Unit => quote! { Self },
+    };
+
+    // 按声明顺序依次萃取，只有最后一个字段可以读取请求体
+    let where_clause = input.generics.make_where_clause();
+    for (i, field) in fields.iter().enumerate() {
+        let ty = &field.ty;
+        let predicate = if i + 1 == fields.len() {
+            parse_quote! { #ty: ::silent::extractor::FromRequest + ::core::marker::Send + 'static }
+        } else {
+            parse_quote! { #ty: ::silent::extractor::FromRequestParts + ::core::marker::Send + 'static }
+        };
+        where_clause.predicates.push(predicate);
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let extracts = fields.iter().enumerate().map(|(i, f)| {
+        let ty = &f.ty;
+        if i + 1 == fields.len() {
+            quote! { <#ty as ::silent::extractor::FromRequest>::from_request(req) }
+        } else {
+            quote! { <#ty as ::silent::extractor::FromRequestParts>::from_request_parts(req) }
+        }
+    });
+
+    Ok(quote! {
+        #[::silent::__private::async_trait]
+        impl #impl_generics ::silent::extractor::FromRequest for #ident #ty_generics #where_clause {
+            type Rejection = ::silent::Response;
+
+            async fn from_request(
+                req: &mut ::silent::Request,
+            ) -> ::core::result::Result<Self, Self::Rejection> {
+                #(
+                    let #bindings = #extracts
+                        .await
+                        .map_err(::core::convert::Into::<::silent::Response>::into)?;
+                )*
+                ::core::result::Result::Ok(#build)
+            }
+        }
+    })
+}
@@ -0,0 +1,151 @@
+//! Silent 框架的过程宏
+//!
+//! 通常无需直接依赖本 crate，相关宏均通过 `silent` 重新导出。
+
+use proc_macro::TokenStream;
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, Lit, LitStr, PathArguments, Type, parse_macro_input,
+};
+
+mod from_request;
+mod typed_multipart;
+mod typed_path;
+
+/// 为由萃取器组成的结构体生成 `FromRequest` 实现。
+///
+/// 萃取时按字段声明顺序依次进行，任一字段失败即返回该字段的拒绝响应。
+/// 最后一个字段需实现 `FromRequest`，可以读取请求体；其余字段需实现 `FromRequestParts`。
+///
+/// 支持具名字段、元组结构体与单元结构体。
+#[proc_macro_derive(FromRequest)]
+pub fn derive_from_request(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_request::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 为结构体生成 `FromMultipart` 实现，配合 `TypedMultipart<T>` 萃取器使用。
+///
+/// 字段属性：
+///
+/// - `#[multipart(rename = "field")]`：指定表单字段名，默认与结构体字段同名
+/// - `#[multipart(limit = "10MB")]`：单个字段大小上限，支持 `B`/`KB`/`MB`/`GB` 或直接使用字节数
+///
+/// 字段类型为 `Option<T>` 时可缺省，为 `Vec<T>`（`Vec<u8>` 除外）时收集同名的多个字段。
+#[proc_macro_derive(TypedMultipart, attributes(multipart))]
+pub fn derive_typed_multipart(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    typed_multipart::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 由路由模板生成类型化的路由键，可同时用于注册路由与生成 URL。
+///
+/// 参数的名称与类型取自模板（如 `<id:u64>`），未知的参数类型、重复的参数名等在编译期报错。
+///
+/// - `path!("/users/<id:u64>")`：表达式形式，得到的值提供 `pattern()` 与按参数顺序传参的 `url(id)`
+/// - `path!(pub UserPath = "/users/<id:u64>")`：定义同名结构体，字段即路径参数，可作为 `Path<UserPath>`
+///   萃取，并通过 `UserPath::PATTERN` 注册路由、`url()` 生成 URL
+///
+/// 生成 URL 时参数值按路径段进行百分号编码，`<p:path>`、`<p:**>` 参数中的 `/` 原样保留。
+#[proc_macro]
+pub fn path(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as typed_path::PathInput);
+    typed_path::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// 字段在结构体中的包装形式
+enum FieldShape<'a> {
+    /// 必填单值
+    Required(&'a Type),
+    /// `Option<T>`
+    Optional(&'a Type),
+    /// `Vec<T>`
+    Repeated(&'a Type),
+}
+
+/// 识别 `Option<T>` 与 `Vec<T>`，其余类型视为必填单值
+fn field_shape(ty: &Type) -> FieldShape<'_> {
+    if let Some(inner) = generic_inner(ty, "Option") {
+        return FieldShape::Optional(inner);
+    }
+    if let Some(inner) = generic_inner(ty, "Vec") {
+        // Vec<u8> 表示原始字节，按单值处理
+        let is_u8 = matches!(inner, Type::Path(p) if p.path.is_ident("u8"));
+        if !is_u8 {
+            return FieldShape::Repeated(inner);
+        }
+    }
+    FieldShape::Required(ty)
+}
+
+fn generic_inner<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// 解析 `10MB`、`512KB`、`1024` 等大小表示
+fn parse_size(lit: &Lit) -> syn::Result<usize> {
+    match lit {
+        Lit::Int(int) => int.base10_parse(),
+        Lit::Str(s) => {
+            let value = s.value();
+            let trimmed = value.trim();
+            let split = trimmed
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(trimmed.len());
+            let (num, unit) = trimmed.split_at(split);
+            let num: usize = num
+                .parse()
+                .map_err(|_| syn::Error::new(s.span(), "invalid size"))?;
+            let factor = match unit.trim().to_ascii_uppercase().as_str() {
+                "" | "B" => 1,
+                "K" | "KB" | "KIB" => 1024,
+                "M" | "MB" | "MIB" => 1024 * 1024,
+                "G" | "GB" | "GIB" => 1024 * 1024 * 1024,
+                _ => return Err(syn::Error::new(s.span(), "unknown size unit")),
+            };
+            Ok(num * factor)
+        }
+        _ => Err(syn::Error::new_spanned(lit, "expected integer or string")),
+    }
+}
+
+fn named_fields(input: &DeriveInput) -> syn::Result<&syn::FieldsNamed> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                "only structs with named fields are supported",
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "only structs are supported",
+        )),
+    }
+}
+
+/// 读取字段上的 `rename = "..."`，缺省为字段名
+fn rename_or_ident(rename: Option<LitStr>, ident: &syn::Ident) -> String {
+    rename
+        .map(|s| s.value())
+        .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string())
+}
@@ -0,0 +1,108 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Lit, LitStr};
+
+use crate::{FieldShape, field_shape, named_fields, parse_size, rename_or_ident};
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = named_fields(&input)?;
+
+    let mut slots = Vec::new();
+    let mut arms = Vec::new();
+    let mut builds = Vec::new();
+
+    for field in &fields.named {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let slot = format_ident!("__field_{}", field_ident);
+
+        let mut rename: Option<LitStr> = None;
+        let mut limit: Option<usize> = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("multipart"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    rename = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("limit") {
+                    let lit: Lit = meta.value()?.parse()?;
+                    limit = Some(parse_size(&lit)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported multipart attribute"))
+                }
+            })?;
+        }
+        let name = rename_or_ident(rename, field_ident);
+        let limit = match limit {
+            Some(limit) => quote! { ::core::option::Option::Some(#limit) },
+            None => quote! { ::core::option::Option::None },
+        };
+
+        match field_shape(&field.ty) {
+            FieldShape::Required(ty) | FieldShape::Optional(ty) => {
+                slots.push(quote! {
+                    let mut #slot: ::core::option::Option<#ty> = ::core::option::Option::None;
+                });
+                arms.push(quote! {
+                    #name => {
+                        #slot = ::core::option::Option::Some(
+                            <#ty as ::silent::extractor::FromMultipartField>::from_multipart_field(
+                                field, #name, #limit,
+                            )
+                            .await?,
+                        );
+                    }
+                });
+            }
+            FieldShape::Repeated(ty) => {
+                slots.push(quote! {
+                    let mut #slot: ::std::vec::Vec<#ty> = ::std::vec::Vec::new();
+                });
+                arms.push(quote! {
+                    #name => {
+                        #slot.push(
+                            <#ty as ::silent::extractor::FromMultipartField>::from_multipart_field(
+                                field, #name, #limit,
+                            )
+                            .await?,
+                        );
+                    }
+                });
+            }
+        }
+
+        builds.push(match field_shape(&field.ty) {
+            FieldShape::Required(_) => quote! {
+                #field_ident: #slot.ok_or_else(|| ::silent::__private::multipart_missing_field(#name))?
+            },
+            FieldShape::Optional(_) | FieldShape::Repeated(_) => quote! { #field_ident: #slot },
+        });
+    }
+
+    Ok(quote! {
+        #[::silent::__private::async_trait]
+        impl #impl_generics ::silent::extractor::FromMultipart for #ident #ty_generics #where_clause {
+            async fn from_multipart(
+                mut multipart: ::silent::extractor::Multipart,
+            ) -> ::core::result::Result<Self, ::silent::SilentError> {
+                #(#slots)*
+                while let ::core::option::Option::Some(field) = multipart.next_field().await? {
+                    let name = field.name().unwrap_or_default().to_owned();
+                    match name.as_str() {
+                        #(#arms)*
+                        // 未声明的字段直接跳过
+                        _ => {}
+                    }
+                }
+                ::core::result::Result::Ok(Self {
+                    #(#builds,)*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,242 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Token, Visibility};
+
+/// `path!("...")` 或 `path!(pub Name = "...")`
+pub(crate) struct PathInput {
+    item: Option<(Visibility, Ident)>,
+    pattern: LitStr,
+}
+
+impl Parse for PathInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            return Ok(Self {
+                item: None,
+                pattern: input.parse()?,
+            });
+        }
+        let vis: Visibility = input.parse()?;
+        let ident: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let pattern = input.parse()?;
+        Ok(Self {
+            item: Some((vis, ident)),
+            pattern,
+        })
+    }
+}
+
+/// 路由模板中的一段
+enum Segment {
+    Static(String),
+    Param(Param),
+}
+
+struct Param {
+    name: Ident,
+    kind: ParamKind,
+}
+
+#[derive(Clone, Copy)]
+enum ParamKind {
+    Str,
+    I32,
+    I64,
+    U32,
+    U64,
+    Uuid,
+    DateTime,
+    /// `<p:path>` 与 `<p:**>`，值中的 `/` 原样保留
+    Path,
+}
+
+impl ParamKind {
+    /// 与 `SpecialPath` 的解析规则一致，但未知类型在编译期报错
+    fn parse(kind: &str) -> Option<Self> {
+        Some(match kind {
+            "" | "str" => Self::Str,
+            "int" | "i32" => Self::I32,
+            "i64" => Self::I64,
+            "u32" => Self::U32,
+            "u64" => Self::U64,
+            "uuid" => Self::Uuid,
+            "datetime" => Self::DateTime,
+            "*" | "**" | "path" | "full_path" => Self::Path,
+            _ => return None,
+        })
+    }
+
+    fn owned_type(self) -> TokenStream {
+        match self {
+            Self::Str | Self::Path => quote!(::std::string::String),
+            Self::I32 => quote!(i32),
+            Self::I64 => quote!(i64),
+            Self::U32 => quote!(u32),
+            Self::U64 => quote!(u64),
+            Self::Uuid => quote!(::silent::__private::uuid::Uuid),
+            Self::DateTime => {
+                quote!(::silent::__private::chrono::DateTime<::silent::__private::chrono::Utc>)
+            }
+        }
+    }
+
+    fn arg_type(self) -> TokenStream {
+        match self {
+            Self::Str | Self::Path => quote!(&str),
+            _ => self.owned_type(),
+        }
+    }
+
+    /// 将参数值写入 URL 的表达式
+    fn render(self, value: TokenStream) -> TokenStream {
+        match self {
+            Self::Str => quote!(::silent::__private::encode_path_segment(&#value, false)),
+            Self::Path => quote!(::silent::__private::encode_path_segment(&#value, true)),
+            Self::DateTime => quote!(::silent::__private::encode_path_segment(
+                &#value.to_rfc3339_opts(::silent::__private::chrono::SecondsFormat::AutoSi, true),
+                false
+            )),
+            _ => quote!(::std::string::ToString::to_string(&#value)),
+        }
+    }
+}
+
+fn parse_pattern(lit: &LitStr) -> syn::Result<Vec<Segment>> {
+    let value = lit.value();
+    let mut segments = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+    let raw_segments: Vec<&str> = value
+        .trim_matches('/')
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .collect();
+    for (i, raw) in raw_segments.iter().enumerate() {
+        let Some(inner) = raw.strip_prefix('<').and_then(|s| s.strip_suffix('>')) else {
+            if raw.contains(['<', '>']) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("invalid segment `{raw}`: parameters must span a whole segment"),
+                ));
+            }
+            segments.push(Segment::Static(raw.to_string()));
+            continue;
+        };
+        let (name, kind) = inner.split_once(':').unwrap_or((inner, ""));
+        let kind = ParamKind::parse(kind).ok_or_else(|| {
+            syn::Error::new(
+                lit.span(),
+                format!("unknown parameter type `{kind}` in `{raw}`"),
+            )
+        })?;
+        if matches!(kind, ParamKind::Path) && i + 1 != raw_segments.len() {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!("path parameter `{raw}` must be the last segment"),
+            ));
+        }
+        let name = syn::parse_str::<Ident>(name).map_err(|_| {
+            syn::Error::new(
+                lit.span(),
+                format!("parameter name `{name}` is not a valid identifier"),
+            )
+        })?;
+        if names.contains(&name.to_string()) {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!("duplicate parameter `{name}`"),
+            ));
+        }
+        names.push(name.to_string());
+        segments.push(Segment::Param(Param { name, kind }));
+    }
+    Ok(segments)
+}
+
+/// 生成拼接 URL 的语句，`value` 给出每个参数值的表达式
+fn url_body(segments: &[Segment], value: impl Fn(&Ident) -> TokenStream) -> TokenStream {
+    if segments.is_empty() {
+        return quote!(::std::string::String::from("/"));
+    }
+    let pushes = segments.iter().map(|segment| match segment {
+        Segment::Static(s) => quote!(url.push_str(#s);),
+        Segment::Param(param) => {
+            let rendered = param.kind.render(value(&param.name));
+            quote!(url.push_str(&#rendered);)
+        }
+    });
+    quote! {
+        let mut url = ::std::string::String::new();
+        #(
+            url.push('/');
+            #pushes
+        )*
+        url
+    }
+}
+
+pub(crate) fn expand(input: PathInput) -> syn::Result<TokenStream> {
+    let segments = parse_pattern(&input.pattern)?;
+    let pattern = &input.pattern;
+    let params: Vec<&Param> = segments
+        .iter()
+        .filter_map(|segment| match segment {
+            Segment::Param(param) => Some(param),
+            Segment::Static(_) => None,
+        })
+        .collect();
+    let names: Vec<&Ident> = params.iter().map(|param| &param.name).collect();
+
+    let Some((vis, ident)) = input.item else {
+        // 表达式形式：按参数顺序传入的 URL 生成函数
+        let arg_types = params.iter().map(|param| param.kind.arg_type());
+        let body = url_body(&segments, |name| quote!(#name));
+        let ident = format_ident!("__SilentTypedPath");
+        return Ok(quote! {{
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            struct #ident;
+
+            impl #ident {
+                /// 路由模板
+                #[allow(dead_code)]
+                pub const PATTERN: &'static str = #pattern;
+
+                /// 路由模板，可直接用于 `Route::new`
+                #[allow(dead_code)]
+                pub fn pattern(&self) -> &'static str {
+                    Self::PATTERN
+                }
+
+                /// 以给定的参数生成 URL 路径
+                #[allow(dead_code)]
+                pub fn url(&self, #(#names: #arg_types),*) -> ::std::string::String {
+                    #body
+                }
+            }
+
+            #ident
+        }});
+    };
+
+    let field_types = params.iter().map(|param| param.kind.owned_type());
+    let body = url_body(&segments, |name| quote!(self.#name));
+    let fields = quote!(#(pub #names: #field_types,)*);
+    Ok(quote! {
+        #[derive(Clone, Debug, PartialEq, ::silent::__private::serde::Deserialize)]
+        #[serde(crate = "::silent::__private::serde")]
+        #vis struct #ident {
+            #fields
+        }
+
+        impl #ident {
+            /// 路由模板
+            pub const PATTERN: &'static str = #pattern;
+
+            /// 以当前参数值生成 URL 路径
+            pub fn url(&self) -> ::std::string::String {
+                #body
+            }
+        }
+    })
+}
@@ -7,6 +7,41 @@ use syn::{
     Expr, ExprLit, FnArg, ItemFn, Lit, Meta, Result as SynResult, parse::Parse, parse::ParseStream,
 };
 
+/// `Json<T>`、`PrettyJson<T>`、`Created<T>` 响应包装按内部类型 `T` 注册响应元信息与 schema
+fn unwrap_json_response(ty: &syn::Type) -> &syn::Type {
+    if let syn::Type::Path(tp) = ty
+        && let Some(seg) = tp.path.segments.last()
+        && (seg.ident == "Json" || seg.ident == "PrettyJson" || seg.ident == "Created")
+        && let syn::PathArguments::AngleBracketed(args) = &seg.arguments
+        && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+    {
+        return inner;
+    }
+    ty
+}
+
+/// 不携带类型化响应体的返回类型（不生成响应元信息与 schema）
+fn is_untyped_response(ident: &syn::Ident) -> bool {
+    [
+        "Response",
+        "NoContent",
+        "Redirect",
+        "Created",
+        "Attachment",
+        "StreamBody",
+        "Either",
+        "Either3",
+        "Either4",
+    ]
+    .iter()
+    .any(|name| ident == name)
+}
+
+/// 按文本处理的返回类型
+fn is_text_response(ident: &syn::Ident) -> bool {
+    ["String", "Html", "Text"].iter().any(|name| ident == name)
+}
+
 fn endpoint_impl(
     attr: proc_macro2::TokenStream,
     item: proc_macro2::TokenStream,
@@ -171,12 +206,13 @@ fn endpoint_impl(
                         if seg.ident == "Result" || seg.ident == "SilentResult" {
                             if let syn::PathArguments::AngleBracketed(args) = &seg.arguments {
                                 if let Some(syn::GenericArgument::Type(ok_ty)) = args.args.first() {
+                                    let ok_ty = unwrap_json_response(ok_ty);
                                     match ok_ty {
                                         syn::Type::Path(tpath) => {
                                             if let Some(id) = tpath.path.segments.last() {
-                                                if id.ident == "Response" {
+                                                if is_untyped_response(&id.ident) {
                                                     quote!(None)
-                                                } else if id.ident == "String" {
+                                                } else if is_text_response(&id.ident) {
                                                     quote!(Some(::silent_openapi::doc::ResponseMeta::TextPlain))
                                                 } else {
                                                     let tn = id.ident.to_string();
@@ -233,10 +269,13 @@ fn endpoint_impl(
                         if seg.ident == "Result" || seg.ident == "SilentResult" {
                             if let syn::PathArguments::AngleBracketed(args) = &seg.arguments {
                                 if let Some(syn::GenericArgument::Type(ok_ty)) = args.args.first() {
+                                    let ok_ty = unwrap_json_response(ok_ty);
                                     match ok_ty {
                                         syn::Type::Path(tpath) => {
                                             if let Some(id) = tpath.path.segments.last() {
-                                                if id.ident == "Response" || id.ident == "String" {
+                                                if is_untyped_response(&id.ident)
+                                                    || is_text_response(&id.ident)
+                                                {
                                                     quote!()
                                                 } else {
                                                     let ty = ok_ty.clone();
@@ -629,6 +668,60 @@ mod tests {
         assert!(s.contains("根据用户 ID 查询完整的用户资料"));
     }
 
+    #[test]
+    fn registers_inner_type_for_json_response() {
+        for item in [
+            quote!(
+                async fn get_user(_req: ::silent::Request) -> ::silent::Result<Json<User>> {
+                    unimplemented!()
+                }
+            ),
+            quote!(
+                async fn get_user(_req: ::silent::Request) -> ::silent::Result<PrettyJson<User>> {
+                    unimplemented!()
+                }
+            ),
+        ] {
+            let out = super::endpoint_impl(quote!(), item);
+            let s = render(out);
+            assert!(s.contains("ResponseMeta :: Json { type_name : \"User\" }"));
+            assert!(s.contains("register_schema_for :: < User >"));
+        }
+    }
+
+    #[test]
+    fn response_helpers_skip_schema_registration() {
+        for item in [
+            quote!(
+                async fn remove(_req: ::silent::Request) -> ::silent::Result<NoContent> {
+                    unimplemented!()
+                }
+            ),
+            quote!(
+                async fn login(_req: ::silent::Request) -> ::silent::Result<Redirect> {
+                    unimplemented!()
+                }
+            ),
+            quote!(
+                async fn page(_req: ::silent::Request) -> ::silent::Result<Html<String>> {
+                    unimplemented!()
+                }
+            ),
+        ] {
+            let s = render(super::endpoint_impl(quote!(), item));
+            assert!(!s.contains("register_schema_for"));
+            assert!(!s.contains("ResponseMeta :: Json"));
+        }
+
+        let item = quote!(
+            async fn create(_req: ::silent::Request) -> ::silent::Result<Created<User>> {
+                unimplemented!()
+            }
+        );
+        let s = render(super::endpoint_impl(quote!(), item));
+        assert!(s.contains("register_schema_for :: < User >"));
+    }
+
     #[test]
     fn registers_response_meta_for_string() {
         let attr = quote!();
@@ -0,0 +1,524 @@
+//! OpenAPI 规范差异对比
+//!
+//! 比较两个版本的 OpenAPI 文档，并识别会破坏已有客户端的变更，
+//! 便于在 CI 中阻止不兼容的发布。
+//!
+//! 当前被视为破坏性变更的情况：
+//!
+//! - 删除路径或操作（方法）
+//! - 新增必填参数，或已有参数变为必填
+//! - schema 的 `required` 字段集合发生变化
+//! - 枚举值被收窄（删除了原有取值）
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use serde_json::Value;
+use utoipa::openapi::OpenApi;
+
+/// 路径项中表示 HTTP 操作的字段
+const HTTP_METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// 变更类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// 新增路径
+    PathAdded,
+    /// 删除路径
+    PathRemoved,
+    /// 新增操作
+    OperationAdded,
+    /// 删除操作
+    OperationRemoved,
+    /// 新增必填参数或参数变为必填
+    ParameterRequired,
+    /// schema 新增必填字段
+    RequiredFieldAdded,
+    /// schema 删除必填字段
+    RequiredFieldRemoved,
+    /// 枚举值被收窄
+    EnumNarrowed,
+    /// 枚举值被扩展
+    EnumWidened,
+}
+
+impl ChangeKind {
+    /// 该类变更是否会破坏已有客户端
+    pub fn is_breaking(&self) -> bool {
+        matches!(
+            self,
+            ChangeKind::PathRemoved
+                | ChangeKind::OperationRemoved
+                | ChangeKind::ParameterRequired
+                | ChangeKind::RequiredFieldAdded
+                | ChangeKind::RequiredFieldRemoved
+                | ChangeKind::EnumNarrowed
+        )
+    }
+}
+
+/// 单条变更记录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// 变更类型
+    pub kind: ChangeKind,
+    /// 变更位置，例如 `GET /users` 或 `#/components/schemas/User`
+    pub location: String,
+    /// 变更描述
+    pub message: String,
+}
+
+impl Change {
+    /// 是否为破坏性变更
+    pub fn is_breaking(&self) -> bool {
+        self.kind.is_breaking()
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = if self.is_breaking() {
+            "BREAKING"
+        } else {
+            "info"
+        };
+        write!(f, "[{}] {}: {}", level, self.location, self.message)
+    }
+}
+
+/// 两个 OpenAPI 文档之间的差异
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpecDiff {
+    /// 全部变更
+    pub changes: Vec<Change>,
+}
+
+impl SpecDiff {
+    /// 是否没有任何变更
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// 是否包含破坏性变更
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(Change::is_breaking)
+    }
+
+    /// 破坏性变更列表
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|c| c.is_breaking())
+    }
+
+    /// 非破坏性变更列表
+    pub fn non_breaking_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|c| !c.is_breaking())
+    }
+
+    fn push(&mut self, kind: ChangeKind, location: impl Into<String>, message: impl Into<String>) {
+        self.changes.push(Change {
+            kind,
+            location: location.into(),
+            message: message.into(),
+        });
+    }
+}
+
+impl fmt::Display for SpecDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// 对比两个 OpenAPI 文档
+///
+/// # 示例
+///
+/// ```rust
+/// use silent_openapi::{create_openapi_doc, diff};
+///
+/// let old = create_openapi_doc("API", "1.0.0", "");
+/// let new = create_openapi_doc("API", "1.1.0", "");
+/// let result = diff(&old, &new);
+/// assert!(!result.is_breaking());
+/// ```
+pub fn diff(old: &OpenApi, new: &OpenApi) -> SpecDiff {
+    let old = serde_json::to_value(old).unwrap_or_default();
+    let new = serde_json::to_value(new).unwrap_or_default();
+    diff_values(&old, &new)
+}
+
+/// 对比两个已序列化为 JSON 的 OpenAPI 文档
+///
+/// 适用于从文件加载的历史版本规范。
+pub fn diff_values(old: &Value, new: &Value) -> SpecDiff {
+    let mut result = SpecDiff::default();
+    diff_paths(old, new, &mut result);
+    diff_component_schemas(old, new, &mut result);
+    result
+}
+
+fn diff_paths(old: &Value, new: &Value, out: &mut SpecDiff) {
+    let empty = serde_json::Map::new();
+    let old_paths = old
+        .get("paths")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let new_paths = new
+        .get("paths")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+
+    for (path, old_item) in old_paths {
+        let Some(new_item) = new_paths.get(path) else {
+            out.push(ChangeKind::PathRemoved, path, "路径已删除");
+            continue;
+        };
+        for method in HTTP_METHODS {
+            let location = format!("{} {}", method.to_uppercase(), path);
+            match (old_item.get(method), new_item.get(method)) {
+                (Some(_), None) => out.push(ChangeKind::OperationRemoved, location, "操作已删除"),
+                (None, Some(_)) => out.push(ChangeKind::OperationAdded, location, "新增操作"),
+                (Some(old_op), Some(new_op)) => diff_operation(old_op, new_op, &location, out),
+                (None, None) => {}
+            }
+        }
+    }
+
+    for path in new_paths.keys() {
+        if !old_paths.contains_key(path) {
+            out.push(ChangeKind::PathAdded, path, "新增路径");
+        }
+    }
+}
+
+fn diff_operation(old: &Value, new: &Value, location: &str, out: &mut SpecDiff) {
+    // 参数以 (in, name) 作为唯一标识
+    let params = |op: &Value| -> Vec<(String, Value)> {
+        op.get("parameters")
+            .and_then(Value::as_array)
+            .map(|list| {
+                list.iter()
+                    .map(|p| {
+                        let key = format!(
+                            "{}:{}",
+                            p.get("in").and_then(Value::as_str).unwrap_or_default(),
+                            p.get("name").and_then(Value::as_str).unwrap_or_default()
+                        );
+                        (key, p.clone())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let old_params = params(old);
+    let new_params = params(new);
+
+    for (key, new_param) in &new_params {
+        let required = is_required_param(new_param);
+        let old_param = old_params.iter().find(|(k, _)| k == key).map(|(_, p)| p);
+        match old_param {
+            None if required => out.push(
+                ChangeKind::ParameterRequired,
+                location,
+                format!("新增必填参数 `{}`", key),
+            ),
+            Some(old_param) => {
+                if required && !is_required_param(old_param) {
+                    out.push(
+                        ChangeKind::ParameterRequired,
+                        location,
+                        format!("参数 `{}` 变为必填", key),
+                    );
+                }
+                if let (Some(old_schema), Some(new_schema)) =
+                    (old_param.get("schema"), new_param.get("schema"))
+                {
+                    diff_schema(
+                        old_schema,
+                        new_schema,
+                        &format!("{} 参数 `{}`", location, key),
+                        out,
+                    );
+                }
+            }
+            None => {}
+        }
+    }
+
+    if let (Some(old_body), Some(new_body)) = (old.get("requestBody"), new.get("requestBody")) {
+        diff_content(old_body, new_body, &format!("{} 请求体", location), out);
+    }
+
+    if let (Some(old_res), Some(new_res)) = (
+        old.get("responses").and_then(Value::as_object),
+        new.get("responses").and_then(Value::as_object),
+    ) {
+        for (status, old_item) in old_res {
+            if let Some(new_item) = new_res.get(status) {
+                diff_content(
+                    old_item,
+                    new_item,
+                    &format!("{} 响应 {}", location, status),
+                    out,
+                );
+            }
+        }
+    }
+}
+
+fn diff_content(old: &Value, new: &Value, location: &str, out: &mut SpecDiff) {
+    let (Some(old_content), Some(new_content)) = (
+        old.get("content").and_then(Value::as_object),
+        new.get("content").and_then(Value::as_object),
+    ) else {
+        return;
+    };
+    for (media_type, old_media) in old_content {
+        if let (Some(old_schema), Some(new_schema)) = (
+            old_media.get("schema"),
+            new_content.get(media_type).and_then(|m| m.get("schema")),
+        ) {
+            diff_schema(
+                old_schema,
+                new_schema,
+                &format!("{} ({})", location, media_type),
+                out,
+            );
+        }
+    }
+}
+
+fn diff_component_schemas(old: &Value, new: &Value, out: &mut SpecDiff) {
+    let schemas = |doc: &Value| {
+        doc.get("components")
+            .and_then(|c| c.get("schemas"))
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default()
+    };
+    let old_schemas = schemas(old);
+    let new_schemas = schemas(new);
+    for (name, old_schema) in &old_schemas {
+        if let Some(new_schema) = new_schemas.get(name) {
+            diff_schema(
+                old_schema,
+                new_schema,
+                &format!("#/components/schemas/{}", name),
+                out,
+            );
+        }
+    }
+}
+
+fn diff_schema(old: &Value, new: &Value, location: &str, out: &mut SpecDiff) {
+    let old_required = string_set(old.get("required"));
+    let new_required = string_set(new.get("required"));
+    for field in new_required.difference(&old_required) {
+        out.push(
+            ChangeKind::RequiredFieldAdded,
+            location,
+            format!("字段 `{}` 变为必填", field),
+        );
+    }
+    for field in old_required.difference(&new_required) {
+        out.push(
+            ChangeKind::RequiredFieldRemoved,
+            location,
+            format!("字段 `{}` 不再必填", field),
+        );
+    }
+
+    if let (Some(old_enum), Some(new_enum)) = (
+        old.get("enum").and_then(Value::as_array),
+        new.get("enum").and_then(Value::as_array),
+    ) {
+        let removed: Vec<String> = old_enum
+            .iter()
+            .filter(|v| !new_enum.contains(v))
+            .map(Value::to_string)
+            .collect();
+        let added: Vec<String> = new_enum
+            .iter()
+            .filter(|v| !old_enum.contains(v))
+            .map(Value::to_string)
+            .collect();
+        if !removed.is_empty() {
+            out.push(
+                ChangeKind::EnumNarrowed,
+                location,
+                format!("枚举值被删除: {}", removed.join(", ")),
+            );
+        }
+        if !added.is_empty() {
+            out.push(
+                ChangeKind::EnumWidened,
+                location,
+                format!("新增枚举值: {}", added.join(", ")),
+            );
+        }
+    }
+
+    if let (Some(old_props), Some(new_props)) = (
+        old.get("properties").and_then(Value::as_object),
+        new.get("properties").and_then(Value::as_object),
+    ) {
+        for (name, old_prop) in old_props {
+            if let Some(new_prop) = new_props.get(name) {
+                diff_schema(old_prop, new_prop, &format!("{}.{}", location, name), out);
+            }
+        }
+    }
+
+    for (key, suffix) in [("items", "[]"), ("additionalProperties", "{}")] {
+        if let (Some(old_sub), Some(new_sub)) = (old.get(key), new.get(key))
+            && old_sub.is_object()
+            && new_sub.is_object()
+        {
+            diff_schema(old_sub, new_sub, &format!("{}{}", location, suffix), out);
+        }
+    }
+}
+
+fn is_required_param(param: &Value) -> bool {
+    param.get("in").and_then(Value::as_str) == Some("path")
+        || param
+            .get("required")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+}
+
+fn string_set(value: Option<&Value>) -> BTreeSet<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|list| {
+            list.iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn spec(paths: Value, schemas: Value) -> Value {
+        json!({
+            "openapi": "3.1.0",
+            "info": {"title": "t", "version": "1"},
+            "paths": paths,
+            "components": {"schemas": schemas}
+        })
+    }
+
+    #[test]
+    fn test_identical_specs() {
+        let doc = crate::create_openapi_doc("API", "1.0.0", "desc");
+        let result = diff(&doc, &doc);
+        assert!(result.is_empty());
+        assert!(!result.is_breaking());
+    }
+
+    #[test]
+    fn test_removed_path_is_breaking() {
+        let old = spec(
+            json!({"/users": {"get": {}}, "/pets": {"get": {}}}),
+            json!({}),
+        );
+        let new = spec(
+            json!({"/users": {"get": {}}, "/orders": {"get": {}}}),
+            json!({}),
+        );
+        let result = diff_values(&old, &new);
+        assert!(result.is_breaking());
+        let kinds: Vec<_> = result.changes.iter().map(|c| c.kind).collect();
+        assert!(kinds.contains(&ChangeKind::PathRemoved));
+        assert!(kinds.contains(&ChangeKind::PathAdded));
+        assert_eq!(result.breaking_changes().count(), 1);
+    }
+
+    #[test]
+    fn test_removed_operation_is_breaking() {
+        let old = spec(json!({"/users": {"get": {}, "post": {}}}), json!({}));
+        let new = spec(json!({"/users": {"get": {}}}), json!({}));
+        let result = diff_values(&old, &new);
+        assert_eq!(result.changes.len(), 1);
+        assert_eq!(result.changes[0].kind, ChangeKind::OperationRemoved);
+        assert_eq!(result.changes[0].location, "POST /users");
+    }
+
+    #[test]
+    fn test_new_required_parameter_is_breaking() {
+        let old = spec(json!({"/users": {"get": {"parameters": []}}}), json!({}));
+        let new = spec(
+            json!({"/users": {"get": {"parameters": [
+                {"name": "page", "in": "query", "required": false},
+                {"name": "tenant", "in": "header", "required": true}
+            ]}}}),
+            json!({}),
+        );
+        let result = diff_values(&old, &new);
+        assert_eq!(result.breaking_changes().count(), 1);
+        assert!(result.changes[0].message.contains("header:tenant"));
+    }
+
+    #[test]
+    fn test_required_field_change_is_breaking() {
+        let old = spec(
+            json!({}),
+            json!({"User": {"type": "object", "required": ["id"]}}),
+        );
+        let new = spec(
+            json!({}),
+            json!({"User": {"type": "object", "required": ["id", "email"]}}),
+        );
+        let result = diff_values(&old, &new);
+        assert!(result.is_breaking());
+        assert_eq!(result.changes[0].kind, ChangeKind::RequiredFieldAdded);
+        assert_eq!(result.changes[0].location, "#/components/schemas/User");
+    }
+
+    #[test]
+    fn test_enum_narrowed_and_widened() {
+        let old = spec(
+            json!({}),
+            json!({"Status": {"type": "string", "enum": ["a", "b"]}}),
+        );
+        let widened = spec(
+            json!({}),
+            json!({"Status": {"type": "string", "enum": ["a", "b", "c"]}}),
+        );
+        let narrowed = spec(
+            json!({}),
+            json!({"Status": {"type": "string", "enum": ["a"]}}),
+        );
+        assert!(!diff_values(&old, &widened).is_breaking());
+        let result = diff_values(&old, &narrowed);
+        assert!(result.is_breaking());
+        assert_eq!(result.changes[0].kind, ChangeKind::EnumNarrowed);
+    }
+
+    #[test]
+    fn test_nested_request_body_schema() {
+        let body = |required: Value| {
+            json!({"/users": {"post": {"requestBody": {"content": {"application/json": {
+                "schema": {"type": "object", "properties": {
+                    "profile": {"type": "object", "required": required}
+                }}
+            }}}}}})
+        };
+        let old = spec(body(json!([])), json!({}));
+        let new = spec(body(json!(["nickname"])), json!({}));
+        let result = diff_values(&old, &new);
+        assert!(result.is_breaking());
+        assert!(result.changes[0].location.ends_with(".profile"));
+        assert!(result.to_string().starts_with("[BREAKING]"));
+    }
+}
@@ -37,6 +37,7 @@
 //! }
 //! ```
 
+pub mod diff;
 pub mod doc;
 #[cfg(feature = "swagger-ui-embedded")]
 pub mod embedded;
@@ -50,6 +51,7 @@ pub mod schema;
 pub mod ui_html;
 
 // 重新导出核心类型
+pub use diff::{SpecDiff, diff};
 pub use error::{OpenApiError, Result};
 pub use handler::SwaggerUiHandler;
 pub use middleware::SwaggerUiMiddleware;
@@ -546,6 +546,9 @@ fn rust_type_to_schema(
         "f64" => (SchemaType::Type(Type::Number), Some("double")),
         // 布尔
         "bool" => (SchemaType::Type(Type::Boolean), None),
+        // 路由内置的特殊段类型
+        "uuid" => (SchemaType::Type(Type::String), Some("uuid")),
+        "datetime" => (SchemaType::Type(Type::String), Some("date-time")),
         // 字符串（默认）
         "String" | "str" | "&str" | "" => (SchemaType::Type(Type::String), None),
         // 未知类型也映射为 string
@@ -967,6 +970,21 @@ mod tests {
         assert!(schema.is_some());
     }
 
+    #[test]
+    fn test_rust_type_to_schema_special_segments() {
+        for (ty, format) in [("uuid", "uuid"), ("datetime", "date-time")] {
+            let Some(utoipa::openapi::RefOr::T(utoipa::openapi::schema::Schema::Object(obj))) =
+                rust_type_to_schema(ty)
+            else {
+                panic!("expected object schema");
+            };
+            assert!(matches!(
+                obj.format,
+                Some(utoipa::openapi::schema::SchemaFormat::Custom(ref f)) if f == format
+            ));
+        }
+    }
+
     #[test]
     fn test_rust_type_to_schema_default_string() {
         let schema = rust_type_to_schema("");
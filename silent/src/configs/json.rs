@@ -0,0 +1,329 @@
+use std::cell::RefCell;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use http::StatusCode;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::SilentError;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<JsonConfig>>> = const { RefCell::new(None) };
+}
+
+/// JSON 序列化配置
+///
+/// 通过 `Route::with_state` 或 `Server::with_state` 注入后，在处理函数内对
+/// `Response::json`、[`Json`](crate::extractor::Json) 响应、直接返回的 `Serialize` 类型以及
+/// `SilentError::business_error_obj` 的错误体统一生效；未注入时使用默认配置（紧凑输出、保留字段顺序）。
+///
+/// - `pretty`：带缩进输出
+/// - `preserve_order`：保留字段的序列化顺序，关闭后对象键按字母序输出
+/// - `date_format`：将 RFC 3339 格式的时间字符串按 chrono 格式串重新格式化，如 `%Y-%m-%d %H:%M:%S`
+/// - `path_diagnostics`：序列化与 [`Json`](crate::extractor::Json) 萃取失败时在错误信息中给出字段路径，如 `items[2].id`
+///
+/// ```rust
+/// use silent::JsonConfig;
+/// use silent::prelude::*;
+///
+/// let route = Route::new("api").with_state(
+///     JsonConfig::new()
+///         .pretty(true)
+///         .date_format("%Y-%m-%d %H:%M:%S")
+///         .path_diagnostics(true),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonConfig {
+    pretty: bool,
+    preserve_order: bool,
+    date_format: Option<String>,
+    path_diagnostics: bool,
+}
+
+impl Default for JsonConfig {
+    fn default() -> Self {
+        Self {
+            pretty: false,
+            preserve_order: true,
+            date_format: None,
+            path_diagnostics: false,
+        }
+    }
+}
+
+impl JsonConfig {
+    /// 创建默认配置
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 是否带缩进输出
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// 是否保留字段的序列化顺序，关闭后对象键按字母序输出
+    pub fn preserve_order(mut self, preserve_order: bool) -> Self {
+        self.preserve_order = preserve_order;
+        self
+    }
+
+    /// 设置时间字符串的输出格式（chrono 格式串）
+    pub fn date_format(mut self, format: impl Into<String>) -> Self {
+        self.date_format = Some(format.into());
+        self
+    }
+
+    /// 是否在序列化、反序列化错误中给出字段路径
+    pub fn path_diagnostics(mut self, path_diagnostics: bool) -> Self {
+        self.path_diagnostics = path_diagnostics;
+        self
+    }
+
+    /// 按当前配置序列化
+    pub fn to_vec<T: Serialize + ?Sized>(&self, value: &T) -> Result<Vec<u8>, SilentError> {
+        self.serialize(value, self.pretty)
+    }
+
+    pub(crate) fn serialize<T: Serialize + ?Sized>(
+        &self,
+        value: &T,
+        pretty: bool,
+    ) -> Result<Vec<u8>, SilentError> {
+        let body = if self.preserve_order {
+            self.write(value, pretty)?
+        } else {
+            let value = sort_keys(self.encode(value, serde_json::value::Serializer)?);
+            self.write(&value, pretty)?
+        };
+        Ok(match &self.date_format {
+            Some(format) => reformat_dates(&body, format),
+            None => body,
+        })
+    }
+
+    /// 按配置反序列化已解析的 JSON 值
+    pub(crate) fn deserialize_value<T: serde::de::DeserializeOwned>(
+        &self,
+        value: Value,
+    ) -> Result<T, SilentError> {
+        if !self.path_diagnostics {
+            return serde_json::from_value(value).map_err(Into::into);
+        }
+        serde_path_to_error::deserialize(value).map_err(|e| {
+            SilentError::business_error(StatusCode::UNPROCESSABLE_ENTITY, e.to_string())
+        })
+    }
+
+    /// 以处理函数所在请求的配置执行 `f`，未注入时使用默认配置
+    pub(crate) fn with_current<R>(f: impl FnOnce(&JsonConfig) -> R) -> R {
+        match CURRENT.with(|current| current.borrow().clone()) {
+            Some(config) => f(&config),
+            None => f(&JsonConfig::default()),
+        }
+    }
+
+    fn write<T: Serialize + ?Sized>(
+        &self,
+        value: &T,
+        pretty: bool,
+    ) -> Result<Vec<u8>, SilentError> {
+        let mut body = Vec::with_capacity(128);
+        if pretty {
+            self.encode(value, &mut serde_json::Serializer::pretty(&mut body))?;
+        } else {
+            self.encode(value, &mut serde_json::Serializer::new(&mut body))?;
+        }
+        Ok(body)
+    }
+
+    fn encode<T, S>(&self, value: &T, serializer: S) -> Result<S::Ok, SilentError>
+    where
+        T: Serialize + ?Sized,
+        S: Serializer<Error = serde_json::Error>,
+    {
+        let result = if self.path_diagnostics {
+            serde_path_to_error::serialize(value, serializer).map_err(|e| e.to_string())
+        } else {
+            value.serialize(serializer).map_err(|e| e.to_string())
+        };
+        result.map_err(|e| {
+            SilentError::business_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("failed to serialize json: {e}"),
+            )
+        })
+    }
+}
+
+/// 递归地将对象键按字母序排列
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().map(|(k, v)| (k, sort_keys(v))).collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(entries.into_iter().collect())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(sort_keys).collect()),
+        other => other,
+    }
+}
+
+/// 扫描序列化结果中的字符串，将 RFC 3339 时间按 `format` 重新格式化
+fn reformat_dates(json: &[u8], format: &str) -> Vec<u8> {
+    let mut out = Vec::with_capacity(json.len());
+    let mut i = 0;
+    while i < json.len() {
+        if json[i] != b'"' {
+            out.push(json[i]);
+            i += 1;
+            continue;
+        }
+        let mut end = i + 1;
+        while end < json.len() && json[end] != b'"' {
+            end += if json[end] == b'\\' { 2 } else { 1 };
+        }
+        let end = end.min(json.len() - 1);
+        let token = &json[i..=end];
+        match std::str::from_utf8(&json[i + 1..end])
+            .ok()
+            .and_then(|s| format_date(s, format))
+        {
+            Some(formatted) => out.extend_from_slice(formatted.as_bytes()),
+            None => out.extend_from_slice(token),
+        }
+        i = end + 1;
+    }
+    out
+}
+
+fn format_date(s: &str, format: &str) -> Option<String> {
+    // 快速排除非时间字符串，避免对每个字符串都尝试解析
+    if s.len() < 20 || s.as_bytes()[4] != b'-' {
+        return None;
+    }
+    let datetime = chrono::DateTime::parse_from_rfc3339(s).ok()?;
+    let mut formatted = String::new();
+    write!(formatted, "{}", datetime.format(format)).ok()?;
+    serde_json::to_string(&formatted).ok()
+}
+
+/// 在轮询期间设置当前 [`JsonConfig`] 的 Future 包装
+pub(crate) struct JsonScope<F> {
+    config: Arc<JsonConfig>,
+    inner: F,
+}
+
+impl<F> JsonScope<F> {
+    pub(crate) fn new(config: JsonConfig, inner: F) -> Self {
+        Self {
+            config: Arc::new(config),
+            inner,
+        }
+    }
+}
+
+impl<F: Future + Unpin> Future for JsonScope<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        struct Reset(Option<Arc<JsonConfig>>);
+
+        impl Drop for Reset {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let this = &mut *self;
+        let _reset = Reset(CURRENT.with(|current| current.replace(Some(this.config.clone()))));
+        Pin::new(&mut this.inner).poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Serialize)]
+    struct Event {
+        name: &'static str,
+        at: chrono::DateTime<chrono::Utc>,
+    }
+
+    fn event() -> Event {
+        Event {
+            name: "deploy",
+            at: "2024-05-01T08:30:00Z".parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_json_config_serialize() {
+        let body = JsonConfig::new().to_vec(&event()).unwrap();
+        assert_eq!(body, br#"{"name":"deploy","at":"2024-05-01T08:30:00Z"}"#);
+
+        let body = JsonConfig::new()
+            .preserve_order(false)
+            .date_format("%Y-%m-%d %H:%M")
+            .to_vec(&event())
+            .unwrap();
+        assert_eq!(body, br#"{"at":"2024-05-01 08:30","name":"deploy"}"#);
+
+        let body = JsonConfig::new().pretty(true).to_vec(&[1]).unwrap();
+        assert_eq!(body, b"[\n  1\n]");
+    }
+
+    #[test]
+    fn test_json_config_path_diagnostics() {
+        #[derive(Debug, Deserialize)]
+        struct Item {
+            #[allow(dead_code)]
+            id: u64,
+        }
+        #[derive(Debug, Deserialize)]
+        struct Order {
+            #[allow(dead_code)]
+            items: Vec<Item>,
+        }
+
+        let value = serde_json::json!({"items": [{"id": 1}, {"id": "x"}]});
+        let err = JsonConfig::new()
+            .path_diagnostics(true)
+            .deserialize_value::<Order>(value)
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(err.message().starts_with("items[1].id: "));
+
+        #[derive(Serialize)]
+        struct Meta {
+            tags: std::collections::BTreeMap<Vec<u8>, u8>,
+        }
+        let meta = Meta {
+            tags: [(vec![1u8], 1)].into(),
+        };
+        let err = JsonConfig::new()
+            .path_diagnostics(true)
+            .to_vec(&meta)
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(err.message().contains("tags"));
+    }
+
+    #[tokio::test]
+    async fn test_json_scope() {
+        let fut = Box::pin(async {
+            tokio::task::yield_now().await;
+            JsonConfig::with_current(|config| config.pretty)
+        });
+        assert!(JsonScope::new(JsonConfig::new().pretty(true), fut).await);
+        assert!(!JsonConfig::with_current(|config| config.pretty));
+    }
+}
@@ -4,6 +4,11 @@ use std::fmt;
 use std::hash::{BuildHasherDefault, Hasher};
 use std::sync::Arc;
 
+mod json;
+
+pub use json::JsonConfig;
+pub(crate) use json::JsonScope;
+
 type AnyMap = HashMap<TypeId, Arc<dyn Any + Send + Sync>, BuildHasherDefault<IdHasher>>;
 
 // With TypeIds as keys, there's no need to hash them. They are already hashes
@@ -52,7 +57,7 @@ pub type Configs = State;
 impl State {
     /// Create an empty `State`.
     #[inline]
-    pub fn new() -> State {
+    pub const fn new() -> State {
         State { map: None }
     }
 
@@ -96,6 +101,51 @@ impl State {
             .and_then(|boxed| (&**boxed as &(dyn Any + 'static)).downcast_ref())
     }
 
+    /// Insert a shared value into this `State` without requiring `T: Clone`.
+    ///
+    /// The value can be read back with [`State::get`] or, without cloning
+    /// the inner value, with [`State::get_arc`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use silent::State;
+    /// struct Pool(Vec<u8>);
+    ///
+    /// let mut cfg = State::new();
+    /// assert!(cfg.insert_arc(Arc::new(Pool(vec![1]))).is_none());
+    /// assert_eq!(cfg.get::<Pool>().unwrap().0, vec![1]);
+    /// ```
+    pub fn insert_arc<T: Send + Sync + 'static>(&mut self, val: Arc<T>) -> Option<Arc<T>> {
+        self.map
+            .get_or_insert_with(Box::default)
+            .insert(TypeId::of::<T>(), val)
+            .and_then(|boxed| boxed.downcast().ok())
+    }
+
+    /// Get a shared handle to a type previously inserted on this `State`.
+    ///
+    /// Only the `Arc` is cloned, never the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use silent::State;
+    /// let mut cfg = State::new();
+    /// cfg.insert(5i32);
+    /// let a = cfg.get_arc::<i32>().unwrap();
+    /// let b = cfg.get_arc::<i32>().unwrap();
+    /// assert!(Arc::ptr_eq(&a, &b));
+    /// ```
+    pub fn get_arc<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.map
+            .as_ref()
+            .and_then(|map| map.get(&TypeId::of::<T>()))
+            .and_then(|boxed| boxed.clone().downcast().ok())
+    }
+
     /// Remove a type from this `State`.
     ///
     /// If aa extension of this type existed, it will be returned.
@@ -1,3 +1,4 @@
+use crate::extractor::Cookies;
 use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, SilentError};
 use async_trait::async_trait;
 use cookie::{Cookie, CookieJar};
@@ -34,15 +35,19 @@ impl MiddleWareHandler for CookieMiddleware {
             }
         }
         req.extensions_mut().insert(jar.clone());
+        let cookies = Cookies::new(jar.clone());
+        req.extensions_mut().insert(cookies.clone());
         let mut res = next.call(req).await?;
+        // 先应用 Cookies 萃取器的修改，再应用响应上直接设置的 Cookie
+        for cookie in cookies.delta() {
+            jar.add(cookie)
+        }
         if let Some(cookie_jar) = res.extensions().get::<CookieJar>() {
             for cookie in cookie_jar.delta().cloned() {
                 jar.add(cookie)
             }
-            res.extensions_mut().insert(jar);
-        } else {
-            res.extensions_mut().insert(jar);
-        };
+        }
+        res.extensions_mut().insert(jar);
         Ok(res)
     }
 }
@@ -370,6 +375,40 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_cookie_middleware_applies_extractor_changes() {
+        use crate::CookieExt;
+        use crate::extractor::FromRequest;
+        use crate::route::Route;
+
+        let route =
+            Route::new("/")
+                .hook(CookieMiddleware::new())
+                .get(|mut req: Request| async move {
+                    let cookies = Cookies::from_request(&mut req).await?;
+                    assert_eq!(cookies.get("session").unwrap().value(), "abc");
+                    cookies.add(Cookie::new("theme", "dark"));
+                    cookies.remove(Cookie::from("session"));
+                    Ok("updated")
+                });
+        let route = Route::new_root().append(route);
+
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert(header::COOKIE, "session=abc; user=bob".parse().unwrap());
+        let res: Response = route.call(req).await.unwrap();
+
+        let jar = res.cookies();
+        let delta: Vec<_> = jar.delta().collect();
+        assert_eq!(delta.len(), 2);
+        assert_eq!(jar.get("theme").unwrap().value(), "dark");
+        let removed = delta.iter().find(|c| c.name() == "session").unwrap();
+        assert_eq!(removed.value(), "");
+        // 未修改的 Cookie 不会写回响应
+        assert!(delta.iter().all(|c| c.name() != "user"));
+    }
+
     // ==================== 边界条件测试 ====================
 
     #[cfg(feature = "server")]
@@ -130,6 +130,14 @@ impl FilePart {
     /// deleted once the FilePart object goes out of scope).
     #[inline]
     pub async fn create(field: &mut Field<'_>) -> Result<FilePart, SilentError> {
+        Self::create_with_limit(field, None).await
+    }
+
+    /// 创建临时 FilePart，写入内容超过 `limit` 字节时删除临时文件并返回 413 错误。
+    pub(crate) async fn create_with_limit(
+        field: &mut Field<'_>,
+        limit: Option<usize>,
+    ) -> Result<FilePart, SilentError> {
         // Set up a file to capture the contents.
         let mut path = Builder::new()
             .prefix("silent_http_multipart")
@@ -148,6 +156,19 @@ impl FilePart {
         let mut size = 0;
         while let Some(chunk) = field.chunk().await? {
             size += chunk.len() as u64;
+            if let Some(limit) = limit
+                && size > limit as u64
+            {
+                drop(file);
+                let _ = std::fs::remove_file(&path);
+                if let Some(temp_dir) = &temp_dir {
+                    let _ = std::fs::remove_dir(temp_dir);
+                }
+                return Err(crate::extractor::multipart_field_too_large(
+                    field.name().unwrap_or_default(),
+                    limit,
+                ));
+            }
             file.write_all(&chunk).await?;
         }
         Ok(FilePart {
@@ -1,4 +1,5 @@
-use crate::Response;
+use crate::{Response, StatusCode};
+use http::HeaderMap;
 
 /// 将类型转换为 HTTP 响应的 trait。
 ///
@@ -12,6 +13,38 @@ use crate::Response;
 /// - `String` / `&str` — 通过 `Serialize` 转为 JSON 响应
 /// - `SilentError` — 转为对应状态码的错误响应
 /// - 任何实现了 `Serialize` 的类型 — 自动 JSON 序列化
+/// - `Json<T>`、`PrettyJson<T>` — 序列化为 JSON 响应（后者带缩进格式化）
+///
+/// 另有以下实现直接构造响应，不经过 `Into<Response>` 桥接：
+/// - `StatusCode` — 仅设置状态码的空响应
+/// - `(StatusCode, T)` — 以 `T` 生成响应后覆盖状态码
+/// - `(StatusCode, HeaderMap, T)` — 额外合并响应头，同名头以 `HeaderMap` 为准
+///
+/// 受 trait 一致性规则限制（上游可能为元组实现 `Serialize`），这些实现挂在标记类型
+/// [`WithStatus`] 上，trait 的类型参数 `M` 由编译器推断，编写 handler 时无需关心：
+///
+/// ```rust
+/// use serde::Serialize;
+/// use silent::header::{HeaderMap, HeaderValue, LOCATION};
+/// use silent::{Request, Result, StatusCode};
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     id: u64,
+/// }
+///
+/// async fn create(_req: Request) -> Result<(StatusCode, HeaderMap, User)> {
+///     let mut headers = HeaderMap::new();
+///     headers.insert(LOCATION, HeaderValue::from_static("/users/1"));
+///     Ok((StatusCode::CREATED, headers, User { id: 1 }))
+/// }
+///
+/// async fn remove(_req: Request) -> Result<StatusCode> {
+///     Ok(StatusCode::NO_CONTENT)
+/// }
+///
+/// let route = silent::prelude::Route::new("users").post(create).delete(remove);
+/// ```
 ///
 /// # 自定义错误示例
 ///
@@ -38,10 +71,13 @@ use crate::Response;
 ///     }
 /// }
 /// ```
-pub trait IntoResponse {
+pub trait IntoResponse<M = ()> {
     fn into_response(self) -> Response;
 }
 
+/// `StatusCode` 及状态码元组实现 [`IntoResponse`] 时使用的标记类型
+pub struct WithStatus;
+
 /// 桥接：任何实现了 `Into<Response>` 的类型自动获得 `IntoResponse`。
 ///
 /// 这确保了向后兼容——现有的 `Serialize` 类型、`SilentError`、`Response`
@@ -52,10 +88,38 @@ impl<T: Into<Response>> IntoResponse for T {
     }
 }
 
+impl IntoResponse<WithStatus> for StatusCode {
+    fn into_response(self) -> Response {
+        Response::empty().with_status(self)
+    }
+}
+
+impl<T: IntoResponse> IntoResponse<WithStatus> for (StatusCode, T) {
+    fn into_response(self) -> Response {
+        let (status, body) = self;
+        body.into_response().with_status(status)
+    }
+}
+
+impl<T: IntoResponse> IntoResponse<WithStatus> for (StatusCode, HeaderMap, T) {
+    fn into_response(self) -> Response {
+        let (status, headers, body) = self;
+        let mut res = body.into_response().with_status(status);
+        res.headers_mut().extend(headers);
+        res
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::StatusCode;
+    use http::header::{CONTENT_TYPE, HeaderValue, LOCATION};
+    use http_body_util::BodyExt;
+
+    async fn body_string(res: Response) -> String {
+        let bytes = res.body.collect().await.unwrap().to_bytes();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
 
     #[test]
     fn test_string_into_response() {
@@ -107,4 +171,57 @@ mod tests {
         let res = AppError::BadRequest("invalid".to_string()).into_response();
         assert_eq!(res.status(), StatusCode::BAD_REQUEST);
     }
+
+    #[tokio::test]
+    async fn test_shapes_body() {
+        let res = "hello".to_string().into_response();
+        assert_eq!(body_string(res).await, "hello");
+
+        let res = "hello".into_response();
+        assert_eq!(body_string(res).await, "hello");
+
+        let res = Response::text("raw").into_response();
+        assert_eq!(body_string(res).await, "raw");
+    }
+
+    #[tokio::test]
+    async fn test_status_code_into_response() {
+        let res = StatusCode::NO_CONTENT.into_response();
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        assert!(res.headers().is_empty());
+        assert_eq!(body_string(res).await, "");
+    }
+
+    #[tokio::test]
+    async fn test_status_tuple_into_response() {
+        let res = (StatusCode::CREATED, "created").into_response();
+        assert_eq!(res.status(), StatusCode::CREATED);
+        assert_eq!(body_string(res).await, "created");
+
+        let res = (StatusCode::ACCEPTED, serde_json::json!({"id": 1})).into_response();
+        assert_eq!(res.status(), StatusCode::ACCEPTED);
+        let content_type = res.headers().get(CONTENT_TYPE).unwrap();
+        assert!(content_type.to_str().unwrap().contains("application/json"));
+        assert_eq!(body_string(res).await, r#"{"id":1}"#);
+    }
+
+    #[tokio::test]
+    async fn test_status_headers_tuple_into_response() {
+        let mut headers = HeaderMap::new();
+        headers.insert(LOCATION, HeaderValue::from_static("/users/1"));
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/custom"));
+        let res = (StatusCode::CREATED, headers, "user").into_response();
+        assert_eq!(res.status(), StatusCode::CREATED);
+        assert_eq!(res.headers().get(LOCATION).unwrap(), "/users/1");
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "text/custom");
+        assert_eq!(body_string(res).await, "user");
+    }
+
+    #[tokio::test]
+    async fn test_tuple_overrides_inner_status() {
+        let inner = Response::text("gone").with_status(StatusCode::NOT_FOUND);
+        let res = (StatusCode::GONE, inner).into_response();
+        assert_eq!(res.status(), StatusCode::GONE);
+        assert_eq!(body_string(res).await, "gone");
+    }
 }
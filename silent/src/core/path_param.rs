@@ -1,4 +1,5 @@
 use crate::SilentError;
+use chrono::{DateTime, Utc};
 use std::borrow::Cow;
 use std::ops::Range;
 use std::sync::Arc;
@@ -21,6 +22,8 @@ pub enum PathParam {
     UInt32(u32),
     /// Uuid 参数
     Uuid(Uuid),
+    /// 时间参数 `<key:datetime>`（RFC 3339，已转换为 UTC）
+    DateTime(DateTime<Utc>),
     /// 通配路径参数 `<key:path>` / `<key:*>` / `<key:**>`
     Path(PathString),
 }
@@ -35,6 +38,24 @@ impl PathParam {
     }
 }
 
+/// 以路由中的文本形式输出参数值，时间参数为 RFC 3339 格式
+impl std::fmt::Display for PathParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathParam::Str(s) | PathParam::Path(s) => f.write_str(s.as_str()),
+            PathParam::Int(v) => write!(f, "{v}"),
+            PathParam::Int32(v) => write!(f, "{v}"),
+            PathParam::Int64(v) => write!(f, "{v}"),
+            PathParam::UInt32(v) => write!(f, "{v}"),
+            PathParam::UInt64(v) => write!(f, "{v}"),
+            PathParam::Uuid(u) => write!(f, "{u}"),
+            PathParam::DateTime(t) => {
+                f.write_str(&t.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+            }
+        }
+    }
+}
+
 impl From<String> for PathParam {
     fn from(value: String) -> Self {
         PathParam::Str(PathString::Owned(value))
@@ -71,6 +92,12 @@ impl From<Uuid> for PathParam {
     }
 }
 
+impl From<DateTime<Utc>> for PathParam {
+    fn from(value: DateTime<Utc>) -> Self {
+        PathParam::DateTime(value)
+    }
+}
+
 impl<'a> TryFrom<&'a PathParam> for i32 {
     type Error = SilentError;
 
@@ -140,6 +167,17 @@ impl<'a> TryFrom<&'a PathParam> for Uuid {
     }
 }
 
+impl<'a> TryFrom<&'a PathParam> for DateTime<Utc> {
+    type Error = SilentError;
+
+    fn try_from(value: &'a PathParam) -> Result<Self, Self::Error> {
+        match value {
+            PathParam::DateTime(v) => Ok(*v),
+            _ => Err(SilentError::ParamsNotFound),
+        }
+    }
+}
+
 /// 字符串参数的持有方式，支持借用路径缓冲或拥有独立字符串。
 #[derive(Debug, Clone, PartialEq)]
 pub enum PathString {
@@ -6,7 +6,7 @@ use crate::core::req_body::ReqBody;
 #[cfg(feature = "multipart")]
 use crate::core::serde::from_str_multi_val;
 use crate::header::CONTENT_TYPE;
-use crate::{Result, SilentError, State};
+use crate::{JsonConfig, Result, SilentError, State};
 use bytes::Bytes;
 use http::request::Parts;
 use http::{Extensions, HeaderMap, HeaderValue, Method, Uri, Version};
@@ -17,6 +17,7 @@ use once_cell::sync::OnceCell;
 use serde::Deserialize;
 use serde::de::StdError;
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 use url::form_urlencoded;
@@ -34,6 +35,7 @@ pub struct Request {
     params: HashMap<String, String>,
     body: ReqBody,
     path_source: Option<Arc<str>>,
+    peer_addr: Option<RemoteAddr>,
     #[cfg(feature = "multipart")]
     form_data: OnceCell<FormData>,
     json_data: OnceCell<Value>,
@@ -119,6 +121,7 @@ impl Request {
             params: HashMap::new(),
             body: ReqBody::Empty,
             path_source: None,
+            peer_addr: None,
             #[cfg(feature = "multipart")]
             form_data: OnceCell::new(),
             json_data: OnceCell::new(),
@@ -138,6 +141,7 @@ impl Request {
             path_params: HashMap::new(),
             params: HashMap::new(),
             path_source: None,
+            peer_addr: None,
             #[cfg(feature = "multipart")]
             form_data: OnceCell::new(),
             json_data: OnceCell::new(),
@@ -159,6 +163,15 @@ impl Request {
             .expect("remote addr not set or invalid in x-real-ip header")
     }
 
+    /// 获取底层连接的对端地址
+    ///
+    /// 与 [`Request::remote`] 不同，该地址仅来自 `set_remote` 传入的连接地址，不受任何请求头影响；
+    /// 未经服务器处理的请求返回 `None`。
+    #[inline]
+    pub fn peer_addr(&self) -> Option<&RemoteAddr> {
+        self.peer_addr.as_ref()
+    }
+
     /// 设置访问真实地址
     ///
     /// 适配策略：
@@ -167,6 +180,7 @@ impl Request {
     /// - 若仍不可用，则退回使用传入的 `remote_addr`。
     #[inline]
     pub fn set_remote(&mut self, remote_addr: RemoteAddr) {
+        self.peer_addr = Some(remote_addr.clone());
         // 已有合法 x-real-ip，则尊重上游配置
         if self
             .headers()
@@ -206,6 +220,11 @@ impl Request {
         self.path_source = Some(source);
     }
 
+    #[cfg(feature = "tower-compat")]
+    pub(crate) fn path_source(&self) -> Option<&Arc<str>> {
+        self.path_source.as_ref()
+    }
+
     /// 获取请求方法
     #[inline]
     pub fn method(&self) -> &Method {
@@ -267,6 +286,12 @@ impl Request {
         self.state.get::<T>().ok_or(SilentError::ConfigNotFound)
     }
 
+    /// 获取状态的共享引用，仅克隆 `Arc` 而不克隆内部值
+    #[inline]
+    pub fn get_state_arc<T: Send + Sync + 'static>(&self) -> Result<Arc<T>> {
+        self.state.get_arc::<T>().ok_or(SilentError::ConfigNotFound)
+    }
+
     /// 获取状态(Uncheck)
     #[inline]
     pub fn get_state_uncheck<T: Send + Sync + 'static>(&self) -> &T {
@@ -318,6 +343,25 @@ impl Request {
         &self.path_params
     }
 
+    /// 获取全部路径参数的文本形式，如 `{"id": "42"}`
+    ///
+    /// 与 [`path_params`](Self::path_params) 不同，返回值不区分参数类型，适合写入日志或交给通用的鉴权中间件。
+    pub fn path_params_map(&self) -> HashMap<String, String> {
+        self.path_params
+            .iter()
+            .map(|(key, value)| (key.clone(), value.to_string()))
+            .collect()
+    }
+
+    /// 获取命中的路由模板，如 `/users/<id:u64>`，未经路由匹配时为 `None`
+    ///
+    /// 与 [`MatchedPath`](crate::extractor::MatchedPath) 萃取器一致，适合作为指标标签或访问日志字段。
+    pub fn matched_path(&self) -> Option<&str> {
+        self.extensions()
+            .get::<crate::extractor::MatchedPath>()
+            .map(|matched| matched.as_str())
+    }
+
     /// 获取路径参数
     pub fn get_path_params<'a, T>(&'a self, key: &'a str) -> Result<T>
     where
@@ -422,10 +466,16 @@ impl Request {
     where
         for<'de> T: Deserialize<'de>,
     {
-        // 复用 form_data 的缓存机制
+        // 复用 form_data 的缓存机制，将文本字段按 urlencoded 规则反序列化，
+        // 使同一结构体无论表单 enctype 如何都能得到一致的解析结果（文件字段被忽略）
         let form_data = self.form_data().await?;
-        let value = serde_json::to_value(form_data.fields.clone()).map_err(SilentError::from)?;
-        serde_json::from_value(value).map_err(Into::into)
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        for (key, values) in form_data.fields.iter_all() {
+            for value in values {
+                serializer.append_pair(key, value);
+            }
+        }
+        serde_html_form::from_str(&serializer.finish()).map_err(SilentError::from)
     }
 
     /// 解析 application/x-www-form-urlencoded 数据
@@ -458,7 +508,11 @@ impl Request {
             bytes
         };
 
-        // 解析 form-urlencoded 数据
+        // 按 Content-Type 中的 charset 转换为 UTF-8 后解析 form-urlencoded 数据
+        let charset = self
+            .content_type()
+            .and_then(|m| m.get_param(mime::CHARSET).map(|c| c.as_str().to_owned()));
+        let bytes = form_bytes_to_utf8(charset.as_deref(), &bytes)?;
         let parsed_data: T = serde_html_form::from_bytes(&bytes).map_err(SilentError::from)?;
 
         Ok(parsed_data)
@@ -494,7 +548,7 @@ impl Request {
     {
         // 检查是否已缓存
         if let Some(cached_value) = self.json_data.get() {
-            return serde_json::from_value(cached_value.clone()).map_err(Into::into);
+            return self.json_from_value(cached_value.clone());
         }
 
         let content_type = self
@@ -524,7 +578,18 @@ impl Request {
         // 缓存结果
         let _ = self.json_data.set(value.clone());
 
-        serde_json::from_value(value).map_err(Into::into)
+        self.json_from_value(value)
+    }
+
+    /// 按 [`JsonConfig`] 将已解析的 JSON 转换为目标类型
+    fn json_from_value<T>(&self, value: Value) -> Result<T>
+    where
+        for<'de> T: Deserialize<'de>,
+    {
+        match self.get_state::<JsonConfig>() {
+            Ok(config) => config.deserialize_value(value),
+            Err(_) => serde_json::from_value(value).map_err(Into::into),
+        }
     }
 
     /// 转换body参数按Json匹配
@@ -555,6 +620,49 @@ impl Request {
     }
 }
 
+/// 将 urlencoded 表单数据按 charset 转换为 UTF-8 编码
+///
+/// 支持 UTF-8、US-ASCII 与 ISO-8859-1；其他字符集返回 `415 Unsupported Media Type`。
+fn form_bytes_to_utf8<'a>(charset: Option<&str>, bytes: &'a [u8]) -> Result<Cow<'a, [u8]>> {
+    let Some(charset) = charset else {
+        return Ok(Cow::Borrowed(bytes));
+    };
+    match charset.to_ascii_lowercase().as_str() {
+        "utf-8" | "utf8" | "us-ascii" | "ascii" => Ok(Cow::Borrowed(bytes)),
+        "iso-8859-1" | "latin1" | "latin-1" | "l1" => Ok(Cow::Owned(latin1_to_utf8(bytes))),
+        _ => Err(SilentError::business_error(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            format!("unsupported form charset: {charset}"),
+        )),
+    }
+}
+
+/// ISO-8859-1 的每个字节即对应的 Unicode 码点，非 ASCII 字节重新编码为 UTF-8 的百分号转义
+fn latin1_to_utf8(bytes: &[u8]) -> Vec<u8> {
+    let hex = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let (byte, len) = match bytes[i] {
+            b'%' if i + 2 < bytes.len() => match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                (Some(h), Some(l)) => (h << 4 | l, 3),
+                _ => (b'%', 1),
+            },
+            b => (b, 1),
+        };
+        if byte.is_ascii() {
+            out.extend_from_slice(&bytes[i..i + len]);
+        } else {
+            let mut buf = [0u8; 2];
+            for b in (byte as char).encode_utf8(&mut buf).bytes() {
+                out.extend_from_slice(format!("%{b:02X}").as_bytes());
+            }
+        }
+        i += len;
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -645,6 +753,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_peer_addr_ignores_headers() {
+        let mut req = Request::empty();
+        assert!(req.peer_addr().is_none());
+
+        req.headers_mut()
+            .insert("x-forwarded-for", "203.0.113.1".parse().unwrap());
+        let addr = RemoteAddr::from(StdSocketAddr::new(
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            8080,
+        ));
+        req.set_remote(addr);
+
+        assert_eq!(req.peer_addr().unwrap().to_string(), "10.0.0.1:8080");
+        assert_eq!(req.remote().to_string(), "203.0.113.1");
+    }
+
     #[test]
     fn test_set_remote_without_headers() {
         let mut req = Request::empty();
@@ -800,6 +925,23 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_path_params_map() {
+        let mut req = Request::empty();
+        req.path_params.insert(
+            "slug".to_string(),
+            PathParam::Str(PathString::Owned("intro".to_string())),
+        );
+        req.path_params
+            .insert("id".to_string(), PathParam::UInt64(42));
+
+        let params = req.path_params_map();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params["slug"], "intro");
+        assert_eq!(params["id"], "42");
+        assert_eq!(req.matched_path(), None);
+    }
+
     // ==================== params 相关测试 ====================
 
     #[test]
@@ -1054,6 +1196,73 @@ mod tests {
         );
     }
 
+    /// 测试 form_parse 按 charset 解码 urlencoded 表单
+    #[tokio::test]
+    async fn test_form_parse_charset() {
+        #[derive(Deserialize, Debug)]
+        struct Greeting {
+            name: String,
+        }
+
+        // ISO-8859-1 下 0xE9 为 "é"，同时兼容转义与未转义的字节
+        for body in [b"name=Jos%E9".to_vec(), b"name=Jos\xe9".to_vec()] {
+            let mut req = create_request_with_body(
+                "application/x-www-form-urlencoded; charset=ISO-8859-1",
+                body,
+            );
+            let greeting = req.form_parse::<Greeting>().await.unwrap();
+            assert_eq!(greeting.name, "José");
+        }
+
+        let mut req = create_request_with_body(
+            "application/x-www-form-urlencoded; charset=utf-8",
+            "name=Jos%C3%A9".into(),
+        );
+        assert_eq!(req.form_parse::<Greeting>().await.unwrap().name, "José");
+
+        let mut req = create_request_with_body(
+            "application/x-www-form-urlencoded; charset=shift_jis",
+            b"name=a".to_vec(),
+        );
+        let err = req.form_parse::<Greeting>().await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    /// 测试 form_parse 对 multipart/form-data 文本字段的解析与 urlencoded 一致
+    #[cfg(feature = "multipart")]
+    #[tokio::test]
+    async fn test_form_parse_multipart_fields() {
+        #[derive(Deserialize, Debug)]
+        struct Profile {
+            name: String,
+            age: u32,
+            tags: Vec<String>,
+        }
+
+        let body = "--B\r\n\
+Content-Disposition: form-data; name=\"name\"\r\n\r\n\
+Alice\r\n\
+--B\r\n\
+Content-Disposition: form-data; name=\"age\"\r\n\r\n\
+25\r\n\
+--B\r\n\
+Content-Disposition: form-data; name=\"tags\"\r\n\r\n\
+a\r\n\
+--B\r\n\
+Content-Disposition: form-data; name=\"tags\"\r\n\r\n\
+b&c=d\r\n\
+--B\r\n\
+Content-Disposition: form-data; name=\"avatar\"; filename=\"a.png\"\r\n\
+Content-Type: image/png\r\n\r\n\
+png\r\n\
+--B--\r\n";
+        let mut req = create_request_with_body("multipart/form-data; boundary=B", body.into());
+        let profile = req.form_parse::<Profile>().await.unwrap();
+        assert_eq!(profile.name, "Alice");
+        assert_eq!(profile.age, 25);
+        assert_eq!(profile.tags, vec!["a", "b&c=d"]);
+    }
+
     /// 辅助函数：创建带有指定内容类型和内容的请求
     fn create_request_with_body(content_type: &str, body: Vec<u8>) -> Request {
         let mut req = Request::empty();
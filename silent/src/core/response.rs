@@ -3,11 +3,10 @@ use std::fmt::{Display, Formatter};
 
 use crate::core::res_body::{ResBody, full};
 use crate::headers::{ContentType, Header, HeaderMap, HeaderMapExt};
-use crate::{Result, SilentError, State, StatusCode, header};
+use crate::{JsonConfig, Result, SilentError, State, StatusCode, header};
 use http::{Extensions, Version};
 use http_body::{Body, SizeHint};
 use serde::Serialize;
-use serde_json::Value;
 
 /// 响应体
 /// ```
@@ -97,10 +96,15 @@ impl Response {
     #[inline]
     /// 生成json响应
     pub fn json<T: Serialize>(json: &T) -> Self {
-        let mut res = Self::empty();
-        res.set_typed_header(ContentType::json());
-        res.set_body(full(serde_json::to_vec(json).unwrap()));
-        res
+        match JsonConfig::with_current(|config| config.to_vec(json)) {
+            Ok(body) => {
+                let mut res = Self::empty();
+                res.set_typed_header(ContentType::json());
+                res.set_body(full(body));
+                res
+            }
+            Err(e) => e.into(),
+        }
     }
 }
 
@@ -249,13 +253,23 @@ impl<B: Body> Response<B> {
 
 impl<S: Serialize> From<S> for Response {
     fn from(value: S) -> Self {
-        match serde_json::to_value(&value).unwrap() {
-            Value::String(value) => Response::empty()
-                .with_typed_header(ContentType::json())
-                .with_body(full(value.as_bytes().to_vec())),
-            Value::Null => Response::empty().with_status(StatusCode::NO_CONTENT),
-            _ => Self::json(&value),
+        let body = match JsonConfig::with_current(|config| config.to_vec(&value)) {
+            Ok(body) => body,
+            Err(e) => return e.into(),
+        };
+        if body == b"null" {
+            return Response::empty().with_status(StatusCode::NO_CONTENT);
         }
+        // 字符串直接作为响应体，不带 JSON 引号
+        let body = match body.first() {
+            Some(&b'"') => serde_json::from_slice::<String>(&body)
+                .map(String::into_bytes)
+                .unwrap_or(body),
+            _ => body,
+        };
+        Response::empty()
+            .with_typed_header(ContentType::json())
+            .with_body(full(body))
     }
 }
 
@@ -37,6 +37,17 @@ where
     T::deserialize(CowValue(input.into()))
 }
 
+#[inline]
+pub fn from_str_seq<'de, I, T, V>(input: I) -> Result<T, ValError>
+where
+    I: IntoIterator<Item = V>,
+    T: Deserialize<'de>,
+    V: Into<Cow<'de, str>>,
+{
+    let iter = input.into_iter().map(|v| CowValue(v.into()));
+    T::deserialize(serde::de::value::SeqDeserializer::new(iter))
+}
+
 macro_rules! forward_cow_parsed_value {
     ($($ty:ident => $method:ident,)*) => {
         $(
@@ -210,6 +221,14 @@ mod tests {
         assert_eq!(result, "hello");
     }
 
+    #[test]
+    fn test_from_str_seq() {
+        let result: Vec<String> = from_str_seq(["a", "b"]).unwrap();
+        assert_eq!(result, ["a", "b"]);
+        let result: (u32, String) = from_str_seq(["1", "x"]).unwrap();
+        assert_eq!(result, (1, "x".to_string()));
+    }
+
     #[test]
     fn test_from_str_val_integer() {
         let result: i32 = from_str_val("42").unwrap();
@@ -1,5 +1,5 @@
 use crate::headers::ContentType;
-use crate::{Response, StatusCode};
+use crate::{JsonConfig, Response, StatusCode};
 use serde::Serialize;
 use serde_json::Value;
 use std::backtrace::Backtrace;
@@ -99,7 +99,10 @@ impl SilentError {
     where
         S: Serialize,
     {
-        let msg = serde_json::to_string(&msg).unwrap_or_default();
+        let msg = JsonConfig::with_current(|config| config.to_vec(&msg))
+            .ok()
+            .and_then(|body| String::from_utf8(body).ok())
+            .unwrap_or_default();
         Self::BusinessError { code, msg }
     }
     pub fn business_error<T: Into<String>>(code: StatusCode, msg: T) -> Self {
@@ -0,0 +1,150 @@
+//! 认证相关萃取器
+
+use async_trait::async_trait;
+use headers::Authorization;
+use headers::authorization::{Basic, Bearer};
+use http::HeaderValue;
+use http::header::WWW_AUTHENTICATE;
+
+use super::FromRequestParts;
+use super::from_request::from_request_via_parts;
+use crate::headers::HeaderMapExt;
+use crate::{Request, Response, SilentError, StatusCode};
+
+/// 构造带 `WWW-Authenticate` 质询头的 `401` 响应
+fn unauthorized(challenge: &'static str, message: &str) -> Response {
+    let mut res: Response = SilentError::business_error(StatusCode::UNAUTHORIZED, message).into();
+    res.headers_mut()
+        .insert(WWW_AUTHENTICATE, HeaderValue::from_static(challenge));
+    res
+}
+
+/// BasicAuth 萃取器：解析 `Authorization: Basic ...` 中的用户名与密码
+///
+/// 请求头缺失或格式错误时返回带 `WWW-Authenticate: Basic` 的 `401`，浏览器会据此弹出登录框。
+///
+/// ```rust
+/// use silent::Result;
+/// use silent::extractor::BasicAuth;
+///
+/// async fn admin(auth: BasicAuth) -> Result<String> {
+///     if auth.username != "admin" || auth.password != "secret" {
+///         return Err(silent::SilentError::business_error(
+///             silent::StatusCode::FORBIDDEN,
+///             "forbidden",
+///         ));
+///     }
+///     Ok(format!("welcome, {}", auth.username))
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BasicAuth {
+    pub username: String,
+    pub password: String,
+}
+
+#[async_trait]
+impl FromRequestParts for BasicAuth {
+    type Rejection = Response;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        const CHALLENGE: &str = "Basic realm=\"Restricted\", charset=\"UTF-8\"";
+        let Authorization(basic) = req
+            .headers()
+            .typed_get::<Authorization<Basic>>()
+            .ok_or_else(|| unauthorized(CHALLENGE, "missing or invalid basic credentials"))?;
+        Ok(BasicAuth {
+            username: basic.username().to_string(),
+            password: basic.password().to_string(),
+        })
+    }
+}
+
+from_request_via_parts!([] BasicAuth);
+
+/// BearerToken 萃取器：获取 `Authorization: Bearer ...` 中的令牌
+///
+/// 仅负责提取令牌，不做校验；请求头缺失或格式错误时返回带 `WWW-Authenticate: Bearer` 的 `401`。
+///
+/// ```rust
+/// use silent::Result;
+/// use silent::extractor::BearerToken;
+///
+/// async fn me(BearerToken(token): BearerToken) -> Result<String> {
+///     Ok(format!("token length: {}", token.len()))
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BearerToken(pub String);
+
+#[async_trait]
+impl FromRequestParts for BearerToken {
+    type Rejection = Response;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let Authorization(bearer) = req
+            .headers()
+            .typed_get::<Authorization<Bearer>>()
+            .ok_or_else(|| unauthorized("Bearer", "missing or invalid bearer token"))?;
+        Ok(BearerToken(bearer.token().to_string()))
+    }
+}
+
+from_request_via_parts!([] BearerToken);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::FromRequest;
+    use http::header::AUTHORIZATION;
+
+    fn request(authorization: Option<&'static str>) -> Request {
+        let mut req = Request::empty();
+        if let Some(value) = authorization {
+            req.headers_mut()
+                .insert(AUTHORIZATION, HeaderValue::from_static(value));
+        }
+        req
+    }
+
+    // ==================== BasicAuth 测试 ====================
+
+    #[tokio::test]
+    async fn test_basic_auth_extract() {
+        // "aladdin:open sesame"
+        let mut req = request(Some("Basic YWxhZGRpbjpvcGVuIHNlc2FtZQ=="));
+        let auth = BasicAuth::from_request(&mut req).await.unwrap();
+        assert_eq!(auth.username, "aladdin");
+        assert_eq!(auth.password, "open sesame");
+    }
+
+    #[tokio::test]
+    async fn test_basic_auth_rejection() {
+        for authorization in [None, Some("Bearer token"), Some("Basic !!!")] {
+            let mut req = request(authorization);
+            let res = BasicAuth::from_request(&mut req).await.unwrap_err();
+            assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+            let challenge = res.headers().get(WWW_AUTHENTICATE).unwrap();
+            assert!(challenge.to_str().unwrap().starts_with("Basic "));
+        }
+    }
+
+    // ==================== BearerToken 测试 ====================
+
+    #[tokio::test]
+    async fn test_bearer_token_extract() {
+        let mut req = request(Some("Bearer abc.def.ghi"));
+        let BearerToken(token) = BearerToken::from_request(&mut req).await.unwrap();
+        assert_eq!(token, "abc.def.ghi");
+    }
+
+    #[tokio::test]
+    async fn test_bearer_token_rejection() {
+        for authorization in [None, Some("Basic YWxhZGRpbjpvcGVuIHNlc2FtZQ==")] {
+            let mut req = request(authorization);
+            let res = BearerToken::from_request(&mut req).await.unwrap_err();
+            assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(res.headers().get(WWW_AUTHENTICATE).unwrap(), "Bearer");
+        }
+    }
+}
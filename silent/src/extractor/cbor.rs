@@ -0,0 +1,162 @@
+//! CBOR（RFC 8949）萃取器与响应
+
+use async_trait::async_trait;
+use http::HeaderValue;
+use http::header::CONTENT_TYPE;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::FromRequest;
+use super::from_request::read_body_limited;
+use crate::core::res_body::full;
+use crate::{Request, Response, SilentError, StatusCode};
+
+/// Cbor 萃取器/响应：解析 `application/cbor` 请求体，或将 T 序列化为 CBOR 响应
+///
+/// 请求体大小受 `BodyLimit` 限制，适合资源受限的 IoT 设备直接提交二进制载荷。
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use silent::Result;
+/// use silent::extractor::Cbor;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Reading {
+///     sensor: u32,
+///     value: f32,
+/// }
+///
+/// async fn handler(Cbor(reading): Cbor<Reading>) -> Result<Cbor<Reading>> {
+///     Ok(Cbor(reading))
+/// }
+/// ```
+pub struct Cbor<T>(pub T);
+
+#[async_trait]
+impl<T> FromRequest for Cbor<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .content_type()
+            .ok_or(SilentError::ContentTypeMissingError)?;
+        let is_cbor = content_type.type_() == mime::APPLICATION
+            && (content_type.subtype() == "cbor"
+                || content_type.suffix().is_some_and(|s| s == "cbor"));
+        if !is_cbor {
+            return Err(SilentError::ContentTypeError);
+        }
+
+        let bytes = read_body_limited(req).await?;
+        if bytes.is_empty() {
+            return Err(SilentError::BodyEmpty);
+        }
+        let value = ciborium::from_reader(&bytes[..]).map_err(|e| {
+            SilentError::business_error(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("cbor deserialize error: {e}"),
+            )
+        })?;
+        Ok(Cbor(value))
+    }
+}
+
+impl<T: Serialize> From<Cbor<T>> for Response {
+    fn from(Cbor(value): Cbor<T>) -> Self {
+        let mut body = Vec::new();
+        match ciborium::into_writer(&value, &mut body) {
+            Ok(()) => Response::empty()
+                .with_header(CONTENT_TYPE, HeaderValue::from_static("application/cbor"))
+                .with_body(full(body)),
+            Err(e) => SilentError::business_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("cbor serialize error: {e}"),
+            )
+            .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::req_body::ReqBody;
+    use crate::core::res_body::ResBody;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Reading {
+        sensor: u32,
+        value: f32,
+    }
+
+    fn reading() -> Reading {
+        Reading {
+            sensor: 3,
+            value: 21.5,
+        }
+    }
+
+    fn encode<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut buf = Vec::new();
+        ciborium::into_writer(value, &mut buf).unwrap();
+        buf
+    }
+
+    fn cbor_request(content_type: &'static str, body: Vec<u8>) -> Request {
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        req.replace_body(ReqBody::Once(body.into()));
+        req
+    }
+
+    // ==================== 萃取测试 ====================
+
+    #[tokio::test]
+    async fn test_cbor_extract() {
+        for content_type in ["application/cbor", "application/senml+cbor"] {
+            let mut req = cbor_request(content_type, encode(&reading()));
+            let Cbor(value): Cbor<Reading> = Cbor::from_request(&mut req).await.unwrap();
+            assert_eq!(value, reading());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cbor_extract_rejections() {
+        let mut req = cbor_request("application/json", b"{}".to_vec());
+        assert!(matches!(
+            Cbor::<Reading>::from_request(&mut req).await,
+            Err(SilentError::ContentTypeError)
+        ));
+
+        let mut req = cbor_request("application/cbor", Vec::new());
+        assert!(matches!(
+            Cbor::<Reading>::from_request(&mut req).await,
+            Err(SilentError::BodyEmpty)
+        ));
+
+        // 类型不匹配
+        let mut req = cbor_request("application/cbor", encode(&"text"));
+        let err = Cbor::<Reading>::from_request(&mut req).await.err().unwrap();
+        assert_eq!(err.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    // ==================== 响应测试 ====================
+
+    #[test]
+    fn test_cbor_response() {
+        let res: Response = Cbor(reading()).into();
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/cbor");
+        match res.body {
+            ResBody::Once(bytes) => {
+                let decoded: Reading = ciborium::from_reader(&bytes[..]).unwrap();
+                assert_eq!(decoded, reading());
+            }
+            _ => panic!("unexpected body"),
+        }
+    }
+}
@@ -0,0 +1,316 @@
+//! ClientIp 萃取器：在可信代理之后解析真实客户端地址
+
+use std::io;
+use std::net::{IpAddr, SocketAddr as StdSocketAddr};
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use http::header::FORWARDED;
+
+use super::FromRequestParts;
+use super::from_request::from_request_via_parts;
+use crate::core::remote_addr::RemoteAddr;
+use crate::{Request, SilentError, StatusCode};
+
+/// ClientIp 萃取器：获取真实的客户端 IP
+///
+/// 仅当连接对端（[`Request::peer_addr`]）位于 [`TrustedProxies`] 中时才会读取转发头，
+/// 依次尝试 `X-Forwarded-For`、`Forwarded` 的 `for=` 与 `X-Real-IP`。转发链从右向左遍历，
+/// 跳过所有可信代理，取第一个不可信的地址作为客户端，避免客户端通过伪造头部冒充他人。
+/// 未配置可信代理或对端不可信时，直接返回对端 IP。
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::extractor::{ClientIp, TrustedProxies};
+///
+/// let proxies = TrustedProxies::new(["10.0.0.0/8", "::1"]).unwrap();
+/// let route = Route::new("")
+///     .with_state(proxies)
+///     .get(|ClientIp(ip): ClientIp| async move { Ok(ip.to_string()) });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+/// 可信代理列表，通过 `Route::with_state` 或 `Server::with_state` 注入
+#[derive(Clone, Debug, Default)]
+pub struct TrustedProxies {
+    cidrs: Vec<IpCidr>,
+    unix_socket: bool,
+}
+
+impl TrustedProxies {
+    /// 从 CIDR 列表创建，单个 IP 视为 `/32`（IPv6 为 `/128`）
+    pub fn new<I, S>(cidrs: I) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let cidrs = cidrs
+            .into_iter()
+            .map(|cidr| cidr.as_ref().parse())
+            .collect::<io::Result<_>>()?;
+        Ok(Self {
+            cidrs,
+            unix_socket: false,
+        })
+    }
+
+    /// 信任通过 Unix Socket 连接的对端（通常为同机部署的反向代理）
+    pub fn with_unix_socket(mut self) -> Self {
+        self.unix_socket = true;
+        self
+    }
+
+    /// 判断 IP 是否属于可信代理
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        self.cidrs.iter().any(|cidr| cidr.contains(ip))
+    }
+
+    /// 判断连接对端是否为可信代理，Unix Socket 对端取决于 [`with_unix_socket`](Self::with_unix_socket)
+    pub(crate) fn trusts(&self, peer: &RemoteAddr) -> bool {
+        match peer.ip() {
+            Some(ip) => self.contains(ip),
+            None => self.unix_socket,
+        }
+    }
+}
+
+/// IP 网段
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct IpCidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl IpCidr {
+    pub(crate) fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpCidr {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidInput, format!("invalid cidr: {s}"));
+        let (addr, prefix) = match s.trim().split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (s.trim(), None),
+        };
+        let addr = addr
+            .parse::<IpAddr>()
+            .map_err(|_| invalid())?
+            .to_canonical();
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix.parse::<u8>().map_err(|_| invalid())?,
+            None => max,
+        };
+        if prefix > max {
+            return Err(invalid());
+        }
+        Ok(Self { addr, prefix })
+    }
+}
+
+/// 解析转发头中的节点，支持 `ip`、`ip:port`、`[ipv6]:port` 及带引号的形式
+fn parse_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim().trim_matches('"');
+    if let Ok(ip) = node.parse::<IpAddr>() {
+        return Some(ip);
+    }
+    if let Ok(addr) = node.parse::<StdSocketAddr>() {
+        return Some(addr.ip());
+    }
+    node.strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .and_then(|(ip, _)| ip.parse().ok())
+}
+
+/// 按出现顺序收集转发链，无法解析的节点记为 `None`
+fn forwarded_chain(req: &Request) -> Option<Vec<Option<IpAddr>>> {
+    let headers = req.headers();
+    let xff: Vec<_> = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter(|v| !v.trim().is_empty())
+        .map(parse_node)
+        .collect();
+    if !xff.is_empty() {
+        return Some(xff);
+    }
+
+    let forwarded: Vec<_> = headers
+        .get_all(FORWARDED)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter_map(|element| {
+            element.split(';').find_map(|pair| {
+                let (key, value) = pair.split_once('=')?;
+                key.trim()
+                    .eq_ignore_ascii_case("for")
+                    .then(|| parse_node(value))
+            })
+        })
+        .collect();
+    if !forwarded.is_empty() {
+        return Some(forwarded);
+    }
+
+    let real_ip = headers.get("x-real-ip")?.to_str().ok()?;
+    Some(vec![parse_node(real_ip)])
+}
+
+#[async_trait]
+impl FromRequestParts for ClientIp {
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let unavailable = || {
+            SilentError::business_error(StatusCode::INTERNAL_SERVER_ERROR, "client ip unavailable")
+        };
+        let proxies = req.get_state::<TrustedProxies>().ok();
+        let peer = req.peer_addr().ok_or_else(unavailable)?;
+
+        // 仅 Unix Socket 对端没有 IP
+        let mut client = peer.ip();
+        let peer_trusted = proxies.is_some_and(|p| p.trusts(peer));
+        if !peer_trusted {
+            return client.map(ClientIp).ok_or_else(unavailable);
+        }
+
+        // 从最靠近服务器的一跳开始，逐个跳过可信代理
+        let proxies = proxies.expect("trusted peer implies configured proxies");
+        for hop in forwarded_chain(req).unwrap_or_default().into_iter().rev() {
+            if client.is_some_and(|ip| !proxies.contains(ip)) {
+                break;
+            }
+            match hop {
+                Some(ip) => client = Some(ip),
+                None => break,
+            }
+        }
+        client.map(ClientIp).ok_or_else(unavailable)
+    }
+}
+
+from_request_via_parts!([] ClientIp);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::FromRequest;
+    use http::HeaderValue;
+    use std::net::Ipv4Addr;
+
+    fn request(peer: &str, headers: &[(&'static str, &'static str)]) -> Request {
+        let mut req = Request::empty();
+        for (name, value) in headers {
+            req.headers_mut()
+                .append(*name, HeaderValue::from_static(value));
+        }
+        req.set_remote(peer.parse().unwrap());
+        req
+    }
+
+    async fn client_ip(mut req: Request, proxies: Option<&[&str]>) -> IpAddr {
+        if let Some(proxies) = proxies {
+            req.state_mut()
+                .insert(TrustedProxies::new(proxies.iter()).unwrap());
+        }
+        ClientIp::from_request(&mut req).await.unwrap().0
+    }
+
+    // ==================== CIDR 测试 ====================
+
+    #[test]
+    fn test_trusted_proxies_contains() {
+        let proxies = TrustedProxies::new(["10.0.0.0/8", "192.168.1.1", "fd00::/8"]).unwrap();
+        assert!(proxies.contains("10.1.2.3".parse().unwrap()));
+        assert!(proxies.contains("192.168.1.1".parse().unwrap()));
+        assert!(!proxies.contains("192.168.1.2".parse().unwrap()));
+        assert!(proxies.contains("fd12::1".parse().unwrap()));
+        // IPv4 映射的 IPv6 地址按 IPv4 匹配
+        assert!(proxies.contains("::ffff:10.0.0.1".parse().unwrap()));
+        assert!(
+            TrustedProxies::new(["0.0.0.0/0"])
+                .unwrap()
+                .contains(Ipv4Addr::BROADCAST.into())
+        );
+
+        assert!(TrustedProxies::new(["10.0.0.0/33"]).is_err());
+        assert!(TrustedProxies::new(["not-an-ip"]).is_err());
+    }
+
+    // ==================== 解析测试 ====================
+
+    #[tokio::test]
+    async fn test_client_ip_ignores_headers_from_untrusted_peer() {
+        let headers = [("x-forwarded-for", "1.1.1.1")];
+        let req = request("203.0.113.9:5000", &headers);
+        assert_eq!(client_ip(req, None).await.to_string(), "203.0.113.9");
+
+        let req = request("203.0.113.9:5000", &headers);
+        let ip = client_ip(req, Some(&["10.0.0.0/8"])).await;
+        assert_eq!(ip.to_string(), "203.0.113.9");
+    }
+
+    #[tokio::test]
+    async fn test_client_ip_skips_trusted_hops() {
+        // 客户端伪造了最左侧的地址，真实地址为第一个不可信的节点
+        let req = request(
+            "10.0.0.1:5000",
+            &[("x-forwarded-for", "6.6.6.6, 198.51.100.7, 10.0.0.2")],
+        );
+        let ip = client_ip(req, Some(&["10.0.0.0/8"])).await;
+        assert_eq!(ip.to_string(), "198.51.100.7");
+    }
+
+    #[tokio::test]
+    async fn test_client_ip_forwarded_and_real_ip() {
+        let req = request(
+            "10.0.0.1:5000",
+            &[(
+                "forwarded",
+                "for=\"[2001:db8::1]:4711\";proto=https, for=10.0.0.2",
+            )],
+        );
+        let ip = client_ip(req, Some(&["10.0.0.0/8"])).await;
+        assert_eq!(ip.to_string(), "2001:db8::1");
+
+        let req = request("10.0.0.1:5000", &[("x-real-ip", "198.51.100.7")]);
+        let ip = client_ip(req, Some(&["10.0.0.0/8"])).await;
+        assert_eq!(ip.to_string(), "198.51.100.7");
+    }
+
+    #[tokio::test]
+    async fn test_client_ip_stops_at_invalid_hop() {
+        let req = request(
+            "10.0.0.1:5000",
+            &[("x-forwarded-for", "198.51.100.7, unknown, 10.0.0.2")],
+        );
+        let ip = client_ip(req, Some(&["10.0.0.0/8"])).await;
+        assert_eq!(ip.to_string(), "10.0.0.2");
+    }
+
+    #[tokio::test]
+    async fn test_client_ip_without_peer() {
+        let mut req = Request::empty();
+        let err = ClientIp::from_request(&mut req).await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,100 @@
+//! Cookies 萃取器：读取请求 Cookie，并将修改自动写回响应
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use cookie::{Cookie, CookieJar};
+
+use super::FromRequestParts;
+use super::from_request::from_request_via_parts;
+use crate::{Request, SilentError};
+
+/// Cookies 萃取器：请求 Cookie 的读写句柄
+///
+/// 通过 [`Cookies::add`]、[`Cookies::remove`] 所做的修改由内置的 Cookie 中间件在响应阶段
+/// 自动转换为 `Set-Cookie` 头，无需手动操作响应。句柄可被克隆，所有克隆共享同一份 Cookie。
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::extractor::Cookies;
+///
+/// async fn visit(cookies: Cookies) -> Result<String> {
+///     let count = cookies
+///         .get("visits")
+///         .and_then(|c| c.value().parse::<u32>().ok())
+///         .unwrap_or(0)
+///         + 1;
+///     cookies.add(Cookie::new("visits", count.to_string()));
+///     Ok(format!("visits: {count}"))
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Cookies {
+    jar: Arc<Mutex<CookieJar>>,
+}
+
+impl Cookies {
+    pub(crate) fn new(jar: CookieJar) -> Self {
+        Self {
+            jar: Arc::new(Mutex::new(jar)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CookieJar> {
+        self.jar.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 获取指定名称的 Cookie
+    pub fn get(&self, name: &str) -> Option<Cookie<'static>> {
+        self.lock().get(name).cloned()
+    }
+
+    /// 添加或覆盖 Cookie，响应时写入 `Set-Cookie`
+    pub fn add<C: Into<Cookie<'static>>>(&self, cookie: C) {
+        self.lock().add(cookie);
+    }
+
+    /// 删除 Cookie，响应时写入使其过期的 `Set-Cookie`
+    ///
+    /// 删除时需要提供与设置时相同的 `path`/`domain`，浏览器才会移除对应的 Cookie。
+    pub fn remove<C: Into<Cookie<'static>>>(&self, cookie: C) {
+        self.lock().remove(cookie);
+    }
+
+    /// 当前所有 Cookie（包含本次请求中的修改）
+    pub fn all(&self) -> Vec<Cookie<'static>> {
+        self.lock().iter().cloned().collect()
+    }
+
+    /// 当前 Cookie 的快照
+    pub fn jar(&self) -> CookieJar {
+        self.lock().clone()
+    }
+
+    /// 本次请求中新增或删除的 Cookie
+    pub(crate) fn delta(&self) -> Vec<Cookie<'static>> {
+        self.lock().delta().cloned().collect()
+    }
+}
+
+#[async_trait]
+impl FromRequestParts for Cookies {
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        if let Some(cookies) = req.extensions().get::<Cookies>() {
+            return Ok(cookies.clone());
+        }
+        // 未经过 Cookie 中间件（如直接调用处理函数）时，修改不会写回响应
+        let jar = req
+            .extensions()
+            .get::<CookieJar>()
+            .cloned()
+            .unwrap_or_default();
+        let cookies = Cookies::new(jar);
+        req.extensions_mut().insert(cookies.clone());
+        Ok(cookies)
+    }
+}
+
+from_request_via_parts!([] Cookies);
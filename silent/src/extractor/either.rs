@@ -0,0 +1,255 @@
+//! 多选一萃取器，同时可作为多选一的响应类型
+
+use async_trait::async_trait;
+
+use super::from_request::read_body_limited;
+use super::{FromRequest, FromRequestParts};
+use crate::core::req_body::ReqBody;
+use crate::{Request, Response};
+
+/// 每次尝试前恢复已缓冲的请求体，使后续分支仍能读取完整数据
+fn restore_body(req: &mut Request, body: &bytes::Bytes) {
+    let body = if body.is_empty() {
+        ReqBody::Empty
+    } else {
+        ReqBody::Once(body.clone())
+    };
+    req.replace_body(body);
+}
+
+macro_rules! impl_either {
+    ($(#[$meta:meta])* $name:ident { $($var:ident),+; $last:ident }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub enum $name<$($var,)+ $last> {
+            $($var($var),)+
+            $last($last),
+        }
+
+        #[async_trait]
+        impl<$($var,)+ $last> FromRequest for $name<$($var,)+ $last>
+        where
+            $($var: FromRequest + Send + 'static,)+
+            $last: FromRequest + Send + 'static,
+        {
+            type Rejection = Response;
+
+            async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+                // 先缓冲请求体（受 BodyLimit 限制），失败的分支不会影响后续分支
+                let body = read_body_limited(req).await.map_err(Response::from)?;
+                $(
+                    restore_body(req, &body);
+                    if let Ok(value) = <$var as FromRequest>::from_request(req).await {
+                        return Ok(Self::$var(value));
+                    }
+                )+
+                restore_body(req, &body);
+                <$last as FromRequest>::from_request(req)
+                    .await
+                    .map(Self::$last)
+                    .map_err(Into::into)
+            }
+        }
+
+        #[async_trait]
+        impl<$($var,)+ $last> FromRequestParts for $name<$($var,)+ $last>
+        where
+            $($var: FromRequestParts + Send + 'static,)+
+            $last: FromRequestParts + Send + 'static,
+        {
+            type Rejection = Response;
+
+            async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+                $(
+                    if let Ok(value) = <$var as FromRequestParts>::from_request_parts(req).await {
+                        return Ok(Self::$var(value));
+                    }
+                )+
+                <$last as FromRequestParts>::from_request_parts(req)
+                    .await
+                    .map(Self::$last)
+                    .map_err(Into::into)
+            }
+        }
+
+        impl<$($var,)+ $last> From<$name<$($var,)+ $last>> for Response
+        where
+            $($var: Into<Response>,)+
+            $last: Into<Response>,
+        {
+            fn from(value: $name<$($var,)+ $last>) -> Self {
+                match value {
+                    $($name::$var(value) => value.into(),)+
+                    $name::$last(value) => value.into(),
+                }
+            }
+        }
+    };
+}
+
+impl_either!(
+    /// Either 萃取器：依次尝试 `E1`、`E2`，返回第一个成功的结果
+    ///
+    /// 读取请求体的分支会先将请求体缓冲（受 [`BodyLimit`](super::BodyLimit) 限制），
+    /// 因此前一个分支失败不会影响后一个分支；全部失败时返回最后一个分支的拒绝响应。
+    /// 当所有分支都实现 [`FromRequestParts`] 时，`Either` 也可放在元组的非末尾位置。
+    ///
+    /// 各分支都实现 `Into<Response>` 时，`Either` 也可作为处理函数的返回值，按实际分支生成响应：
+    ///
+    /// ```rust
+    /// use silent::extractor::{Either, Json, Query};
+    /// use silent::response::Html;
+    /// use silent::Result;
+    ///
+    /// async fn page(
+    ///     Query(q): Query<std::collections::HashMap<String, String>>,
+    /// ) -> Result<Either<Html<&'static str>, Json<serde_json::Value>>> {
+    ///     if q.contains_key("json") {
+    ///         Ok(Either::E2(Json(serde_json::json!({ "page": "home" }))))
+    ///     } else {
+    ///         Ok(Either::E1(Html("<h1>home</h1>")))
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```rust
+    /// use serde::{Deserialize, Serialize};
+    /// use silent::Result;
+    /// use silent::extractor::{Either, Form, Json};
+    ///
+    /// #[derive(Deserialize, Serialize)]
+    /// struct Signup {
+    ///     email: String,
+    /// }
+    ///
+    /// async fn signup(input: Either<Json<Signup>, Form<Signup>>) -> Result<String> {
+    ///     let signup = match input {
+    ///         Either::E1(Json(s)) => s,
+    ///         Either::E2(Form(s)) => s,
+    ///     };
+    ///     Ok(signup.email)
+    /// }
+    /// ```
+    Either { E1; E2 }
+);
+
+impl_either!(
+    /// 三选一萃取器，语义同 [`Either`]
+    Either3 { E1, E2; E3 }
+);
+
+impl_either!(
+    /// 四选一萃取器，语义同 [`Either`]
+    Either4 { E1, E2, E3; E4 }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::{BasicAuth, BearerToken, BodyLimit, Form, Json, Query};
+    use crate::{SilentError, StatusCode};
+    use http::HeaderValue;
+    use http::header::{AUTHORIZATION, CONTENT_TYPE};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Item {
+        name: String,
+    }
+
+    fn request(content_type: &'static str, body: &'static str) -> Request {
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        req.replace_body(ReqBody::Once(body.into()));
+        req
+    }
+
+    #[tokio::test]
+    async fn test_either_json_or_form() {
+        let mut req = request("application/json", r#"{"name":"a"}"#);
+        let value = Either::<Json<Item>, Form<Item>>::from_request(&mut req)
+            .await
+            .unwrap();
+        assert!(matches!(value, Either::E1(Json(item)) if item.name == "a"));
+
+        let mut req = request("application/x-www-form-urlencoded", "name=b");
+        let value = Either::<Json<Item>, Form<Item>>::from_request(&mut req)
+            .await
+            .unwrap();
+        assert!(matches!(value, Either::E2(Form(item)) if item.name == "b"));
+
+        // 全部失败时返回最后一个分支的拒绝响应
+        let mut req = request("text/plain", "name=c");
+        let Err(res) = Either::<Json<Item>, Form<Item>>::from_request(&mut req).await else {
+            panic!("expected rejection");
+        };
+        assert_eq!(
+            res.status(),
+            Response::from(SilentError::ContentTypeError).status()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_either_retries_with_buffered_body() {
+        // 第一个分支读取请求体后失败，第二个分支仍能读到完整数据
+        #[derive(Debug, Deserialize, Serialize)]
+        struct Other {
+            #[allow(dead_code)]
+            id: u64,
+        }
+
+        let mut req = request("application/json", r#"{"name":"a"}"#);
+        let value = Either3::<Json<Other>, Form<Item>, Json<Item>>::from_request(&mut req)
+            .await
+            .unwrap();
+        assert!(matches!(value, Either3::E3(Json(item)) if item.name == "a"));
+
+        let mut req = request("application/json", r#"{"name":"a"}"#);
+        req.state_mut().insert(BodyLimit(4));
+        let Err(res) = Either::<Json<Item>, Form<Item>>::from_request(&mut req).await else {
+            panic!("expected rejection");
+        };
+        assert_eq!(res.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_either_parts() {
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert(AUTHORIZATION, HeaderValue::from_static("Bearer token"));
+        let value = Either::<BasicAuth, BearerToken>::from_request_parts(&mut req)
+            .await
+            .unwrap();
+        assert_eq!(value, Either::E2(BearerToken("token".into())));
+
+        // 所有分支均不读取请求体时，可放在元组的非末尾位置
+        let mut req = request("application/json", r#"{"name":"body"}"#);
+        *req.uri_mut() = "/?name=query".parse().unwrap();
+        let (auth, Json(item)) =
+            <(Either3<BasicAuth, BearerToken, Query<Item>>, Json<Item>)>::from_request(&mut req)
+                .await
+                .unwrap_or_else(|_| panic!("extract failed"));
+        assert!(matches!(auth, Either3::E3(Query(q)) if q.name == "query"));
+        assert_eq!(item.name, "body");
+    }
+
+    #[test]
+    fn test_either_into_response() {
+        use crate::response::{Html, NoContent};
+
+        let res: Response = Either::<Html<&'static str>, Json<Item>>::E1(Html("<p>hi</p>")).into();
+        assert_eq!(
+            res.headers().get(CONTENT_TYPE).unwrap(),
+            "text/html; charset=utf-8"
+        );
+
+        let res: Response =
+            Either::<Html<&'static str>, Json<Item>>::E2(Json(Item { name: "a".into() })).into();
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+
+        let res: Response =
+            Either3::<Html<&'static str>, Json<Item>, NoContent>::E3(NoContent).into();
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+}
@@ -1,12 +1,13 @@
 use async_trait::async_trait;
 
-use crate::core::path_param::PathParam as CorePathParam;
 use crate::{Request, Response, SilentError, headers::HeaderMapExt};
 
 #[allow(deprecated)]
 use super::types::Configs;
 use super::types::{
-    Extension, Form, Json, Method, Path, Query, RemoteAddr, State, TypedHeader, Uri, Version,
+    Body, BodyLimit, Extension, Form, Json, MatchedPath, Method, OptionalExtension, OptionalState,
+    OriginalUri, Path, Query, QueryMap, QueryOrDefault, RawQuery, RemoteAddr, State, StateArc,
+    Text, TypedHeader, Uri, Version,
 };
 
 /// `FromRequest` 是萃取器的核心 trait，用于从 HTTP 请求中提取特定类型的数据。
@@ -126,15 +127,100 @@ pub trait FromRequest: Sized {
     async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection>;
 }
 
+/// `FromRequestParts` 用于不读取请求体的萃取器，仅访问请求行、请求头、路径参数、扩展与状态。
+///
+/// 请求体只能被读取一次，因此元组与 `#[derive(FromRequest)]` 结构体中，除最后一个元素外
+/// 都必须实现 `FromRequestParts`，读取请求体的萃取器（`Json`、`Form`、`Body` 等）只能放在最后。
+/// 顺序错误会在编译期报错，而不是在运行时因请求体已被读取而失败：
+///
+/// ```rust,compile_fail
+/// use silent::extractor::{FromRequest, Json, Path};
+///
+/// // Json<T> 读取请求体，只能作为最后一个元素
+/// fn assert_extractor<T: FromRequest>() {}
+/// assert_extractor::<(Json<String>, Path<u64>)>();
+/// ```
+///
+/// 最多 12 个元素的元组同样如此，读取请求体的萃取器不在最后时无法编译：
+///
+/// ```rust,compile_fail
+/// use silent::extractor::{FromRequest, Json, Path, Query};
+///
+/// fn assert_extractor<T: FromRequest>() {}
+/// type P = Path<u64>;
+/// type Q = Query<String>;
+/// assert_extractor::<(P, Q, P, Q, P, Json<String>, Q, P, Q, P, Q, P)>();
+/// ```
+///
+/// 内置的 `Path`、`Query`、`TypedHeader`、`Extension`、`State` 等都实现了该 trait，同时也
+/// 实现了 `FromRequest`，可直接作为处理函数参数。自定义萃取器在实现 `FromRequestParts` 后，
+/// 同样需要实现一个委托给它的 `FromRequest`：
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use silent::extractor::{FromRequest, FromRequestParts};
+/// use silent::{Request, SilentError};
+///
+/// struct TenantId(String);
+///
+/// #[async_trait]
+/// impl FromRequestParts for TenantId {
+///     type Rejection = SilentError;
+///
+///     async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+///         req.headers()
+///             .get("x-tenant-id")
+///             .and_then(|v| v.to_str().ok())
+///             .map(|s| TenantId(s.to_string()))
+///             .ok_or(SilentError::ParamsNotFound)
+///     }
+/// }
+///
+/// #[async_trait]
+/// impl FromRequest for TenantId {
+///     type Rejection = SilentError;
+///
+///     async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+///         Self::from_request_parts(req).await
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait FromRequestParts: Sized {
+    /// 萃取失败时的错误类型
+    type Rejection: Into<crate::Response> + Send + 'static;
+
+    /// 从请求中提取数据，不得读取请求体
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection>;
+}
+
+/// 为已实现 `FromRequestParts` 的类型实现委托给它的 `FromRequest`
+macro_rules! from_request_via_parts {
+    ($(#[$attr:meta])* [$($gen:ident),*] $ty:ty $(where $($bound:tt)+)?) => {
+        $(#[$attr])*
+        #[async_trait::async_trait]
+        impl<$($gen),*> $crate::extractor::FromRequest for $ty $(where $($bound)+)? {
+            type Rejection = <Self as $crate::extractor::FromRequestParts>::Rejection;
+
+            async fn from_request(req: &mut $crate::Request) -> Result<Self, Self::Rejection> {
+                <Self as $crate::extractor::FromRequestParts>::from_request_parts(req).await
+            }
+        }
+    };
+}
+
+pub(crate) use from_request_via_parts;
+
 #[async_trait]
-impl<T> FromRequest for Path<T>
+impl<T> FromRequestParts for Path<T>
 where
     for<'de> T: serde::Deserialize<'de> + Send + 'static,
 {
     type Rejection = SilentError;
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
-        use crate::core::serde::{from_str_map, from_str_val};
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        use crate::core::path_param::PathParam;
+        use crate::core::serde::{from_str_map, from_str_seq, from_str_val};
         let params = req.path_params();
         if params.is_empty() {
             return Err(SilentError::ParamsEmpty);
@@ -142,32 +228,121 @@ where
 
         if params.len() == 1 {
             let value = params.values().next().unwrap();
-            let single = path_param_to_string(value);
-            let parsed: T = from_str_val(single.as_str())?;
-            return Ok(Path(parsed));
+            let single = value.to_string();
+            match from_str_val(single.as_str()) {
+                Ok(parsed) => return Ok(Path(parsed)),
+                Err(err) => {
+                    // 通配路径参数可按 `/` 拆分为序列，如 `Vec<String>`
+                    if let PathParam::Path(path) = value
+                        && let Ok(parsed) =
+                            from_str_seq(path.as_str().split('/').filter(|s| !s.is_empty()))
+                    {
+                        return Ok(Path(parsed));
+                    }
+                    // 单个参数也可解析到同名字段的结构体，如 `path!` 生成的路由键
+                    let (key, _) = params.iter().next().unwrap();
+                    return from_str_map([(key.as_str(), single)])
+                        .map(Path)
+                        .map_err(|_| err.into());
+                }
+            }
         }
 
-        let map_iter = params
-            .iter()
-            .map(|(k, v)| (k.as_str(), path_param_to_string(v)));
+        let map_iter = params.iter().map(|(k, v)| (k.as_str(), v.to_string()));
         let parsed: T = from_str_map(map_iter)?;
         Ok(Path(parsed))
     }
 }
 
+from_request_via_parts!([T] Path<T> where for<'de> T: serde::Deserialize<'de> + Send + 'static);
+
 #[async_trait]
-impl<T> FromRequest for Query<T>
+impl<T> FromRequestParts for Query<T>
 where
     for<'de> T: serde::Deserialize<'de> + Send + 'static,
 {
     type Rejection = SilentError;
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
         let value = req.params_parse::<T>()?;
         Ok(Query(value))
     }
 }
 
+from_request_via_parts!([T] Query<T> where for<'de> T: serde::Deserialize<'de> + Send + 'static);
+
+#[async_trait]
+impl<T> FromRequestParts for QueryOrDefault<T>
+where
+    T: Default + serde::de::DeserializeOwned + Send + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let query = req.uri().query().unwrap_or("");
+        if query.is_empty() {
+            return Ok(QueryOrDefault(T::default()));
+        }
+        // 缺失的字段由 T 上的 #[serde(default)] 补全
+        let value = serde_html_form::from_str(query).unwrap_or_else(|e| {
+            tracing::debug!("QueryOrDefault: invalid query value, skipping invalid fields: {e}");
+            from_valid_fields(query).unwrap_or_default()
+        });
+        Ok(QueryOrDefault(value))
+    }
+}
+
+/// 逐个参数名尝试解析，丢弃无法解析的参数后重新解析，被丢弃的字段取默认值
+fn from_valid_fields<T: serde::de::DeserializeOwned>(query: &str) -> Option<T> {
+    let pairs: Vec<(&str, &str)> = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| (pair.split('=').next().unwrap_or(pair), pair))
+        .collect();
+    // 同名参数（如序列字段）作为一组解析
+    let group = |key: &str| {
+        pairs
+            .iter()
+            .filter(|(k, _)| *k == key)
+            .map(|(_, pair)| *pair)
+            .collect::<Vec<_>>()
+            .join("&")
+    };
+    let valid: Vec<&str> = pairs
+        .iter()
+        .filter(|(key, _)| serde_html_form::from_str::<T>(&group(key)).is_ok())
+        .map(|(_, pair)| *pair)
+        .collect();
+    serde_html_form::from_str(&valid.join("&")).ok()
+}
+
+from_request_via_parts!(
+    [T] QueryOrDefault<T>
+    where T: Default + serde::de::DeserializeOwned + Send + 'static
+);
+
+#[async_trait]
+impl FromRequestParts for RawQuery {
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(RawQuery(req.uri().query().map(str::to_string)))
+    }
+}
+
+from_request_via_parts!([] RawQuery);
+
+#[async_trait]
+impl FromRequestParts for QueryMap {
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(QueryMap::parse(req.uri().query().unwrap_or("")))
+    }
+}
+
+from_request_via_parts!([] QueryMap);
+
 #[async_trait]
 impl<T> FromRequest for Json<T>
 where
@@ -194,41 +369,69 @@ where
     }
 }
 
+#[cfg(feature = "multipart")]
 #[async_trait]
-impl<T> FromRequest for State<T>
+impl FromRequest for super::types::Multipart {
+    type Rejection = SilentError;
+
+    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .ok_or(SilentError::ContentTypeMissingError)?;
+        let boundary =
+            multer::parse_boundary(content_type).map_err(|_| SilentError::ContentTypeError)?;
+        // 请求体只能被读取一次，已被 form_data 等消费时视为空
+        let body = req.take_body();
+        if matches!(body, crate::core::req_body::ReqBody::Empty) {
+            return Err(SilentError::BodyEmpty);
+        }
+        Ok(super::types::Multipart::new(multer::Multipart::new(
+            body, boundary,
+        )))
+    }
+}
+
+#[async_trait]
+impl<T> FromRequestParts for State<T>
 where
     T: Send + Sync + Clone + 'static,
 {
     type Rejection = SilentError;
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
         let val = req.get_state::<T>()?.clone();
         Ok(State(val))
     }
 }
 
+from_request_via_parts!([T] State<T> where T: Send + Sync + Clone + 'static);
+
 #[allow(deprecated)]
 #[async_trait]
-impl<T> FromRequest for Configs<T>
+impl<T> FromRequestParts for Configs<T>
 where
     T: Send + Sync + Clone + 'static,
 {
     type Rejection = SilentError;
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
         let cfg = req.get_state::<T>()?.clone();
         Ok(Configs(cfg))
     }
 }
 
+from_request_via_parts!(#[allow(deprecated)] [T] Configs<T> where T: Send + Sync + Clone + 'static);
+
 #[async_trait]
-impl<T> FromRequest for Extension<T>
+impl<T> FromRequestParts for Extension<T>
 where
     T: Clone + Send + Sync + 'static,
 {
     type Rejection = SilentError;
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
         let ext = req
             .extensions()
             .get::<T>()
@@ -238,14 +441,58 @@ where
     }
 }
 
+from_request_via_parts!([T] Extension<T> where T: Clone + Send + Sync + 'static);
+
+#[async_trait]
+impl<T> FromRequestParts for OptionalState<T>
+where
+    T: Send + Sync + Clone + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(OptionalState(req.get_state::<T>().ok().cloned()))
+    }
+}
+
+from_request_via_parts!([T] OptionalState<T> where T: Send + Sync + Clone + 'static);
+
+#[async_trait]
+impl<T> FromRequestParts for StateArc<T>
+where
+    T: Send + Sync + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(StateArc(req.get_state_arc::<T>()?))
+    }
+}
+
+from_request_via_parts!([T] StateArc<T> where T: Send + Sync + 'static);
+
+#[async_trait]
+impl<T> FromRequestParts for OptionalExtension<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(OptionalExtension(req.extensions().get::<T>().cloned()))
+    }
+}
+
+from_request_via_parts!([T] OptionalExtension<T> where T: Clone + Send + Sync + 'static);
+
 #[async_trait]
-impl<H> FromRequest for TypedHeader<H>
+impl<H> FromRequestParts for TypedHeader<H>
 where
     H: headers::Header + Send + 'static,
 {
     type Rejection = SilentError;
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
         let h = req
             .headers()
             .typed_get::<H>()
@@ -254,132 +501,174 @@ where
     }
 }
 
+from_request_via_parts!([H] TypedHeader<H> where H: headers::Header + Send + 'static);
+
 #[async_trait]
-impl FromRequest for Method {
+impl FromRequest for Body {
     type Rejection = SilentError;
+
     async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
-        Ok(Method(req.method().clone()))
+        Ok(Body(read_body_limited(req).await?))
     }
 }
 
 #[async_trait]
-impl FromRequest for Uri {
+impl FromRequest for Text {
     type Rejection = SilentError;
+
     async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
-        Ok(Uri(req.uri().clone()))
+        let Body(bytes) = Body::from_request(req).await?;
+        let text = String::from_utf8(bytes.to_vec()).map_err(|e| {
+            SilentError::business_error(
+                http::StatusCode::BAD_REQUEST,
+                format!("request body is not valid utf-8: {e}"),
+            )
+        })?;
+        Ok(Text(text))
     }
 }
 
 #[async_trait]
-impl FromRequest for Version {
+impl FromRequestParts for Method {
     type Rejection = SilentError;
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
-        Ok(Version(req.version()))
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(Method(req.method().clone()))
     }
 }
 
+from_request_via_parts!([] Method);
+
 #[async_trait]
-impl FromRequest for RemoteAddr {
+impl FromRequestParts for Uri {
     type Rejection = SilentError;
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
-        Ok(RemoteAddr(req.remote()))
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(Uri(req.uri().clone()))
     }
 }
 
-#[async_trait]
-impl<A> FromRequest for (A,)
-where
-    A: FromRequest + Send + 'static,
-{
-    type Rejection = Response;
+from_request_via_parts!([] Uri);
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
-        let a = match <A as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        Ok((a,))
+#[async_trait]
+impl FromRequestParts for Version {
+    type Rejection = SilentError;
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(Version(req.version()))
     }
 }
 
-#[async_trait]
-impl<A, B> FromRequest for (A, B)
-where
-    A: FromRequest + Send + 'static,
-    B: FromRequest + Send + 'static,
-{
-    type Rejection = Response;
+from_request_via_parts!([] Version);
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
-        let a = match <A as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        let b = match <B as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        Ok((a, b))
+#[async_trait]
+impl FromRequestParts for RemoteAddr {
+    type Rejection = SilentError;
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(RemoteAddr(req.remote()))
     }
 }
 
-#[async_trait]
-impl<A, B, C> FromRequest for (A, B, C)
-where
-    A: FromRequest + Send + 'static,
-    B: FromRequest + Send + 'static,
-    C: FromRequest + Send + 'static,
-{
-    type Rejection = Response;
+from_request_via_parts!([] RemoteAddr);
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
-        let a = match <A as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        let b = match <B as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        let c = match <C as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        Ok((a, b, c))
+#[async_trait]
+impl FromRequestParts for MatchedPath {
+    type Rejection = SilentError;
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        req.extensions()
+            .get::<MatchedPath>()
+            .cloned()
+            .ok_or_else(|| {
+                SilentError::business_error(
+                    http::StatusCode::INTERNAL_SERVER_ERROR,
+                    "matched path unavailable outside of route handlers",
+                )
+            })
     }
 }
 
-#[async_trait]
-impl<A, B, C, D> FromRequest for (A, B, C, D)
-where
-    A: FromRequest + Send + 'static,
-    B: FromRequest + Send + 'static,
-    C: FromRequest + Send + 'static,
-    D: FromRequest + Send + 'static,
-{
-    type Rejection = Response;
+from_request_via_parts!([] MatchedPath);
 
-    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
-        let a = match <A as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        let b = match <B as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        let c = match <C as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        let d = match <D as FromRequest>::from_request(req).await {
-            Ok(v) => v,
-            Err(e) => return Err(e.into()),
-        };
-        Ok((a, b, c, d))
+#[async_trait]
+impl FromRequestParts for OriginalUri {
+    type Rejection = SilentError;
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(req
+            .extensions()
+            .get::<OriginalUri>()
+            .cloned()
+            .unwrap_or_else(|| OriginalUri(req.uri().clone())))
     }
 }
 
+from_request_via_parts!([] OriginalUri);
+
+/// 为元组实现 `FromRequestParts` 与 `FromRequest`，按从左到右的顺序依次萃取各元素
+///
+/// 作为 `FromRequest` 时，最后一个元素可以读取请求体，其余元素必须实现 `FromRequestParts`，
+/// 从而在编译期保证每个处理函数至多有一个读取请求体的萃取器。
+macro_rules! impl_from_request_for_tuple {
+    ($($ty:ident),* ; $last:ident) => {
+        #[async_trait]
+        #[allow(non_snake_case)]
+        impl<$($ty,)* $last> FromRequestParts for ($($ty,)* $last,)
+        where
+            $($ty: FromRequestParts + Send + 'static,)*
+            $last: FromRequestParts + Send + 'static,
+        {
+            type Rejection = Response;
+
+            async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+                $(
+                    let $ty = match <$ty as FromRequestParts>::from_request_parts(req).await {
+                        Ok(v) => v,
+                        Err(e) => return Err(e.into()),
+                    };
+                )*
+                let $last = match <$last as FromRequestParts>::from_request_parts(req).await {
+                    Ok(v) => v,
+                    Err(e) => return Err(e.into()),
+                };
+                Ok(($($ty,)* $last,))
+            }
+        }
+
+        #[async_trait]
+        #[allow(non_snake_case)]
+        impl<$($ty,)* $last> FromRequest for ($($ty,)* $last,)
+        where
+            $($ty: FromRequestParts + Send + 'static,)*
+            $last: FromRequest + Send + 'static,
+        {
+            type Rejection = Response;
+
+            async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+                $(
+                    let $ty = match <$ty as FromRequestParts>::from_request_parts(req).await {
+                        Ok(v) => v,
+                        Err(e) => return Err(e.into()),
+                    };
+                )*
+                let $last = match <$last as FromRequest>::from_request(req).await {
+                    Ok(v) => v,
+                    Err(e) => return Err(e.into()),
+                };
+                Ok(($($ty,)* $last,))
+            }
+        }
+    };
+}
+
+impl_from_request_for_tuple!(; A);
+impl_from_request_for_tuple!(A; B);
+impl_from_request_for_tuple!(A, B; C);
+impl_from_request_for_tuple!(A, B, C; D);
+impl_from_request_for_tuple!(A, B, C, D; E);
+impl_from_request_for_tuple!(A, B, C, D, E; F);
+impl_from_request_for_tuple!(A, B, C, D, E, F; G);
+impl_from_request_for_tuple!(A, B, C, D, E, F, G; H);
+impl_from_request_for_tuple!(A, B, C, D, E, F, G, H; I);
+impl_from_request_for_tuple!(A, B, C, D, E, F, G, H, I; J);
+impl_from_request_for_tuple!(A, B, C, D, E, F, G, H, I, J; K);
+impl_from_request_for_tuple!(A, B, C, D, E, F, G, H, I, J, K; L);
+
 #[async_trait]
 impl<T> FromRequest for Option<T>
 where
@@ -410,15 +699,72 @@ where
     }
 }
 
-#[inline]
-fn path_param_to_string(param: &CorePathParam) -> String {
-    match param {
-        CorePathParam::Str(s) | CorePathParam::Path(s) => s.as_str().to_string(),
-        CorePathParam::Int(v) => v.to_string(),
-        CorePathParam::Int32(v) => v.to_string(),
-        CorePathParam::Int64(v) => v.to_string(),
-        CorePathParam::UInt32(v) => v.to_string(),
-        CorePathParam::UInt64(v) => v.to_string(),
-        CorePathParam::Uuid(u) => u.to_string(),
+#[async_trait]
+impl<T> FromRequestParts for Option<T>
+where
+    T: FromRequestParts + Send + 'static,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(T::from_request_parts(req).await.ok())
+    }
+}
+
+#[async_trait]
+impl<T> FromRequestParts for Result<T, Response>
+where
+    T: FromRequestParts + Send + 'static,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(T::from_request_parts(req).await.map_err(Into::into))
+    }
+}
+
+/// 读取完整请求体，超过 [`BodyLimit`] 时返回 413
+pub(super) async fn read_body_limited(req: &mut Request) -> Result<bytes::Bytes, SilentError> {
+    let limit = req.get_state::<BodyLimit>().copied().unwrap_or_default().0;
+    let declared = content_length(req);
+    read_limited(req.take_body(), declared, limit).await
+}
+
+/// 请求头中声明的 `Content-Length`
+pub(super) fn content_length(req: &Request) -> Option<u64> {
+    req.headers()
+        .get(http::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+}
+
+/// 读取请求体直至结束，累计超过 `limit` 字节时返回 413
+pub(super) async fn read_limited(
+    mut body: crate::core::req_body::ReqBody,
+    declared: Option<u64>,
+    limit: usize,
+) -> Result<bytes::Bytes, SilentError> {
+    use futures_util::StreamExt;
+
+    let too_large = || {
+        SilentError::business_error(
+            http::StatusCode::PAYLOAD_TOO_LARGE,
+            format!("request body exceeds limit of {limit} bytes"),
+        )
+    };
+
+    // Content-Length 已声明超限时无需读取请求体
+    if declared.is_some_and(|len| len > limit as u64) {
+        return Err(too_large());
+    }
+
+    let mut buf = bytes::BytesMut::new();
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk?;
+        if buf.len() + chunk.len() > limit {
+            return Err(too_large());
+        }
+        buf.extend_from_slice(&chunk);
     }
+    Ok(buf.freeze())
 }
@@ -0,0 +1,183 @@
+//! Host 萃取器：解析请求的有效主机名
+
+use async_trait::async_trait;
+use http::header::{FORWARDED, HOST};
+
+use super::FromRequestParts;
+use super::from_request::from_request_via_parts;
+use crate::{Request, SilentError, StatusCode};
+
+/// Host 萃取器：获取请求的有效主机（可能包含端口）
+///
+/// 解析顺序为 `Forwarded` 的 `host=`、`X-Forwarded-Host`、`Host` 头，最后回退到请求 URI 的 authority
+/// （HTTP/2 的 `:authority`）。前两个转发头可由客户端任意伪造，因此仅在 [`HostTrust::Forwarded`]
+/// 策略下才会被采用，默认只信任 `Host` 头。
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::extractor::{Host, HostTrust};
+///
+/// // 部署在反向代理之后时信任转发头
+/// let route = Route::new("")
+///     .with_state(HostTrust::Forwarded)
+///     .get(|host: Host| async move { Ok(format!("tenant: {}", host.hostname())) });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Host(pub String);
+
+impl Host {
+    /// 去除端口后的主机名，IPv6 地址保留方括号
+    pub fn hostname(&self) -> &str {
+        let host = self.0.as_str();
+        if host.starts_with('[') {
+            return match host.find(']') {
+                Some(end) => &host[..=end],
+                None => host,
+            };
+        }
+        match host.rsplit_once(':') {
+            Some((name, port)) if port.bytes().all(|b| b.is_ascii_digit()) => name,
+            _ => host,
+        }
+    }
+
+    /// 主机中携带的端口
+    pub fn port(&self) -> Option<u16> {
+        self.0[self.hostname().len()..]
+            .strip_prefix(':')
+            .and_then(|port| port.parse().ok())
+    }
+}
+
+/// [`Host`] 萃取器对转发头的信任策略，通过 `with_state` 注入
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HostTrust {
+    /// 仅使用 `Host` 头，忽略 `Forwarded` 与 `X-Forwarded-Host`
+    #[default]
+    HostOnly,
+    /// 信任反向代理写入的 `Forwarded` 与 `X-Forwarded-Host`
+    Forwarded,
+}
+
+/// 读取 `Forwarded` 头（RFC 7239）第一个元素中指定参数的值
+///
+/// 多个代理依次追加时第一个元素最接近客户端；带引号的值会去除引号。
+pub(super) fn forwarded_param(req: &Request, name: &str) -> Option<String> {
+    let value = req.headers().get(FORWARDED)?.to_str().ok()?;
+    let first = value.split(',').next()?;
+    first.split(';').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        if !key.trim().eq_ignore_ascii_case(name) {
+            return None;
+        }
+        let value = value.trim().trim_matches('"');
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+fn header_host(req: &Request, name: &str) -> Option<String> {
+    let value = req.headers().get(name)?.to_str().ok()?;
+    let first = value.split(',').next()?.trim();
+    (!first.is_empty()).then(|| first.to_string())
+}
+
+#[async_trait]
+impl FromRequestParts for Host {
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let trust = req.get_state::<HostTrust>().copied().unwrap_or_default();
+        let forwarded = match trust {
+            HostTrust::Forwarded => {
+                forwarded_param(req, "host").or_else(|| header_host(req, "x-forwarded-host"))
+            }
+            HostTrust::HostOnly => None,
+        };
+        forwarded
+            .or_else(|| header_host(req, HOST.as_str()))
+            .or_else(|| req.uri().authority().map(|a| a.to_string()))
+            .map(Host)
+            .ok_or_else(|| SilentError::business_error(StatusCode::BAD_REQUEST, "missing host"))
+    }
+}
+
+from_request_via_parts!([] Host);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::FromRequest;
+    use http::HeaderValue;
+
+    fn request(headers: &[(&'static str, &'static str)]) -> Request {
+        let mut req = Request::empty();
+        for (name, value) in headers {
+            req.headers_mut()
+                .append(*name, HeaderValue::from_static(value));
+        }
+        req
+    }
+
+    // ==================== 解析顺序测试 ====================
+
+    #[tokio::test]
+    async fn test_host_ignores_forwarded_by_default() {
+        let mut req = request(&[
+            ("forwarded", "host=evil.example"),
+            ("x-forwarded-host", "evil.example"),
+            ("host", "api.example.com"),
+        ]);
+        let host = Host::from_request(&mut req).await.unwrap();
+        assert_eq!(host.0, "api.example.com");
+    }
+
+    #[tokio::test]
+    async fn test_host_trusts_forwarded_headers() {
+        let headers = [
+            (
+                "forwarded",
+                "for=1.2.3.4;host=\"tenant.example:8443\";proto=https, host=proxy",
+            ),
+            ("x-forwarded-host", "other.example"),
+            ("host", "internal:8080"),
+        ];
+        let mut req = request(&headers);
+        req.state_mut().insert(HostTrust::Forwarded);
+        let host = Host::from_request(&mut req).await.unwrap();
+        assert_eq!(host.0, "tenant.example:8443");
+
+        let mut req = request(&headers[1..]);
+        req.state_mut().insert(HostTrust::Forwarded);
+        let host = Host::from_request(&mut req).await.unwrap();
+        assert_eq!(host.0, "other.example");
+    }
+
+    #[tokio::test]
+    async fn test_host_fallback_to_uri_authority() {
+        let mut req = Request::empty();
+        *req.uri_mut() = "http://h2.example/path".parse().unwrap();
+        let host = Host::from_request(&mut req).await.unwrap();
+        assert_eq!(host.0, "h2.example");
+
+        let mut req = Request::empty();
+        let err = Host::from_request(&mut req).await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
+    }
+
+    // ==================== 主机名与端口测试 ====================
+
+    #[test]
+    fn test_host_hostname_and_port() {
+        let cases = [
+            ("example.com", "example.com", None),
+            ("example.com:8080", "example.com", Some(8080)),
+            ("[::1]:443", "[::1]", Some(443)),
+            ("[::1]", "[::1]", None),
+        ];
+        for (raw, hostname, port) in cases {
+            let host = Host(raw.to_string());
+            assert_eq!(host.hostname(), hostname);
+            assert_eq!(host.port(), port);
+        }
+    }
+}
@@ -0,0 +1,586 @@
+//! JWT 萃取器：校验 Bearer 令牌的签名与有效期并反序列化声明
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use http::HeaderValue;
+use http::header::WWW_AUTHENTICATE;
+use jsonwebtoken::Algorithm;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{DecodingKey, Header, Validation};
+use serde::de::DeserializeOwned;
+
+use super::from_request::from_request_via_parts;
+use super::{BearerToken, FromRequestParts};
+use crate::{Request, Response, SilentError, StatusCode};
+
+const HMAC_ALGORITHMS: &[Algorithm] = &[Algorithm::HS256, Algorithm::HS384, Algorithm::HS512];
+const RSA_ALGORITHMS: &[Algorithm] = &[
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+];
+const EC_ALGORITHMS: &[Algorithm] = &[Algorithm::ES256, Algorithm::ES384];
+const JWKS_ALGORITHMS: &[Algorithm] = &[
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::EdDSA,
+];
+
+/// JWKS 缓存的默认有效期
+const JWKS_TTL: Duration = Duration::from_secs(600);
+/// 遇到未知 `kid` 时两次强制刷新 JWKS 的最小间隔，避免被伪造的 `kid` 放大请求
+const JWKS_MIN_REFRESH: Duration = Duration::from_secs(30);
+/// 拉取 JWKS 的连接超时
+const JWKS_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// 拉取 JWKS 的整体超时，避免无响应的授权服务器长期占用刷新锁
+const JWKS_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// JWT 校验配置，供 [`JwtClaims`] 使用
+///
+/// 通过路由或 [`ServerConfig`](crate::ServerConfig) 的 `with_state` 注入请求的 [`State`](crate::State)
+/// （即旧版的 `Configs`，二者为同一份存储）后，[`JwtClaims`] 从中读取。
+///
+/// 支持 HMAC 共享密钥、RSA/ECDSA 公钥（PEM）以及远程 JWKS 地址。
+///
+/// ```rust
+/// use silent::extractor::JwtConfig;
+/// use silent::prelude::*;
+///
+/// let config = JwtConfig::hmac(b"secret")
+///     .issuer("https://auth.example.com")
+///     .audience("api")
+///     .leeway(30);
+/// let route = Route::new("").with_state(config);
+/// ```
+#[derive(Clone)]
+pub struct JwtConfig {
+    source: KeySource,
+    algorithms: Vec<Algorithm>,
+    issuer: Vec<String>,
+    audience: Vec<String>,
+    leeway: u64,
+}
+
+#[derive(Clone)]
+enum KeySource {
+    Static(DecodingKey),
+    Jwks(Arc<JwksCache>),
+}
+
+impl JwtConfig {
+    fn new(source: KeySource, algorithms: &[Algorithm]) -> Self {
+        Self {
+            source,
+            algorithms: algorithms.to_vec(),
+            issuer: Vec::new(),
+            audience: Vec::new(),
+            leeway: 60,
+        }
+    }
+
+    /// 使用 HMAC 共享密钥校验（HS256/HS384/HS512）
+    pub fn hmac(secret: impl AsRef<[u8]>) -> Self {
+        Self::new(
+            KeySource::Static(DecodingKey::from_secret(secret.as_ref())),
+            HMAC_ALGORITHMS,
+        )
+    }
+
+    /// 使用 PEM 格式的 RSA 公钥校验（RS*/PS*）
+    pub fn rsa_pem(pem: impl AsRef<[u8]>) -> crate::Result<Self> {
+        let key = DecodingKey::from_rsa_pem(pem.as_ref()).map_err(invalid_key)?;
+        Ok(Self::new(KeySource::Static(key), RSA_ALGORITHMS))
+    }
+
+    /// 使用 PEM 格式的 ECDSA 公钥校验（ES256/ES384）
+    pub fn ec_pem(pem: impl AsRef<[u8]>) -> crate::Result<Self> {
+        let key = DecodingKey::from_ec_pem(pem.as_ref()).map_err(invalid_key)?;
+        Ok(Self::new(KeySource::Static(key), EC_ALGORITHMS))
+    }
+
+    /// 从 JWKS 地址获取公钥，按令牌头中的 `kid` 选择密钥
+    ///
+    /// 公钥集首次使用时拉取并缓存 10 分钟；遇到未知 `kid` 时会提前刷新，以支持密钥轮换。
+    /// 同一时刻至多一个拉取请求，两次刷新尝试至少间隔 30 秒，伪造的 `kid` 不会放大对 JWKS 的请求；
+    /// 刷新期间已缓存的密钥照常可用，单次拉取最长 10 秒。
+    pub fn jwks_url(url: impl Into<String>) -> Self {
+        Self::new(
+            KeySource::Jwks(Arc::new(JwksCache::new(url.into()))),
+            JWKS_ALGORITHMS,
+        )
+    }
+
+    /// 限定允许的签名算法，默认为密钥类型支持的全部算法
+    pub fn algorithms(mut self, algorithms: &[Algorithm]) -> Self {
+        self.algorithms = algorithms.to_vec();
+        self
+    }
+
+    /// 要求 `iss` 声明与之匹配，可多次调用以允许多个签发者
+    pub fn issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer.push(issuer.into());
+        self
+    }
+
+    /// 要求 `aud` 声明包含该受众，可多次调用以允许多个受众
+    pub fn audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience.push(audience.into());
+        self
+    }
+
+    /// 校验 `exp`/`nbf` 时允许的时钟偏差（秒），默认 60 秒
+    pub fn leeway(mut self, seconds: u64) -> Self {
+        self.leeway = seconds;
+        self
+    }
+
+    /// 校验令牌并返回其中的声明
+    pub async fn verify<T: DeserializeOwned>(&self, token: &str) -> Result<T, JwtError> {
+        let header = jsonwebtoken::decode_header(token).map_err(JwtError::Invalid)?;
+        if !self.algorithms.contains(&header.alg) {
+            return Err(JwtError::Algorithm(header.alg));
+        }
+        let key = match &self.source {
+            KeySource::Static(key) => key.clone(),
+            KeySource::Jwks(jwks) => jwks.key(&header).await?,
+        };
+
+        let mut validation = Validation::new(header.alg);
+        validation.leeway = self.leeway;
+        // 配置了签发者或受众时，令牌必须携带对应声明
+        if !self.issuer.is_empty() {
+            validation.set_issuer(&self.issuer);
+            validation.required_spec_claims.insert("iss".to_string());
+        }
+        if self.audience.is_empty() {
+            validation.validate_aud = false;
+        } else {
+            validation.set_audience(&self.audience);
+            validation.required_spec_claims.insert("aud".to_string());
+        }
+        jsonwebtoken::decode::<T>(token, &key, &validation)
+            .map(|data| data.claims)
+            .map_err(JwtError::Invalid)
+    }
+}
+
+fn invalid_key(e: jsonwebtoken::errors::Error) -> SilentError {
+    SilentError::business_error(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        format!("invalid jwt key: {e}"),
+    )
+}
+
+/// JWT 校验失败的原因
+#[derive(Debug)]
+pub enum JwtError {
+    /// 令牌格式、签名或声明（如 `exp`、`iss`、`aud`）校验失败
+    Invalid(jsonwebtoken::errors::Error),
+    /// 令牌使用了未被允许的签名算法
+    Algorithm(Algorithm),
+    /// JWKS 中找不到与令牌匹配的公钥
+    UnknownKey,
+    /// 无法获取 JWKS
+    Jwks(String),
+}
+
+impl std::fmt::Display for JwtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JwtError::Invalid(e) => write!(f, "invalid token: {e}"),
+            JwtError::Algorithm(alg) => write!(f, "algorithm {alg:?} is not allowed"),
+            JwtError::UnknownKey => write!(f, "no matching key for token"),
+            JwtError::Jwks(e) => write!(f, "failed to fetch jwks: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for JwtError {}
+
+impl From<JwtError> for Response {
+    fn from(e: JwtError) -> Self {
+        if let JwtError::Jwks(_) = e {
+            return SilentError::business_error(StatusCode::SERVICE_UNAVAILABLE, e.to_string())
+                .into();
+        }
+        invalid_token(e.to_string())
+    }
+}
+
+/// 带 `WWW-Authenticate: Bearer error="invalid_token"` 的 `401` 响应
+fn invalid_token(message: String) -> Response {
+    let mut res: Response = SilentError::business_error(StatusCode::UNAUTHORIZED, message).into();
+    res.headers_mut().insert(
+        WWW_AUTHENTICATE,
+        HeaderValue::from_static("Bearer error=\"invalid_token\""),
+    );
+    res
+}
+
+// ==================== JWKS 缓存 ====================
+
+struct JwksCache {
+    url: String,
+    client: reqwest::Client,
+    state: Mutex<JwksState>,
+    /// 串行化刷新，缺少密钥的请求等待拉取完成后直接使用新的缓存
+    refreshing: futures::lock::Mutex<()>,
+}
+
+#[derive(Default)]
+struct JwksState {
+    keys: Option<JwkSet>,
+    /// 最近一次成功拉取的时间
+    fetched_at: Option<Instant>,
+    /// 最近一次尝试拉取的时间，无论成功与否
+    attempted_at: Option<Instant>,
+}
+
+impl JwksCache {
+    fn new(url: String) -> Self {
+        Self {
+            url,
+            client: reqwest::Client::builder()
+                .connect_timeout(JWKS_CONNECT_TIMEOUT)
+                .timeout(JWKS_FETCH_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            state: Mutex::default(),
+            refreshing: futures::lock::Mutex::new(()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, JwksState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 在缓存中查找密钥，未找到或缓存已过期时返回 `Err`，其中为已缓存的密钥
+    fn lookup(&self, header: &Header) -> Result<DecodingKey, Option<DecodingKey>> {
+        let state = self.lock();
+        let key = state.keys.as_ref().and_then(|keys| find_key(keys, header));
+        match (key, state.fetched_at) {
+            (Some(key), Some(fetched_at)) if fetched_at.elapsed() < JWKS_TTL => Ok(key),
+            (key, _) => Err(key),
+        }
+    }
+
+    /// 在锁内记录一次刷新尝试，距上次尝试不足 [`JWKS_MIN_REFRESH`] 时返回 `false`
+    fn begin_refresh(&self) -> bool {
+        let mut state = self.lock();
+        if state
+            .attempted_at
+            .is_some_and(|t| t.elapsed() < JWKS_MIN_REFRESH)
+        {
+            return false;
+        }
+        state.attempted_at = Some(Instant::now());
+        true
+    }
+
+    /// 本轮不刷新时的结果：使用已缓存的密钥，公钥集从未拉取成功时视为 JWKS 不可用
+    fn cached(&self, cached: Option<DecodingKey>) -> Result<DecodingKey, JwtError> {
+        cached.ok_or_else(|| match self.lock().keys {
+            Some(_) => JwtError::UnknownKey,
+            None => JwtError::Jwks("jwks is unavailable".to_string()),
+        })
+    }
+
+    async fn key(&self, header: &Header) -> Result<DecodingKey, JwtError> {
+        let _refreshing = match (self.lookup(header), self.refreshing.try_lock()) {
+            (Ok(key), _) => return Ok(key),
+            (Err(_), Some(guard)) => guard,
+            // 已缓存匹配的密钥（仅是过期）时不等待进行中的刷新
+            (Err(Some(cached)), None) => return Ok(cached),
+            (Err(None), None) => self.refreshing.lock().await,
+        };
+        // 等待期间其他请求可能已完成刷新
+        let cached = match self.lookup(header) {
+            Ok(key) => return Ok(key),
+            Err(cached) => cached,
+        };
+        if !self.begin_refresh() {
+            return self.cached(cached);
+        }
+        match self.fetch().await {
+            Ok(keys) => {
+                let key = find_key(&keys, header);
+                let mut state = self.lock();
+                state.keys = Some(keys);
+                state.fetched_at = Some(Instant::now());
+                key.ok_or(JwtError::UnknownKey)
+            }
+            // 刷新失败时回退到已缓存的密钥
+            Err(e) => cached.ok_or(e),
+        }
+    }
+
+    async fn fetch(&self) -> Result<JwkSet, JwtError> {
+        self.client
+            .get(&self.url)
+            .send()
+            .await
+            .and_then(|res| res.error_for_status())
+            .map_err(|e| JwtError::Jwks(e.to_string()))?
+            .json::<JwkSet>()
+            .await
+            .map_err(|e| JwtError::Jwks(e.to_string()))
+    }
+}
+
+fn find_key(keys: &JwkSet, header: &Header) -> Option<DecodingKey> {
+    let jwk = match &header.kid {
+        Some(kid) => keys.find(kid)?,
+        // 未携带 kid 时仅在公钥集只有一个密钥时使用它
+        None if keys.keys.len() == 1 => &keys.keys[0],
+        None => return None,
+    };
+    DecodingKey::from_jwk(jwk).ok()
+}
+
+// ==================== JwtClaims 萃取器 ====================
+
+/// 由 [`JwtAuth`](crate::middlewares::JwtAuth) 校验通过的声明，写入请求扩展供 [`JwtClaims`] 使用
+#[derive(Clone, Debug)]
+pub(crate) struct VerifiedClaims(pub(crate) serde_json::Value);
+
+/// JwtClaims 萃取器：校验 `Authorization: Bearer` 中的 JWT 并反序列化其声明
+///
+/// 需要通过 `with_state` 注入 [`JwtConfig`]，或在路由上挂载 [`JwtAuth`](crate::middlewares::JwtAuth)
+/// 中间件，此时直接使用中间件校验过的声明而不再重复校验。令牌缺失时返回带 `WWW-Authenticate: Bearer` 的
+/// `401`；签名、有效期或声明校验失败时返回带 `error="invalid_token"` 的 `401`。
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use silent::Result;
+/// use silent::extractor::JwtClaims;
+///
+/// #[derive(Deserialize)]
+/// struct Claims {
+///     sub: String,
+///     exp: u64,
+/// }
+///
+/// async fn me(JwtClaims(claims): JwtClaims<Claims>) -> Result<String> {
+///     Ok(format!("hello, {}", claims.sub))
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JwtClaims<T>(pub T);
+
+#[async_trait]
+impl<T> FromRequestParts for JwtClaims<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        if let Some(VerifiedClaims(claims)) = req.extensions().get::<VerifiedClaims>() {
+            return T::deserialize(claims)
+                .map(JwtClaims)
+                .map_err(|e| invalid_token(format!("invalid claims: {e}")));
+        }
+        let BearerToken(token) = BearerToken::from_request_parts(req).await?;
+        let config = req.get_state::<JwtConfig>().cloned().map_err(|_| {
+            Response::from(SilentError::business_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "jwt config is not configured",
+            ))
+        })?;
+        Ok(JwtClaims(config.verify(&token).await?))
+    }
+}
+
+from_request_via_parts!([T] JwtClaims<T> where T: DeserializeOwned + Send + 'static);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::FromRequest;
+    use http::header::AUTHORIZATION;
+    use jsonwebtoken::{EncodingKey, encode, get_current_timestamp};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Claims {
+        sub: String,
+        exp: u64,
+    }
+
+    fn token(secret: &[u8], exp: u64) -> String {
+        let claims = Claims {
+            sub: "alice".to_string(),
+            exp,
+        };
+        encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &EncodingKey::from_secret(secret),
+        )
+        .unwrap()
+    }
+
+    fn request(config: Option<JwtConfig>, token: &str) -> Request {
+        let mut req = Request::empty();
+        req.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {token}")).unwrap(),
+        );
+        if let Some(config) = config {
+            req.state_mut().insert(config);
+        }
+        req
+    }
+
+    #[tokio::test]
+    async fn test_jwt_claims_hmac_valid() {
+        let token = token(b"secret", get_current_timestamp() + 60);
+        let mut req = request(Some(JwtConfig::hmac(b"secret")), &token);
+        let JwtClaims(claims) = JwtClaims::<Claims>::from_request(&mut req).await.unwrap();
+        assert_eq!(claims.sub, "alice");
+    }
+
+    #[tokio::test]
+    async fn test_jwt_claims_rejects_invalid_tokens() {
+        let now = get_current_timestamp();
+        for token in [
+            token(b"other", now + 60),
+            token(b"secret", now - 600),
+            "not-a-jwt".to_string(),
+        ] {
+            let mut req = request(Some(JwtConfig::hmac(b"secret")), &token);
+            let res = JwtClaims::<Claims>::from_request(&mut req)
+                .await
+                .unwrap_err();
+            assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(
+                res.headers().get(WWW_AUTHENTICATE).unwrap(),
+                "Bearer error=\"invalid_token\""
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jwt_claims_validates_audience_and_algorithm() {
+        let token = token(b"secret", get_current_timestamp() + 60);
+        let config = JwtConfig::hmac(b"secret").audience("api");
+        assert!(matches!(
+            config.verify::<Claims>(&token).await,
+            Err(JwtError::Invalid(_))
+        ));
+
+        let config = JwtConfig::hmac(b"secret").algorithms(&[Algorithm::HS512]);
+        assert!(matches!(
+            config.verify::<Claims>(&token).await,
+            Err(JwtError::Algorithm(Algorithm::HS256))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_jwt_claims_missing_token_or_config() {
+        let mut req = Request::empty();
+        let res = JwtClaims::<Claims>::from_request(&mut req)
+            .await
+            .unwrap_err();
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(res.headers().get(WWW_AUTHENTICATE).unwrap(), "Bearer");
+
+        let token = token(b"secret", get_current_timestamp() + 60);
+        let mut req = request(None, &token);
+        let res = JwtClaims::<Claims>::from_request(&mut req)
+            .await
+            .unwrap_err();
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    /// 每次请求计数并返回空公钥集的 JWKS 服务
+    async fn jwks_server(fetches: Arc<std::sync::atomic::AtomicUsize>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let fetches = fetches.clone();
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 1024];
+                    let _ = stream.read(&mut buf).await;
+                    fetches.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    let body = r#"{"keys":[]}"#;
+                    let res = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = stream.write_all(res.as_bytes()).await;
+                });
+            }
+        });
+        format!("http://{addr}/jwks")
+    }
+
+    #[tokio::test]
+    async fn test_jwks_unknown_kid_fetches_once_per_window() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let config = JwtConfig::jwks_url(jwks_server(fetches.clone()).await);
+        // 头部为 {"alg":"RS256","kid":"forged"}
+        let token = "eyJhbGciOiJSUzI1NiIsImtpZCI6ImZvcmdlZCJ9.e30.c2ln".to_string();
+
+        let verify = || {
+            let config = config.clone();
+            let token = token.clone();
+            async move { config.verify::<Claims>(&token).await }
+        };
+        let results = futures::future::join_all((0..16).map(|_| verify())).await;
+        assert!(
+            results
+                .iter()
+                .all(|r| matches!(r, Err(JwtError::UnknownKey)))
+        );
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        // 刷新间隔内不再拉取，间隔过后同样只拉取一次
+        assert!(matches!(verify().await, Err(JwtError::UnknownKey)));
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        let KeySource::Jwks(jwks) = &config.source else {
+            unreachable!()
+        };
+        jwks.lock().attempted_at = Some(Instant::now() - JWKS_MIN_REFRESH);
+        futures::future::join_all((0..16).map(|_| verify())).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_jwks_stale_key_served_during_refresh() {
+        let jwks = JwksCache::new("http://127.0.0.1:9/jwks".to_string());
+        {
+            let mut state = jwks.lock();
+            state.keys = Some(
+                serde_json::from_str(r#"{"keys":[{"kty":"oct","kid":"k1","k":"c2VjcmV0"}]}"#)
+                    .unwrap(),
+            );
+            state.fetched_at = Some(Instant::now() - JWKS_TTL);
+        }
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some("k1".to_string());
+
+        // 模拟进行中的刷新：已缓存的密钥直接可用，不等待刷新锁
+        let _refreshing = jwks.refreshing.lock().await;
+        let key = tokio::time::timeout(Duration::from_millis(100), jwks.key(&header)).await;
+        assert!(matches!(key, Ok(Ok(_))));
+    }
+}
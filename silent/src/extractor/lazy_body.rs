@@ -0,0 +1,164 @@
+//! 延迟读取的请求体萃取器
+
+use std::fmt;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use mime::Mime;
+use serde::de::DeserializeOwned;
+
+use super::from_request::{content_length, read_limited};
+use super::{BodyLimit, FromRequest};
+use crate::core::req_body::ReqBody;
+use crate::{Request, SilentError, StatusCode};
+
+/// LazyBody 萃取器：持有尚未读取的请求体，在处理函数中按需解析
+///
+/// 萃取时不会读取任何数据，只有调用 [`LazyBody::bytes`]、[`LazyBody::json`] 等方法时才开始读取，
+/// 因此可以先完成鉴权等检查，在拒绝请求时避免缓冲大体积上传。读取大小受 [`BodyLimit`] 限制。
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use silent::extractor::{BearerToken, LazyBody};
+/// use silent::{Result, SilentError, StatusCode};
+///
+/// #[derive(Deserialize)]
+/// struct Import {
+///     items: Vec<String>,
+/// }
+///
+/// async fn import((BearerToken(token), body): (BearerToken, LazyBody)) -> Result<String> {
+///     if token != "secret" {
+///         return Err(SilentError::business_error(StatusCode::FORBIDDEN, "forbidden"));
+///     }
+///     let import: Import = body.json().await?;
+///     Ok(format!("imported {}", import.items.len()))
+/// }
+/// ```
+pub struct LazyBody {
+    body: ReqBody,
+    content_type: Option<Mime>,
+    declared: Option<u64>,
+    limit: usize,
+}
+
+impl fmt::Debug for LazyBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyBody")
+            .field("content_type", &self.content_type)
+            .field("content_length", &self.declared)
+            .field("limit", &self.limit)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LazyBody {
+    /// 请求的 `Content-Type`
+    pub fn content_type(&self) -> Option<&Mime> {
+        self.content_type.as_ref()
+    }
+
+    /// 请求头中声明的 `Content-Length`
+    pub fn content_length(&self) -> Option<u64> {
+        self.declared
+    }
+
+    /// 读取完整请求体
+    pub async fn bytes(self) -> crate::Result<Bytes> {
+        read_limited(self.body, self.declared, self.limit).await
+    }
+
+    /// 将请求体读取为 UTF-8 文本
+    pub async fn text(self) -> crate::Result<String> {
+        let bytes = self.bytes().await?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| {
+            SilentError::business_error(
+                StatusCode::BAD_REQUEST,
+                format!("request body is not valid utf-8: {e}"),
+            )
+        })
+    }
+
+    /// 将请求体解析为 JSON，要求 `Content-Type` 为 `application/json` 或 `+json` 后缀
+    pub async fn json<T: DeserializeOwned>(self) -> crate::Result<T> {
+        let content_type = self
+            .content_type
+            .as_ref()
+            .ok_or(SilentError::ContentTypeMissingError)?;
+        if content_type.subtype() != mime::JSON && content_type.suffix() != Some(mime::JSON) {
+            return Err(SilentError::ContentTypeError);
+        }
+        let bytes = self.bytes().await?;
+        if bytes.is_empty() {
+            return Err(SilentError::JsonEmpty);
+        }
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// 取出原始请求体，用于流式处理，此时不再受 [`BodyLimit`] 限制
+    pub fn into_inner(self) -> ReqBody {
+        self.body
+    }
+}
+
+#[async_trait]
+impl FromRequest for LazyBody {
+    type Rejection = SilentError;
+
+    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(LazyBody {
+            content_type: req.content_type(),
+            declared: content_length(req),
+            limit: req.get_state::<BodyLimit>().copied().unwrap_or_default().0,
+            body: req.take_body(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+    use http::header::CONTENT_TYPE;
+    use serde::Deserialize;
+
+    fn request(content_type: &'static str, body: &'static str) -> Request {
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        req.replace_body(ReqBody::Once(body.into()));
+        req
+    }
+
+    #[tokio::test]
+    async fn test_lazy_body_defers_reading() {
+        #[derive(Deserialize)]
+        struct Item {
+            id: u64,
+        }
+
+        let mut req = request("application/json", r#"{"id":7}"#);
+        let body = LazyBody::from_request(&mut req).await.unwrap();
+        assert_eq!(body.content_type(), Some(&mime::APPLICATION_JSON));
+        let item: Item = body.json().await.unwrap();
+        assert_eq!(item.id, 7);
+
+        let mut req = request("text/plain", "hello");
+        let body = LazyBody::from_request(&mut req).await.unwrap();
+        assert_eq!(body.text().await.unwrap(), "hello");
+    }
+
+    #[tokio::test]
+    async fn test_lazy_body_rejections() {
+        let mut req = request("text/plain", "{}");
+        let body = LazyBody::from_request(&mut req).await.unwrap();
+        let err = body.json::<serde_json::Value>().await.unwrap_err();
+        assert!(matches!(err, SilentError::ContentTypeError));
+
+        let mut req = request("text/plain", "0123456789");
+        req.state_mut().insert(BodyLimit(4));
+        let body = LazyBody::from_request(&mut req).await.unwrap();
+        let err = body.bytes().await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -72,15 +72,44 @@
 //! 本模块提供以下萃取器：
 //!
 //! - **Path<T>**：从 URL 路径中提取参数
+//! - **RelativePath**：配合 `Path<T>` 萃取 `<path:**>` 参数，得到规范化且不会越出根目录的相对路径；`Path<Vec<String>>` 则按 `/` 拆分为路径段
 //! - **Query<T>**：从查询字符串中提取参数
+//! - **QueryOrDefault<T>**：宽松解析查询参数，缺失的字段按 `#[serde(default)]` 取 `T::default()` 中的值，无效时回退为 `T::default()`
+//! - **RawQuery、QueryMap**：原始查询字符串，以及保留重复键的多值查询参数
 //! - **Json<T>**：从 JSON 请求体中提取数据
 //! - **Form<T>**：从表单数据中提取参数
 //! - **TypedHeader<T>**：提取并解析特定类型的请求头
 //! - **Extension<T>**：从请求扩展中提取数据
 //! - **State<T>**：从应用级共享状态中提取数据
 //! - **Configs<T>**：（已弃用）从请求配置中提取数据，请使用 State<T> 代替
+//! - **OptionalState<T>、OptionalExtension<T>**：状态或扩展缺失时得到 `None` 而非错误
+//! - **StateArc<T>**：以 `Arc<T>` 获取共享状态，不克隆内部值，`T` 无需实现 `Clone`（配合 `with_state_arc` 注入）
+//! - **Body、Text**：读取原始请求体（字节或 UTF-8 文本），大小受 `BodyLimit` 限制
+//! - **LazyBody**：延迟读取请求体，在处理函数中按需调用 `bytes()`/`text()`/`json()`，便于先鉴权再读取大体积载荷
+//! - **NdJsonStream<T>**：逐行流式解析 NDJSON 请求体，无需缓存完整载荷
+//! - **Multipart**：流式读取 multipart/form-data 请求体（需启用 `multipart` 特性）
+//! - **TypedMultipart<T>**：配合 `#[derive(TypedMultipart)]` 将 multipart 表单解析为结构体
+//! - **Xml<T>**：解析 XML 请求体，也可作为 XML 响应返回（需启用 `xml` 特性）
+//! - **MsgPack<T>**：解析 MessagePack 请求体，也可作为 MessagePack 响应返回（需启用 `msgpack` 特性）
+//! - **Cbor<T>**：解析 CBOR（RFC 8949）请求体，也可作为 CBOR 响应返回（需启用 `cbor` 特性）
+//! - **Protobuf<T>**：解析 `application/x-protobuf` 请求体，也可作为 protobuf 响应返回（需启用 `protobuf` 特性）
+//! - **Validated<E>**：在 Json/Query/Form/Path 萃取后执行 `validator` 校验（需启用 `validator` 特性）
+//! - **Host**：解析请求的有效主机，可通过 `HostTrust` 信任 `Forwarded`/`X-Forwarded-Host`
+//! - **ClientIp**：仅在对端属于 `TrustedProxies` 时读取转发头，解析真实客户端 IP
+//! - **MatchedPath**：命中的路由模板（如 `/users/<id:u64>`），适合作为指标标签
+//! - **OriginalUri**：`Route::mount` 去除挂载前缀之前的原始请求 URI
+//! - **Cookies**：读写请求 Cookie，修改会自动写入响应的 `Set-Cookie`（需启用 `cookie` 特性）
+//! - **Session、SessionValue<T>**：读写当前会话，或按 `SessionKey` 读写类型化会话值（需启用 `session` 特性）
+//! - **BasicAuth**：解析 Basic 认证的用户名与密码，缺失时返回带质询头的 `401`
+//! - **BearerToken**：提取 Bearer 令牌，缺失时返回带质询头的 `401`
+//! - **JwtClaims<T>**：按 `JwtConfig`（HMAC、RSA/ECDSA 公钥或 JWKS）校验 Bearer JWT 并反序列化声明（需启用 `jwt` 或 `security` 特性）
+//! - **Accept、Negotiate<T>**：按 `Accept` 请求头在 JSON/MessagePack/XML 间协商响应格式，无可接受格式时返回 `406`
+//! - **Either、Either3、Either4**：依次尝试多个萃取器并返回第一个成功的结果，如同时接受 `Json<T>` 与 `Form<T>`
 //! - **Method、Uri、Version**：提取请求的基础信息
 //!
+//! 不读取请求体的萃取器（Path、Query、请求头、扩展、状态等）同时实现了 `FromRequestParts`。
+//! 元组与 `#[derive(FromRequest)]` 结构体中只有最后一个元素可以读取请求体，顺序错误会在编译期报错。
+//!
 //! ## 自定义萃取器
 //!
 //! 您可以通过实现 `FromRequest` trait 来创建自定义萃取器：
@@ -108,6 +137,35 @@
 //! }
 //! ```
 //!
+//! 也可以为字段均为萃取器的结构体派生 `FromRequest`，用一个具名参数代替冗长的元组：
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use silent::Result;
+//! use silent::extractor::{FromRequest, Json, Path, Query};
+//!
+//! #[derive(Deserialize)]
+//! struct Page {
+//!     page: u32,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Data {
+//!     name: String,
+//! }
+//!
+//! #[derive(FromRequest)]
+//! struct UpdateParams {
+//!     id: Path<i64>,
+//!     page: Query<Page>,
+//!     body: Json<Data>,
+//! }
+//!
+//! async fn handler(params: UpdateParams) -> Result<String> {
+//!     Ok(format!("{} {} {}", params.id.0, params.page.0.page, params.body.0.name))
+//! }
+//! ```
+//!
 //! ## 组合使用
 //!
 //! 萃取器可以组合使用：
@@ -179,11 +237,73 @@ use std::sync::Arc;
 
 use crate::{Request, Response};
 
-pub use self::from_request::FromRequest;
+pub use self::auth::{BasicAuth, BearerToken};
+#[cfg(feature = "cbor")]
+pub use self::cbor::Cbor;
+pub(crate) use self::client_ip::IpCidr;
+pub use self::client_ip::{ClientIp, TrustedProxies};
+#[cfg(feature = "cookie")]
+pub use self::cookies::Cookies;
+pub use self::either::{Either, Either3, Either4};
+pub use self::from_request::{FromRequest, FromRequestParts};
+pub use self::host::{Host, HostTrust};
+#[cfg(feature = "jwt")]
+pub(crate) use self::jwt::VerifiedClaims;
+#[cfg(feature = "jwt")]
+pub use self::jwt::{JwtClaims, JwtConfig, JwtError};
+pub use self::lazy_body::LazyBody;
+#[cfg(feature = "msgpack")]
+pub use self::msgpack::MsgPack;
+#[cfg(feature = "multipart")]
+pub(crate) use self::multipart::multipart_field_too_large;
+#[cfg(feature = "multipart")]
+pub use self::multipart::{FromMultipart, FromMultipartField, TempFile, TypedMultipart};
+pub use self::ndjson::NdJsonStream;
+pub use self::negotiate::{Accept, Negotiate, NegotiateConfig, ResponseFormat};
+#[cfg(feature = "protobuf")]
+pub use self::protobuf::Protobuf;
+pub use self::relative_path::RelativePath;
+#[cfg(feature = "session")]
+pub use self::session::{Session, SessionKey, SessionValue};
 pub use self::types::*;
-
+#[cfg(feature = "validator")]
+pub use self::validated::{Validated, ValidatedExtractor, validation_error_response};
+#[cfg(feature = "xml")]
+pub use self::xml::Xml;
+#[cfg(feature = "jwt")]
+pub use jsonwebtoken::Algorithm as JwtAlgorithm;
+pub use silent_macros::FromRequest;
+#[cfg(feature = "multipart")]
+pub use silent_macros::TypedMultipart;
+
+mod auth;
+#[cfg(feature = "cbor")]
+mod cbor;
+mod client_ip;
+#[cfg(feature = "cookie")]
+mod cookies;
+mod either;
 mod from_request;
+mod host;
+#[cfg(feature = "jwt")]
+mod jwt;
+mod lazy_body;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(feature = "multipart")]
+pub(crate) mod multipart;
+mod ndjson;
+mod negotiate;
+#[cfg(feature = "protobuf")]
+mod protobuf;
+mod relative_path;
+#[cfg(feature = "session")]
+mod session;
 mod types;
+#[cfg(feature = "validator")]
+mod validated;
+#[cfg(feature = "xml")]
+mod xml;
 
 /// 将使用萃取器参数的处理函数适配为接收 `Request` 的处理函数
 /// 仅萃取器参数的处理函数：`F: Fn(Args) -> Fut`
@@ -196,6 +316,34 @@ where
     F: Fn(Args) -> Fut + Send + Sync + 'static,
     Fut: core::future::Future<Output = crate::Result<T>> + Send + 'static,
     T: crate::IntoResponse + Send + 'static,
+{
+    extractor_handler::<Args, F, Fut, T, ()>(f)
+}
+
+/// 同时接收 Request 与萃取器参数：`F: Fn(Request, Args) -> Fut`
+pub fn handler_from_extractor_with_request<Args, F, Fut, T>(
+    f: F,
+) -> impl Fn(crate::Request) -> BoxFuture<'static, crate::Result<Response>> + Send + Sync + 'static
+where
+    Args: FromRequest + Send + 'static,
+    <Args as FromRequest>::Rejection: Into<Response> + Send + 'static,
+    F: Fn(crate::Request, Args) -> Fut + Send + Sync + 'static,
+    Fut: core::future::Future<Output = crate::Result<T>> + Send + 'static,
+    T: crate::IntoResponse + Send + 'static,
+{
+    extractor_handler_with_request::<Args, F, Fut, T, ()>(f)
+}
+
+/// [`handler_from_extractor`] 的内部实现，额外接受 `IntoResponse` 的标记类型 `M`
+pub(crate) fn extractor_handler<Args, F, Fut, T, M>(
+    f: F,
+) -> impl Fn(crate::Request) -> BoxFuture<'static, crate::Result<Response>> + Send + Sync + 'static
+where
+    Args: FromRequest + Send + 'static,
+    <Args as FromRequest>::Rejection: Into<Response> + Send + 'static,
+    F: Fn(Args) -> Fut + Send + Sync + 'static,
+    Fut: core::future::Future<Output = crate::Result<T>> + Send + 'static,
+    T: crate::IntoResponse<M> + Send + 'static,
 {
     let f = Arc::new(f);
     move |mut req: Request| {
@@ -212,8 +360,8 @@ where
     }
 }
 
-/// 同时接收 Request 与萃取器参数：`F: Fn(Request, Args) -> Fut`
-pub fn handler_from_extractor_with_request<Args, F, Fut, T>(
+/// [`handler_from_extractor_with_request`] 的内部实现，额外接受 `IntoResponse` 的标记类型 `M`
+pub(crate) fn extractor_handler_with_request<Args, F, Fut, T, M>(
     f: F,
 ) -> impl Fn(crate::Request) -> BoxFuture<'static, crate::Result<Response>> + Send + Sync + 'static
 where
@@ -221,7 +369,7 @@ where
     <Args as FromRequest>::Rejection: Into<Response> + Send + 'static,
     F: Fn(crate::Request, Args) -> Fut + Send + Sync + 'static,
     Fut: core::future::Future<Output = crate::Result<T>> + Send + 'static,
-    T: crate::IntoResponse + Send + 'static,
+    T: crate::IntoResponse<M> + Send + 'static,
 {
     let f = Arc::new(f);
     move |mut req: Request| {
@@ -244,6 +392,7 @@ mod tests {
     use crate::{Request, Response};
     use headers::UserAgent;
     use serde::Deserialize;
+    use std::sync::Arc;
 
     #[derive(Deserialize)]
     struct Page {
@@ -390,6 +539,13 @@ mod tests {
         );
         let RemoteAddr(addr): RemoteAddr = RemoteAddr::from_request(&mut req).await.unwrap();
         assert_eq!(addr.to_string(), "127.0.0.1:9090");
+
+        // matched path：未经路由分发时不可用
+        assert!(MatchedPath::from_request(&mut req).await.is_err());
+        req.extensions_mut()
+            .insert(MatchedPath::new("/users/<id:u64>".into()));
+        let matched = MatchedPath::from_request(&mut req).await.unwrap();
+        assert_eq!(matched.as_str(), "/users/<id:u64>");
     }
 
     #[tokio::test]
@@ -417,6 +573,39 @@ mod tests {
         assert_eq!(id, 5);
     }
 
+    #[tokio::test]
+    async fn test_optional_and_arc_state_extension() {
+        // 未实现 Clone 的共享状态
+        struct Pool(u32);
+        let pool = Arc::new(Pool(3));
+        let mut req = Request::empty();
+        req.state_mut().insert_arc(pool.clone());
+        let StateArc(shared) = StateArc::<Pool>::from_request(&mut req).await.unwrap();
+        assert!(Arc::ptr_eq(&shared, &pool));
+        assert_eq!(shared.0, 3);
+
+        // 通过 insert 注入的状态同样可以共享获取
+        req.state_mut().insert(7u64);
+        let StateArc(n) = StateArc::<u64>::from_request(&mut req).await.unwrap();
+        assert_eq!(*n, 7);
+        assert!(StateArc::<i8>::from_request(&mut req).await.is_err());
+
+        let OptionalState(v) = OptionalState::<u64>::from_request(&mut req).await.unwrap();
+        assert_eq!(v, Some(7));
+        let OptionalState(v) = OptionalState::<i8>::from_request(&mut req).await.unwrap();
+        assert_eq!(v, None);
+
+        req.extensions_mut().insert("ext");
+        let OptionalExtension(v) = OptionalExtension::<&'static str>::from_request(&mut req)
+            .await
+            .unwrap();
+        assert_eq!(v, Some("ext"));
+        let OptionalExtension(v) = OptionalExtension::<u8>::from_request(&mut req)
+            .await
+            .unwrap();
+        assert_eq!(v, None);
+    }
+
     #[tokio::test]
     async fn test_tuple_triple_quad_and_result_ok() {
         #[derive(Deserialize)]
@@ -468,6 +657,124 @@ mod tests {
         assert!(matches!(r, Ok(Json(U { name })) if name == "ok"));
     }
 
+    #[tokio::test]
+    async fn test_tuple_twelve_with_body_last() {
+        #[derive(Deserialize)]
+        struct Q {
+            page: u32,
+        }
+        #[derive(Deserialize)]
+        struct U {
+            name: String,
+        }
+        let mut req = Request::empty();
+        req.set_path_params("id".to_owned(), crate::core::path_param::PathParam::Int(1));
+        *req.uri_mut() = http::Uri::from_static("http://localhost/test?page=3");
+        req.headers_mut()
+            .insert("user-agent", http::HeaderValue::from_static("ua"));
+        req.headers_mut().insert(
+            "content-type",
+            http::HeaderValue::from_static("application/json"),
+        );
+        req.extensions_mut().insert(7u8);
+        req.replace_body(crate::core::req_body::ReqBody::Once(
+            bytes::Bytes::from_static(br#"{"name":"ok"}"#),
+        ));
+
+        type Twelve = (
+            Path<i32>,
+            Query<Q>,
+            TypedHeader<UserAgent>,
+            Extension<u8>,
+            Method,
+            Uri,
+            Version,
+            RawQuery,
+            QueryMap,
+            Option<Path<String>>,
+            Query<Q>,
+            Json<U>,
+        );
+        let (Path(id), Query(q), _, Extension(ext), _, _, _, RawQuery(raw), map, _, _, Json(u)) =
+            <Twelve as FromRequest>::from_request(&mut req)
+                .await
+                .unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(q.page, 3);
+        assert_eq!(ext, 7);
+        assert_eq!(raw.as_deref(), Some("page=3"));
+        assert_eq!(map.get("page"), Some("3"));
+        assert_eq!(u.name, "ok");
+    }
+
+    #[tokio::test]
+    async fn test_from_request_parts_leaves_body() {
+        #[derive(Deserialize)]
+        struct U {
+            name: String,
+        }
+        let mut req = Request::empty();
+        *req.uri_mut() = http::Uri::from_static("http://localhost/test?page=3");
+        req.headers_mut().insert(
+            "content-type",
+            http::HeaderValue::from_static("application/json"),
+        );
+        req.replace_body(crate::core::req_body::ReqBody::Once(
+            bytes::Bytes::from_static(br#"{"name":"ok"}"#),
+        ));
+
+        // 嵌套元组、Option 与 Result 在全部元素不读取请求体时同样是 FromRequestParts
+        type Parts = (
+            (Method, RawQuery),
+            Option<Path<i32>>,
+            Result<QueryMap, Response>,
+        );
+        let ((Method(method), _), id, map) = Parts::from_request_parts(&mut req).await.unwrap();
+        assert_eq!(method, http::Method::GET);
+        assert!(id.is_none());
+        assert_eq!(map.unwrap().get("page"), Some("3"));
+
+        let (_, Json(u)) = <(Parts, Json<U>)>::from_request(&mut req).await.unwrap();
+        assert_eq!(u.name, "ok");
+    }
+
+    #[tokio::test]
+    async fn test_derive_from_request() {
+        #[derive(Deserialize)]
+        struct Q {
+            page: u32,
+        }
+
+        #[derive(FromRequest)]
+        struct Params {
+            id: Path<i32>,
+            query: Query<Q>,
+            agent: Option<TypedHeader<UserAgent>>,
+        }
+
+        #[derive(FromRequest)]
+        struct Pair<T: Send + 'static>(Extension<T>, Method);
+
+        let mut req = Request::empty();
+        req.set_path_params("id".to_owned(), crate::core::path_param::PathParam::Int(1));
+        *req.uri_mut() = http::Uri::from_static("http://localhost/test?page=3");
+        req.extensions_mut().insert(7u8);
+
+        let params = Params::from_request(&mut req).await.unwrap();
+        assert_eq!(params.id.0, 1);
+        assert_eq!(params.query.0.page, 3);
+        assert!(params.agent.is_none());
+
+        let Pair(Extension(ext), Method(method)) =
+            Pair::<u8>::from_request(&mut req).await.unwrap();
+        assert_eq!(ext, 7);
+        assert_eq!(method, http::Method::GET);
+
+        *req.uri_mut() = http::Uri::from_static("http://localhost/test");
+        let res = Params::from_request(&mut req).await.err().unwrap();
+        assert_eq!(res.status(), http::StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     #[tokio::test]
     async fn test_path_param_edge_cases() {
         // 测试各种数字类型
@@ -561,6 +868,120 @@ mod tests {
         assert!(matches!(params.status, Status::Active));
     }
 
+    #[tokio::test]
+    async fn test_raw_query_and_query_map() {
+        let mut req = Request::empty();
+        *req.uri_mut() = http::Uri::from_static("http://localhost/test");
+        let RawQuery(raw) = RawQuery::from_request(&mut req).await.unwrap();
+        assert!(raw.is_none());
+        assert!(QueryMap::from_request(&mut req).await.unwrap().is_empty());
+
+        *req.uri_mut() =
+            http::Uri::from_static("http://localhost/test?tag=a&tag=b%20c&page=2&filter=(x%3D1)");
+        let RawQuery(raw) = RawQuery::from_request(&mut req).await.unwrap();
+        assert_eq!(
+            raw.as_deref(),
+            Some("tag=a&tag=b%20c&page=2&filter=(x%3D1)")
+        );
+
+        let query = QueryMap::from_request(&mut req).await.unwrap();
+        assert_eq!(query.len(), 3);
+        assert_eq!(query.get_all("tag"), ["a", "b c"]);
+        assert_eq!(query.get("tag"), Some("a"));
+        assert_eq!(query.get("filter"), Some("(x=1)"));
+        assert!(query.get_all("missing").is_empty());
+        assert!(!query.contains_key("missing"));
+    }
+
+    #[tokio::test]
+    async fn test_query_or_default() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(default)]
+        struct Paging {
+            page: u32,
+            size: u32,
+            keyword: Option<String>,
+        }
+
+        impl Default for Paging {
+            fn default() -> Self {
+                Self {
+                    page: 1,
+                    size: 20,
+                    keyword: None,
+                }
+            }
+        }
+
+        async fn extract(uri: &'static str) -> Paging {
+            let mut req = Request::empty();
+            *req.uri_mut() = http::Uri::from_static(uri);
+            QueryOrDefault::<Paging>::from_request(&mut req)
+                .await
+                .unwrap()
+                .0
+        }
+
+        assert_eq!(extract("http://localhost/list").await, Paging::default());
+        assert_eq!(
+            extract("http://localhost/list?page=3&size=5&keyword=a%20b").await,
+            Paging {
+                page: 3,
+                size: 5,
+                keyword: Some("a b".into()),
+            }
+        );
+        assert_eq!(
+            extract("http://localhost/list?size=50").await,
+            Paging {
+                size: 50,
+                ..Paging::default()
+            }
+        );
+        assert_eq!(
+            extract("http://localhost/list?size=10&extra=1").await,
+            Paging {
+                size: 10,
+                ..Paging::default()
+            }
+        );
+        // 仅无法解析的字段取默认值
+        assert_eq!(
+            extract("http://localhost/list?page=abc&size=50&keyword=x").await,
+            Paging {
+                size: 50,
+                keyword: Some("x".into()),
+                ..Paging::default()
+            }
+        );
+        assert_eq!(
+            extract("http://localhost/list?page=abc&size=-1").await,
+            Paging::default()
+        );
+
+        // 非扁平的字段同样由 #[serde(default)] 补全
+        #[derive(Debug, Default, Deserialize, PartialEq)]
+        #[serde(default)]
+        struct Filter {
+            tag: Vec<String>,
+            #[serde(skip)]
+            cursor: Option<u64>,
+            limit: u32,
+        }
+        let mut req = Request::empty();
+        *req.uri_mut() = http::Uri::from_static("http://localhost/list?tag=a&tag=b");
+        let QueryOrDefault(filter) = QueryOrDefault::<Filter>::from_request(&mut req)
+            .await
+            .unwrap();
+        assert_eq!(
+            filter,
+            Filter {
+                tag: vec!["a".into(), "b".into()],
+                ..Filter::default()
+            }
+        );
+    }
+
     #[tokio::test]
     async fn test_json_and_form_error_cases() {
         // 测试无效 JSON
@@ -813,4 +1234,285 @@ mod tests {
         let result = Extension::<NonExistent>::from_request(&mut req).await;
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "multipart")]
+    #[tokio::test]
+    async fn test_multipart_streaming_fields() {
+        let body = "--X-BOUNDARY\r\n\
+Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+hello\r\n\
+--X-BOUNDARY\r\n\
+Content-Disposition: form-data; name=\"file\"; filename=\"a.txt\"\r\n\
+Content-Type: text/plain\r\n\r\n\
+file content\r\n\
+--X-BOUNDARY--\r\n";
+        let mut req = Request::empty();
+        req.headers_mut().insert(
+            "content-type",
+            http::HeaderValue::from_static("multipart/form-data; boundary=X-BOUNDARY"),
+        );
+        req.replace_body(crate::core::req_body::ReqBody::Once(body.into()));
+
+        let mut m = Multipart::from_request(&mut req).await.unwrap();
+        let field = m.next_field().await.unwrap().unwrap();
+        assert_eq!(field.name(), Some("title"));
+        assert_eq!(field.text().await.unwrap(), "hello");
+
+        let field = m.next_field().await.unwrap().unwrap();
+        assert_eq!(field.name(), Some("file"));
+        assert_eq!(field.file_name(), Some("a.txt"));
+        assert_eq!(&field.bytes().await.unwrap()[..], b"file content");
+
+        assert!(m.next_field().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_body_and_text() {
+        let mut req = Request::empty();
+        req.replace_body(crate::core::req_body::ReqBody::Once("raw payload".into()));
+        let Body(bytes) = Body::from_request(&mut req).await.unwrap();
+        assert_eq!(&bytes[..], b"raw payload");
+
+        // 空请求体
+        let mut req = Request::empty();
+        let Body(bytes) = Body::from_request(&mut req).await.unwrap();
+        assert!(bytes.is_empty());
+
+        let mut req = Request::empty();
+        req.replace_body(crate::core::req_body::ReqBody::Once("你好".into()));
+        let Text(text) = Text::from_request(&mut req).await.unwrap();
+        assert_eq!(text, "你好");
+
+        // 非 UTF-8 内容
+        let mut req = Request::empty();
+        req.replace_body(crate::core::req_body::ReqBody::Once(
+            vec![0xff, 0xfe].into(),
+        ));
+        let err = Text::from_request(&mut req).await.err().unwrap();
+        assert_eq!(err.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_body_limit() {
+        // 通过 state 配置上限
+        let mut req = Request::empty();
+        req.state_mut().insert(BodyLimit(4));
+        req.replace_body(crate::core::req_body::ReqBody::Once("12345".into()));
+        let err = Body::from_request(&mut req).await.err().unwrap();
+        assert_eq!(err.status(), http::StatusCode::PAYLOAD_TOO_LARGE);
+
+        // Content-Length 超限时直接拒绝
+        let mut req = Request::empty();
+        req.state_mut().insert(BodyLimit(4));
+        req.headers_mut()
+            .insert("content-length", http::HeaderValue::from_static("100"));
+        let err = Text::from_request(&mut req).await.err().unwrap();
+        assert_eq!(err.status(), http::StatusCode::PAYLOAD_TOO_LARGE);
+
+        // 恰好等于上限
+        let mut req = Request::empty();
+        req.state_mut().insert(BodyLimit(4));
+        req.replace_body(crate::core::req_body::ReqBody::Once("1234".into()));
+        assert!(Body::from_request(&mut req).await.is_ok());
+
+        assert_eq!(BodyLimit::default().0, BodyLimit::DEFAULT);
+    }
+
+    #[cfg(feature = "validator")]
+    #[tokio::test]
+    async fn test_validated_json_and_query() {
+        use validator::Validate;
+
+        #[derive(Deserialize, Validate)]
+        struct SignUp {
+            #[validate(email)]
+            email: String,
+            #[validate(length(min = 8))]
+            password: String,
+        }
+
+        let json_req = |body: &'static str| {
+            let mut req = Request::empty();
+            req.headers_mut().insert(
+                "content-type",
+                http::HeaderValue::from_static("application/json"),
+            );
+            req.replace_body(crate::core::req_body::ReqBody::Once(body.into()));
+            req
+        };
+
+        let mut req = json_req(r#"{"email":"a@b.com","password":"12345678"}"#);
+        let Validated(Json(form)): Validated<Json<SignUp>> =
+            Validated::from_request(&mut req).await.unwrap();
+        assert_eq!(form.email, "a@b.com");
+
+        // 校验失败返回 422 并列出字段错误
+        let mut req = json_req(r#"{"email":"invalid","password":"123"}"#);
+        let res = Validated::<Json<SignUp>>::from_request(&mut req)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::UNPROCESSABLE_ENTITY);
+        let body = match res.body {
+            crate::core::res_body::ResBody::Once(bytes) => bytes,
+            _ => panic!("unexpected body"),
+        };
+        let value: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(value["errors"]["email"].is_array());
+        assert!(value["errors"]["password"].is_array());
+
+        // 反序列化失败时沿用内部萃取器的错误
+        let mut req = json_req("not json");
+        let res = Validated::<Json<SignUp>>::from_request(&mut req)
+            .await
+            .err()
+            .unwrap();
+        let body = match res.body {
+            crate::core::res_body::ResBody::Once(bytes) => bytes,
+            _ => panic!("unexpected body"),
+        };
+        assert!(!String::from_utf8_lossy(&body).contains("validation failed"));
+
+        #[derive(Deserialize, Validate)]
+        struct Paging {
+            #[validate(range(min = 1, max = 100))]
+            size: u32,
+        }
+        let mut req = Request::empty();
+        *req.uri_mut() = http::Uri::from_static("http://localhost/?size=500");
+        let res = Validated::<Query<Paging>>::from_request(&mut req)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(res.status(), http::StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[cfg(feature = "multipart")]
+    fn multipart_request(parts: &[(&str, Option<&str>, &str)]) -> Request {
+        let mut body = String::new();
+        for (name, filename, value) in parts {
+            body.push_str("--B\r\n");
+            match filename {
+                Some(f) => body.push_str(&format!(
+                    "Content-Disposition: form-data; name=\"{name}\"; filename=\"{f}\"\r\n\
+                     Content-Type: application/octet-stream\r\n\r\n"
+                )),
+                None => body.push_str(&format!(
+                    "Content-Disposition: form-data; name=\"{name}\"\r\n\r\n"
+                )),
+            }
+            body.push_str(value);
+            body.push_str("\r\n");
+        }
+        body.push_str("--B--\r\n");
+        let mut req = Request::empty();
+        req.headers_mut().insert(
+            "content-type",
+            http::HeaderValue::from_static("multipart/form-data; boundary=B"),
+        );
+        req.replace_body(crate::core::req_body::ReqBody::Once(body.into()));
+        req
+    }
+
+    #[cfg(feature = "multipart")]
+    #[derive(TypedMultipart)]
+    struct Upload {
+        title: String,
+        count: u32,
+        #[multipart(rename = "avatar", limit = "1KB")]
+        file: TempFile,
+        #[multipart(limit = 4)]
+        note: Option<bytes::Bytes>,
+        tags: Vec<String>,
+    }
+
+    #[cfg(feature = "multipart")]
+    #[tokio::test]
+    async fn test_typed_multipart() {
+        let mut req = multipart_request(&[
+            ("title", None, "hello"),
+            ("count", None, "3"),
+            ("avatar", Some("a.png"), "binary"),
+            ("tags", None, "a"),
+            ("tags", None, "b"),
+            ("unknown", None, "ignored"),
+        ]);
+        let TypedMultipart(upload): TypedMultipart<Upload> =
+            TypedMultipart::from_request(&mut req).await.unwrap();
+        assert_eq!(upload.title, "hello");
+        assert_eq!(upload.count, 3);
+        assert_eq!(upload.file.size(), 6);
+        assert_eq!(upload.file.name(), Some("a.png"));
+        assert!(upload.note.is_none());
+        assert_eq!(upload.tags, vec!["a", "b"]);
+    }
+
+    #[cfg(feature = "multipart")]
+    #[tokio::test]
+    async fn test_typed_multipart_rejections() {
+        // 缺少必填字段
+        let mut req = multipart_request(&[("title", None, "hello"), ("count", None, "1")]);
+        let err = TypedMultipart::<Upload>::from_request(&mut req)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.status(), http::StatusCode::BAD_REQUEST);
+        assert!(err.message().contains("avatar"));
+
+        // 字段超出大小上限
+        let mut req = multipart_request(&[
+            ("title", None, "hello"),
+            ("count", None, "1"),
+            ("avatar", Some("a.png"), "x"),
+            ("note", None, "too long"),
+        ]);
+        let err = TypedMultipart::<Upload>::from_request(&mut req)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.status(), http::StatusCode::PAYLOAD_TOO_LARGE);
+        assert!(err.message().contains("note"));
+
+        // 字段格式错误
+        let mut req = multipart_request(&[("count", None, "abc")]);
+        let err = TypedMultipart::<Upload>::from_request(&mut req)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.status(), http::StatusCode::BAD_REQUEST);
+        assert!(err.message().contains("count"));
+    }
+
+    #[cfg(feature = "multipart")]
+    #[tokio::test]
+    async fn test_multipart_rejections() {
+        // 缺少 content-type
+        let mut req = Request::empty();
+        assert!(matches!(
+            Multipart::from_request(&mut req).await,
+            Err(crate::SilentError::ContentTypeMissingError)
+        ));
+
+        // 非 multipart content-type
+        let mut req = Request::empty();
+        req.headers_mut().insert(
+            "content-type",
+            http::HeaderValue::from_static("application/json"),
+        );
+        assert!(matches!(
+            Multipart::from_request(&mut req).await,
+            Err(crate::SilentError::ContentTypeError)
+        ));
+
+        // 空请求体
+        let mut req = Request::empty();
+        req.headers_mut().insert(
+            "content-type",
+            http::HeaderValue::from_static("multipart/form-data; boundary=X"),
+        );
+        assert!(matches!(
+            Multipart::from_request(&mut req).await,
+            Err(crate::SilentError::BodyEmpty)
+        ));
+    }
 }
@@ -0,0 +1,175 @@
+//! MessagePack 萃取器与响应
+
+use async_trait::async_trait;
+use http::HeaderValue;
+use http::header::CONTENT_TYPE;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::FromRequest;
+use super::from_request::read_body_limited;
+use crate::core::res_body::full;
+use crate::{Request, Response, SilentError, StatusCode};
+
+/// MsgPack 萃取器/响应：解析 `application/msgpack` 请求体，或将 T 序列化为 MessagePack 响应
+///
+/// 同时接受 `application/x-msgpack` 与 `application/vnd.msgpack`，请求体大小受 `BodyLimit` 限制。
+/// 响应使用带字段名的结构编码，便于与其他语言的客户端互通。
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use silent::Result;
+/// use silent::extractor::MsgPack;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Event {
+///     id: u64,
+/// }
+///
+/// async fn handler(MsgPack(event): MsgPack<Event>) -> Result<MsgPack<Event>> {
+///     Ok(MsgPack(event))
+/// }
+/// ```
+pub struct MsgPack<T>(pub T);
+
+const MSGPACK_SUBTYPES: [&str; 3] = ["msgpack", "x-msgpack", "vnd.msgpack"];
+
+#[async_trait]
+impl<T> FromRequest for MsgPack<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .content_type()
+            .ok_or(SilentError::ContentTypeMissingError)?;
+        if content_type.type_() != mime::APPLICATION
+            || !MSGPACK_SUBTYPES.contains(&content_type.subtype().as_str())
+        {
+            return Err(SilentError::ContentTypeError);
+        }
+
+        let bytes = read_body_limited(req).await?;
+        if bytes.is_empty() {
+            return Err(SilentError::BodyEmpty);
+        }
+        let value = rmp_serde::from_slice(&bytes).map_err(|e| {
+            SilentError::business_error(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("msgpack deserialize error: {e}"),
+            )
+        })?;
+        Ok(MsgPack(value))
+    }
+}
+
+impl<T: Serialize> From<MsgPack<T>> for Response {
+    fn from(MsgPack(value): MsgPack<T>) -> Self {
+        match rmp_serde::to_vec_named(&value) {
+            Ok(body) => Response::empty()
+                .with_header(
+                    CONTENT_TYPE,
+                    HeaderValue::from_static("application/msgpack"),
+                )
+                .with_body(full(body)),
+            Err(e) => SilentError::business_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("msgpack serialize error: {e}"),
+            )
+            .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::req_body::ReqBody;
+    use crate::core::res_body::ResBody;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Event {
+        id: u64,
+        tags: Vec<String>,
+    }
+
+    fn event() -> Event {
+        Event {
+            id: 9,
+            tags: vec!["a".into(), "b".into()],
+        }
+    }
+
+    fn msgpack_request(content_type: &'static str, body: Vec<u8>) -> Request {
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        req.replace_body(ReqBody::Once(body.into()));
+        req
+    }
+
+    // ==================== 萃取测试 ====================
+
+    #[tokio::test]
+    async fn test_msgpack_extract() {
+        for content_type in [
+            "application/msgpack",
+            "application/x-msgpack",
+            "application/vnd.msgpack",
+        ] {
+            let body = rmp_serde::to_vec_named(&event()).unwrap();
+            let mut req = msgpack_request(content_type, body);
+            let MsgPack(value): MsgPack<Event> = MsgPack::from_request(&mut req).await.unwrap();
+            assert_eq!(value, event());
+        }
+
+        // 兼容紧凑（数组）编码
+        let body = rmp_serde::to_vec(&event()).unwrap();
+        let mut req = msgpack_request("application/msgpack", body);
+        let MsgPack(value): MsgPack<Event> = MsgPack::from_request(&mut req).await.unwrap();
+        assert_eq!(value, event());
+    }
+
+    #[tokio::test]
+    async fn test_msgpack_extract_rejections() {
+        let mut req = msgpack_request("application/json", b"{}".to_vec());
+        assert!(matches!(
+            MsgPack::<Event>::from_request(&mut req).await,
+            Err(SilentError::ContentTypeError)
+        ));
+
+        let mut req = msgpack_request("application/msgpack", Vec::new());
+        assert!(matches!(
+            MsgPack::<Event>::from_request(&mut req).await,
+            Err(SilentError::BodyEmpty)
+        ));
+
+        let mut req = msgpack_request("application/msgpack", vec![0xc1]);
+        let err = MsgPack::<Event>::from_request(&mut req)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    // ==================== 响应测试 ====================
+
+    #[test]
+    fn test_msgpack_response() {
+        let res: Response = MsgPack(event()).into();
+        assert_eq!(
+            res.headers().get(CONTENT_TYPE).unwrap(),
+            "application/msgpack"
+        );
+        match res.body {
+            ResBody::Once(bytes) => {
+                let decoded: Event = rmp_serde::from_slice(&bytes).unwrap();
+                assert_eq!(decoded, event());
+            }
+            _ => panic!("unexpected body"),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! 强类型 multipart 表单萃取
+//!
+//! 通过 `#[derive(TypedMultipart)]` 将 multipart 字段映射到结构体成员，
+//! 并由 `TypedMultipart<T>` 萃取器在处理函数中直接获得解析结果。
+
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+
+use super::FromRequest;
+use super::types::{Multipart, MultipartField};
+use crate::core::form::FilePart;
+use crate::{Request, SilentError, StatusCode};
+
+/// 上传到临时文件的 multipart 字段，离开作用域后自动删除
+pub type TempFile = FilePart;
+
+/// TypedMultipart 萃取器：将 multipart/form-data 解析为实现了 `FromMultipart` 的结构体
+///
+/// ```rust
+/// use bytes::Bytes;
+/// use silent::Result;
+/// use silent::extractor::{TempFile, TypedMultipart};
+///
+/// #[derive(TypedMultipart)]
+/// struct Upload {
+///     title: String,
+///     #[multipart(rename = "avatar", limit = "2MB")]
+///     file: TempFile,
+///     #[multipart(limit = 1024)]
+///     note: Option<Bytes>,
+///     tags: Vec<String>,
+/// }
+///
+/// async fn upload(TypedMultipart(form): TypedMultipart<Upload>) -> Result<String> {
+///     Ok(format!("{}: {} bytes", form.title, form.file.size()))
+/// }
+/// ```
+pub struct TypedMultipart<T>(pub T);
+
+/// 从 multipart 表单整体构造类型，通常由 `#[derive(TypedMultipart)]` 生成
+#[async_trait]
+pub trait FromMultipart: Sized {
+    async fn from_multipart(multipart: Multipart) -> Result<Self, SilentError>;
+}
+
+/// 从单个 multipart 字段构造值
+///
+/// `limit` 为字段大小上限（字节），超出时返回 413 错误。
+#[async_trait]
+pub trait FromMultipartField: Sized {
+    async fn from_multipart_field(
+        field: MultipartField<'static>,
+        name: &str,
+        limit: Option<usize>,
+    ) -> Result<Self, SilentError>;
+}
+
+#[async_trait]
+impl<T> FromRequest for TypedMultipart<T>
+where
+    T: FromMultipart + Send + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let multipart = Multipart::from_request(req).await?;
+        Ok(TypedMultipart(T::from_multipart(multipart).await?))
+    }
+}
+
+/// 缺少必填字段时的错误
+pub fn multipart_missing_field(name: &str) -> SilentError {
+    SilentError::business_error(
+        StatusCode::BAD_REQUEST,
+        format!("missing multipart field `{name}`"),
+    )
+}
+
+/// 字段超出大小上限时的错误
+pub(crate) fn multipart_field_too_large(name: &str, limit: usize) -> SilentError {
+    SilentError::business_error(
+        StatusCode::PAYLOAD_TOO_LARGE,
+        format!("multipart field `{name}` exceeds limit of {limit} bytes"),
+    )
+}
+
+fn invalid_field(name: &str, reason: impl std::fmt::Display) -> SilentError {
+    SilentError::business_error(
+        StatusCode::BAD_REQUEST,
+        format!("invalid multipart field `{name}`: {reason}"),
+    )
+}
+
+/// 按块读取字段内容，超出上限时立即终止
+async fn read_limited(
+    mut field: MultipartField<'static>,
+    name: &str,
+    limit: Option<usize>,
+) -> Result<Bytes, SilentError> {
+    let mut buf = BytesMut::new();
+    while let Some(chunk) = field.chunk().await? {
+        if let Some(limit) = limit
+            && buf.len() + chunk.len() > limit
+        {
+            return Err(multipart_field_too_large(name, limit));
+        }
+        buf.extend_from_slice(&chunk);
+    }
+    Ok(buf.freeze())
+}
+
+#[async_trait]
+impl FromMultipartField for Bytes {
+    async fn from_multipart_field(
+        field: MultipartField<'static>,
+        name: &str,
+        limit: Option<usize>,
+    ) -> Result<Self, SilentError> {
+        read_limited(field, name, limit).await
+    }
+}
+
+#[async_trait]
+impl FromMultipartField for Vec<u8> {
+    async fn from_multipart_field(
+        field: MultipartField<'static>,
+        name: &str,
+        limit: Option<usize>,
+    ) -> Result<Self, SilentError> {
+        Ok(read_limited(field, name, limit).await?.to_vec())
+    }
+}
+
+#[async_trait]
+impl FromMultipartField for String {
+    async fn from_multipart_field(
+        field: MultipartField<'static>,
+        name: &str,
+        limit: Option<usize>,
+    ) -> Result<Self, SilentError> {
+        let bytes = read_limited(field, name, limit).await?;
+        String::from_utf8(bytes.to_vec()).map_err(|e| invalid_field(name, e))
+    }
+}
+
+#[async_trait]
+impl FromMultipartField for FilePart {
+    async fn from_multipart_field(
+        mut field: MultipartField<'static>,
+        _name: &str,
+        limit: Option<usize>,
+    ) -> Result<Self, SilentError> {
+        FilePart::create_with_limit(&mut field, limit).await
+    }
+}
+
+macro_rules! impl_from_multipart_field_parse {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            #[async_trait]
+            impl FromMultipartField for $ty {
+                async fn from_multipart_field(
+                    field: MultipartField<'static>,
+                    name: &str,
+                    limit: Option<usize>,
+                ) -> Result<Self, SilentError> {
+                    let text = String::from_multipart_field(field, name, limit).await?;
+                    text.trim().parse().map_err(|e| invalid_field(name, e))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_multipart_field_parse!(
+    bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
@@ -0,0 +1,271 @@
+//! NDJSON（换行分隔 JSON）流式萃取器
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_trait::async_trait;
+use bytes::BytesMut;
+use futures_util::{Stream, StreamExt, stream};
+use serde::de::DeserializeOwned;
+
+use super::{BodyLimit, FromRequest};
+use crate::core::req_body::ReqBody;
+use crate::{Request, SilentError, StatusCode};
+
+/// NdJsonStream 萃取器：逐行解析 `application/x-ndjson` 请求体
+///
+/// 请求体按块读取，每读到一个完整的行即反序列化为 `T` 并产出，整体载荷不会缓存在内存中，
+/// 适合批量导入等大体积请求。空行会被跳过；单行解析失败时产出 `422` 错误并继续读取后续行，
+/// 单行长度超过 [`BodyLimit`] 时产出 `413` 错误并结束。
+///
+/// ```rust
+/// use futures_util::StreamExt;
+/// use serde::Deserialize;
+/// use silent::Result;
+/// use silent::extractor::NdJsonStream;
+///
+/// #[derive(Deserialize)]
+/// struct Event {
+///     id: u64,
+/// }
+///
+/// async fn ingest(mut events: NdJsonStream<Event>) -> Result<String> {
+///     let mut count = 0;
+///     while let Some(event) = events.next().await {
+///         let _id = event?.id;
+///         count += 1;
+///     }
+///     Ok(format!("ingested {count}"))
+/// }
+/// ```
+pub struct NdJsonStream<T> {
+    inner: Pin<Box<dyn Stream<Item = crate::Result<T>> + Send>>,
+}
+
+impl<T> fmt::Debug for NdJsonStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NdJsonStream").finish_non_exhaustive()
+    }
+}
+
+impl<T> Stream for NdJsonStream<T> {
+    type Item = crate::Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl<T> NdJsonStream<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    /// 从请求体构建流，`max_line` 为单行字节数上限
+    pub(crate) fn new(body: ReqBody, max_line: usize) -> Self {
+        let state = LineReader::<T> {
+            body,
+            buf: BytesMut::new(),
+            scanned: 0,
+            line: 0,
+            max_line,
+            done: false,
+            _marker: PhantomData,
+        };
+        Self {
+            inner: Box::pin(stream::unfold(state, |mut state| async move {
+                let item = state.next_item().await?;
+                Some((item, state))
+            })),
+        }
+    }
+}
+
+/// 是否为 NDJSON / JSON Lines 内容类型
+fn is_ndjson(content_type: &mime::Mime) -> bool {
+    content_type.type_() == mime::APPLICATION
+        && matches!(
+            content_type.subtype().as_str(),
+            "x-ndjson" | "ndjson" | "jsonl" | "x-jsonlines" | "jsonlines"
+        )
+}
+
+#[async_trait]
+impl<T> FromRequest for NdJsonStream<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .content_type()
+            .ok_or(SilentError::ContentTypeMissingError)?;
+        if !is_ndjson(&content_type) {
+            return Err(SilentError::ContentTypeError);
+        }
+        let max_line = req.get_state::<BodyLimit>().copied().unwrap_or_default().0;
+        Ok(NdJsonStream::new(req.take_body(), max_line))
+    }
+}
+
+/// 按行切分请求体的内部状态
+struct LineReader<T> {
+    body: ReqBody,
+    buf: BytesMut,
+    /// `buf` 中已确认不含换行符的前缀长度，避免长行被重复扫描
+    scanned: usize,
+    /// 已消费的行号（从 1 开始计数）
+    line: usize,
+    max_line: usize,
+    done: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> LineReader<T> {
+    async fn next_item(&mut self) -> Option<crate::Result<T>> {
+        loop {
+            if let Some(pos) = memchr::memchr(b'\n', &self.buf[self.scanned..]) {
+                let pos = self.scanned + pos;
+                let line = self.buf.split_to(pos + 1);
+                self.scanned = 0;
+                self.line += 1;
+                match self.parse(&line[..pos]) {
+                    Some(item) => return Some(item),
+                    None => continue,
+                }
+            }
+            if self.done {
+                // 末尾没有换行符的最后一行
+                if self.buf.is_empty() {
+                    return None;
+                }
+                let line = self.buf.split();
+                self.scanned = 0;
+                self.line += 1;
+                return self.parse(&line);
+            }
+            self.scanned = self.buf.len();
+            if self.buf.len() > self.max_line {
+                self.done = true;
+                self.buf.clear();
+                self.scanned = 0;
+                return Some(Err(SilentError::business_error(
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    format!(
+                        "ndjson line {} exceeds limit of {} bytes",
+                        self.line + 1,
+                        self.max_line
+                    ),
+                )));
+            }
+            match self.body.next().await {
+                Some(Ok(chunk)) => self.buf.extend_from_slice(&chunk),
+                Some(Err(e)) => {
+                    self.done = true;
+                    self.buf.clear();
+                    self.scanned = 0;
+                    return Some(Err(e.into()));
+                }
+                None => self.done = true,
+            }
+        }
+    }
+
+    /// 解析单行，空行返回 `None`
+    fn parse(&self, line: &[u8]) -> Option<crate::Result<T>> {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.iter().all(u8::is_ascii_whitespace) {
+            return None;
+        }
+        Some(serde_json::from_slice(line).map_err(|e| {
+            SilentError::business_error(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("ndjson line {}: {e}", self.line),
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::HeaderValue;
+    use http::header::CONTENT_TYPE;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Event {
+        id: u64,
+    }
+
+    fn ndjson_request(chunks: Vec<&'static str>) -> Request {
+        let mut req = Request::empty();
+        req.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-ndjson"),
+        );
+        let chunks = chunks
+            .into_iter()
+            .map(|c| Ok::<_, std::io::Error>(Bytes::from_static(c.as_bytes())));
+        req.replace_body(ReqBody::from_stream(stream::iter(chunks)));
+        req
+    }
+
+    // ==================== 解析测试 ====================
+
+    #[tokio::test]
+    async fn test_ndjson_lines_across_chunks() {
+        // 行被切分在多个数据块中，末尾行没有换行符
+        let mut req = ndjson_request(vec!["{\"id\":1}\n{\"i", "d\":2}\r\n\n", "{\"id\":3}"]);
+        let events: NdJsonStream<Event> = NdJsonStream::from_request(&mut req).await.unwrap();
+        let ids: Vec<u64> = events.map(|e| e.unwrap().id).collect().await;
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_invalid_line_continues() {
+        let mut req = ndjson_request(vec!["{\"id\":1}\nnot json\n{\"id\":3}\n"]);
+        let events: NdJsonStream<Event> = NdJsonStream::from_request(&mut req).await.unwrap();
+        let items: Vec<_> = events.collect().await;
+        assert_eq!(items.len(), 3);
+        let err = items[1].as_ref().err().unwrap();
+        assert_eq!(err.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(err.message().contains("line 2"));
+        assert_eq!(items[2].as_ref().unwrap(), &Event { id: 3 });
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_line_limit() {
+        let mut req = ndjson_request(vec!["{\"id\":1}\n", "{\"id\":", "12345678", "9}\n"]);
+        req.state_mut().insert(BodyLimit(8));
+        let events: NdJsonStream<Event> = NdJsonStream::from_request(&mut req).await.unwrap();
+        let items: Vec<_> = events.collect().await;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].as_ref().unwrap(), &Event { id: 1 });
+        assert_eq!(
+            items[1].as_ref().err().unwrap().status(),
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+    }
+
+    // ==================== 拒绝测试 ====================
+
+    #[tokio::test]
+    async fn test_ndjson_content_type_rejection() {
+        let mut req = Request::empty();
+        assert!(matches!(
+            NdJsonStream::<Event>::from_request(&mut req).await,
+            Err(SilentError::ContentTypeMissingError)
+        ));
+
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        assert!(matches!(
+            NdJsonStream::<Event>::from_request(&mut req).await,
+            Err(SilentError::ContentTypeError)
+        ));
+    }
+}
@@ -0,0 +1,389 @@
+//! 基于 `Accept` 请求头的内容协商
+
+use async_trait::async_trait;
+use http::HeaderValue;
+use http::header::{ACCEPT, CONTENT_TYPE, VARY};
+use mime::Mime;
+use serde::Serialize;
+
+use super::FromRequestParts;
+use super::from_request::from_request_via_parts;
+use crate::core::res_body::full;
+use crate::{Request, Response, SilentError, StatusCode};
+
+/// 可协商的响应格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResponseFormat {
+    /// `application/json`
+    Json,
+    /// `application/msgpack`（需启用 `msgpack` 特性）
+    #[cfg(feature = "msgpack")]
+    MsgPack,
+    /// `application/xml`（需启用 `xml` 特性）
+    #[cfg(feature = "xml")]
+    Xml,
+}
+
+impl ResponseFormat {
+    /// 当前启用的全部格式
+    pub const ALL: &'static [ResponseFormat] = &[
+        ResponseFormat::Json,
+        #[cfg(feature = "msgpack")]
+        ResponseFormat::MsgPack,
+        #[cfg(feature = "xml")]
+        ResponseFormat::Xml,
+    ];
+
+    /// 判断媒体类型（不含通配符）是否对应该格式
+    fn matches(self, mime: &Mime) -> bool {
+        let (ty, subtype, suffix) = (mime.type_(), mime.subtype(), mime.suffix());
+        match self {
+            ResponseFormat::Json => {
+                ty == mime::APPLICATION && (subtype == mime::JSON || suffix == Some(mime::JSON))
+            }
+            #[cfg(feature = "msgpack")]
+            ResponseFormat::MsgPack => {
+                ty == mime::APPLICATION
+                    && matches!(subtype.as_str(), "msgpack" | "x-msgpack" | "vnd.msgpack")
+            }
+            #[cfg(feature = "xml")]
+            ResponseFormat::Xml => {
+                (ty == mime::APPLICATION || ty == mime::TEXT)
+                    && (subtype == mime::XML || suffix == Some(mime::XML))
+            }
+        }
+    }
+
+    /// 判断主类型是否可能对应该格式，用于匹配 `type/*` 形式的媒体范围
+    fn matches_type(self, ty: &mime::Name<'_>) -> bool {
+        match self {
+            #[cfg(feature = "xml")]
+            ResponseFormat::Xml => *ty == mime::APPLICATION || *ty == mime::TEXT,
+            _ => *ty == mime::APPLICATION,
+        }
+    }
+}
+
+/// 内容协商配置，通过 `with_state` 注入
+///
+/// - `default`：`Accept` 缺失或为 `*/*` 时使用的格式，默认 JSON
+/// - `formats`：服务端可提供的格式，排在前面的在质量值相同时优先
+/// - `strict`：没有可接受的格式时返回 `406 Not Acceptable`（默认），关闭后回退到默认格式
+///
+/// ```rust
+/// use silent::extractor::{NegotiateConfig, ResponseFormat};
+/// use silent::prelude::*;
+///
+/// let route = Route::new("users").with_state(
+///     NegotiateConfig::default()
+///         .default_format(ResponseFormat::Json)
+///         .strict(false),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct NegotiateConfig {
+    default: ResponseFormat,
+    formats: Vec<ResponseFormat>,
+    strict: bool,
+}
+
+impl Default for NegotiateConfig {
+    fn default() -> Self {
+        Self {
+            default: ResponseFormat::Json,
+            formats: ResponseFormat::ALL.to_vec(),
+            strict: true,
+        }
+    }
+}
+
+impl NegotiateConfig {
+    /// 设置默认格式
+    pub fn default_format(mut self, format: ResponseFormat) -> Self {
+        self.default = format;
+        self
+    }
+
+    /// 设置服务端可提供的格式
+    pub fn formats(mut self, formats: &[ResponseFormat]) -> Self {
+        self.formats = formats.to_vec();
+        self
+    }
+
+    /// 设置没有可接受格式时是否返回 `406`
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// 按 `Accept` 中的媒体范围选择格式，没有可接受的格式时返回 `None`
+    fn select(&self, ranges: &[MediaRange]) -> Option<ResponseFormat> {
+        if ranges.is_empty() {
+            return Some(self.default);
+        }
+        // 默认格式优先参与比较，质量值相同时胜出
+        let candidates = std::iter::once(self.default)
+            .filter(|f| self.formats.contains(f))
+            .chain(self.formats.iter().copied().filter(|f| *f != self.default));
+        let mut best: Option<(ResponseFormat, f32)> = None;
+        for format in candidates {
+            let q = quality(ranges, format);
+            if q > 0.0 && best.is_none_or(|(_, best_q)| q > best_q) {
+                best = Some((format, q));
+            }
+        }
+        best.map(|(format, _)| format)
+    }
+}
+
+/// `Accept` 中的一个媒体范围
+#[derive(Clone, Debug)]
+struct MediaRange {
+    mime: Mime,
+    q: f32,
+}
+
+impl MediaRange {
+    /// 匹配的具体程度：2 为完全匹配，1 为 `type/*`，0 为 `*/*`
+    fn specificity(&self, format: ResponseFormat) -> Option<u8> {
+        if self.mime.type_() == mime::STAR {
+            Some(0)
+        } else if self.mime.subtype() == mime::STAR {
+            format.matches_type(&self.mime.type_()).then_some(1)
+        } else {
+            format.matches(&self.mime).then_some(2)
+        }
+    }
+}
+
+/// 取最具体的匹配范围的质量值（RFC 9110 §12.5.1）
+fn quality(ranges: &[MediaRange], format: ResponseFormat) -> f32 {
+    ranges
+        .iter()
+        .filter_map(|range| range.specificity(format).map(|s| (s, range.q)))
+        .max_by_key(|(s, _)| *s)
+        .map_or(0.0, |(_, q)| q)
+}
+
+fn parse_accept(req: &Request) -> Vec<MediaRange> {
+    req.headers()
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|item| {
+            let mime = item.trim().parse::<Mime>().ok()?;
+            let q = mime
+                .get_param("q")
+                .map_or(Some(1.0), |q| q.as_str().parse::<f32>().ok())?
+                .clamp(0.0, 1.0);
+            Some(MediaRange { mime, q })
+        })
+        .collect()
+}
+
+/// Accept 萃取器：解析 `Accept` 请求头并按 [`NegotiateConfig`] 选出响应格式
+///
+/// 未注入配置时使用 [`NegotiateConfig::default`]。严格模式下没有可接受的格式时直接返回
+/// `406 Not Acceptable`，处理函数不会被调用。
+///
+/// ```rust
+/// use serde::Serialize;
+/// use silent::Result;
+/// use silent::extractor::{Accept, Negotiate};
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     id: u64,
+/// }
+///
+/// async fn user(accept: Accept) -> Result<Negotiate<User>> {
+///     Ok(accept.negotiate(User { id: 1 }))
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Accept {
+    ranges: Vec<MediaRange>,
+    format: ResponseFormat,
+}
+
+impl Accept {
+    /// 协商得到的响应格式
+    pub fn format(&self) -> ResponseFormat {
+        self.format
+    }
+
+    /// 客户端是否接受指定的媒体类型（按质量值大于 0 判断）
+    pub fn accepts(&self, mime: &Mime) -> bool {
+        if self.ranges.is_empty() {
+            return true;
+        }
+        self.ranges
+            .iter()
+            .filter(|range| {
+                let (ty, subtype) = (range.mime.type_(), range.mime.subtype());
+                ty == mime::STAR
+                    || (ty == mime.type_() && (subtype == mime::STAR || subtype == mime.subtype()))
+            })
+            .max_by_key(|range| {
+                (range.mime.type_() != mime::STAR) as u8
+                    + (range.mime.subtype() != mime::STAR) as u8
+            })
+            .is_some_and(|range| range.q > 0.0)
+    }
+
+    /// 以协商得到的格式包装响应数据
+    pub fn negotiate<T>(&self, value: T) -> Negotiate<T> {
+        Negotiate::new(self.format, value)
+    }
+}
+
+#[async_trait]
+impl FromRequestParts for Accept {
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let config = req
+            .get_state::<NegotiateConfig>()
+            .cloned()
+            .unwrap_or_default();
+        let ranges = parse_accept(req);
+        let format = match config.select(&ranges) {
+            Some(format) => format,
+            None if config.strict => {
+                return Err(SilentError::business_error(
+                    StatusCode::NOT_ACCEPTABLE,
+                    "no acceptable representation",
+                ));
+            }
+            None => config.default,
+        };
+        Ok(Accept { ranges, format })
+    }
+}
+
+from_request_via_parts!([] Accept);
+
+/// Negotiate 响应：按协商得到的格式序列化 T，并附带 `Vary: Accept`
+///
+/// 通常由 [`Accept::negotiate`] 构造。
+pub struct Negotiate<T> {
+    format: ResponseFormat,
+    value: T,
+}
+
+impl<T> Negotiate<T> {
+    /// 以指定格式包装响应数据
+    pub fn new(format: ResponseFormat, value: T) -> Self {
+        Self { format, value }
+    }
+
+    /// 响应格式
+    pub fn format(&self) -> ResponseFormat {
+        self.format
+    }
+}
+
+impl<T: Serialize> From<Negotiate<T>> for Response {
+    fn from(Negotiate { format, value }: Negotiate<T>) -> Self {
+        let mut res: Response = match format {
+            ResponseFormat::Json => match serde_json::to_vec(&value) {
+                Ok(body) => Response::empty()
+                    .with_header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+                    .with_body(full(body)),
+                Err(e) => SilentError::business_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("json serialize error: {e}"),
+                )
+                .into(),
+            },
+            #[cfg(feature = "msgpack")]
+            ResponseFormat::MsgPack => super::MsgPack(value).into(),
+            #[cfg(feature = "xml")]
+            ResponseFormat::Xml => super::Xml(value).into(),
+        };
+        res.headers_mut()
+            .append(VARY, HeaderValue::from_static("accept"));
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::FromRequest;
+
+    fn request(accept: Option<&'static str>, config: Option<NegotiateConfig>) -> Request {
+        let mut req = Request::empty();
+        if let Some(accept) = accept {
+            req.headers_mut()
+                .insert(ACCEPT, HeaderValue::from_static(accept));
+        }
+        if let Some(config) = config {
+            req.state_mut().insert(config);
+        }
+        req
+    }
+
+    async fn format(accept: Option<&'static str>) -> Result<ResponseFormat, SilentError> {
+        let mut req = request(accept, None);
+        Accept::from_request(&mut req).await.map(|a| a.format())
+    }
+
+    // ==================== 协商测试 ====================
+
+    #[tokio::test]
+    async fn test_accept_defaults_to_json() {
+        for accept in [
+            None,
+            Some("*/*"),
+            Some("application/*"),
+            Some("text/html, */*;q=0.1"),
+        ] {
+            assert_eq!(format(accept).await.unwrap(), ResponseFormat::Json);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_accept_not_acceptable() {
+        let err = format(Some("text/html")).await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::NOT_ACCEPTABLE);
+
+        let config = NegotiateConfig::default().formats(&[ResponseFormat::Json]);
+        let mut req = request(Some("application/json;q=0, */*"), Some(config));
+        let err = Accept::from_request(&mut req).await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::NOT_ACCEPTABLE);
+
+        let config = NegotiateConfig::default().strict(false);
+        let mut req = request(Some("text/html"), Some(config));
+        let accept = Accept::from_request(&mut req).await.unwrap();
+        assert_eq!(accept.format(), ResponseFormat::Json);
+        assert!(!accept.accepts(&mime::APPLICATION_JSON));
+        assert!(accept.accepts(&mime::TEXT_HTML));
+    }
+
+    #[cfg(all(feature = "msgpack", feature = "xml"))]
+    #[tokio::test]
+    async fn test_accept_quality_and_default() {
+        assert_eq!(
+            format(Some("application/json;q=0.5, application/msgpack"))
+                .await
+                .unwrap(),
+            ResponseFormat::MsgPack
+        );
+        assert_eq!(format(Some("text/xml")).await.unwrap(), ResponseFormat::Xml);
+
+        let config = NegotiateConfig::default().default_format(ResponseFormat::Xml);
+        let mut req = request(Some("*/*"), Some(config));
+        let accept = Accept::from_request(&mut req).await.unwrap();
+        assert_eq!(accept.format(), ResponseFormat::Xml);
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_response() {
+        let res: Response = Negotiate::new(ResponseFormat::Json, vec![1, 2]).into();
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+        assert_eq!(res.headers().get(VARY).unwrap(), "accept");
+    }
+}
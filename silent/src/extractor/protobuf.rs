@@ -0,0 +1,158 @@
+//! Protobuf 萃取器与响应
+
+use async_trait::async_trait;
+use http::HeaderValue;
+use http::header::CONTENT_TYPE;
+
+use super::FromRequest;
+use super::from_request::read_body_limited;
+use crate::core::res_body::full;
+use crate::{Request, Response, SilentError, StatusCode};
+
+/// Protobuf 萃取器/响应：解析 `application/x-protobuf` 请求体，或将消息编码为 protobuf 响应
+///
+/// 适用于非 gRPC 的 REST 接口直接收发 protobuf 消息，请求体大小受 `BodyLimit` 限制。
+///
+/// ```rust
+/// use silent::Result;
+/// use silent::extractor::Protobuf;
+///
+/// #[derive(Clone, PartialEq, prost::Message)]
+/// struct Ping {
+///     #[prost(string, tag = "1")]
+///     message: String,
+/// }
+///
+/// async fn handler(Protobuf(ping): Protobuf<Ping>) -> Result<Protobuf<Ping>> {
+///     Ok(Protobuf(ping))
+/// }
+/// ```
+pub struct Protobuf<T>(pub T);
+
+/// 判断内容类型是否为 protobuf
+fn is_protobuf(content_type: &mime::Mime) -> bool {
+    content_type.type_() == mime::APPLICATION
+        && matches!(
+            content_type.subtype().as_str(),
+            "x-protobuf" | "protobuf" | "vnd.google.protobuf"
+        )
+}
+
+#[async_trait]
+impl<T> FromRequest for Protobuf<T>
+where
+    T: prost::Message + Default + Send + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .content_type()
+            .ok_or(SilentError::ContentTypeMissingError)?;
+        if !is_protobuf(&content_type) {
+            return Err(SilentError::ContentTypeError);
+        }
+
+        // protobuf 中空消息是合法编码，不视为空请求体错误
+        let bytes = read_body_limited(req).await?;
+        let value = T::decode(bytes).map_err(|e| {
+            SilentError::business_error(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("protobuf decode error: {e}"),
+            )
+        })?;
+        Ok(Protobuf(value))
+    }
+}
+
+impl<T: prost::Message> From<Protobuf<T>> for Response {
+    fn from(Protobuf(value): Protobuf<T>) -> Self {
+        Response::empty()
+            .with_header(
+                CONTENT_TYPE,
+                HeaderValue::from_static("application/x-protobuf"),
+            )
+            .with_body(full(value.encode_to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::req_body::ReqBody;
+    use crate::core::res_body::ResBody;
+    use prost::Message;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Ping {
+        #[prost(string, tag = "1")]
+        message: String,
+        #[prost(uint32, tag = "2")]
+        seq: u32,
+    }
+
+    fn ping() -> Ping {
+        Ping {
+            message: "hello".to_string(),
+            seq: 7,
+        }
+    }
+
+    fn protobuf_request(content_type: &'static str, body: Vec<u8>) -> Request {
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        req.replace_body(ReqBody::Once(body.into()));
+        req
+    }
+
+    // ==================== 萃取测试 ====================
+
+    #[tokio::test]
+    async fn test_protobuf_extract() {
+        for content_type in ["application/x-protobuf", "application/protobuf"] {
+            let mut req = protobuf_request(content_type, ping().encode_to_vec());
+            let Protobuf(value): Protobuf<Ping> = Protobuf::from_request(&mut req).await.unwrap();
+            assert_eq!(value, ping());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_protobuf_extract_empty_message() {
+        let mut req = protobuf_request("application/x-protobuf", Vec::new());
+        let Protobuf(value): Protobuf<Ping> = Protobuf::from_request(&mut req).await.unwrap();
+        assert_eq!(value, Ping::default());
+    }
+
+    #[tokio::test]
+    async fn test_protobuf_extract_rejections() {
+        let mut req = protobuf_request("application/json", b"{}".to_vec());
+        assert!(matches!(
+            Protobuf::<Ping>::from_request(&mut req).await,
+            Err(SilentError::ContentTypeError)
+        ));
+
+        // 截断的字符串字段
+        let mut req = protobuf_request("application/x-protobuf", vec![0x0a, 0x05, b'h']);
+        let err = Protobuf::<Ping>::from_request(&mut req)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    // ==================== 响应测试 ====================
+
+    #[test]
+    fn test_protobuf_response() {
+        let res: Response = Protobuf(ping()).into();
+        assert_eq!(
+            res.headers().get(CONTENT_TYPE).unwrap(),
+            "application/x-protobuf"
+        );
+        match res.body {
+            ResBody::Once(bytes) => assert_eq!(Ping::decode(bytes).unwrap(), ping()),
+            _ => panic!("unexpected body"),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer};
+
+/// 规范化且不会越出根目录的相对路径，用于萃取 `<path:**>` 等通配路径参数
+///
+/// 解析时先对路径做百分号解码，再去除空段与 `.` 段；含 `..`、反斜杠、空字符、
+/// Windows 盘符或解码后含 `/` 的段会被拒绝，因此可以直接与静态目录拼接，无需调用方自行校验。
+///
+/// ```rust
+/// use silent::extractor::{Path, RelativePath};
+/// use silent::Result;
+///
+/// // 路由：Route::new("files/<path:**>").get(download)
+/// async fn download(Path(path): Path<RelativePath>) -> Result<String> {
+///     let file = std::path::Path::new("/srv/files").join(path.to_path_buf());
+///     Ok(file.display().to_string())
+/// }
+///
+/// let path = RelativePath::new("docs/./read%20me.md").unwrap();
+/// assert_eq!(path.as_str(), "docs/read me.md");
+/// assert!(RelativePath::new("docs/../../etc/passwd").is_none());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RelativePath(String);
+
+impl RelativePath {
+    /// 解析并规范化路径，路径不安全时返回 `None`
+    pub fn new(raw: &str) -> Option<Self> {
+        let mut segments = Vec::new();
+        for segment in raw.split('/') {
+            let segment = percent_decode(segment)?;
+            match segment.as_str() {
+                "" | "." => continue,
+                ".." => return None,
+                _ => {}
+            }
+            if segment.contains(['/', '\\', '\0']) || is_drive(&segment) {
+                return None;
+            }
+            segments.push(segment);
+        }
+        Some(Self(segments.join("/")))
+    }
+
+    /// 以 `/` 分隔的规范化路径，不含首尾的 `/`
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 路径为空，即请求的是通配参数所在的目录本身
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// 依次返回各个路径段
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.0.split('/').filter(|segment| !segment.is_empty())
+    }
+
+    /// 转换为当前平台的相对路径
+    pub fn to_path_buf(&self) -> std::path::PathBuf {
+        self.segments().collect()
+    }
+}
+
+/// Windows 盘符，如 `C:`
+fn is_drive(segment: &str) -> bool {
+    let bytes = segment.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+fn percent_decode(segment: &str) -> Option<String> {
+    if !segment.contains('%') {
+        return Some(segment.to_string());
+    }
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = segment.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+impl fmt::Display for RelativePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for RelativePath {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for RelativePath {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = std::borrow::Cow::<str>::deserialize(deserializer)?;
+        RelativePath::new(&raw)
+            .ok_or_else(|| serde::de::Error::custom(format!("unsafe relative path `{raw}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::{FromRequest, Path};
+    use crate::prelude::*;
+    use http_body_util::BodyExt;
+
+    #[test]
+    fn test_relative_path_normalize() {
+        let path = RelativePath::new("/a//./b%20c/").unwrap();
+        assert_eq!(path.as_str(), "a/b c");
+        assert_eq!(path.segments().collect::<Vec<_>>(), ["a", "b c"]);
+        assert!(RelativePath::new("").unwrap().is_empty());
+
+        for unsafe_path in [
+            "../a",
+            "a/%2e%2e/b",
+            "a%2Fb",
+            "a\\b",
+            "C:/windows",
+            "%zz",
+            "%ff",
+        ] {
+            assert!(RelativePath::new(unsafe_path).is_none(), "{unsafe_path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_path_wildcard_extract() {
+        async fn segments(mut req: Request) -> Result<String> {
+            let Path(segments) = Path::<Vec<String>>::from_request(&mut req).await?;
+            Ok(segments.join(","))
+        }
+        async fn relative(mut req: Request) -> Result<String> {
+            let Path(path) = Path::<RelativePath>::from_request(&mut req).await?;
+            Ok(path.to_string())
+        }
+
+        let tree = Route::new("")
+            .append(Route::new("segments/<rest:**>").get(segments))
+            .append(Route::new("relative/<rest:**>").get(relative))
+            .convert_to_route_tree();
+
+        let call = |path: &'static str| {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            tree.call(req)
+        };
+        for (path, expected) in [
+            ("/segments/a/b/c", "a,b,c"),
+            ("/relative/docs/./a%20b.md", "docs/a b.md"),
+        ] {
+            let res = call(path).await.unwrap();
+            let body = res.body.collect().await.unwrap().to_bytes();
+            assert_eq!(&body[..], expected.as_bytes());
+        }
+        assert!(call("/relative/docs/%2e%2e/%2e%2e/etc").await.is_err());
+    }
+}
@@ -0,0 +1,226 @@
+//! Session 萃取器
+
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::FromRequestParts;
+use super::from_request::from_request_via_parts;
+use crate::{Request, SilentError, StatusCode};
+
+/// Session 萃取器：当前请求的会话
+///
+/// 由 Session 中间件加载，与请求扩展中的会话共享数据，写入的内容在响应阶段自动保存。
+///
+/// ```rust
+/// use silent::Result;
+/// use silent::extractor::Session;
+///
+/// async fn counter(mut session: Session) -> Result<String> {
+///     let count = session.get::<u32>("count").unwrap_or(0) + 1;
+///     session.insert("count", count)?;
+///     Ok(format!("count: {count}"))
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Session(pub async_session::Session);
+
+impl Session {
+    /// 获取键为 [`SessionKey::KEY`] 的类型化会话值
+    pub fn value<T: SessionKey>(&self) -> SessionValue<T> {
+        SessionValue {
+            session: self.0.clone(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// 写入会话值，序列化失败时返回 `500`
+    pub fn insert(&mut self, key: &str, value: impl Serialize) -> crate::Result<()> {
+        self.0.insert(key, value).map_err(|e| {
+            SilentError::business_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("failed to serialize session value: {e}"),
+            )
+        })
+    }
+}
+
+impl Deref for Session {
+    type Target = async_session::Session;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Session {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait]
+impl FromRequestParts for Session {
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        req.extensions()
+            .get::<async_session::Session>()
+            .cloned()
+            .map(Session)
+            .ok_or_else(|| {
+                SilentError::business_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "session middleware is not configured",
+                )
+            })
+    }
+}
+
+from_request_via_parts!([] Session);
+
+/// 为会话中存储的类型指定键名，配合 [`SessionValue`] 使用
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use silent::extractor::SessionKey;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct CurrentUser {
+///     id: u64,
+/// }
+///
+/// impl SessionKey for CurrentUser {
+///     const KEY: &'static str = "current_user";
+/// }
+/// ```
+pub trait SessionKey: Serialize + DeserializeOwned + Send + 'static {
+    /// 会话中的键名
+    const KEY: &'static str;
+}
+
+/// SessionValue 萃取器：按 [`SessionKey`] 读写单个类型化会话值
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use silent::extractor::SessionKey;
+/// # #[derive(Deserialize, Serialize)]
+/// # struct CurrentUser {
+/// #     id: u64,
+/// # }
+/// # impl SessionKey for CurrentUser {
+/// #     const KEY: &'static str = "current_user";
+/// # }
+/// use silent::Result;
+/// use silent::extractor::SessionValue;
+///
+/// async fn login(mut user: SessionValue<CurrentUser>) -> Result<&'static str> {
+///     user.set(&CurrentUser { id: 1 })?;
+///     Ok("ok")
+/// }
+///
+/// async fn whoami(user: SessionValue<CurrentUser>) -> Result<String> {
+///     Ok(match user.get() {
+///         Some(user) => format!("user {}", user.id),
+///         None => "anonymous".to_string(),
+///     })
+/// }
+/// ```
+pub struct SessionValue<T> {
+    session: async_session::Session,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: SessionKey> SessionValue<T> {
+    /// 读取会话值，不存在或无法反序列化时返回 `None`
+    pub fn get(&self) -> Option<T> {
+        self.session.get(T::KEY)
+    }
+
+    /// 写入会话值
+    pub fn set(&mut self, value: &T) -> crate::Result<()> {
+        Session(self.session.clone()).insert(T::KEY, value)
+    }
+
+    /// 删除会话值
+    pub fn remove(&mut self) {
+        self.session.remove(T::KEY);
+    }
+}
+
+#[async_trait]
+impl<T: SessionKey> FromRequestParts for SessionValue<T> {
+    type Rejection = SilentError;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        Ok(Session::from_request_parts(req).await?.value())
+    }
+}
+
+from_request_via_parts!([T] SessionValue<T> where T: SessionKey);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::FromRequest;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct CurrentUser {
+        id: u64,
+    }
+
+    impl SessionKey for CurrentUser {
+        const KEY: &'static str = "current_user";
+    }
+
+    fn session_request() -> (Request, async_session::Session) {
+        let session = async_session::Session::new();
+        let mut req = Request::empty();
+        req.extensions_mut().insert(session.clone());
+        (req, session)
+    }
+
+    // ==================== Session 测试 ====================
+
+    #[tokio::test]
+    async fn test_session_shares_request_session() {
+        let (mut req, original) = session_request();
+        let mut session = Session::from_request(&mut req).await.unwrap();
+        session.insert("count", 1).unwrap();
+
+        assert_eq!(original.get::<u32>("count"), Some(1));
+        assert!(original.data_changed());
+    }
+
+    #[tokio::test]
+    async fn test_session_missing_middleware() {
+        let mut req = Request::empty();
+        let err = Session::from_request(&mut req).await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    // ==================== SessionValue 测试 ====================
+
+    #[tokio::test]
+    async fn test_session_value_roundtrip() {
+        let (mut req, original) = session_request();
+        let mut user = SessionValue::<CurrentUser>::from_request(&mut req)
+            .await
+            .unwrap();
+        assert!(user.get().is_none());
+
+        user.set(&CurrentUser { id: 7 }).unwrap();
+        assert_eq!(user.get(), Some(CurrentUser { id: 7 }));
+        assert_eq!(
+            original.get::<CurrentUser>("current_user"),
+            Some(CurrentUser { id: 7 })
+        );
+
+        user.remove();
+        assert!(original.get_raw("current_user").is_none());
+    }
+}
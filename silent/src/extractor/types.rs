@@ -12,10 +12,111 @@ pub struct Path<T>(pub T);
 /// Query 萃取器：从 URL 查询参数解析为 T
 pub struct Query<T>(pub T);
 
+/// QueryOrDefault 萃取器：宽松地解析查询参数，缺失或无效时回退为默认值
+///
+/// - 无查询字符串时直接返回 `T::default()`
+/// - 缺失的字段取 `T::default()` 中对应字段的值，需在 T 上标注 `#[serde(default)]`
+/// - 存在无法解析的值（如 `page=abc`）时仅该字段取默认值，其余字段照常解析，而不是拒绝请求；
+///   跨字段校验等仍无法通过时返回 `T::default()`
+///
+/// 适用于分页等参数均有合理默认值的接口，无需在每个字段上使用 `Option<T>`。
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use silent::Result;
+/// use silent::extractor::QueryOrDefault;
+///
+/// #[derive(Deserialize)]
+/// #[serde(default)]
+/// struct Paging {
+///     page: u32,
+///     size: u32,
+/// }
+///
+/// impl Default for Paging {
+///     fn default() -> Self {
+///         Self { page: 1, size: 20 }
+///     }
+/// }
+///
+/// async fn list(QueryOrDefault(paging): QueryOrDefault<Paging>) -> Result<String> {
+///     Ok(format!("page={} size={}", paging.page, paging.size))
+/// }
+/// ```
+pub struct QueryOrDefault<T>(pub T);
+
+/// RawQuery 萃取器：未经解码的原始查询字符串，无查询参数时为 `None`
+///
+/// 适用于 `Query<T>` 无法建模的不透明过滤表达式等场景。
+pub struct RawQuery(pub Option<String>);
+
+/// QueryMap 萃取器：保留重复键的查询参数，如 `?tag=a&tag=b`
+///
+/// ```rust
+/// use silent::Result;
+/// use silent::extractor::QueryMap;
+///
+/// async fn search(query: QueryMap) -> Result<String> {
+///     let tags = query.get_all("tag").join(",");
+///     Ok(format!("page={:?} tags={tags}", query.get("page")))
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryMap(std::collections::HashMap<String, Vec<String>>);
+
+impl QueryMap {
+    /// 解析 `application/x-www-form-urlencoded` 格式的查询字符串
+    pub fn parse(query: &str) -> Self {
+        let mut map = std::collections::HashMap::<String, Vec<String>>::new();
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()).into_owned() {
+            map.entry(key).or_default().push(value);
+        }
+        Self(map)
+    }
+
+    /// 获取键的第一个值
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.first()).map(String::as_str)
+    }
+
+    /// 获取键的全部值，按出现顺序排列
+    pub fn get_all(&self, key: &str) -> &[String] {
+        self.0.get(key).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// 是否包含指定键
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// 遍历所有键及其值
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_slice()))
+    }
+
+    /// 不同键的数量
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// 是否没有任何查询参数
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// 取出内部的多值映射
+    pub fn into_inner(self) -> std::collections::HashMap<String, Vec<String>> {
+        self.0
+    }
+}
+
 /// Json 萃取器：从 application/json 解析为 T（带缓存）
 pub struct Json<T>(pub T);
 
 /// Form 萃取器：从表单解析为 T
+///
+/// 支持 `application/x-www-form-urlencoded`（遵循 `charset` 参数，支持 UTF-8 与 ISO-8859-1），
+/// 启用 `multipart` 特性时也接受 `multipart/form-data` 中的文本字段，解析规则与 urlencoded 一致。
 pub struct Form<T>(pub T);
 
 /// State 萃取器：从应用级共享状态中提取 T
@@ -27,17 +128,158 @@ pub struct State<T>(pub T);
 )]
 pub struct Configs<T>(pub T);
 
+/// 可选的 State 萃取器：状态不存在时为 `None` 而不是返回错误
+pub struct OptionalState<T>(pub Option<T>);
+
+/// StateArc 萃取器：以 `Arc<T>` 共享应用状态，不克隆内部值，`T` 也无需实现 `Clone`
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use silent::Result;
+/// use silent::extractor::StateArc;
+///
+/// // 未实现 Clone 的大型共享状态
+/// struct Templates {
+///     pages: Vec<String>,
+/// }
+///
+/// async fn count(StateArc(templates): StateArc<Templates>) -> Result<String> {
+///     Ok(templates.pages.len().to_string())
+/// }
+///
+/// let route = silent::prelude::Route::new("")
+///     .with_state_arc(Arc::new(Templates { pages: vec![] }))
+///     .get(count);
+/// ```
+pub struct StateArc<T>(pub std::sync::Arc<T>);
+
 /// 从 Extensions 中提取扩展
 pub struct Extension<T>(pub T);
 
+/// 可选的 Extension 萃取器：扩展不存在时为 `None` 而不是返回错误
+///
+/// 对于克隆代价较高的值，可以插入 `Arc<T>` 并萃取 `OptionalExtension<Arc<T>>`。
+pub struct OptionalExtension<T>(pub Option<T>);
+
 /// 头部类型化提取（等价 axum 的 TypedHeader）
 pub struct TypedHeader<H>(pub H);
 
+/// Body 萃取器：读取完整的原始请求体，不做任何解析
+///
+/// 适用于需要对原始载荷做签名校验（如 Webhook 的 HMAC）的场景。
+/// 请求体大小受 [`BodyLimit`] 限制，超出时返回 `413 Payload Too Large`。
+pub struct Body(pub bytes::Bytes);
+
+/// Text 萃取器：将原始请求体读取为 UTF-8 字符串，大小限制同 [`Body`]
+pub struct Text(pub String);
+
+/// `Body`/`Text` 萃取器的请求体大小上限（字节）
+///
+/// 通过 `with_state` 注入以覆盖默认值 [`BodyLimit::DEFAULT`]：
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::extractor::{Body, BodyLimit};
+///
+/// let route = Route::new("webhook")
+///     .with_state(BodyLimit(64 * 1024))
+///     .post(|Body(raw): Body| async move { Ok(raw.len().to_string()) });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BodyLimit(pub usize);
+
+impl BodyLimit {
+    /// 默认上限 2MB
+    pub const DEFAULT: usize = 2 * 1024 * 1024;
+}
+
+impl Default for BodyLimit {
+    fn default() -> Self {
+        Self(Self::DEFAULT)
+    }
+}
+
 pub struct Method(pub crate::Method);
 pub struct Uri(pub HttpUri);
 pub struct Version(pub HttpVersion);
 pub struct RemoteAddr(pub crate::core::remote_addr::RemoteAddr);
 
+/// MatchedPath 萃取器：命中的路由模板，如 `/users/<id:u64>`
+///
+/// 与实际请求路径不同，模板不含具体参数值，适合作为指标标签或访问日志字段，避免高基数。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatchedPath(std::sync::Arc<str>);
+
+impl MatchedPath {
+    pub(crate) fn new(pattern: std::sync::Arc<str>) -> Self {
+        Self(pattern)
+    }
+
+    /// 路由模板字符串
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for MatchedPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// OriginalUri 萃取器：路由改写前的原始请求 URI
+///
+/// 通过 `Route::mount` 挂载的子应用中，`req.uri()` 已去除挂载前缀，
+/// 需要完整路径（如生成绝对链接）时使用该萃取器；未经挂载时与 [`Uri`] 相同。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OriginalUri(pub HttpUri);
+
+/// Multipart 萃取器：流式读取 multipart/form-data 请求体
+///
+/// 与 `req.form_data()` 不同，字段按顺序逐个读取，文件内容不会落盘，
+/// 适合大文件或需要自行处理上传内容的场景。
+///
+/// ```rust
+/// use silent::extractor::Multipart;
+/// use silent::Result;
+///
+/// async fn upload(mut m: Multipart) -> Result<String> {
+///     let mut names = Vec::new();
+///     while let Some(field) = m.next_field().await? {
+///         names.push(field.name().unwrap_or_default().to_string());
+///         let _data = field.bytes().await?;
+///     }
+///     Ok(names.join(","))
+/// }
+/// ```
+#[cfg(feature = "multipart")]
+pub struct Multipart {
+    inner: multer::Multipart<'static>,
+}
+
+/// Multipart 中的单个字段
+#[cfg(feature = "multipart")]
+pub use multer::Field as MultipartField;
+
+#[cfg(feature = "multipart")]
+impl Multipart {
+    pub(crate) fn new(inner: multer::Multipart<'static>) -> Self {
+        Self { inner }
+    }
+
+    /// 读取下一个字段，所有字段读取完毕时返回 `None`
+    ///
+    /// 必须在读取下一个字段之前消费或丢弃当前字段。
+    pub async fn next_field(&mut self) -> crate::Result<Option<MultipartField<'static>>> {
+        Ok(self.inner.next_field().await?)
+    }
+
+    /// 获取底层 multer::Multipart
+    pub fn into_inner(self) -> multer::Multipart<'static> {
+        self.inner
+    }
+}
+
 /// Request 便捷扩展：通用萃取
 #[async_trait]
 pub trait RequestExt {
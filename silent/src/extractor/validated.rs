@@ -0,0 +1,111 @@
+//! 输入校验萃取
+//!
+//! `Validated<E>` 在内部萃取器完成反序列化后调用 `validator::Validate`，
+//! 校验失败时返回 `422 Unprocessable Entity`，响应体列出各字段的错误。
+
+use async_trait::async_trait;
+use serde_json::json;
+use validator::{Validate, ValidationErrors};
+
+use super::types::{Form, Json, Path, Query};
+use super::{FromRequest, FromRequestParts};
+use crate::{Request, Response, StatusCode};
+
+/// Validated 萃取器：反序列化后执行 `T: validator::Validate` 校验
+///
+/// 支持包裹 `Json<T>`、`Query<T>`、`Form<T>`、`Path<T>`。
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use silent::Result;
+/// use silent::extractor::{Json, Validated};
+/// use validator::Validate;
+///
+/// #[derive(Deserialize, Validate)]
+/// struct SignUp {
+///     #[validate(email)]
+///     email: String,
+///     #[validate(length(min = 8))]
+///     password: String,
+/// }
+///
+/// async fn sign_up(Validated(Json(form)): Validated<Json<SignUp>>) -> Result<String> {
+///     Ok(form.email)
+/// }
+/// ```
+///
+/// 校验失败时的响应体：
+///
+/// ```json
+/// {"code": 422, "message": "validation failed", "errors": {"email": [{"code": "email", ...}]}}
+/// ```
+pub struct Validated<E>(pub E);
+
+/// 可被 `Validated` 校验的萃取器，提供对内部数据的访问
+pub trait ValidatedExtractor {
+    /// 被校验的数据类型
+    type Target: Validate;
+
+    /// 获取被校验的数据
+    fn target(&self) -> &Self::Target;
+}
+
+macro_rules! impl_validated_extractor {
+    ($($extractor:ident),* $(,)?) => {
+        $(
+            impl<T: Validate> ValidatedExtractor for $extractor<T> {
+                type Target = T;
+
+                fn target(&self) -> &T {
+                    &self.0
+                }
+            }
+        )*
+    };
+}
+
+impl_validated_extractor!(Json, Query, Form, Path);
+
+/// 将校验错误转换为 422 响应
+pub fn validation_error_response(errors: &ValidationErrors) -> Response {
+    Response::json(&json!({
+        "code": StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+        "message": "validation failed",
+        "errors": errors,
+    }))
+    .with_status(StatusCode::UNPROCESSABLE_ENTITY)
+}
+
+#[async_trait]
+impl<E> FromRequest for Validated<E>
+where
+    E: FromRequest + ValidatedExtractor + Send + 'static,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let inner = E::from_request(req).await.map_err(Into::into)?;
+        inner
+            .target()
+            .validate()
+            .map_err(|errors| validation_error_response(&errors))?;
+        Ok(Validated(inner))
+    }
+}
+
+#[async_trait]
+impl<E> FromRequestParts for Validated<E>
+where
+    E: FromRequestParts + ValidatedExtractor + Send + 'static,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let inner = E::from_request_parts(req).await.map_err(Into::into)?;
+        inner
+            .target()
+            .validate()
+            .map_err(|errors| validation_error_response(&errors))?;
+        Ok(Validated(inner))
+    }
+}
@@ -0,0 +1,163 @@
+//! XML 萃取器与响应
+
+use async_trait::async_trait;
+use http::HeaderValue;
+use http::header::CONTENT_TYPE;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::FromRequest;
+use super::from_request::read_body_limited;
+use crate::core::res_body::full;
+use crate::{Request, Response, SilentError, StatusCode};
+
+/// Xml 萃取器/响应：解析 `application/xml` 请求体，或将 T 序列化为 XML 响应
+///
+/// 同时接受 `text/xml` 与 `+xml` 后缀的媒体类型，请求体大小受 `BodyLimit` 限制。
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use silent::Result;
+/// use silent::extractor::Xml;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Order {
+///     id: u64,
+/// }
+///
+/// async fn handler(Xml(order): Xml<Order>) -> Result<Xml<Order>> {
+///     Ok(Xml(order))
+/// }
+/// ```
+pub struct Xml<T>(pub T);
+
+#[async_trait]
+impl<T> FromRequest for Xml<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    type Rejection = SilentError;
+
+    async fn from_request(req: &mut Request) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .content_type()
+            .ok_or(SilentError::ContentTypeMissingError)?;
+        let is_xml =
+            content_type.subtype() == mime::XML || content_type.suffix() == Some(mime::XML);
+        if !is_xml {
+            return Err(SilentError::ContentTypeError);
+        }
+
+        let bytes = read_body_limited(req).await?;
+        if bytes.is_empty() {
+            return Err(SilentError::BodyEmpty);
+        }
+        let text = std::str::from_utf8(&bytes).map_err(|e| {
+            SilentError::business_error(StatusCode::BAD_REQUEST, format!("xml body error: {e}"))
+        })?;
+        let value = quick_xml::de::from_str(text).map_err(|e| {
+            SilentError::business_error(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("xml deserialize error: {e}"),
+            )
+        })?;
+        Ok(Xml(value))
+    }
+}
+
+impl<T: Serialize> From<Xml<T>> for Response {
+    fn from(Xml(value): Xml<T>) -> Self {
+        match quick_xml::se::to_string(&value) {
+            Ok(body) => Response::empty()
+                .with_header(
+                    CONTENT_TYPE,
+                    HeaderValue::from_static("application/xml; charset=utf-8"),
+                )
+                .with_body(full(body)),
+            Err(e) => SilentError::business_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("xml serialize error: {e}"),
+            )
+            .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::req_body::ReqBody;
+    use crate::core::res_body::ResBody;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Order {
+        id: u64,
+        name: String,
+    }
+
+    fn xml_request(content_type: &'static str, body: &'static str) -> Request {
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        req.replace_body(ReqBody::Once(body.into()));
+        req
+    }
+
+    // ==================== 萃取测试 ====================
+
+    #[tokio::test]
+    async fn test_xml_extract() {
+        for content_type in ["application/xml", "text/xml", "application/soap+xml"] {
+            let mut req = xml_request(content_type, "<Order><id>7</id><name>book</name></Order>");
+            let Xml(order): Xml<Order> = Xml::from_request(&mut req).await.unwrap();
+            assert_eq!(
+                order,
+                Order {
+                    id: 7,
+                    name: "book".into()
+                }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_xml_extract_rejections() {
+        let mut req = xml_request("application/json", "{}");
+        assert!(matches!(
+            Xml::<Order>::from_request(&mut req).await,
+            Err(SilentError::ContentTypeError)
+        ));
+
+        let mut req = xml_request("application/xml", "");
+        assert!(matches!(
+            Xml::<Order>::from_request(&mut req).await,
+            Err(SilentError::BodyEmpty)
+        ));
+
+        let mut req = xml_request("application/xml", "<Order><id>x</id></Order>");
+        let err = Xml::<Order>::from_request(&mut req).await.err().unwrap();
+        assert_eq!(err.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    // ==================== 响应测试 ====================
+
+    #[test]
+    fn test_xml_response() {
+        let res: Response = Xml(Order {
+            id: 1,
+            name: "pen".into(),
+        })
+        .into();
+        assert_eq!(
+            res.headers().get(CONTENT_TYPE).unwrap(),
+            "application/xml; charset=utf-8"
+        );
+        match res.body {
+            ResBody::Once(bytes) => {
+                assert_eq!(&bytes[..], b"<Order><id>1</id><name>pen</name></Order>")
+            }
+            _ => panic!("unexpected body"),
+        }
+    }
+}
@@ -36,9 +36,30 @@ impl Handler for HashMap<Method, Arc<dyn Handler>> {
             return Ok(pre_res);
         }
 
+        // 未显式注册 OPTIONS 时自动响应，通过 Allow 头声明该路径支持的方法
+        if method == http::Method::OPTIONS && !self.is_empty() {
+            let mut res = Response::empty().with_status(StatusCode::NO_CONTENT);
+            res.headers_mut()
+                .insert(http::header::ALLOW, allow_header(self));
+            return Ok(res);
+        }
+
         Err(SilentError::business_error(
             StatusCode::METHOD_NOT_ALLOWED,
             "method not allowed".to_string(),
         ))
     }
 }
+
+/// 生成 `Allow` 头：已注册的方法，注册了 GET 时附带 HEAD，并始终包含 OPTIONS
+pub(crate) fn allow_header(handlers: &HashMap<Method, Arc<dyn Handler>>) -> http::HeaderValue {
+    let mut methods: Vec<&str> = handlers.keys().map(Method::as_str).collect();
+    if handlers.contains_key(&Method::GET) {
+        methods.push(Method::HEAD.as_str());
+    }
+    methods.push(Method::OPTIONS.as_str());
+    methods.sort_unstable();
+    methods.dedup();
+    http::HeaderValue::from_str(&methods.join(", "))
+        .unwrap_or_else(|_| http::HeaderValue::from_static("OPTIONS"))
+}
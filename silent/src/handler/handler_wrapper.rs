@@ -1,30 +1,92 @@
 use crate::core::into_response::IntoResponse;
 use crate::handler::handler_trait::Handler;
-use crate::{Request, Response, Result};
+use crate::{Request, Response, Result, SilentError};
 use async_trait::async_trait;
+use std::any::Any;
 use std::future::Future;
+use std::marker::PhantomData;
 use std::sync::Arc;
 
 /// 处理器包装结构体
 ///
-/// 将返回 `Result<T>` 的异步函数包装为 `Handler`，
-/// 其中 `T` 需实现 `IntoResponse`。
-pub struct HandlerWrapper<F> {
+/// 将返回 `Result<T, E>` 的异步函数包装为 `Handler`，其中 `T` 需实现 `IntoResponse`，
+/// `M` 为其标记类型，由编译器推断。
+///
+/// 错误类型 `E` 只需实现 `Into<Response>`：`SilentError` 仍以 `Err` 返回，交由
+/// `ExceptionHandler` 等中间件处理；其余错误类型按自身的方式生成响应并以 `Ok` 返回，
+/// 领域错误枚举无需在每个 `?` 处转换为 [`SilentError`]。
+///
+/// 闭包中的 `Ok(..)` 无法推断错误类型，因此 [`HandlerWrapper::new`] 与 `Route::get` 等方法
+/// 要求 `Result<T>`；返回自定义错误类型的处理函数通过 [`HandlerWrapper::with_error`] 包装后注册：
+///
+/// ```rust
+/// use silent::prelude::*;
+///
+/// enum ApiError {
+///     NotFound(String),
+/// }
+///
+/// impl From<ApiError> for Response {
+///     fn from(err: ApiError) -> Self {
+///         match err {
+///             ApiError::NotFound(id) => {
+///                 Response::text(&format!("user {id} not found")).with_status(StatusCode::NOT_FOUND)
+///             }
+///         }
+///     }
+/// }
+///
+/// async fn get_user(req: Request) -> std::result::Result<String, ApiError> {
+///     let id: String = req
+///         .get_path_params("id")
+///         .map_err(|_| ApiError::NotFound(String::new()))?;
+///     if id == "0" {
+///         return Err(ApiError::NotFound(id));
+///     }
+///     Ok(format!("user {id}"))
+/// }
+///
+/// let route = Route::new("users/<id>").get(HandlerWrapper::with_error(get_user));
+/// ```
+pub struct HandlerWrapper<F, M = ()> {
     handler: F,
+    _marker: PhantomData<fn() -> M>,
 }
 
-impl<F, T, Fut> HandlerWrapper<F>
+impl<F, T, Fut, M> HandlerWrapper<F, M>
 where
     Fut: Future<Output = Result<T>> + Send + 'static,
     F: Fn(Request) -> Fut,
-    T: IntoResponse,
+    T: IntoResponse<M>,
 {
     pub fn new(handler: F) -> Self {
-        HandlerWrapper { handler }
+        HandlerWrapper {
+            handler,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F, T, E, Fut, M> HandlerWrapper<F, M>
+where
+    Fut: Future<Output = std::result::Result<T, E>> + Send + 'static,
+    F: Fn(Request) -> Fut,
+    T: IntoResponse<M>,
+    E: Into<Response> + 'static,
+{
+    /// 包装返回 `Result<T, E>` 的处理函数，`E` 为任意实现 `Into<Response>` 的错误类型
+    pub fn with_error(handler: F) -> Self {
+        HandlerWrapper {
+            handler,
+            _marker: PhantomData,
+        }
     }
 
     pub async fn handle(&self, req: Request) -> Result<Response> {
-        Ok((self.handler)(req).await?.into_response())
+        match (self.handler)(req).await {
+            Ok(value) => Ok(value.into_response()),
+            Err(err) => error_result(err),
+        }
     }
 
     pub fn arc(self) -> Arc<Self> {
@@ -32,13 +94,27 @@ where
     }
 }
 
+/// `SilentError` 保持为 `Err`，其余错误类型直接生成响应
+fn error_result<E: Into<Response> + 'static>(err: E) -> Result<Response> {
+    let mut err = Some(err);
+    if let Some(err) = (&mut err as &mut dyn Any)
+        .downcast_mut::<Option<SilentError>>()
+        .and_then(Option::take)
+    {
+        return Err(err);
+    }
+    Ok(err.expect("error taken only for SilentError").into())
+}
+
 /// 为HandlerWrapper实现Handler
 #[async_trait]
-impl<F, T, Fut> Handler for HandlerWrapper<F>
+impl<F, T, E, Fut, M> Handler for HandlerWrapper<F, M>
 where
-    Fut: Future<Output = Result<T>> + Send + 'static,
+    Fut: Future<Output = std::result::Result<T, E>> + Send + 'static,
     F: Fn(Request) -> Fut + Send + Sync + 'static,
-    T: IntoResponse,
+    T: IntoResponse<M>,
+    E: Into<Response> + 'static,
+    M: 'static,
 {
     async fn call(&self, req: Request) -> Result<Response> {
         self.handle(req).await
@@ -103,4 +179,71 @@ mod tests {
             .unwrap();
         assert_eq!(res_l, serde_json::to_string(&hello).unwrap());
     }
+
+    // ==================== 自定义错误类型测试 ====================
+
+    enum ApiError {
+        Forbidden,
+    }
+
+    impl From<ApiError> for Response {
+        fn from(err: ApiError) -> Self {
+            match err {
+                ApiError::Forbidden => {
+                    Response::text("forbidden").with_status(http::StatusCode::FORBIDDEN)
+                }
+            }
+        }
+    }
+
+    async fn guarded(req: Request) -> std::result::Result<String, ApiError> {
+        if req.headers().contains_key("x-admin") {
+            Ok("welcome".into())
+        } else {
+            Err(ApiError::Forbidden)
+        }
+    }
+
+    #[tokio::test]
+    async fn handler_wrapper_renders_custom_error() {
+        let handler = HandlerWrapper::with_error(guarded);
+        let res = handler.handle(Request::empty()).await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::FORBIDDEN);
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(body, "forbidden");
+
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert("x-admin", http::HeaderValue::from_static("1"));
+        let res = handler.handle(req).await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn handler_wrapper_keeps_silent_error() {
+        async fn failed(_req: Request) -> Result<String> {
+            Err(crate::SilentError::business_error(
+                http::StatusCode::CONFLICT,
+                "conflict",
+            ))
+        }
+
+        let err = HandlerWrapper::new(failed)
+            .handle(Request::empty())
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), http::StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn handler_wrapper_custom_error_route() {
+        use crate::prelude::Route;
+
+        let route = Route::new("admin").get(HandlerWrapper::with_error(guarded));
+        let tree = Route::new("").append(route).convert_to_route_tree();
+        let mut req = Request::empty();
+        *req.uri_mut() = "/admin".parse().unwrap();
+        let res = tree.call(req).await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::FORBIDDEN);
+    }
 }
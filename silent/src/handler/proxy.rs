@@ -0,0 +1,628 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use http::header::{CONNECTION, HOST, HeaderName, TE};
+use http::uri::{Authority, PathAndQuery, Scheme};
+use http::{HeaderMap, HeaderValue, Method, Uri};
+use hyper::body::Incoming;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::{Client, Error as ClientError};
+use hyper_util::rt::{TokioExecutor, TokioTimer};
+
+use crate::core::remote_addr::RemoteAddr;
+use crate::core::req_body::ReqBody;
+use crate::core::res_body::ResBody;
+#[cfg(feature = "tls")]
+use crate::core::socket_addr::SocketAddr;
+use crate::extractor::TrustedProxies;
+use crate::prelude::HandlerGetter;
+use crate::route::Route;
+use crate::{Handler, Request, Response, Result, SilentError, StatusCode};
+
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+const X_FORWARDED_HOST: HeaderName = HeaderName::from_static("x-forwarded-host");
+const X_FORWARDED_PROTO: HeaderName = HeaderName::from_static("x-forwarded-proto");
+
+/// 逐跳头部，只对单个连接有效，不能转发
+const HOP_BY_HOP: [&str; 8] = [
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// 反向代理处理器，将请求转发到上游 HTTP 服务
+///
+/// 通过 [`Route::with_proxy`] 挂载后，该路由及其全部子路径的请求都会被转发：
+///
+/// - 请求体与响应体均以流的方式转发，不在内存中缓冲
+/// - 上游路径为上游地址的路径前缀拼接请求路径（可先用 [`strip_prefix`](Self::strip_prefix) 去掉挂载前缀），查询字符串原样保留
+/// - `Host` 改写为上游地址（[`preserve_host`](Self::preserve_host) 可保留原值），并写入 `X-Forwarded-For`、
+///   `X-Forwarded-Host`、`X-Forwarded-Proto`；仅来自 [`TrustedProxies`] 的请求保留其已有的转发头，
+///   逐跳头部在两个方向上都会被移除
+/// - 上游连接由连接池复用，可配置空闲超时与每个主机的最大空闲连接数
+/// - 连接上游失败返回 `502 Bad Gateway`，等待响应超时返回 `504 Gateway Timeout`
+///
+/// 目前仅支持 `http://` 上游。
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::ProxyHandler;
+/// use std::time::Duration;
+///
+/// // /api/users -> http://127.0.0.1:9000/v1/users
+/// let route = Route::new_root().append(
+///     Route::new("api").with_proxy(
+///         ProxyHandler::new("http://127.0.0.1:9000/v1")
+///             .strip_prefix("/api")
+///             .timeout(Duration::from_secs(10))
+///             .retries(2),
+///     ),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ProxyHandler {
+    scheme: Scheme,
+    authority: Authority,
+    base_path: String,
+    strip_prefix: Option<String>,
+    preserve_host: bool,
+    timeout: Option<Duration>,
+    retries: usize,
+    connect_timeout: Option<Duration>,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: usize,
+    client: Client<HttpConnector, ReqBody>,
+}
+
+impl ProxyHandler {
+    /// 创建转发到 `upstream` 的代理，如 `http://127.0.0.1:9000` 或 `http://backend/v1`
+    ///
+    /// 默认不设置响应超时、不重试，连接超时为 10 秒，空闲连接保留 90 秒。
+    ///
+    /// # Panics
+    ///
+    /// `upstream` 不是合法的 `http://` 地址时 panic。
+    pub fn new(upstream: &str) -> Self {
+        let uri: Uri = upstream
+            .parse()
+            .unwrap_or_else(|_| panic!("ProxyHandler: invalid upstream url \"{upstream}\""));
+        let (Some(scheme), Some(authority)) = (uri.scheme(), uri.authority()) else {
+            panic!("ProxyHandler: upstream url \"{upstream}\" must be absolute");
+        };
+        if *scheme != Scheme::HTTP {
+            panic!("ProxyHandler: unsupported upstream scheme \"{scheme}\"");
+        }
+        let connect_timeout = Some(Duration::from_secs(10));
+        let pool_idle_timeout = Some(Duration::from_secs(90));
+        let pool_max_idle_per_host = usize::MAX;
+        Self {
+            scheme: scheme.clone(),
+            authority: authority.clone(),
+            base_path: uri.path().trim_end_matches('/').to_string(),
+            strip_prefix: None,
+            preserve_host: false,
+            timeout: None,
+            retries: 0,
+            connect_timeout,
+            pool_idle_timeout,
+            pool_max_idle_per_host,
+            client: build_client(connect_timeout, pool_idle_timeout, pool_max_idle_per_host),
+        }
+    }
+
+    /// 转发前从请求路径中去掉的前缀，通常为挂载路由的路径
+    pub fn strip_prefix(mut self, prefix: &str) -> Self {
+        let prefix = prefix.trim_end_matches('/');
+        self.strip_prefix = (!prefix.is_empty()).then(|| prefix.to_string());
+        self
+    }
+
+    /// 保留客户端的 `Host` 头，而不是改写为上游地址
+    pub fn preserve_host(mut self, preserve: bool) -> Self {
+        self.preserve_host = preserve;
+        self
+    }
+
+    /// 等待上游响应头的超时，超时返回 `504 Gateway Timeout`
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 连接上游失败时的重试次数
+    ///
+    /// 只重试请求尚未发出的连接错误，且仅限请求体可重放（为空或已完整读入内存）的请求，
+    /// 因此对非幂等方法同样安全。
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// 建立上游连接的超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self.rebuild_client()
+    }
+
+    /// 连接池中空闲连接的保留时间
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self.rebuild_client()
+    }
+
+    /// 每个上游主机保留的最大空闲连接数，为 0 时不复用连接
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = max;
+        self.rebuild_client()
+    }
+
+    fn rebuild_client(mut self) -> Self {
+        self.client = build_client(
+            self.connect_timeout,
+            self.pool_idle_timeout,
+            self.pool_max_idle_per_host,
+        );
+        self
+    }
+
+    /// 上游请求的 URI
+    fn upstream_uri(&self, uri: &Uri) -> Result<Uri> {
+        let mut path = uri.path();
+        if let Some(prefix) = &self.strip_prefix
+            && let Some(rest) = path.strip_prefix(prefix.as_str())
+            && (rest.is_empty() || rest.starts_with('/'))
+        {
+            path = rest;
+        }
+        let mut path_and_query = format!("{}{}", self.base_path, path);
+        if path_and_query.is_empty() {
+            path_and_query.push('/');
+        }
+        if let Some(query) = uri.query() {
+            path_and_query.push('?');
+            path_and_query.push_str(query);
+        }
+        let path_and_query = PathAndQuery::try_from(path_and_query).map_err(|e| {
+            SilentError::business_error(StatusCode::BAD_REQUEST, format!("invalid path: {e}"))
+        })?;
+        Uri::builder()
+            .scheme(self.scheme.clone())
+            .authority(self.authority.clone())
+            .path_and_query(path_and_query)
+            .build()
+            .map_err(|e| {
+                SilentError::business_error(StatusCode::BAD_REQUEST, format!("invalid uri: {e}"))
+            })
+    }
+
+    /// 移除逐跳头部，写入 `X-Forwarded-*` 并改写 `Host`
+    ///
+    /// 仅当连接对端属于 [`TrustedProxies`] 时保留客户端传入的 `X-Forwarded-Host`、`X-Forwarded-Proto`，
+    /// 并在其 `X-Forwarded-For` 之后追加对端地址；否则三者均按本次连接重写，避免客户端伪造。
+    fn forward_headers(&self, req: &Request, headers: &mut HeaderMap) {
+        let original_host = req.headers().get(HOST).cloned().or_else(|| {
+            req.uri()
+                .authority()
+                .and_then(|a| HeaderValue::from_str(a.as_str()).ok())
+        });
+        let peer = req.peer_addr();
+        let trusted = peer.is_some_and(|peer| {
+            req.get_state::<TrustedProxies>()
+                .is_ok_and(|proxies| proxies.trusts(peer))
+        });
+        if !trusted {
+            headers.remove(&X_FORWARDED_FOR);
+            headers.remove(&X_FORWARDED_HOST);
+            headers.remove(&X_FORWARDED_PROTO);
+        }
+        remove_hop_by_hop(headers);
+        // 客户端可能发送 `TE: trailers`，其余取值不应转发
+        if req
+            .headers()
+            .get(TE)
+            .is_some_and(|v| v.as_bytes().eq_ignore_ascii_case(b"trailers"))
+        {
+            headers.insert(TE, HeaderValue::from_static("trailers"));
+        }
+
+        if let Some(ip) = peer.and_then(|addr| addr.ip()) {
+            let prior: Vec<_> = headers
+                .get_all(&X_FORWARDED_FOR)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .collect();
+            let forwarded_for = if prior.is_empty() {
+                ip.to_string()
+            } else {
+                format!("{}, {ip}", prior.join(", "))
+            };
+            if let Ok(value) = HeaderValue::from_str(&forwarded_for) {
+                headers.insert(X_FORWARDED_FOR, value);
+            }
+        }
+        if let Some(host) = &original_host
+            && !headers.contains_key(&X_FORWARDED_HOST)
+        {
+            headers.insert(X_FORWARDED_HOST, host.clone());
+        }
+        if !headers.contains_key(&X_FORWARDED_PROTO) {
+            headers.insert(
+                X_FORWARDED_PROTO,
+                HeaderValue::from_static(connection_proto(peer)),
+            );
+        }
+        if !self.preserve_host || original_host.is_none() {
+            if let Ok(host) = HeaderValue::from_str(self.authority.as_str()) {
+                headers.insert(HOST, host);
+            }
+        }
+    }
+
+    /// 发送一次上游请求，超时返回 `504`；连接等错误交由调用方决定是否重试
+    async fn send(
+        &self,
+        request: http::Request<ReqBody>,
+    ) -> Result<std::result::Result<http::Response<Incoming>, ClientError>> {
+        let future = self.client.request(request);
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, future).await.map_err(|_| {
+                SilentError::business_error(
+                    StatusCode::GATEWAY_TIMEOUT,
+                    "upstream timed out".to_string(),
+                )
+            }),
+            None => Ok(future.await),
+        }
+    }
+}
+
+fn build_client(
+    connect_timeout: Option<Duration>,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: usize,
+) -> Client<HttpConnector, ReqBody> {
+    let mut connector = HttpConnector::new();
+    connector.set_connect_timeout(connect_timeout);
+    connector.set_nodelay(true);
+    Client::builder(TokioExecutor::new())
+        .pool_timer(TokioTimer::new())
+        .pool_idle_timeout(pool_idle_timeout)
+        .pool_max_idle_per_host(pool_max_idle_per_host)
+        .build(connector)
+}
+
+/// 移除逐跳头部，包括 `Connection` 中列出的头部
+fn remove_hop_by_hop(headers: &mut HeaderMap) {
+    let listed: Vec<HeaderName> = headers
+        .get_all(CONNECTION)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter_map(|name| HeaderName::try_from(name.trim()).ok())
+        .collect();
+    for name in listed {
+        headers.remove(name);
+    }
+    for name in HOP_BY_HOP {
+        headers.remove(name);
+    }
+}
+
+/// 客户端到本服务的连接协议，由接受连接的监听器决定
+fn connection_proto(peer: Option<&RemoteAddr>) -> &'static str {
+    match peer {
+        #[cfg(feature = "tls")]
+        Some(RemoteAddr::Socket(SocketAddr::TlsTcp(_))) => "https",
+        _ => "http",
+    }
+}
+
+/// 请求体可重放时返回其副本，用于重试
+fn replayable(body: &ReqBody) -> Option<ReqBody> {
+    match body {
+        ReqBody::Empty => Some(ReqBody::Empty),
+        ReqBody::Once(bytes) => Some(ReqBody::Once(bytes.clone())),
+        _ => None,
+    }
+}
+
+#[async_trait]
+impl Handler for ProxyHandler {
+    async fn call(&self, mut req: Request) -> Result<Response> {
+        let uri = self.upstream_uri(req.uri())?;
+        let mut headers = req.headers().clone();
+        self.forward_headers(&req, &mut headers);
+        let mut body = req.take_body();
+
+        let mut attempt = 0;
+        let upstream = loop {
+            let retry_body = if attempt < self.retries {
+                replayable(&body)
+            } else {
+                None
+            };
+            let mut request = http::Request::new(body);
+            *request.method_mut() = req.method().clone();
+            *request.uri_mut() = uri.clone();
+            *request.headers_mut() = headers.clone();
+            match self.send(request).await? {
+                Ok(upstream) => break upstream,
+                Err(e) => match retry_body {
+                    Some(next) if e.is_connect() => {
+                        attempt += 1;
+                        tracing::debug!(upstream = %uri, attempt, error = %e, "retrying upstream");
+                        body = next;
+                    }
+                    _ => {
+                        return Err(SilentError::business_error(
+                            StatusCode::BAD_GATEWAY,
+                            format!("upstream error: {e}"),
+                        ));
+                    }
+                },
+            }
+        };
+
+        let (mut parts, body) = upstream.into_parts();
+        remove_hop_by_hop(&mut parts.headers);
+        let mut res = Response::empty();
+        res.set_status(parts.status);
+        *res.headers_mut() = parts.headers;
+        res.set_body(ResBody::Incoming(body));
+        Ok(res)
+    }
+}
+
+impl Route {
+    /// 将该路由及其全部子路径的请求交给 `proxy` 转发，支持所有请求方法
+    ///
+    /// 用法见 [`ProxyHandler`]。
+    pub fn with_proxy(self, proxy: ProxyHandler) -> Self {
+        const METHODS: [Method; 9] = [
+            Method::GET,
+            Method::HEAD,
+            Method::POST,
+            Method::PUT,
+            Method::DELETE,
+            Method::PATCH,
+            Method::OPTIONS,
+            Method::TRACE,
+            Method::CONNECT,
+        ];
+        let proxy: Arc<dyn Handler> = Arc::new(proxy);
+        let mut route = self;
+        let mut rest = Route::new("<path:**>");
+        for method in METHODS {
+            route = route.insert_handler(method.clone(), proxy.clone());
+            rest = rest.insert_handler(method.clone(), proxy.clone());
+        }
+        route.append(rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http_body_util::{BodyExt, Full};
+    use hyper::service::service_fn;
+    use hyper_util::rt::TokioIo;
+    use std::net::SocketAddr;
+    use tokio::net::TcpListener;
+
+    /// 将收到的请求行、关键头部与请求体回显为文本
+    async fn upstream(delay: Duration) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    return;
+                };
+                tokio::spawn(async move {
+                    let service = service_fn(move |req: http::Request<Incoming>| async move {
+                        tokio::time::sleep(delay).await;
+                        let header = |name: &str| {
+                            req.headers()
+                                .get(name)
+                                .and_then(|v| v.to_str().ok())
+                                .unwrap_or("-")
+                                .to_string()
+                        };
+                        let head = format!(
+                            "{} {} host={} xff={} xfh={} xfp={} custom={}\n",
+                            req.method(),
+                            req.uri(),
+                            header("host"),
+                            header("x-forwarded-for"),
+                            header("x-forwarded-host"),
+                            header("x-forwarded-proto"),
+                            header("x-custom"),
+                        );
+                        let body = req.into_body().collect().await?.to_bytes();
+                        let mut res = http::Response::new(Full::new(Bytes::from(
+                            [head.as_bytes(), &body].concat(),
+                        )));
+                        res.headers_mut()
+                            .insert("keep-alive", HeaderValue::from_static("timeout=5"));
+                        Ok::<_, hyper::Error>(res)
+                    });
+                    let _ = hyper::server::conn::http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await;
+                });
+            }
+        });
+        addr
+    }
+
+    fn request(method: Method, uri: &str, body: &'static str) -> Request {
+        let mut req = Request::empty();
+        *req.method_mut() = method;
+        *req.uri_mut() = uri.parse().unwrap();
+        req.headers_mut()
+            .insert(HOST, HeaderValue::from_static("gateway.example"));
+        req.set_remote("203.0.113.7:5000".parse().unwrap());
+        req.replace_body(ReqBody::Once(Bytes::from_static(body.as_bytes())));
+        req
+    }
+
+    #[test]
+    fn test_proxy_upstream_uri() {
+        let proxy = ProxyHandler::new("http://backend:9000/v1/").strip_prefix("/api/");
+        let uri = |path: &str| {
+            proxy
+                .upstream_uri(&path.parse().unwrap())
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(
+            uri("/api/users?page=2"),
+            "http://backend:9000/v1/users?page=2"
+        );
+        assert_eq!(uri("/api"), "http://backend:9000/v1");
+        assert_eq!(uri("/apis"), "http://backend:9000/v1/apis");
+        let proxy = ProxyHandler::new("http://backend:9000");
+        assert_eq!(
+            proxy
+                .upstream_uri(&"/".parse().unwrap())
+                .unwrap()
+                .to_string(),
+            "http://backend:9000/"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ProxyHandler: unsupported upstream scheme")]
+    fn test_proxy_rejects_https() {
+        let _ = ProxyHandler::new("https://backend");
+    }
+
+    #[tokio::test]
+    async fn test_proxy_forwards_request() {
+        let addr = upstream(Duration::ZERO).await;
+        let route = Route::new_root().append(
+            Route::new("api")
+                .with_proxy(ProxyHandler::new(&format!("http://{addr}/v1")).strip_prefix("/api")),
+        );
+
+        let mut req = request(Method::POST, "/api/users/7?debug=1", "payload");
+        req.headers_mut()
+            .insert(CONNECTION, HeaderValue::from_static("x-custom"));
+        req.headers_mut()
+            .insert("x-custom", HeaderValue::from_static("1"));
+        let res = crate::Handler::call(&route, req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(!res.headers().contains_key("keep-alive"));
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(
+            String::from_utf8_lossy(&body),
+            format!(
+                "POST /v1/users/7?debug=1 host={addr} xff=203.0.113.7 xfh=gateway.example xfp=http custom=-\npayload"
+            )
+        );
+
+        // 挂载路径本身同样转发
+        let res = crate::Handler::call(&route, request(Method::GET, "/api", ""))
+            .await
+            .unwrap();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert!(body.starts_with(b"GET /v1 "));
+    }
+
+    #[tokio::test]
+    async fn test_proxy_forwarded_headers_trust() {
+        let addr = upstream(Duration::ZERO).await;
+        let spoofed = || {
+            let mut req = request(Method::GET, "/", "");
+            for (name, value) in [
+                ("x-forwarded-for", "198.51.100.1"),
+                ("x-forwarded-host", "evil.example"),
+                ("x-forwarded-proto", "https"),
+            ] {
+                req.headers_mut()
+                    .insert(name, HeaderValue::from_static(value));
+            }
+            req
+        };
+        let head = |res: Response| async move {
+            let body = res.body.collect().await.unwrap().to_bytes();
+            String::from_utf8_lossy(&body).into_owned()
+        };
+
+        // 未配置可信代理时，客户端传入的转发头被重写
+        let route = Route::new_root().with_proxy(ProxyHandler::new(&format!("http://{addr}")));
+        let res = crate::Handler::call(&route, spoofed()).await.unwrap();
+        assert!(
+            head(res)
+                .await
+                .contains("xff=203.0.113.7 xfh=gateway.example xfp=http ")
+        );
+
+        // 对端为可信代理时保留其转发头
+        let route = Route::new_root()
+            .with_state(TrustedProxies::new(["203.0.113.0/24"]).unwrap())
+            .with_proxy(ProxyHandler::new(&format!("http://{addr}")));
+        let res = crate::Handler::call(&route, spoofed()).await.unwrap();
+        assert!(
+            head(res)
+                .await
+                .contains("xff=198.51.100.1, 203.0.113.7 xfh=evil.example xfp=https ")
+        );
+    }
+
+    #[cfg(feature = "tls")]
+    #[test]
+    fn test_proxy_connection_proto() {
+        let addr: SocketAddr = "203.0.113.7:5000".parse().unwrap();
+        let tls = RemoteAddr::from(crate::core::socket_addr::SocketAddr::TlsTcp(addr));
+        assert_eq!(connection_proto(Some(&tls)), "https");
+        assert_eq!(connection_proto(Some(&RemoteAddr::from(addr))), "http");
+        assert_eq!(connection_proto(None), "http");
+    }
+
+    #[tokio::test]
+    async fn test_proxy_preserve_host() {
+        let addr = upstream(Duration::ZERO).await;
+        let route = Route::new_root()
+            .with_proxy(ProxyHandler::new(&format!("http://{addr}")).preserve_host(true));
+        let res = crate::Handler::call(&route, request(Method::GET, "/", ""))
+            .await
+            .unwrap();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert!(body.starts_with(b"GET / host=gateway.example "));
+    }
+
+    #[tokio::test]
+    async fn test_proxy_upstream_errors() {
+        let addr = upstream(Duration::from_millis(200)).await;
+        let route = Route::new_root().with_proxy(
+            ProxyHandler::new(&format!("http://{addr}")).timeout(Duration::from_millis(20)),
+        );
+        let err = crate::Handler::call(&route, request(Method::GET, "/", ""))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::GATEWAY_TIMEOUT);
+
+        // 释放端口后连接被拒绝
+        let closed = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let route =
+            Route::new_root().with_proxy(ProxyHandler::new(&format!("http://{closed}")).retries(2));
+        let err = crate::Handler::call(&route, request(Method::POST, "/", "x"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::BAD_GATEWAY);
+    }
+}
@@ -1,3 +1,5 @@
+extern crate self as silent;
+
 mod configs;
 #[cfg(feature = "cookie")]
 mod cookie;
@@ -11,6 +13,7 @@ mod handler;
 mod log;
 pub mod middleware;
 pub mod prelude;
+pub mod response;
 #[cfg(feature = "server")]
 pub use crate::server::protocol;
 mod route;
@@ -39,10 +42,11 @@ use multer;
 
 #[allow(deprecated)]
 pub use crate::configs::Configs;
+pub use crate::configs::JsonConfig;
 pub use crate::configs::State;
 #[cfg(feature = "cookie")]
 pub use crate::cookie::cookie_ext::CookieExt;
-pub use crate::core::into_response::IntoResponse;
+pub use crate::core::into_response::{IntoResponse, WithStatus};
 #[cfg(feature = "server")]
 pub use crate::core::remote_addr::RemoteAddr;
 #[cfg(feature = "server")]
@@ -51,14 +55,27 @@ pub use crate::core::{next::Next, request::Request, response::Response};
 #[cfg(feature = "grpc")]
 pub use crate::grpc::{GrpcHandler, GrpcRegister};
 pub use crate::middleware::{MiddleWareHandler, middlewares};
-#[cfg(feature = "server")]
-pub use crate::server::RouteConnectionService;
+#[cfg(feature = "acme")]
+pub use crate::server::acme;
+#[cfg(feature = "acme")]
+pub use crate::server::acme::{AcmeCertificateStore, AcmeCertificateStoreBuilder, AcmeChallenge};
 #[cfg(feature = "server")]
 pub use crate::server::connection::{BoxedConnection, Connection};
+#[cfg(all(
+    feature = "server",
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+pub use crate::server::listener::ReuseportListener;
+#[cfg(all(feature = "server", not(target_os = "windows")))]
+pub use crate::server::listener::UnixOptions;
 #[cfg(feature = "server")]
-pub use crate::server::listener::{AcceptFuture, Listen, Listener, Listeners, ListenersBuilder};
+pub use crate::server::listener::{
+    AcceptFuture, HttpListener, HttpProtocols, Listen, Listener, Listeners, ListenersBuilder,
+    TcpOptions,
+};
 #[cfg(feature = "server")]
-pub use crate::server::net_server::{NetServer, RateLimiterConfig};
+pub use crate::server::net_server::{NetServer, RateLimiterConfig, ShutdownHandle};
 #[cfg(feature = "server")]
 pub use crate::server::protocol::Protocol;
 #[cfg(feature = "quic")]
@@ -70,14 +87,68 @@ pub use crate::server::tls::ReloadableCertificateStore;
 #[cfg(feature = "server")]
 pub use crate::server::{BoxError, ConnectionFuture, ConnectionService, Server};
 #[cfg(all(feature = "server", feature = "tls"))]
-pub use crate::server::{CertificateStore, CertificateStoreBuilder};
+pub use crate::server::{CertificateStore, CertificateStoreBuilder, DEFAULT_ALPN, TlsConfigSource};
 #[cfg(feature = "server")]
-pub use crate::server::{ConnectionLimits, ServerConfig};
+pub use crate::server::{ConnectionLimits, Http2Config, ServerConfig};
+#[cfg(feature = "server")]
+pub use crate::server::{ReloadableRoute, RouteConnectionService};
+#[cfg(all(feature = "server", not(target_os = "windows")))]
+pub use crate::server::{UpgradeHandle, sd_notify};
 pub use error::SilentError;
 pub use error::SilentResult as Result;
 pub use handler::Handler;
 pub use handler::HandlerWrapper;
+#[cfg(feature = "proxy")]
+pub use handler::ProxyHandler;
 pub use headers;
 pub use hyper::{Method, StatusCode, header};
+#[cfg(feature = "server")]
+pub use route::{CheckReport, HealthRegistry, HealthReport, HealthStatus};
 #[cfg(feature = "scheduler")]
 pub use scheduler::{ProcessTime, SCHEDULER, Scheduler, SchedulerExt, Task};
+/// 类型化路由键：由路由模板生成，同时用于注册路由与生成 URL
+///
+/// ```rust
+/// use silent::extractor::Path;
+/// use silent::prelude::*;
+///
+/// silent::path!(pub PostPath = "/users/<id:u64>/posts/<slug>");
+///
+/// async fn show(Path(post): Path<PostPath>) -> Result<String> {
+///     Ok(format!("{} {}", post.id, post.slug))
+/// }
+///
+/// let route = Route::new("").append(Route::new(PostPath::PATTERN).get(show));
+/// let url = PostPath { id: 7, slug: "intro".into() }.url();
+/// assert_eq!(url, "/users/7/posts/intro");
+///
+/// // 表达式形式按参数顺序生成 URL
+/// let post = silent::path!("/users/<id:u64>/posts/<slug>");
+/// assert_eq!(post.url(7, "intro"), url);
+/// ```
+///
+/// 参数类型不匹配、未知的参数类型都会在编译期报错：
+///
+/// ```compile_fail
+/// let post = silent::path!("/users/<id:u64>");
+/// post.url("seven");
+/// ```
+///
+/// ```compile_fail
+/// silent::path!(UserPath = "/users/<id:u46>");
+/// ```
+pub use silent_macros::path;
+
+/// 供过程宏生成代码使用，不属于公开 API
+#[doc(hidden)]
+pub mod __private {
+    pub use async_trait::async_trait;
+    pub use chrono;
+    pub use serde;
+    pub use uuid;
+
+    pub use crate::route::encode_path_segment;
+
+    #[cfg(feature = "multipart")]
+    pub use crate::extractor::multipart::multipart_missing_field;
+}
@@ -0,0 +1,399 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use chrono::{DateTime, Utc};
+use futures_util::stream;
+use http::header::CONTENT_TYPE;
+use http::{HeaderMap, StatusCode};
+use http_body::Body;
+use http_body_util::BodyExt;
+use serde::{Serialize, Serializer};
+
+use super::{Redactor, RequestIdValue};
+use crate::core::req_body::ReqBody;
+use crate::core::res_body::full;
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, SilentError};
+
+/// 审计记录的接收端，如写入文件、Kafka 或数据库
+///
+/// 记录在响应返回前写入，耗时较长的接收端应在内部通过队列异步落盘，避免拖慢请求。
+/// 任意 `Fn(AuditRecord) -> impl Future<Output = ()>` 闭包也实现了该 trait。
+#[async_trait]
+pub trait AuditSink: Send + Sync + 'static {
+    async fn record(&self, record: AuditRecord);
+}
+
+#[async_trait]
+impl<F, Fut> AuditSink for F
+where
+    F: Fn(AuditRecord) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    async fn record(&self, record: AuditRecord) {
+        self(record).await
+    }
+}
+
+/// 一次请求的审计记录，可直接序列化为 JSON
+#[derive(Clone, Debug, Serialize)]
+pub struct AuditRecord {
+    /// 请求到达时间
+    pub timestamp: DateTime<Utc>,
+    /// 由 [`RequestId`](super::RequestId) 生成的请求 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    pub method: String,
+    pub uri: String,
+    /// 响应状态码，处理函数返回错误时为错误对应的状态码
+    pub status: u16,
+    pub elapsed_ms: f64,
+    pub request: AuditMessage,
+    pub response: AuditMessage,
+}
+
+/// 请求或响应的头部与响应体摘录
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct AuditMessage {
+    /// 按出现顺序排列的头部，敏感头部的值已按 [`Redactor`] 脱敏
+    pub headers: Vec<(String, String)>,
+    /// 不超过上限的消息体前缀（已脱敏），按 UTF-8 有损转换后序列化；未采集时为 `None`
+    #[serde(serialize_with = "serialize_body")]
+    pub body: Option<Bytes>,
+    /// 消息体超过上限被截断
+    pub truncated: bool,
+}
+
+fn serialize_body<S: Serializer>(
+    body: &Option<Bytes>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match body {
+        Some(body) => serializer.serialize_str(&String::from_utf8_lossy(body)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// AuditLog 中间件
+///
+/// 为合规审计记录请求与响应：方法、URI、状态码、耗时、头部，以及不超过上限（默认 64 KiB）的消息体，
+/// 交给可插拔的 [`AuditSink`] 处理。
+///
+/// # 行为
+///
+/// - 头部与消息体在交给接收端前经 [`Redactor`] 脱敏：默认屏蔽 `Authorization`、`Cookie` 等头部，
+///   以及 JSON 与表单消息体中的 `password`、`token` 字段，可通过 [`redactor`](Self::redactor) 替换规则
+/// - 请求体读取上限以内的前缀用于记录，处理函数仍能读到完整的请求体
+/// - 响应体仅在大小已知且不超过上限时记录；流式响应（如 SSE）不缓冲，`body` 为 `None`
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::{AuditLog, AuditRecord};
+///
+/// let audit = AuditLog::new(|record: AuditRecord| async move {
+///     tracing::info!(target: "audit", "{}", serde_json::to_string(&record).unwrap());
+/// })
+/// .max_body_size(16 * 1024)
+/// .redact_header("x-api-key");
+/// let route = Route::new("payments")
+///     .hook(audit)
+///     .post(|_req: Request| async { Ok("paid") });
+/// ```
+#[derive(Clone)]
+pub struct AuditLog {
+    sink: Arc<dyn AuditSink>,
+    max_body_size: usize,
+    redactor: Redactor,
+}
+
+impl AuditLog {
+    /// 创建中间件，消息体记录上限为 64 KiB
+    pub fn new(sink: impl AuditSink) -> Self {
+        Self {
+            sink: Arc::new(sink),
+            max_body_size: 64 * 1024,
+            redactor: Redactor::new(),
+        }
+    }
+
+    /// 设置记录的消息体大小上限（字节），为 0 时不记录消息体
+    pub fn max_body_size(mut self, size: usize) -> Self {
+        self.max_body_size = size;
+        self
+    }
+
+    /// 追加需要脱敏的头部
+    ///
+    /// # Panics
+    ///
+    /// 头部名称不合法时 panic。
+    pub fn redact_header(mut self, name: &str) -> Self {
+        self.redactor = self.redactor.header(name);
+        self
+    }
+
+    /// 替换脱敏规则，默认为 [`Redactor::new`]
+    pub fn redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = redactor;
+        self
+    }
+
+    /// 按消息的 `Content-Type` 脱敏消息体：表单按查询字符串处理，其余尝试按 JSON 处理
+    fn redact_body(&self, headers: &HeaderMap, body: Bytes) -> Bytes {
+        let form = headers
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("application/x-www-form-urlencoded"));
+        if form {
+            Bytes::from(self.redactor.query(&String::from_utf8_lossy(&body)))
+        } else {
+            self.redactor.body(&body)
+        }
+    }
+
+    /// 读取请求体上限以内的前缀，并将已读部分与剩余部分重新拼接为请求体
+    async fn capture_request(&self, req: &mut Request) -> Result<(Bytes, bool)> {
+        let mut body = req.take_body();
+        if matches!(body, ReqBody::Empty) {
+            return Ok((Bytes::new(), false));
+        }
+        let mut prefix = BytesMut::new();
+        let mut truncated = false;
+        while let Some(frame) = body.frame().await {
+            let frame = frame.map_err(|e| {
+                SilentError::business_error(
+                    StatusCode::BAD_REQUEST,
+                    format!("AuditLog: failed to read request body: {e}"),
+                )
+            })?;
+            if let Ok(data) = frame.into_data() {
+                prefix.extend_from_slice(&data);
+                if prefix.len() > self.max_body_size {
+                    truncated = true;
+                    break;
+                }
+            }
+        }
+        let prefix = prefix.freeze();
+        if truncated {
+            let head = stream::iter([Ok(prefix.clone())]);
+            let chained = futures_util::StreamExt::chain(head, body.into_data_stream());
+            req.replace_body(ReqBody::Streaming(Box::pin(chained)));
+            Ok((prefix.slice(..self.max_body_size), true))
+        } else {
+            req.replace_body(ReqBody::Once(prefix.clone()));
+            Ok((prefix, false))
+        }
+    }
+
+    /// 大小已知且不超过上限时读取响应体
+    async fn capture_response(&self, res: &mut Response) -> Result<(Option<Bytes>, bool)> {
+        match res.body.size_hint().exact() {
+            Some(size) if size as usize <= self.max_body_size => {
+                let body = res
+                    .take_body()
+                    .collect()
+                    .await
+                    .map_err(|e| {
+                        SilentError::business_error(
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            format!("AuditLog: failed to read response body: {e}"),
+                        )
+                    })?
+                    .to_bytes();
+                res.set_body(full(body.clone()));
+                Ok((Some(body), false))
+            }
+            Some(_) => Ok((None, true)),
+            None => Ok((None, false)),
+        }
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for AuditLog {
+    async fn handle(&self, mut req: Request, next: &Next) -> Result<Response> {
+        let timestamp = Utc::now();
+        let start = Instant::now();
+        let mut record = AuditRecord {
+            timestamp,
+            request_id: req
+                .extensions()
+                .get::<RequestIdValue>()
+                .map(|id| id.as_str().to_string()),
+            method: req.method().to_string(),
+            uri: req.uri().to_string(),
+            status: 0,
+            elapsed_ms: 0.0,
+            request: AuditMessage {
+                headers: self.redactor.headers(req.headers()),
+                ..Default::default()
+            },
+            response: AuditMessage::default(),
+        };
+        if self.max_body_size > 0 {
+            let (body, truncated) = self.capture_request(&mut req).await?;
+            record.request.body = Some(self.redact_body(req.headers(), body));
+            record.request.truncated = truncated;
+        }
+
+        let result = next.call(req).await;
+        let result = match result {
+            Ok(mut res) => {
+                record.status = res.status().as_u16();
+                record.response.headers = self.redactor.headers(res.headers());
+                if self.max_body_size > 0 {
+                    let (body, truncated) = self.capture_response(&mut res).await?;
+                    record.response.body = body.map(|body| self.redact_body(res.headers(), body));
+                    record.response.truncated = truncated;
+                }
+                Ok(res)
+            }
+            Err(err) => {
+                record.status = err.status().as_u16();
+                Err(err)
+            }
+        };
+        record.elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+        self.sink.record(record).await;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+    use http::header::{AUTHORIZATION, SET_COOKIE};
+    use std::sync::Mutex;
+
+    const REDACTED: &str = "[REDACTED]";
+
+    fn route(audit: AuditLog) -> Route {
+        let route = Route::new("")
+            .hook(audit)
+            .post(|mut req: Request| async move {
+                let body = req.take_body().collect().await.unwrap().to_bytes();
+                let mut res = Response::text(&format!("echo {}", body.len()));
+                res.headers_mut()
+                    .insert(SET_COOKIE, "session=secret".parse().unwrap());
+                Ok(res)
+            });
+        Route::new_root().append(route)
+    }
+
+    fn request(body: &'static str) -> Request {
+        let mut req = Request::empty();
+        *req.method_mut() = http::Method::POST;
+        req.headers_mut()
+            .insert(AUTHORIZATION, "Bearer token".parse().unwrap());
+        req.headers_mut()
+            .insert("x-api-key", "key".parse().unwrap());
+        req.headers_mut()
+            .insert("x-trace", "trace".parse().unwrap());
+        req.replace_body(ReqBody::Once(Bytes::from_static(body.as_bytes())));
+        req
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_records_and_redacts() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink = records.clone();
+        let audit = AuditLog::new(move |record: AuditRecord| {
+            sink.lock().unwrap().push(record);
+            async {}
+        })
+        .redact_header("x-api-key");
+        let route = route(audit);
+
+        let res = crate::Handler::call(&route, request("hello"))
+            .await
+            .unwrap();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"echo 5");
+
+        let record = records.lock().unwrap().pop().unwrap();
+        assert_eq!(record.method, "POST");
+        assert_eq!(record.status, 200);
+        let header = |headers: &[(String, String)], name: &str| {
+            headers
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v.clone())
+                .unwrap()
+        };
+        assert_eq!(header(&record.request.headers, "authorization"), REDACTED);
+        assert_eq!(header(&record.request.headers, "x-api-key"), REDACTED);
+        assert_eq!(header(&record.request.headers, "x-trace"), "trace");
+        assert_eq!(header(&record.response.headers, "set-cookie"), REDACTED);
+        assert_eq!(record.request.body.as_deref(), Some(&b"hello"[..]));
+        assert_eq!(record.response.body.as_deref(), Some(&b"echo 5"[..]));
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["request"]["body"], "hello");
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_redacts_body() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink = records.clone();
+        let audit = AuditLog::new(move |record: AuditRecord| {
+            sink.lock().unwrap().push(record);
+            async {}
+        })
+        .redactor(Redactor::new().field("pin"));
+        let route = route(audit);
+
+        let res = crate::Handler::call(&route, request(r#"{"user":"a","password":"p"}"#))
+            .await
+            .unwrap();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"echo 27");
+        let record = records.lock().unwrap().pop().unwrap();
+        let body: serde_json::Value =
+            serde_json::from_slice(record.request.body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["user"], "a");
+        assert_eq!(body["password"], REDACTED);
+
+        let mut req = request("user=a&pin=1234");
+        req.headers_mut().insert(
+            http::header::CONTENT_TYPE,
+            "application/x-www-form-urlencoded".parse().unwrap(),
+        );
+        crate::Handler::call(&route, req).await.unwrap();
+        let record = records.lock().unwrap().pop().unwrap();
+        assert_eq!(
+            record.request.body.as_deref(),
+            Some(&b"user=a&pin=[REDACTED]"[..])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_truncates_body() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink = records.clone();
+        let audit = AuditLog::new(move |record: AuditRecord| {
+            sink.lock().unwrap().push(record);
+            async {}
+        })
+        .max_body_size(4);
+        let route = route(audit);
+
+        // 处理函数仍能读到完整请求体
+        let res = crate::Handler::call(&route, request("hello world"))
+            .await
+            .unwrap();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"echo 11");
+
+        let record = records.lock().unwrap().pop().unwrap();
+        assert_eq!(record.request.body.as_deref(), Some(&b"hell"[..]));
+        assert!(record.request.truncated);
+        assert!(record.response.body.is_none());
+        assert!(record.response.truncated);
+    }
+}
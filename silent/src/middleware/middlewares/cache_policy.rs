@@ -0,0 +1,297 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use http::header::{CACHE_CONTROL, CONTENT_TYPE, EXPIRES};
+use http::{HeaderName, HeaderValue};
+
+use super::path_glob::PathGlob;
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result};
+
+const SURROGATE_CONTROL: HeaderName = HeaderName::from_static("surrogate-control");
+
+/// 一组缓存指令，由 [`CachePolicy`] 写入匹配的响应
+///
+/// ```rust
+/// use silent::middlewares::CacheRule;
+/// use std::time::Duration;
+///
+/// let rule = CacheRule::public()
+///     .max_age(Duration::from_secs(3600))
+///     .immutable()
+///     .surrogate_max_age(Duration::from_secs(86400));
+/// assert_eq!(rule.cache_control(), "public, max-age=3600, immutable");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CacheRule {
+    directives: Vec<String>,
+    max_age: Option<Duration>,
+    expires: bool,
+    surrogate: Option<String>,
+}
+
+impl CacheRule {
+    fn with(directive: &str) -> Self {
+        Self::default().directive(directive)
+    }
+
+    /// `public`：允许 CDN 与浏览器缓存
+    pub fn public() -> Self {
+        Self::with("public")
+    }
+
+    /// `private`：仅允许浏览器缓存
+    pub fn private() -> Self {
+        Self::with("private")
+    }
+
+    /// `no-store`：禁止任何缓存
+    pub fn no_store() -> Self {
+        Self::with("no-store")
+    }
+
+    /// `no-cache`：可以缓存，但每次使用前必须重新验证
+    pub fn no_cache() -> Self {
+        Self::with("no-cache")
+    }
+
+    /// 追加任意指令，如 `must-revalidate`
+    pub fn directive(mut self, directive: &str) -> Self {
+        self.directives.push(directive.to_string());
+        self
+    }
+
+    /// `max-age`
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self.directive(&format!("max-age={}", max_age.as_secs()))
+    }
+
+    /// `s-maxage`：共享缓存（CDN）的有效期
+    pub fn s_maxage(self, s_maxage: Duration) -> Self {
+        self.directive(&format!("s-maxage={}", s_maxage.as_secs()))
+    }
+
+    /// `stale-while-revalidate`
+    pub fn stale_while_revalidate(self, duration: Duration) -> Self {
+        self.directive(&format!("stale-while-revalidate={}", duration.as_secs()))
+    }
+
+    /// `immutable`：内容在有效期内不会变化，适合带哈希的静态资源
+    pub fn immutable(self) -> Self {
+        self.directive("immutable")
+    }
+
+    /// `must-revalidate`
+    pub fn must_revalidate(self) -> Self {
+        self.directive("must-revalidate")
+    }
+
+    /// 同时按 `max-age` 写入 `Expires` 头，兼容只认 HTTP/1.0 缓存头的代理
+    pub fn with_expires(mut self) -> Self {
+        self.expires = true;
+        self
+    }
+
+    /// 写入 `Surrogate-Control: max-age=N`，供 Fastly、Varnish 等边缘缓存使用
+    pub fn surrogate_max_age(mut self, max_age: Duration) -> Self {
+        self.surrogate = Some(format!("max-age={}", max_age.as_secs()));
+        self
+    }
+
+    /// 生成的 `Cache-Control` 值
+    pub fn cache_control(&self) -> String {
+        self.directives.join(", ")
+    }
+
+    fn apply(&self, res: &mut Response) {
+        let headers = res.headers_mut();
+        if !self.directives.is_empty()
+            && let Ok(value) = HeaderValue::from_str(&self.cache_control())
+        {
+            headers.insert(CACHE_CONTROL, value);
+        }
+        if self.expires
+            && let Some(max_age) = self.max_age
+            && let Some(expires) = chrono::TimeDelta::from_std(max_age)
+                .ok()
+                .and_then(|delta| chrono::Utc::now().checked_add_signed(delta))
+            && let Ok(value) =
+                HeaderValue::from_str(&expires.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+        {
+            headers.insert(EXPIRES, value);
+        }
+        if let Some(surrogate) = &self.surrogate
+            && let Ok(value) = HeaderValue::from_str(surrogate)
+        {
+            headers.insert(SURROGATE_CONTROL, value);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Matcher {
+    Path(PathGlob),
+    ContentType(String),
+}
+
+impl Matcher {
+    fn matches(&self, path: &str, content_type: &str) -> bool {
+        match self {
+            Matcher::Path(pattern) => pattern.matches(path),
+            Matcher::ContentType(pattern) => match pattern.strip_suffix("/*") {
+                Some(top) => content_type
+                    .split_once('/')
+                    .is_some_and(|(t, _)| t.eq_ignore_ascii_case(top)),
+                None => content_type.eq_ignore_ascii_case(pattern),
+            },
+        }
+    }
+}
+
+/// CachePolicy 中间件
+///
+/// 按路径模式或响应的 `Content-Type` 声明式地设置 `Cache-Control`、`Expires` 与 `Surrogate-Control`，
+/// 让 CDN 缓存策略集中在一处，而不是分散在各个处理函数的 `set_header` 调用中。
+///
+/// # 行为
+///
+/// - 规则按添加顺序匹配，第一条匹配的规则生效；都不匹配时使用 [`fallback`](Self::fallback)（如有）
+/// - 路径模式按 `/` 分段，`**` 匹配任意多段，段内 `*` 匹配任意字符，如 `/assets/**/*.js`
+/// - `Content-Type` 模式支持 `image/*` 形式的通配，忽略 `; charset=...` 等参数
+/// - 只作用于 2xx 响应；处理函数已设置 `Cache-Control` 的响应保持不变
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::{CachePolicy, CacheRule};
+/// use std::time::Duration;
+///
+/// let policy = CachePolicy::new()
+///     .path("/assets/**", CacheRule::public().max_age(Duration::from_secs(31536000)).immutable())
+///     .path("/api/**", CacheRule::no_store())
+///     .content_type("image/*", CacheRule::public().max_age(Duration::from_secs(86400)).with_expires())
+///     .fallback(CacheRule::no_cache());
+/// let route = Route::new("").hook(policy);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CachePolicy {
+    rules: Vec<(Matcher, CacheRule)>,
+    fallback: Option<CacheRule>,
+}
+
+impl CachePolicy {
+    /// 创建空的策略
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 为匹配路径模式的请求设置缓存规则
+    pub fn path(mut self, pattern: &str, rule: CacheRule) -> Self {
+        self.rules
+            .push((Matcher::Path(PathGlob::new(pattern)), rule));
+        self
+    }
+
+    /// 为 `Content-Type` 匹配的响应设置缓存规则
+    pub fn content_type(mut self, pattern: &str, rule: CacheRule) -> Self {
+        self.rules
+            .push((Matcher::ContentType(pattern.trim().to_string()), rule));
+        self
+    }
+
+    /// 没有规则匹配时使用的缓存规则
+    pub fn fallback(mut self, rule: CacheRule) -> Self {
+        self.fallback = Some(rule);
+        self
+    }
+
+    fn find(&self, path: &str, content_type: &str) -> Option<&CacheRule> {
+        self.rules
+            .iter()
+            .find(|(matcher, _)| matcher.matches(path, content_type))
+            .map(|(_, rule)| rule)
+            .or(self.fallback.as_ref())
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for CachePolicy {
+    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+        let path = req.uri().path().to_string();
+        let mut res = next.call(req).await?;
+        if !res.status().is_success() || res.headers().contains_key(CACHE_CONTROL) {
+            return Ok(res);
+        }
+        let content_type = res
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split(';').next())
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        if let Some(rule) = self.find(&path, &content_type) {
+            rule.apply(&mut res);
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+
+    #[tokio::test]
+    async fn test_cache_policy() {
+        let policy = CachePolicy::new()
+            .path(
+                "/assets/**",
+                CacheRule::public()
+                    .max_age(Duration::from_secs(60))
+                    .with_expires()
+                    .surrogate_max_age(Duration::from_secs(600)),
+            )
+            .content_type("text/*", CacheRule::no_cache())
+            .fallback(CacheRule::no_store());
+        let route = Route::new("")
+            .hook(policy)
+            .append(Route::new("assets/<path:**>").get(|_req: Request| async { Ok("asset") }))
+            .append(
+                Route::new("page").get(|_req: Request| async { Ok(Response::html("<p>page</p>")) }),
+            )
+            .append(Route::new("data").get(|_req: Request| async { Ok(vec![1, 2, 3]) }))
+            .append(Route::new("custom").get(|_req: Request| async {
+                let mut res = Response::text("custom");
+                res.headers_mut()
+                    .insert(CACHE_CONTROL, HeaderValue::from_static("max-age=5"));
+                Ok(res)
+            }));
+        let route = Route::new_root().append(route);
+
+        let call = |path: &'static str| {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            crate::Handler::call(&route, req)
+        };
+
+        let res = call("/assets/app.js").await.unwrap();
+        assert_eq!(res.headers()[CACHE_CONTROL], "public, max-age=60");
+        assert!(res.headers()[EXPIRES].to_str().unwrap().ends_with(" GMT"));
+        assert_eq!(res.headers()[SURROGATE_CONTROL], "max-age=600");
+
+        let res = call("/page").await.unwrap();
+        assert_eq!(res.headers()[CACHE_CONTROL], "no-cache");
+        assert!(!res.headers().contains_key(EXPIRES));
+
+        let res = call("/data").await.unwrap();
+        assert_eq!(res.headers()[CACHE_CONTROL], "no-store");
+
+        let res = call("/custom").await.unwrap();
+        assert_eq!(res.headers()[CACHE_CONTROL], "max-age=5");
+
+        // 非 2xx 响应不处理
+        assert!(call("/missing").await.is_err());
+    }
+}
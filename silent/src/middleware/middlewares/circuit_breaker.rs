@@ -0,0 +1,394 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use http::header::RETRY_AFTER;
+
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, StatusCode};
+
+/// 熔断器状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    /// 正常放行并统计失败率
+    Closed,
+    /// 直接返回 `503`，等待冷却结束
+    Open,
+    /// 冷却结束后放行少量探测请求，全部成功则恢复
+    HalfOpen,
+}
+
+enum Phase {
+    Closed,
+    Open { until: Instant },
+    HalfOpen { in_flight: u32, successes: u32 },
+}
+
+struct Circuit {
+    phase: Phase,
+    window_start: Instant,
+    requests: u32,
+    failures: u32,
+}
+
+impl Circuit {
+    fn reset_window(&mut self, now: Instant) {
+        self.window_start = now;
+        self.requests = 0;
+        self.failures = 0;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PermitKind {
+    Normal,
+    Probe,
+}
+
+/// 放行许可，探测请求未完成（如被取消）时按失败处理
+struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    kind: PermitKind,
+    done: bool,
+}
+
+impl Permit<'_> {
+    fn complete(mut self, failed: bool) {
+        self.done = true;
+        self.breaker.record(self.kind, failed);
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if !self.done && self.kind == PermitKind::Probe {
+            self.breaker.record(self.kind, true);
+        }
+    }
+}
+
+/// CircuitBreaker 中间件
+///
+/// 为调用不稳定后端的路由提供熔断保护：统计窗口内失败率达到阈值后进入打开状态，
+/// 冷却期间直接返回 `503 Service Unavailable`（附带 `Retry-After`），不再调用处理函数；
+/// 冷却结束后进入半开状态，放行少量探测请求，全部成功则恢复，任一失败则重新打开。
+///
+/// # 行为
+///
+/// - 处理函数返回 `5xx` 错误或 `5xx` 响应视为失败，`4xx` 等客户端错误不计入失败
+/// - 默认统计窗口 10 秒，窗口内至少 10 个请求且失败率不低于 50% 时打开，冷却 30 秒，半开时放行 1 个探测请求
+/// - 状态保存在中间件实例中，克隆共享同一状态；挂载到哪个路由，即对该路由生效，多个路由应各自创建实例
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::CircuitBreaker;
+/// use std::time::Duration;
+///
+/// let route = Route::new("payments")
+///     .hook(
+///         CircuitBreaker::new()
+///             .failure_rate(0.3)
+///             .min_requests(20)
+///             .open_duration(Duration::from_secs(15)),
+///     )
+///     .post(|_req: Request| async { Ok("paid") });
+/// ```
+#[derive(Clone)]
+pub struct CircuitBreaker {
+    failure_rate: f64,
+    min_requests: u32,
+    window: Duration,
+    open_duration: Duration,
+    half_open_requests: u32,
+    circuit: Arc<Mutex<Circuit>>,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CircuitBreaker {
+    /// 使用默认阈值创建
+    pub fn new() -> Self {
+        Self {
+            failure_rate: 0.5,
+            min_requests: 10,
+            window: Duration::from_secs(10),
+            open_duration: Duration::from_secs(30),
+            half_open_requests: 1,
+            circuit: Arc::new(Mutex::new(Circuit {
+                phase: Phase::Closed,
+                window_start: Instant::now(),
+                requests: 0,
+                failures: 0,
+            })),
+        }
+    }
+
+    /// 触发熔断的失败率，取值范围 `(0, 1]`
+    ///
+    /// # Panics
+    ///
+    /// 取值超出范围时 panic。
+    pub fn failure_rate(mut self, rate: f64) -> Self {
+        assert!(
+            rate > 0.0 && rate <= 1.0,
+            "CircuitBreaker: failure rate must be in (0, 1]"
+        );
+        self.failure_rate = rate;
+        self
+    }
+
+    /// 统计窗口内达到该请求数后才计算失败率
+    pub fn min_requests(mut self, count: u32) -> Self {
+        self.min_requests = count.max(1);
+        self
+    }
+
+    /// 失败率的统计窗口
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// 打开后的冷却时间
+    pub fn open_duration(mut self, duration: Duration) -> Self {
+        self.open_duration = duration;
+        self
+    }
+
+    /// 半开状态下放行的探测请求数
+    pub fn half_open_requests(mut self, count: u32) -> Self {
+        self.half_open_requests = count.max(1);
+        self
+    }
+
+    /// 当前状态，冷却已结束但尚无请求时仍报告为 [`CircuitState::Open`]
+    pub fn state(&self) -> CircuitState {
+        match self.lock().phase {
+            Phase::Closed => CircuitState::Closed,
+            Phase::Open { .. } => CircuitState::Open,
+            Phase::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Circuit> {
+        self.circuit.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 申请放行，拒绝时返回建议的重试等待时间
+    fn acquire(&self) -> std::result::Result<Permit<'_>, Duration> {
+        let mut guard = self.lock();
+        let circuit = &mut *guard;
+        let now = Instant::now();
+        if let Phase::Open { until } = circuit.phase {
+            if now < until {
+                return Err(until - now);
+            }
+            circuit.phase = Phase::HalfOpen {
+                in_flight: 0,
+                successes: 0,
+            };
+        }
+        let kind = match &mut circuit.phase {
+            Phase::Closed => PermitKind::Normal,
+            Phase::HalfOpen {
+                in_flight,
+                successes,
+            } => {
+                if *in_flight + *successes >= self.half_open_requests {
+                    return Err(Duration::from_secs(1));
+                }
+                *in_flight += 1;
+                PermitKind::Probe
+            }
+            Phase::Open { .. } => unreachable!(),
+        };
+        Ok(Permit {
+            breaker: self,
+            kind,
+            done: false,
+        })
+    }
+
+    fn record(&self, kind: PermitKind, failed: bool) {
+        let mut guard = self.lock();
+        let circuit = &mut *guard;
+        let now = Instant::now();
+        match (kind, &mut circuit.phase) {
+            (PermitKind::Normal, Phase::Closed) => {
+                if now.duration_since(circuit.window_start) >= self.window {
+                    circuit.reset_window(now);
+                }
+                circuit.requests += 1;
+                circuit.failures += u32::from(failed);
+                let rate = f64::from(circuit.failures) / f64::from(circuit.requests);
+                if circuit.requests >= self.min_requests && rate >= self.failure_rate {
+                    tracing::warn!(
+                        requests = circuit.requests,
+                        failures = circuit.failures,
+                        "circuit breaker opened"
+                    );
+                    circuit.phase = Phase::Open {
+                        until: now + self.open_duration,
+                    };
+                }
+            }
+            (
+                PermitKind::Probe,
+                Phase::HalfOpen {
+                    in_flight,
+                    successes,
+                },
+            ) => {
+                *in_flight -= 1;
+                if failed {
+                    tracing::warn!("circuit breaker probe failed, reopened");
+                    circuit.phase = Phase::Open {
+                        until: now + self.open_duration,
+                    };
+                } else {
+                    *successes += 1;
+                    if *successes >= self.half_open_requests {
+                        tracing::info!("circuit breaker closed");
+                        circuit.phase = Phase::Closed;
+                        circuit.reset_window(now);
+                    }
+                }
+            }
+            // 状态已被并发请求改变，结果不再计入
+            _ => {}
+        }
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for CircuitBreaker {
+    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+        let permit = match self.acquire() {
+            Ok(permit) => permit,
+            Err(retry_after) => {
+                let mut res = Response::empty();
+                res.set_status(StatusCode::SERVICE_UNAVAILABLE);
+                let secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+                res.headers_mut().insert(RETRY_AFTER, secs.max(1).into());
+                res.set_body(crate::core::res_body::full("Circuit breaker is open"));
+                return Ok(res);
+            }
+        };
+        let res = next.call(req).await;
+        let failed = match &res {
+            Ok(res) => res.status().is_server_error(),
+            Err(e) => e.status().is_server_error(),
+        };
+        permit.complete(failed);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    async fn call(route: &Route) -> StatusCode {
+        match crate::Handler::call(route, Request::empty()).await {
+            Ok(res) => res.status(),
+            Err(err) => err.status(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_transitions() {
+        let healthy = Arc::new(AtomicBool::new(false));
+        let breaker = CircuitBreaker::new()
+            .min_requests(4)
+            .failure_rate(0.5)
+            .open_duration(Duration::from_millis(30));
+        let flag = healthy.clone();
+        let route = Route::new_root().append(Route::new("").hook(breaker.clone()).get(
+            move |_req: Request| {
+                let healthy = flag.load(Ordering::SeqCst);
+                async move {
+                    if healthy {
+                        Ok(Response::text("ok"))
+                    } else {
+                        Err(crate::SilentError::business_error(
+                            StatusCode::BAD_GATEWAY,
+                            "upstream down".to_string(),
+                        ))
+                    }
+                }
+            },
+        ));
+
+        for _ in 0..4 {
+            assert_eq!(call(&route).await, StatusCode::BAD_GATEWAY);
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+        let res = crate::Handler::call(&route, Request::empty())
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(res.headers()[RETRY_AFTER], "1");
+
+        // 冷却结束后探测失败，重新打开
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert_eq!(call(&route).await, StatusCode::BAD_GATEWAY);
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // 探测成功后恢复
+        healthy.store(true, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert_eq!(call(&route).await, StatusCode::OK);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(call(&route).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_ignores_client_errors() {
+        let breaker = CircuitBreaker::new().min_requests(4);
+        let route = Route::new_root().append(Route::new("").hook(breaker.clone()).get(
+            |_req: Request| async {
+                Err::<Response, _>(crate::SilentError::business_error(
+                    StatusCode::BAD_REQUEST,
+                    "bad input".to_string(),
+                ))
+            },
+        ));
+
+        for _ in 0..8 {
+            assert_eq!(call(&route).await, StatusCode::BAD_REQUEST);
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_circuit_breaker_min_requests() {
+        let breaker = CircuitBreaker::new().min_requests(3);
+        for _ in 0..2 {
+            breaker.acquire().ok().unwrap().complete(true);
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.acquire().ok().unwrap().complete(false);
+        // 3 个请求中 2 个失败，超过 50%
+        assert_eq!(breaker.state(), CircuitState::Open);
+    }
+
+    #[test]
+    fn test_circuit_breaker_dropped_probe() {
+        let breaker = CircuitBreaker::new()
+            .min_requests(1)
+            .open_duration(Duration::ZERO);
+        breaker.acquire().ok().unwrap().complete(true);
+        let probe = breaker.acquire().ok().unwrap();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.acquire().is_err());
+        drop(probe);
+        assert_eq!(breaker.state(), CircuitState::Open);
+    }
+}
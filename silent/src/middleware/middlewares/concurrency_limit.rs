@@ -0,0 +1,166 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::Semaphore;
+
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, StatusCode};
+
+/// ConcurrencyLimit 中间件
+///
+/// 限制同时处理的请求数，适合报表生成、导出等占用大量资源的接口。
+///
+/// # 行为
+///
+/// 1. 请求到达时获取一个许可，处理函数返回后释放（流式响应体的发送不计入）
+/// 2. 默认不排队，许可耗尽时立即返回 `503 Service Unavailable`
+/// 3. 设置 [`queue_timeout`](Self::queue_timeout) 后，请求最多排队等待该时长，超时仍未获得许可时返回 `503`
+///
+/// 克隆的中间件共享同一组许可，可挂载到多个路由上共同限流。
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::ConcurrencyLimit;
+/// use std::time::Duration;
+///
+/// // 最多同时生成 4 份报表，其余请求最多排队 10 秒
+/// let route = Route::new("reports")
+///     .hook(ConcurrencyLimit::new(4).queue_timeout(Duration::from_secs(10)))
+///     .post(|_req: Request| async { Ok("generated") });
+/// ```
+#[derive(Clone)]
+pub struct ConcurrencyLimit {
+    semaphore: Arc<Semaphore>,
+    max: usize,
+    queue_timeout: Option<Duration>,
+}
+
+impl ConcurrencyLimit {
+    /// 创建最多同时处理 `max` 个请求的中间件，`max` 至少为 1
+    pub fn new(max: usize) -> Self {
+        let max = max.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(max)),
+            max,
+            queue_timeout: None,
+        }
+    }
+
+    /// 设置排队等待许可的最长时间
+    pub fn queue_timeout(mut self, timeout: Duration) -> Self {
+        self.queue_timeout = Some(timeout);
+        self
+    }
+
+    /// 最大并发数
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// 当前可用的许可数
+    pub fn available(&self) -> usize {
+        self.semaphore.available_permits()
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for ConcurrencyLimit {
+    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+        let permit = match self.queue_timeout {
+            None => self.semaphore.try_acquire().ok(),
+            Some(timeout) => tokio::time::timeout(timeout, self.semaphore.acquire())
+                .await
+                .ok()
+                .and_then(|permit| permit.ok()),
+        };
+        let Some(_permit) = permit else {
+            tracing::debug!(max = self.max, "concurrency limit exceeded");
+            let mut res = Response::empty();
+            res.set_status(StatusCode::SERVICE_UNAVAILABLE);
+            res.set_body(crate::core::res_body::full("Service Unavailable"));
+            return Ok(res);
+        };
+        next.call(req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+
+    async fn slow(_req: Request) -> Result<&'static str> {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        Ok("done")
+    }
+
+    #[test]
+    fn test_concurrency_limit_new() {
+        let limit = ConcurrencyLimit::new(0);
+        assert_eq!(limit.max(), 1);
+        assert_eq!(limit.available(), 1);
+        assert!(limit.queue_timeout.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_limit_sheds_load() {
+        let limit = ConcurrencyLimit::new(2);
+        let route = Route::new("/").hook(limit.clone()).get(slow);
+        let route = Arc::new(Route::new_root().append(route));
+
+        let tasks: Vec<_> = (0..3)
+            .map(|_| {
+                let route = route.clone();
+                tokio::spawn(async move {
+                    crate::Handler::call(&*route, Request::empty())
+                        .await
+                        .unwrap()
+                        .status()
+                })
+            })
+            .collect();
+        let mut statuses = Vec::new();
+        for task in tasks {
+            statuses.push(task.await.unwrap());
+        }
+        statuses.sort();
+        assert_eq!(
+            statuses,
+            [
+                StatusCode::OK,
+                StatusCode::OK,
+                StatusCode::SERVICE_UNAVAILABLE
+            ]
+        );
+        assert_eq!(limit.available(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_limit_queue_timeout() {
+        let route = |timeout: Duration| {
+            let route = Route::new("/")
+                .hook(ConcurrencyLimit::new(1).queue_timeout(timeout))
+                .get(slow);
+            Arc::new(Route::new_root().append(route))
+        };
+
+        for (timeout, expected) in [
+            (Duration::from_secs(5), StatusCode::OK),
+            (Duration::from_millis(10), StatusCode::SERVICE_UNAVAILABLE),
+        ] {
+            let route = route(timeout);
+            let first = {
+                let route = route.clone();
+                tokio::spawn(async move { crate::Handler::call(&*route, Request::empty()).await })
+            };
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let res = crate::Handler::call(&*route, Request::empty())
+                .await
+                .unwrap();
+            assert_eq!(res.status(), expected);
+            assert_eq!(first.await.unwrap().unwrap().status(), StatusCode::OK);
+        }
+    }
+}
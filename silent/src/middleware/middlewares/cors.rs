@@ -1,6 +1,8 @@
+use crate::route::Dispatcher;
 use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, SilentError};
 use async_trait::async_trait;
 use http::{HeaderMap, Method, header};
+use regex::Regex;
 use std::sync::OnceLock;
 
 #[derive(Debug)]
@@ -40,6 +42,7 @@ impl From<Vec<header::HeaderName>> for CorsType {
 enum CorsOriginType {
     Any,
     AllowSome(Vec<String>),
+    Regex(Vec<Regex>),
 }
 
 impl CorsOriginType {
@@ -53,6 +56,13 @@ impl CorsOriginType {
                     "".to_string()
                 }
             }
+            CorsOriginType::Regex(patterns) => {
+                if patterns.iter().any(|p| p.is_match(origin)) {
+                    origin.to_string()
+                } else {
+                    "".to_string()
+                }
+            }
         }
     }
 }
@@ -98,6 +108,16 @@ impl From<&str> for CorsType {
 ///                .methods("POST")
 ///                .headers("authorization,accept")
 ///                .credentials(true);
+/// // 按正则匹配来源，可与精确来源合并
+/// let _ = Cors::new()
+///                .origin("https://example.com")
+///                .origin_regex(r"https://[a-z0-9-]+\.example\.com")
+///                .max_age(600);
+/// ```
+///
+/// 只有携带 `Access-Control-Request-Method` 的 `OPTIONS` 请求被视为预检请求，
+/// 直接返回允许的方法、请求头与 `Max-Age` 而不进入后续中间件与处理函数；未配置 `methods` 时按路由树中该路径
+/// 注册的方法声明，未配置 `headers` 时允许预检声明的全部请求头。
 #[derive(Debug)]
 pub struct Cors {
     origin: Option<CorsOriginType>,
@@ -135,6 +155,27 @@ impl Cors {
         self.origin = Some(origin.into().into());
         self
     }
+    /// 允许匹配正则表达式的来源，如 `r"https://.*\.example\.com"`
+    ///
+    /// 表达式需匹配完整的 `Origin` 值；可多次调用，与已设置的精确来源合并。表达式不合法时 panic。
+    pub fn origin_regex(mut self, pattern: &str) -> Self {
+        let regex = Regex::new(&format!("^(?:{pattern})$"))
+            .unwrap_or_else(|e| panic!("Cors: invalid origin regex {pattern:?}: {e}"));
+        let mut patterns = match self.origin.take() {
+            Some(CorsOriginType::Regex(patterns)) => patterns,
+            Some(CorsOriginType::AllowSome(origins)) => origins
+                .iter()
+                .map(|origin| {
+                    Regex::new(&format!("^{}$", regex::escape(origin)))
+                        .expect("escaped origin is a valid regex")
+                })
+                .collect(),
+            Some(CorsOriginType::Any) | None => Vec::new(),
+        };
+        patterns.push(regex);
+        self.origin = Some(CorsOriginType::Regex(patterns));
+        self
+    }
     pub fn methods<T>(mut self, methods: T) -> Self
     where
         T: Into<CorsType>,
@@ -206,7 +247,7 @@ impl MiddleWareHandler for Cors {
     async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
         let req_origin = req
             .headers()
-            .get("origin")
+            .get(header::ORIGIN)
             .map_or("", |v| v.to_str().unwrap_or(""))
             .to_string();
 
@@ -215,12 +256,26 @@ impl MiddleWareHandler for Cors {
             return next.call(req).await;
         }
 
-        // 优化：复用预构建的响应头模板
+        // 只有携带 Access-Control-Request-Method 的 OPTIONS 请求才是预检请求，
+        // 其余 OPTIONS 请求按普通跨域请求交给路由处理
+        let preflight = req.method() == Method::OPTIONS
+            && req
+                .headers()
+                .contains_key(header::ACCESS_CONTROL_REQUEST_METHOD);
+
         let mut res = Response::empty();
 
-        // 复制缓存的静态头部 (优化：避免重复构建)
-        let cached_headers = self.get_cached_headers();
-        res.headers_mut().extend(cached_headers.clone());
+        // 复制缓存的静态头部：预检响应不带 Expose-Headers，普通响应只带 Credentials 与 Expose-Headers
+        for (name, value) in self.get_cached_headers() {
+            let keep = match *name {
+                header::ACCESS_CONTROL_ALLOW_CREDENTIALS => true,
+                header::ACCESS_CONTROL_EXPOSE_HEADERS => !preflight,
+                _ => preflight,
+            };
+            if keep {
+                res.headers_mut().insert(name, value.clone());
+            }
+        }
 
         // 只处理动态的 Origin 头部
         if let Some(ref origin) = self.origin {
@@ -232,7 +287,7 @@ impl MiddleWareHandler for Cors {
                 ));
             }
             res.headers_mut().insert(
-                "Access-Control-Allow-Origin",
+                header::ACCESS_CONTROL_ALLOW_ORIGIN,
                 origin.parse().map_err(|e| {
                     SilentError::business_error(
                         http::StatusCode::INTERNAL_SERVER_ERROR,
@@ -242,19 +297,50 @@ impl MiddleWareHandler for Cors {
             );
         }
 
-        if req.method() == Method::OPTIONS {
-            return Ok(res);
-        }
-        match next.call(req).await {
-            Ok(result) => {
-                res.copy_from_response(result);
-                Ok(res)
+        if preflight {
+            // 未配置 headers 时，允许预检请求声明的全部请求头
+            if self.headers.is_none()
+                && let Some(request_headers) = req
+                    .headers()
+                    .get(header::ACCESS_CONTROL_REQUEST_HEADERS)
+                    .cloned()
+            {
+                res.headers_mut()
+                    .insert(header::ACCESS_CONTROL_ALLOW_HEADERS, request_headers);
+                res.headers_mut().append(
+                    header::VARY,
+                    header::ACCESS_CONTROL_REQUEST_HEADERS
+                        .as_str()
+                        .parse()
+                        .unwrap(),
+                );
             }
-            Err(e) => {
-                res.copy_from_response(e.into());
-                Ok(res)
+            // 未配置 methods 时，以命中路由的 Allow 头作为预检允许的方法；
+            // 只查询路由树，预检请求不会执行后续中间件与处理函数
+            if self.methods.is_none()
+                && let Some(allow) = req
+                    .extensions()
+                    .get::<Dispatcher>()
+                    .and_then(|dispatcher| dispatcher.allow_header(&req))
+            {
+                res.headers_mut()
+                    .insert(header::ACCESS_CONTROL_ALLOW_METHODS, allow);
             }
+            res.headers_mut()
+                .append(header::VARY, header::ORIGIN.as_str().parse().unwrap());
+            return Ok(res);
         }
+        let cors_headers = std::mem::take(res.headers_mut());
+        let mut res = match next.call(req).await {
+            Ok(result) => result,
+            Err(e) => e.into(),
+        };
+        let mut headers = cors_headers;
+        headers.extend(std::mem::take(res.headers_mut()));
+        *res.headers_mut() = headers;
+        res.headers_mut()
+            .append(header::VARY, header::ORIGIN.as_str().parse().unwrap());
+        Ok(res)
     }
 }
 
@@ -588,6 +674,66 @@ mod tests {
         assert_eq!(res.status, http::StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_cors_preflight_uses_route_methods() {
+        let route = Route::new("items")
+            .hook(Cors::new().origin(CorsType::Any))
+            .get(|_req: Request| async { Ok("list") })
+            .post(|_req: Request| async { Ok("created") });
+        let route = Route::new_root().append(route);
+        let mut req = Request::empty();
+        *req.method_mut() = Method::OPTIONS;
+        *req.uri_mut() = "http://localhost:8080/items".parse().unwrap();
+        req.headers_mut()
+            .insert("origin", "http://localhost:8080".parse().unwrap());
+        req.headers_mut()
+            .insert("access-control-request-method", "POST".parse().unwrap());
+        let res = route.call(req).await.unwrap();
+        assert_eq!(res.status, http::StatusCode::OK);
+        assert_eq!(
+            res.headers().get("Access-Control-Allow-Methods").unwrap(),
+            "GET, HEAD, OPTIONS, POST"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cors_preflight_skips_handler_chain() {
+        struct Deny;
+
+        #[async_trait]
+        impl MiddleWareHandler for Deny {
+            async fn handle(&self, _req: Request, _next: &Next) -> Result<Response> {
+                panic!("preflight must not run downstream middleware");
+            }
+        }
+
+        let route = Route::new("")
+            .hook(Cors::new().origin(CorsType::Any))
+            .append(
+                Route::new("items")
+                    .hook(Deny)
+                    .get(|_req: Request| async { Ok("list") })
+                    .options(|_req: Request| async {
+                        panic!("preflight must not run the OPTIONS handler");
+                        #[allow(unreachable_code)]
+                        Ok("options")
+                    }),
+            );
+        let route = Route::new_root().append(route);
+        let mut req = Request::empty();
+        *req.method_mut() = Method::OPTIONS;
+        *req.uri_mut() = "/items".parse().unwrap();
+        req.headers_mut()
+            .insert("origin", "http://localhost:8080".parse().unwrap());
+        req.headers_mut()
+            .insert("access-control-request-method", "GET".parse().unwrap());
+        let res = route.call(req).await.unwrap();
+        assert_eq!(
+            res.headers().get("Access-Control-Allow-Methods").unwrap(),
+            "GET, HEAD, OPTIONS"
+        );
+    }
+
     #[tokio::test]
     async fn test_cors_with_post_request() {
         let route = Route::new("/")
@@ -664,4 +810,102 @@ mod tests {
         // 空 origin 应该被视为同源请求
         assert_eq!(res.status, http::StatusCode::OK);
     }
+
+    // ==================== 来源正则与预检测试 ====================
+
+    #[test]
+    fn test_cors_origin_regex_merges_exact() {
+        let cors = Cors::new()
+            .origin("https://example.com")
+            .origin_regex(r"https://[a-z]+\.example\.com");
+        let origin = cors.origin.as_ref().unwrap();
+        assert_eq!(
+            origin.get_value("https://example.com"),
+            "https://example.com"
+        );
+        assert_eq!(
+            origin.get_value("https://api.example.com"),
+            "https://api.example.com"
+        );
+        assert_eq!(origin.get_value("https://api.example.com.evil.io"), "");
+        assert_eq!(origin.get_value("https://exampleXcom"), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid origin regex")]
+    fn test_cors_origin_regex_invalid() {
+        let _ = Cors::new().origin_regex("(");
+    }
+
+    #[tokio::test]
+    async fn test_cors_preflight_short_circuit() {
+        let route = Route::new("items")
+            .hook(
+                Cors::new()
+                    .origin_regex(r"https://.*\.example\.com")
+                    .methods("GET,POST")
+                    .max_age(600)
+                    .expose("x-total"),
+            )
+            .get(|_req: Request| async { Ok("list") })
+            .options(|_req: Request| async {
+                Err::<&str, _>(SilentError::business_error(
+                    http::StatusCode::IM_A_TEAPOT,
+                    "handler reached",
+                ))
+            });
+        let route = Route::new_root().append(route);
+
+        let request = |method: Method, preflight: bool| {
+            let mut req = Request::empty();
+            *req.method_mut() = method;
+            *req.uri_mut() = "http://localhost:8080/items".parse().unwrap();
+            req.headers_mut()
+                .insert("origin", "https://app.example.com".parse().unwrap());
+            if preflight {
+                req.headers_mut()
+                    .insert("access-control-request-method", "POST".parse().unwrap());
+                req.headers_mut().insert(
+                    "access-control-request-headers",
+                    "content-type,x-token".parse().unwrap(),
+                );
+            }
+            req
+        };
+
+        // 预检请求不进入处理函数
+        let res = route.call(request(Method::OPTIONS, true)).await.unwrap();
+        assert_eq!(res.status, http::StatusCode::OK);
+        let headers = res.headers();
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+            "https://app.example.com"
+        );
+        assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_METHODS], "GET,POST");
+        assert_eq!(
+            headers[header::ACCESS_CONTROL_ALLOW_HEADERS],
+            "content-type,x-token"
+        );
+        assert_eq!(headers[header::ACCESS_CONTROL_MAX_AGE], "600");
+        assert!(!headers.contains_key(header::ACCESS_CONTROL_EXPOSE_HEADERS));
+        let vary: Vec<_> = headers.get_all(header::VARY).iter().collect();
+        assert_eq!(vary, ["access-control-request-headers", "origin"]);
+
+        // 普通 OPTIONS 请求交给处理函数
+        let res = route.call(request(Method::OPTIONS, false)).await.unwrap();
+        assert_eq!(res.status, http::StatusCode::IM_A_TEAPOT);
+        assert!(
+            res.headers()
+                .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        );
+
+        // 实际请求只带 Expose-Headers，不带预检专用的响应头
+        let res = route.call(request(Method::GET, false)).await.unwrap();
+        assert_eq!(res.status, http::StatusCode::OK);
+        let headers = res.headers();
+        assert_eq!(headers[header::ACCESS_CONTROL_EXPOSE_HEADERS], "x-total");
+        assert!(!headers.contains_key(header::ACCESS_CONTROL_ALLOW_METHODS));
+        assert!(!headers.contains_key(header::ACCESS_CONTROL_MAX_AGE));
+        assert_eq!(headers[header::VARY], "origin");
+    }
 }
@@ -0,0 +1,245 @@
+use crate::core::req_body::ReqBody;
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, SilentError};
+use async_trait::async_trait;
+use http::StatusCode;
+use http::header::{CONTENT_ENCODING, CONTENT_LENGTH};
+
+use async_compression::futures::bufread::{BrotliDecoder, GzipDecoder, ZstdDecoder};
+use bytes::Bytes;
+use futures::io::{AsyncRead, AsyncReadExt, BufReader};
+use futures_util::TryStreamExt;
+
+/// Decompression 中间件
+///
+/// 按请求的 `Content-Encoding` 头（gzip / br / zstd，可叠加多种编码）解压请求体，
+/// 解压完成后移除 `Content-Encoding` 并更新 `Content-Length`，之后的提取器与处理函数看到的是原始请求体。
+///
+/// # 行为
+///
+/// - 未设置 `Content-Encoding` 或为 `identity` 时原样放行
+/// - 不支持的编码返回 `415 Unsupported Media Type`
+/// - 压缩数据损坏返回 `400 Bad Request`
+/// - 解压后大小超过上限（默认 10 MiB）返回 `413 Payload Too Large`，用于防御压缩炸弹
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::Decompression;
+///
+/// let route = Route::new("upload")
+///     .hook(Decompression::new().max_size(1024 * 1024))
+///     .post(|mut req: Request| async move {
+///         let value: serde_json::Value = req.json_parse().await?;
+///         Ok(value)
+///     });
+/// ```
+#[derive(Clone, Debug)]
+pub struct Decompression {
+    /// 解压后的最大字节数
+    max_size: usize,
+}
+
+impl Default for Decompression {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decompression {
+    /// 创建默认中间件，解压后大小上限为 10 MiB。
+    pub fn new() -> Self {
+        Self {
+            max_size: 10 * 1024 * 1024,
+        }
+    }
+
+    /// 设置解压后大小上限（字节）。
+    pub fn max_size(mut self, size: usize) -> Self {
+        self.max_size = size;
+        self
+    }
+}
+
+/// 请求体支持的压缩算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Brotli,
+    Gzip,
+    Zstd,
+}
+
+impl Encoding {
+    fn parse(value: &str) -> Option<Option<Self>> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "identity" | "" => Some(None),
+            "gzip" | "x-gzip" => Some(Some(Self::Gzip)),
+            "br" => Some(Some(Self::Brotli)),
+            "zstd" => Some(Some(Self::Zstd)),
+            _ => None,
+        }
+    }
+}
+
+/// 读取最多 `max_size + 1` 字节，超出上限时返回 413
+async fn read_limited<R>(reader: R, max_size: usize) -> Result<Bytes>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buf = Vec::new();
+    reader
+        .take(max_size as u64 + 1)
+        .read_to_end(&mut buf)
+        .await
+        .map_err(|e| {
+            SilentError::business_error(
+                StatusCode::BAD_REQUEST,
+                format!("Decompression: invalid request body: {e}"),
+            )
+        })?;
+    if buf.len() > max_size {
+        return Err(SilentError::business_error(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "Decompression: decompressed request body exceeds limit",
+        ));
+    }
+    Ok(buf.into())
+}
+
+async fn decode(data: Bytes, encoding: Encoding, max_size: usize) -> Result<Bytes> {
+    let reader = BufReader::new(&data[..]);
+    match encoding {
+        Encoding::Brotli => read_limited(BrotliDecoder::new(reader), max_size).await,
+        Encoding::Gzip => read_limited(GzipDecoder::new(reader), max_size).await,
+        Encoding::Zstd => read_limited(ZstdDecoder::new(reader), max_size).await,
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for Decompression {
+    async fn handle(&self, mut req: Request, next: &Next) -> Result<Response> {
+        let Some(header) = req.headers().get(CONTENT_ENCODING) else {
+            return next.call(req).await;
+        };
+        let header = header.to_str().unwrap_or_default().to_string();
+        let mut encodings = Vec::new();
+        for value in header.split(',') {
+            match Encoding::parse(value) {
+                Some(Some(encoding)) => encodings.push(encoding),
+                Some(None) => {}
+                None => {
+                    return Err(SilentError::business_error(
+                        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                        format!("Decompression: unsupported content encoding \"{header}\""),
+                    ));
+                }
+            }
+        }
+        if encodings.is_empty() {
+            req.headers_mut().remove(CONTENT_ENCODING);
+            return next.call(req).await;
+        }
+
+        // 读取压缩后的请求体，多种编码按应用顺序的逆序解压
+        let body = req.take_body();
+        let reader = body.into_async_read();
+        let mut data = read_limited(reader, self.max_size).await?;
+        for encoding in encodings.into_iter().rev() {
+            data = decode(data, encoding, self.max_size).await?;
+        }
+
+        req.headers_mut().remove(CONTENT_ENCODING);
+        req.headers_mut().insert(CONTENT_LENGTH, data.len().into());
+        req.replace_body(ReqBody::Once(data));
+        next.call(req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+    use async_compression::futures::bufread::{GzipEncoder, ZstdEncoder};
+    use http_body_util::BodyExt;
+
+    async fn encode<R: AsyncRead + Unpin>(mut encoder: R) -> Vec<u8> {
+        let mut buf = Vec::new();
+        encoder.read_to_end(&mut buf).await.unwrap();
+        buf
+    }
+
+    fn route(mid: Decompression) -> Route {
+        let route = Route::new("/")
+            .hook(mid)
+            .post(|mut req: Request| async move {
+                assert!(req.headers().get(CONTENT_ENCODING).is_none());
+                let value: serde_json::Value = req.json_parse().await?;
+                Ok(value)
+            });
+        Route::new_root().append(route)
+    }
+
+    fn request(encoding: &'static str, body: Vec<u8>) -> Request {
+        let mut req = Request::empty();
+        *req.method_mut() = http::Method::POST;
+        req.headers_mut()
+            .insert("content-type", "application/json".parse().unwrap());
+        req.headers_mut()
+            .insert(CONTENT_ENCODING, encoding.parse().unwrap());
+        req.replace_body(ReqBody::Once(body.into()));
+        req
+    }
+
+    #[test]
+    fn test_encoding_parse() {
+        assert_eq!(Encoding::parse(" GZIP "), Some(Some(Encoding::Gzip)));
+        assert_eq!(Encoding::parse("br"), Some(Some(Encoding::Brotli)));
+        assert_eq!(Encoding::parse("zstd"), Some(Some(Encoding::Zstd)));
+        assert_eq!(Encoding::parse("identity"), Some(None));
+        assert_eq!(Encoding::parse("deflate"), None);
+    }
+
+    #[tokio::test]
+    async fn test_decompression_gzip_and_stacked() {
+        let json = br#"{"name":"silent"}"#;
+        let route = route(Decompression::new());
+
+        let gzip = encode(GzipEncoder::new(&json[..])).await;
+        let res = crate::Handler::call(&route, request("gzip", gzip.clone()))
+            .await
+            .unwrap();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], json);
+
+        // gzip 之后再 zstd，按逆序解压
+        let stacked = encode(ZstdEncoder::new(&gzip[..])).await;
+        let res = crate::Handler::call(&route, request("gzip, zstd", stacked))
+            .await
+            .unwrap();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], json);
+    }
+
+    #[tokio::test]
+    async fn test_decompression_errors() {
+        let big = vec![b' '; 4096];
+        let gzip = encode(GzipEncoder::new(&big[..])).await;
+        assert!(gzip.len() < 1024);
+        let route = route(Decompression::new().max_size(1024));
+
+        let err = crate::Handler::call(&route, request("gzip", gzip))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let err = crate::Handler::call(&route, request("gzip", b"not gzip".to_vec()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::BAD_REQUEST);
+
+        let err = crate::Handler::call(&route, request("deflate", b"{}".to_vec()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+}
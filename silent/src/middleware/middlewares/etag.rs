@@ -0,0 +1,264 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::header::{CONTENT_LENGTH, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use http_body::Body;
+use http_body_util::BodyExt;
+
+use crate::core::res_body::full;
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, SilentError};
+
+/// ETag 中间件
+///
+/// 为动态生成的响应计算弱 ETag，并处理条件请求，内容未变化时返回 `304 Not Modified`。
+///
+/// # 行为
+///
+/// 1. 只处理 `GET` / `HEAD` 请求的 `200 OK` 响应
+/// 2. 响应未设置 `ETag` 时，对大小已知且不超过上限（默认 1 MiB）的响应体计算 `W/"..."` 形式的弱 ETag；
+///    流式或超过上限的响应体不缓冲、不计算
+/// 3. 请求携带 `If-None-Match` 时按弱比较匹配 ETag（支持列表与 `*`），否则按 `If-Modified-Since`
+///    与响应的 `Last-Modified` 比较
+/// 4. 条件满足时返回保留原响应头、不含响应体的 `304`
+///
+/// 处理函数自行设置的 `ETag` 会被保留并参与比较。
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::ETag;
+///
+/// let route = Route::new("articles")
+///     .hook(ETag::new())
+///     .get(|_req: Request| async { Ok("article list") });
+/// ```
+#[derive(Clone, Debug)]
+pub struct ETag {
+    max_size: usize,
+}
+
+impl Default for ETag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ETag {
+    /// 创建默认中间件，参与计算的响应体上限为 1 MiB。
+    pub fn new() -> Self {
+        Self {
+            max_size: 1024 * 1024,
+        }
+    }
+
+    /// 设置参与计算 ETag 的响应体大小上限（字节）。
+    pub fn max_size(mut self, size: usize) -> Self {
+        self.max_size = size;
+        self
+    }
+}
+
+/// 对响应体计算弱 ETag：长度与 64 位 FNV-1a 哈希，跨进程与实例保持一致
+fn weak_etag(body: &[u8]) -> HeaderValue {
+    let hash = body.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    HeaderValue::from_str(&format!("W/\"{:x}-{hash:016x}\"", body.len()))
+        .expect("etag is a valid header value")
+}
+
+/// 去掉弱标记 `W/`，用于弱比较
+fn opaque_tag(tag: &str) -> &str {
+    let tag = tag.trim();
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
+
+/// `If-None-Match` 是否与 `etag` 匹配
+fn none_match(headers: &HeaderMap, etag: &str) -> Option<bool> {
+    let mut values = headers.get_all(IF_NONE_MATCH).iter().peekable();
+    values.peek()?;
+    let etag = opaque_tag(etag);
+    Some(values.filter_map(|v| v.to_str().ok()).any(|value| {
+        value
+            .split(',')
+            .any(|tag| tag.trim() == "*" || opaque_tag(tag) == etag)
+    }))
+}
+
+/// `Last-Modified` 不晚于 `If-Modified-Since` 时视为未修改
+fn not_modified_since(req: &HeaderMap, res: &HeaderMap) -> bool {
+    let parse = |value: &HeaderValue| {
+        value
+            .to_str()
+            .ok()
+            .and_then(|v| chrono::DateTime::parse_from_rfc2822(v).ok())
+    };
+    match (
+        req.get(IF_MODIFIED_SINCE).and_then(parse),
+        res.get(LAST_MODIFIED).and_then(parse),
+    ) {
+        (Some(since), Some(modified)) => modified <= since,
+        _ => false,
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for ETag {
+    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+        if !matches!(*req.method(), Method::GET | Method::HEAD) {
+            return next.call(req).await;
+        }
+        let req_headers = req.headers().clone();
+        let mut res = next.call(req).await?;
+        if res.status() != StatusCode::OK {
+            return Ok(res);
+        }
+
+        if !res.headers().contains_key(ETAG) {
+            let size = res.body.size_hint().exact();
+            if let Some(size) = size.filter(|size| *size as usize <= self.max_size) {
+                let body: Bytes = res
+                    .take_body()
+                    .collect()
+                    .await
+                    .map_err(|e| {
+                        SilentError::business_error(
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            format!("ETag: failed to read response body: {e}"),
+                        )
+                    })?
+                    .to_bytes();
+                debug_assert_eq!(body.len() as u64, size);
+                res.headers_mut().insert(ETAG, weak_etag(&body));
+                res.set_body(full(body));
+            }
+        }
+
+        let etag = res
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let not_modified = match etag.and_then(|etag| none_match(&req_headers, &etag)) {
+            Some(matched) => matched,
+            None => {
+                !req_headers.contains_key(IF_NONE_MATCH)
+                    && not_modified_since(&req_headers, res.headers())
+            }
+        };
+        if not_modified {
+            res.set_status(StatusCode::NOT_MODIFIED);
+            res.headers_mut().remove(CONTENT_LENGTH);
+            res.set_body(full(Bytes::new()));
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+
+    fn route() -> Route {
+        let route = Route::new("")
+            .hook(ETag::new().max_size(16))
+            .append(Route::new("small").get(|_req: Request| async { Ok("hello") }))
+            .append(Route::new("large").get(|_req: Request| async { Ok("x".repeat(32)) }))
+            .append(Route::new("dated").get(|_req: Request| async {
+                let mut res = Response::text("dated");
+                res.headers_mut()
+                    .insert(ETAG, HeaderValue::from_static("\"v1\""));
+                res.headers_mut().insert(
+                    LAST_MODIFIED,
+                    HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+                );
+                Ok(res)
+            }));
+        Route::new_root().append(route)
+    }
+
+    async fn call(route: &Route, path: &str, headers: &[(&str, &str)]) -> Response {
+        let mut req = Request::empty();
+        *req.uri_mut() = path.parse().unwrap();
+        for (name, value) in headers {
+            req.headers_mut().append(
+                http::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        crate::Handler::call(route, req).await.unwrap()
+    }
+
+    #[test]
+    fn test_weak_etag_and_match() {
+        assert_eq!(weak_etag(b"hello"), weak_etag(b"hello"));
+        assert_ne!(weak_etag(b"hello"), weak_etag(b"hellp"));
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(none_match(&headers, "W/\"a\""), None);
+        headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"b\", \"a\""));
+        assert_eq!(none_match(&headers, "W/\"a\""), Some(true));
+        assert_eq!(none_match(&headers, "\"c\""), Some(false));
+        headers.insert(IF_NONE_MATCH, HeaderValue::from_static("*"));
+        assert_eq!(none_match(&headers, "\"c\""), Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_etag_if_none_match() {
+        let route = route();
+        let res = call(&route, "/small", &[]).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        let etag = res.headers()[ETAG].to_str().unwrap().to_string();
+        assert!(etag.starts_with("W/\""));
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"hello");
+
+        let res = call(&route, "/small", &[("if-none-match", &etag)]).await;
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.headers()[ETAG], etag.as_str());
+        assert!(res.body.collect().await.unwrap().to_bytes().is_empty());
+
+        // 超过上限的响应体不计算 ETag
+        let res = call(&route, "/large", &[]).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(!res.headers().contains_key(ETAG));
+    }
+
+    #[tokio::test]
+    async fn test_etag_handler_tag_and_if_modified_since() {
+        let route = route();
+        let res = call(&route, "/dated", &[("if-none-match", "W/\"v1\"")]).await;
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(res.headers()[ETAG], "\"v1\"");
+
+        let res = call(
+            &route,
+            "/dated",
+            &[("if-modified-since", "Thu, 22 Oct 2015 07:28:00 GMT")],
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::NOT_MODIFIED);
+
+        let res = call(
+            &route,
+            "/dated",
+            &[("if-modified-since", "Tue, 20 Oct 2015 07:28:00 GMT")],
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // If-None-Match 存在时忽略 If-Modified-Since
+        let res = call(
+            &route,
+            "/dated",
+            &[
+                ("if-none-match", "\"v2\""),
+                ("if-modified-since", "Thu, 22 Oct 2015 07:28:00 GMT"),
+            ],
+        )
+        .await;
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+}
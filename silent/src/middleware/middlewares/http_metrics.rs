@@ -0,0 +1,264 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use http::header::CONTENT_TYPE;
+use http::{HeaderValue, Method};
+use http_body::Body;
+use metrics::{counter, gauge, histogram};
+
+use crate::route::MatchedRoute;
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result};
+
+/// 未命中任何路由（如 404）时使用的 `route` 标签
+const UNMATCHED: &str = "<unmatched>";
+
+/// 请求结束（包括被取消）时减少进行中的请求数
+struct InFlight(String);
+
+impl InFlight {
+    fn new(method: String) -> Self {
+        gauge!("http_requests_in_flight", "method" => method.clone()).increment(1.0);
+        Self(method)
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        gauge!("http_requests_in_flight", "method" => self.0.clone()).decrement(1.0);
+    }
+}
+
+type Render = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// HttpMetrics 中间件
+///
+/// 通过 `metrics` 门面记录 HTTP 请求指标，由应用安装的导出器（如 `metrics-exporter-prometheus`）汇总输出：
+///
+/// | 指标 | 类型 | 标签 |
+/// |------|------|------|
+/// | `http_requests_total` | counter | `method`、`route`、`status` |
+/// | `http_request_duration_seconds` | histogram | `method`、`route`、`status` |
+/// | `http_response_size_bytes` | histogram | `method`、`route`、`status` |
+/// | `http_requests_in_flight` | gauge | `method` |
+///
+/// `route` 标签为命中的路由模板（如 `/users/<id:u64>`），而非实际路径，避免标签基数膨胀；
+/// 未命中路由时为 `<unmatched>`。响应体大小未知的流式响应不记录 `http_response_size_bytes`。
+///
+/// 配置 [`endpoint`](Self::endpoint) 后，中间件直接在业务端口上以 Prometheus 文本格式响应该路径的 `GET` 请求，
+/// 无需为导出器单独监听端口；该路径的请求不计入指标。中间件应挂载在根路由上，以覆盖所有请求。
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::HttpMetrics;
+///
+/// // render 通常为 `move || handle.render()`，handle 来自 `PrometheusBuilder::install_recorder()`
+/// let render = || String::from("# metrics\n");
+/// let route = Route::new_root()
+///     .hook(HttpMetrics::new().endpoint("/metrics", render))
+///     .append(Route::new("users/<id:u64>").get(|_req: Request| async { Ok("user") }));
+/// ```
+#[derive(Clone, Default)]
+pub struct HttpMetrics {
+    endpoint: Option<(String, Render)>,
+}
+
+impl HttpMetrics {
+    /// 创建中间件
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 在 `path` 上暴露指标，`render` 返回 Prometheus 文本格式的指标
+    pub fn endpoint<F>(mut self, path: impl Into<String>, render: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        self.endpoint = Some((path.into(), Arc::new(render)));
+        self
+    }
+
+    fn render(&self, req: &Request) -> Option<Response> {
+        let (path, render) = self.endpoint.as_ref()?;
+        if *req.method() != Method::GET || req.uri().path() != path {
+            return None;
+        }
+        let mut res = Response::text(&render());
+        res.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; version=0.0.4; charset=utf-8"),
+        );
+        Some(res)
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for HttpMetrics {
+    async fn handle(&self, mut req: Request, next: &Next) -> Result<Response> {
+        if let Some(res) = self.render(&req) {
+            return Ok(res);
+        }
+        let method = req.method().as_str().to_string();
+        let matched = MatchedRoute::default();
+        req.extensions_mut().insert(matched.clone());
+
+        let in_flight = InFlight::new(method.clone());
+        let start = Instant::now();
+        let result = next.call(req).await;
+        let elapsed = start.elapsed().as_secs_f64();
+        drop(in_flight);
+
+        let (status, size) = match &result {
+            Ok(res) => (res.status(), res.body.size_hint().exact()),
+            Err(err) => (err.status(), None),
+        };
+        let labels = [
+            ("method", method),
+            ("route", matched.pattern().unwrap_or(UNMATCHED).to_string()),
+            ("status", status.as_u16().to_string()),
+        ];
+        counter!("http_requests_total", &labels).increment(1);
+        histogram!("http_request_duration_seconds", &labels).record(elapsed);
+        if let Some(size) = size {
+            histogram!("http_response_size_bytes", &labels).record(size as f64);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+    use http_body_util::BodyExt;
+    use metrics::{
+        Counter, CounterFn, Gauge, GaugeFn, Histogram, HistogramFn, Key, KeyName, Metadata,
+        Recorder, SharedString, Unit,
+    };
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// 以 `name{label=value,...}` 为键累加所有记录值
+    #[derive(Clone, Default)]
+    struct TestRecorder(Arc<Mutex<HashMap<String, f64>>>);
+
+    struct Handle(String, Arc<Mutex<HashMap<String, f64>>>);
+
+    impl Handle {
+        fn add(&self, value: f64) {
+            *self.1.lock().unwrap().entry(self.0.clone()).or_default() += value;
+        }
+    }
+
+    impl CounterFn for Handle {
+        fn increment(&self, value: u64) {
+            self.add(value as f64);
+        }
+        fn absolute(&self, _value: u64) {}
+    }
+
+    impl GaugeFn for Handle {
+        fn increment(&self, value: f64) {
+            self.add(value);
+        }
+        fn decrement(&self, value: f64) {
+            self.add(-value);
+        }
+        fn set(&self, _value: f64) {}
+    }
+
+    impl HistogramFn for Handle {
+        fn record(&self, _value: f64) {
+            self.add(1.0);
+        }
+    }
+
+    impl TestRecorder {
+        fn handle(&self, key: &Key) -> Arc<Handle> {
+            let labels: Vec<String> = key
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            let name = format!("{}{{{}}}", key.name(), labels.join(","));
+            Arc::new(Handle(name, self.0.clone()))
+        }
+
+        fn get(&self, name: &str) -> f64 {
+            self.0
+                .lock()
+                .unwrap()
+                .get(name)
+                .copied()
+                .unwrap_or_default()
+        }
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.handle(key))
+        }
+        fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::from_arc(self.handle(key))
+        }
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::from_arc(self.handle(key))
+        }
+    }
+
+    fn call(route: &Route, path: &str) -> Result<Response> {
+        let mut req = Request::empty();
+        *req.uri_mut() = path.parse().unwrap();
+        futures::executor::block_on(crate::Handler::call(route, req))
+    }
+
+    #[test]
+    fn test_http_metrics() {
+        let recorder = TestRecorder::default();
+        let route = Route::new_root()
+            .hook(HttpMetrics::new().endpoint("/metrics", || "metric 1\n".to_string()))
+            .append(Route::new("users/<id:u64>").get(|_req: Request| async { Ok("user") }));
+
+        metrics::with_local_recorder(&recorder, || {
+            assert!(call(&route, "/users/1").is_ok());
+            assert!(call(&route, "/users/2").is_ok());
+            assert!(call(&route, "/missing").is_err());
+
+            let res = call(&route, "/metrics").unwrap();
+            assert_eq!(
+                res.headers()[CONTENT_TYPE],
+                "text/plain; version=0.0.4; charset=utf-8"
+            );
+            let body = futures::executor::block_on(res.body.collect())
+                .unwrap()
+                .to_bytes();
+            assert_eq!(&body[..], b"metric 1\n");
+        });
+
+        let ok = "{method=GET,route=/users/<id:u64>,status=200}";
+        assert_eq!(recorder.get(&format!("http_requests_total{ok}")), 2.0);
+        assert_eq!(
+            recorder.get(&format!("http_request_duration_seconds{ok}")),
+            2.0
+        );
+        assert_eq!(recorder.get(&format!("http_response_size_bytes{ok}")), 2.0);
+        assert_eq!(
+            recorder.get("http_requests_total{method=GET,route=<unmatched>,status=404}"),
+            1.0
+        );
+        assert_eq!(recorder.get("http_requests_in_flight{method=GET}"), 0.0);
+        // 指标端点本身不计入
+        let total: usize = recorder
+            .0
+            .lock()
+            .unwrap()
+            .keys()
+            .filter(|key| key.contains("/metrics"))
+            .count();
+        assert_eq!(total, 0);
+    }
+}
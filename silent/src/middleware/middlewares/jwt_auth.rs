@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+
+use crate::extractor::{BearerToken, FromRequestParts, JwtConfig, VerifiedClaims};
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result};
+
+/// JwtAuth 中间件
+///
+/// 校验 `Authorization: Bearer` 中的 JWT，校验通过后将声明写入请求扩展，
+/// 子路由的处理函数可直接使用 [`JwtClaims<T>`](crate::extractor::JwtClaims) 取得声明而无需重复校验。
+/// 签名密钥（HMAC 共享密钥、PEM 公钥或带缓存与轮换的 JWKS 地址）与 `iss`/`aud` 等必需声明由
+/// [`JwtConfig`] 配置，`exp` 总是必需的。
+///
+/// # 行为
+///
+/// - 缺少令牌时返回带 `WWW-Authenticate: Bearer` 的 `401`
+/// - 签名、有效期或声明校验失败时返回带 `error="invalid_token"` 的 `401`
+/// - 无法获取 JWKS 时返回 `503`
+///
+/// # 示例
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use silent::extractor::{JwtClaims, JwtConfig};
+/// use silent::middlewares::JwtAuth;
+/// use silent::prelude::*;
+///
+/// #[derive(Deserialize)]
+/// struct Claims {
+///     sub: String,
+/// }
+///
+/// async fn me(JwtClaims(claims): JwtClaims<Claims>) -> Result<String> {
+///     Ok(claims.sub)
+/// }
+///
+/// let route = Route::new("api")
+///     .hook(JwtAuth::new(
+///         JwtConfig::jwks_url("https://auth.example.com/.well-known/jwks.json")
+///             .issuer("https://auth.example.com")
+///             .audience("api"),
+///     ))
+///     .append(Route::new("me").get(me));
+/// ```
+#[derive(Clone)]
+pub struct JwtAuth {
+    config: JwtConfig,
+}
+
+impl JwtAuth {
+    /// 使用给定的校验配置创建中间件
+    pub fn new(config: JwtConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for JwtAuth {
+    async fn handle(&self, mut req: Request, next: &Next) -> Result<Response> {
+        let token = match BearerToken::from_request_parts(&mut req).await {
+            Ok(BearerToken(token)) => token,
+            Err(res) => return Ok(res),
+        };
+        match self.config.verify::<serde_json::Value>(&token).await {
+            Ok(claims) => {
+                req.extensions_mut().insert(VerifiedClaims(claims));
+                next.call(req).await
+            }
+            Err(e) => {
+                tracing::debug!(error = %e, "jwt rejected");
+                Ok(e.into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StatusCode;
+    use crate::extractor::JwtClaims;
+    use crate::route::Route;
+    use http::header::{AUTHORIZATION, WWW_AUTHENTICATE};
+    use http_body_util::BodyExt;
+    use jsonwebtoken::{EncodingKey, encode, get_current_timestamp};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Deserialize, Serialize)]
+    struct Claims {
+        sub: String,
+        iss: String,
+        exp: u64,
+    }
+
+    fn token(iss: &str) -> String {
+        let claims = Claims {
+            sub: "alice".to_string(),
+            iss: iss.to_string(),
+            exp: get_current_timestamp() + 60,
+        };
+        encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_jwt_auth() {
+        async fn me(JwtClaims(claims): JwtClaims<Claims>) -> Result<String> {
+            Ok(claims.sub)
+        }
+
+        let route = Route::new("me")
+            .hook(JwtAuth::new(
+                JwtConfig::hmac(b"secret").issuer("https://auth.example.com"),
+            ))
+            .get(me);
+        let route = Route::new_root().append(route);
+
+        let call = |token: Option<String>| {
+            let mut req = Request::empty();
+            *req.uri_mut() = "/me".parse().unwrap();
+            if let Some(token) = token {
+                req.headers_mut()
+                    .insert(AUTHORIZATION, format!("Bearer {token}").parse().unwrap());
+            }
+            crate::Handler::call(&route, req)
+        };
+
+        let res = call(Some(token("https://auth.example.com"))).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"alice");
+
+        let res = call(None).await.unwrap();
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(res.headers()[WWW_AUTHENTICATE], "Bearer");
+
+        // iss 不匹配
+        let res = call(Some(token("https://evil.example.com"))).await.unwrap();
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            res.headers()[WWW_AUTHENTICATE],
+            "Bearer error=\"invalid_token\""
+        );
+    }
+}
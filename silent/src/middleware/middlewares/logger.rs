@@ -1,12 +1,19 @@
+use super::Redactor;
 use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result};
 use async_trait::async_trait;
+use http::HeaderName;
+use std::sync::LazyLock;
 use std::time::Instant;
 
 /// Logger 中间件
 ///
-/// 记录每个请求的结构化日志，包含客户端 IP、方法、路径、HTTP 版本、
+/// 记录每个请求的结构化日志，包含客户端 IP、方法、路径、查询字符串、HTTP 版本、
 /// 响应状态码、响应体大小和处理耗时。
 ///
+/// 查询字符串在写出前按 [`Redactor::new`] 的默认规则脱敏，屏蔽 `password`、`token` 等参数。
+/// 需要额外记录请求头或替换脱敏规则时，通过 [`log_header`](Self::log_header) 或
+/// [`redactor`](Self::redactor) 得到 [`RedactingLogger`]。
+///
 /// 日志级别根据响应状态码自动选择：
 /// - 2xx/3xx: `INFO`
 /// - 4xx: `WARN`
@@ -16,10 +23,19 @@ use std::time::Instant;
 ///
 /// ```rust
 /// use silent::prelude::*;
-/// use silent::middlewares::Logger;
+/// use silent::middlewares::{Logger, Redactor};
 ///
 /// let route = Route::new("api")
-///     .hook(Logger::new())
+///     .hook(Logger)
+///     .get(|_req: Request| async { Ok("hello") });
+///
+/// let route = Route::new("admin")
+///     .hook(
+///         Logger::new()
+///             .log_header("user-agent")
+///             .log_header("authorization")
+///             .redactor(Redactor::new().field("api_key")),
+///     )
 ///     .get(|_req: Request| async { Ok("hello") });
 /// ```
 #[derive(Default, Clone)]
@@ -29,86 +45,163 @@ impl Logger {
     pub fn new() -> Self {
         Self
     }
+
+    /// 额外记录指定请求头，敏感头部的值按脱敏规则替换
+    ///
+    /// # Panics
+    ///
+    /// 头部名称不合法时 panic。
+    pub fn log_header(self, name: &str) -> RedactingLogger {
+        RedactingLogger::default().log_header(name)
+    }
+
+    /// 替换脱敏规则，默认为 [`Redactor::new`]
+    pub fn redactor(self, redactor: Redactor) -> RedactingLogger {
+        RedactingLogger::default().redactor(redactor)
+    }
+}
+
+/// 可配置的 [`Logger`]，额外记录指定请求头并使用自定义脱敏规则
+#[derive(Default, Clone)]
+pub struct RedactingLogger {
+    headers: Vec<HeaderName>,
+    redactor: Redactor,
+}
+
+impl RedactingLogger {
+    /// 额外记录指定请求头，敏感头部的值按脱敏规则替换
+    ///
+    /// # Panics
+    ///
+    /// 头部名称不合法时 panic。
+    pub fn log_header(mut self, name: &str) -> Self {
+        let header = HeaderName::try_from(name)
+            .unwrap_or_else(|_| panic!("Logger: invalid header name \"{name}\""));
+        self.headers.push(header);
+        self
+    }
+
+    /// 替换脱敏规则，默认为 [`Redactor::new`]
+    pub fn redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = redactor;
+        self
+    }
+}
+
+/// 需要记录的请求头，格式为 `name=value, ...`
+fn logged_headers(headers: &[HeaderName], redactor: &Redactor, req: &Request) -> String {
+    headers
+        .iter()
+        .flat_map(|name| {
+            req.headers()
+                .get_all(name)
+                .iter()
+                .map(move |value| format!("{name}={}", redactor.header_value(name, value)))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 #[async_trait]
 impl MiddleWareHandler for Logger {
     async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
-        let method = req.method().clone();
-        let path = req.uri().path().to_string();
-        let query = req.uri().query().map(|q| q.to_string());
-        let version = format!("{:?}", req.version());
-        let peer_addr = req
-            .headers()
-            .get("x-real-ip")
-            .and_then(|h| h.to_str().ok())
-            .unwrap_or("-")
-            .to_string();
-
-        let start = Instant::now();
-        let res = next.call(req).await;
-        let elapsed = start.elapsed();
-        let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
-
-        match res {
-            Ok(res) => {
-                let status = res.status.as_u16();
-                let size = res.content_length().lower();
-
-                if status >= 500 {
-                    tracing::error!(
-                        peer = %peer_addr,
-                        %method,
-                        %path,
-                        query = query.as_deref().unwrap_or(""),
-                        %version,
-                        %status,
-                        size,
-                        elapsed_ms,
-                        "request completed"
-                    );
-                } else if status >= 400 {
-                    tracing::warn!(
-                        peer = %peer_addr,
-                        %method,
-                        %path,
-                        query = query.as_deref().unwrap_or(""),
-                        %version,
-                        %status,
-                        size,
-                        elapsed_ms,
-                        "request completed"
-                    );
-                } else {
-                    tracing::info!(
-                        peer = %peer_addr,
-                        %method,
-                        %path,
-                        query = query.as_deref().unwrap_or(""),
-                        %version,
-                        %status,
-                        size,
-                        elapsed_ms,
-                        "request completed"
-                    );
-                }
-                Ok(res)
-            }
-            Err(e) => {
-                let status = e.status().as_u16();
+        static REDACTOR: LazyLock<Redactor> = LazyLock::new(Redactor::new);
+        log_request(&[], &REDACTOR, req, next).await
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for RedactingLogger {
+    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+        log_request(&self.headers, &self.redactor, req, next).await
+    }
+}
+
+async fn log_request(
+    headers: &[HeaderName],
+    redactor: &Redactor,
+    req: Request,
+    next: &Next,
+) -> Result<Response> {
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+    let query = req.uri().query().map(|q| redactor.query(q));
+    let version = format!("{:?}", req.version());
+    let peer_addr = req
+        .headers()
+        .get("x-real-ip")
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or("-")
+        .to_string();
+    let headers = logged_headers(headers, redactor, &req);
+
+    let start = Instant::now();
+    let res = next.call(req).await;
+    let elapsed = start.elapsed();
+    let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+
+    match res {
+        Ok(res) => {
+            let status = res.status.as_u16();
+            let size = res.content_length().lower();
+
+            if status >= 500 {
                 tracing::error!(
                     peer = %peer_addr,
                     %method,
                     %path,
                     query = query.as_deref().unwrap_or(""),
                     %version,
+                    headers,
+                    %status,
+                    size,
+                    elapsed_ms,
+                    "request completed"
+                );
+            } else if status >= 400 {
+                tracing::warn!(
+                    peer = %peer_addr,
+                    %method,
+                    %path,
+                    query = query.as_deref().unwrap_or(""),
+                    %version,
+                    headers,
+                    %status,
+                    size,
+                    elapsed_ms,
+                    "request completed"
+                );
+            } else {
+                tracing::info!(
+                    peer = %peer_addr,
+                    %method,
+                    %path,
+                    query = query.as_deref().unwrap_or(""),
+                    %version,
+                    headers,
                     %status,
+                    size,
                     elapsed_ms,
-                    error = %e,
-                    "request failed"
+                    "request completed"
                 );
-                Err(e)
             }
+            Ok(res)
+        }
+        Err(e) => {
+            let status = e.status().as_u16();
+            tracing::error!(
+                peer = %peer_addr,
+                %method,
+                %path,
+                query = query.as_deref().unwrap_or(""),
+                %version,
+                headers,
+                %status,
+                elapsed_ms,
+                error = %e,
+                "request failed"
+            );
+            Err(e)
         }
     }
 }
@@ -139,6 +232,24 @@ mod tests {
         assert_eq!(std::mem::size_of::<Logger>(), 0);
     }
 
+    #[test]
+    fn test_logger_logged_headers() {
+        let logger = Logger::new()
+            .log_header("user-agent")
+            .log_header("authorization")
+            .log_header("x-missing");
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert("user-agent", "curl/8.0".parse().unwrap());
+        req.headers_mut()
+            .insert("authorization", "Bearer secret".parse().unwrap());
+        assert_eq!(
+            logged_headers(&logger.headers, &logger.redactor, &req),
+            "user-agent=curl/8.0, authorization=[REDACTED]"
+        );
+        assert_eq!(logged_headers(&[], &Redactor::new(), &req), "");
+    }
+
     #[cfg(feature = "server")]
     #[tokio::test]
     async fn test_logger_success_response() {
@@ -0,0 +1,231 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use http::header::RETRY_AFTER;
+
+use crate::extractor::{ClientIp, FromRequestParts, IpCidr};
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, StatusCode};
+
+/// Maintenance 中间件
+///
+/// 可随时开关的维护模式。开启后，除白名单中的路径与客户端 IP 外，所有请求直接返回
+/// `503 Service Unavailable`（配置后附带 `Retry-After`），便于部署时在应用内部平滑摘除流量。
+///
+/// # 行为
+///
+/// - 克隆的中间件共享同一个开关，保留一个克隆即可在运行时调用 [`enable`](Self::enable) / [`disable`](Self::disable)
+/// - 路径白名单匹配完全相同的路径及其子路径，如 `/health` 同时放行 `/health/live`
+/// - IP 白名单支持 CIDR，客户端 IP 按 [`ClientIp`] 的规则解析（位于可信代理之后时读取转发头）
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::Maintenance;
+/// use std::time::Duration;
+///
+/// let maintenance = Maintenance::new()
+///     .retry_after(Duration::from_secs(120))
+///     .allow_path("/health")
+///     .allow_ip("10.0.0.0/8");
+/// let route = Route::new_root()
+///     .hook(maintenance.clone())
+///     .append(Route::new("").get(|_req: Request| async { Ok("ok") }));
+///
+/// // 部署前开启，完成后关闭
+/// maintenance.enable();
+/// assert!(maintenance.is_enabled());
+/// maintenance.disable();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Maintenance {
+    enabled: Arc<AtomicBool>,
+    retry_after: Option<Duration>,
+    message: String,
+    paths: Vec<String>,
+    ips: Vec<IpCidr>,
+}
+
+impl Default for Maintenance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Maintenance {
+    /// 创建中间件，初始为关闭状态
+    pub fn new() -> Self {
+        Self {
+            enabled: Arc::new(AtomicBool::new(false)),
+            retry_after: None,
+            message: "Service under maintenance".to_string(),
+            paths: Vec::new(),
+            ips: Vec::new(),
+        }
+    }
+
+    /// 设置 `Retry-After` 头（秒）
+    pub fn retry_after(mut self, duration: Duration) -> Self {
+        self.retry_after = Some(duration);
+        self
+    }
+
+    /// 设置维护期间的响应体
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// 维护期间放行的路径（含子路径）
+    pub fn allow_path(mut self, path: impl Into<String>) -> Self {
+        let path = path.into();
+        let path = path.trim_end_matches('/');
+        self.paths
+            .push(if path.is_empty() { "/" } else { path }.to_string());
+        self
+    }
+
+    /// 维护期间放行的客户端 IP 或 CIDR 网段
+    ///
+    /// # Panics
+    ///
+    /// `cidr` 不是合法的 IP 或 CIDR 时 panic。
+    pub fn allow_ip(mut self, cidr: &str) -> Self {
+        let cidr = cidr
+            .parse()
+            .unwrap_or_else(|_| panic!("Maintenance: invalid ip or cidr \"{cidr}\""));
+        self.ips.push(cidr);
+        self
+    }
+
+    /// 开启维护模式
+    pub fn enable(&self) {
+        self.set_enabled(true);
+    }
+
+    /// 关闭维护模式
+    pub fn disable(&self) {
+        self.set_enabled(false);
+    }
+
+    /// 设置维护模式开关
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Release);
+    }
+
+    /// 当前是否处于维护模式
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Acquire)
+    }
+
+    fn path_allowed(&self, path: &str) -> bool {
+        self.paths.iter().any(|allowed| {
+            allowed == "/"
+                || path
+                    .strip_prefix(allowed.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+
+    async fn ip_allowed(&self, req: &mut Request) -> bool {
+        if self.ips.is_empty() {
+            return false;
+        }
+        match ClientIp::from_request_parts(req).await {
+            Ok(ClientIp(ip)) => {
+                let ip = ip.to_canonical();
+                self.ips.iter().any(|cidr| cidr.contains(ip))
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for Maintenance {
+    async fn handle(&self, mut req: Request, next: &Next) -> Result<Response> {
+        if !self.is_enabled()
+            || self.path_allowed(req.uri().path())
+            || self.ip_allowed(&mut req).await
+        {
+            return next.call(req).await;
+        }
+        let mut res = Response::empty();
+        res.set_status(StatusCode::SERVICE_UNAVAILABLE);
+        if let Some(retry_after) = self.retry_after {
+            res.headers_mut()
+                .insert(RETRY_AFTER, retry_after.as_secs().into());
+        }
+        res.set_body(crate::core::res_body::full(self.message.clone()));
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+
+    fn request(path: &str, peer: &str) -> Request {
+        let mut req = Request::empty();
+        *req.uri_mut() = path.parse().unwrap();
+        req.set_remote(peer.parse().unwrap());
+        req
+    }
+
+    #[test]
+    fn test_maintenance_path_allowed() {
+        let maintenance = Maintenance::new()
+            .allow_path("/health/")
+            .allow_path("/status");
+        assert!(maintenance.path_allowed("/health"));
+        assert!(maintenance.path_allowed("/health/live"));
+        assert!(maintenance.path_allowed("/status"));
+        assert!(!maintenance.path_allowed("/healthz"));
+        assert!(!maintenance.path_allowed("/"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Maintenance: invalid ip or cidr")]
+    fn test_maintenance_invalid_ip() {
+        let _ = Maintenance::new().allow_ip("10.0.0.0/40");
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_toggle() {
+        let maintenance = Maintenance::new()
+            .retry_after(Duration::from_secs(120))
+            .allow_path("/health")
+            .allow_ip("10.0.0.0/8");
+        let route = Route::new_root()
+            .hook(maintenance.clone())
+            .append(Route::new("").get(|_req: Request| async { Ok("ok") }))
+            .append(Route::new("health").get(|_req: Request| async { Ok("up") }));
+
+        let call = |path: &'static str, peer: &'static str| {
+            let route = &route;
+            async move {
+                crate::Handler::call(route, request(path, peer))
+                    .await
+                    .unwrap()
+            }
+        };
+
+        assert_eq!(call("/", "203.0.113.9:5000").await.status(), StatusCode::OK);
+
+        maintenance.enable();
+        let res = call("/", "203.0.113.9:5000").await;
+        assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(res.headers()[RETRY_AFTER], "120");
+        assert_eq!(
+            call("/health", "203.0.113.9:5000").await.status(),
+            StatusCode::OK
+        );
+        assert_eq!(call("/", "10.1.2.3:5000").await.status(), StatusCode::OK);
+
+        maintenance.disable();
+        assert_eq!(call("/", "203.0.113.9:5000").await.status(), StatusCode::OK);
+    }
+}
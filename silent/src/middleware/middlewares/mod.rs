@@ -1,20 +1,66 @@
+mod audit;
+mod cache_policy;
+mod circuit_breaker;
 #[cfg(feature = "compression")]
 mod compression;
+#[cfg(feature = "server")]
+mod concurrency_limit;
 mod cors;
+#[cfg(feature = "compression")]
+mod decompression;
+mod etag;
 mod exception_handler;
+#[cfg(feature = "metrics")]
+mod http_metrics;
+#[cfg(feature = "jwt")]
+mod jwt_auth;
 mod logger;
+mod maintenance;
+mod path_glob;
+mod rate_limit;
 mod rate_limiter;
+mod redact;
 mod request_id;
 mod request_time_logger;
+mod response_cache;
+mod response_headers;
+mod slow_request;
 mod timeout;
+mod when;
 
+pub use audit::{AuditLog, AuditMessage, AuditRecord, AuditSink};
+pub use cache_policy::{CachePolicy, CacheRule};
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
 #[cfg(feature = "compression")]
 pub use compression::Compression;
+#[cfg(feature = "server")]
+pub use concurrency_limit::ConcurrencyLimit;
 pub use cors::{Cors, CorsType};
+#[cfg(feature = "compression")]
+pub use decompression::Decompression;
+pub use etag::ETag;
 pub use exception_handler::ExceptionHandler;
-pub use logger::Logger;
+#[cfg(feature = "metrics")]
+pub use http_metrics::HttpMetrics;
+#[cfg(feature = "jwt")]
+pub use jwt_auth::JwtAuth;
+pub use logger::{Logger, RedactingLogger};
+pub use maintenance::Maintenance;
+pub use rate_limit::{
+    MemoryStore, Quota, RateLimit, RateLimitDecision, RateLimitKey, RateLimitStore,
+};
 pub use rate_limiter::RateLimiter;
-pub use request_id::RequestId;
+pub use redact::Redactor;
+pub use request_id::{RequestId, RequestIdValue};
 #[allow(deprecated)]
 pub use request_time_logger::RequestTimeLogger;
+pub use response_cache::{CacheStats, ResponseCache};
+pub use response_headers::ResponseHeaders;
+pub use slow_request::SlowRequest;
 pub use timeout::Timeout;
+#[cfg(feature = "server")]
+pub(crate) use timeout::TimeoutDeadline;
+pub use when::{RequestMatcher, When, unless, when};
+
+#[cfg(feature = "tower-compat")]
+pub use crate::middleware::tower_compat::TowerLayerCompat;
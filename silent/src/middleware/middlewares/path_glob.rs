@@ -0,0 +1,65 @@
+/// 按 `/` 分段的路径模式：`**` 匹配任意多段，段内 `*` 匹配任意字符，如 `/assets/**/*.js`
+#[derive(Clone, Debug)]
+pub(crate) struct PathGlob(Vec<String>);
+
+impl PathGlob {
+    pub(crate) fn new(pattern: &str) -> Self {
+        Self(
+            split_path(pattern)
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+
+    pub(crate) fn matches(&self, path: &str) -> bool {
+        match_segments(&self.0, &split_path(path))
+    }
+}
+
+fn match_segments(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=path.len()).any(|skip| match_segments(rest, &path[skip..]))
+        }
+        Some((first, rest)) => path
+            .split_first()
+            .is_some_and(|(seg, tail)| match_wildcard(first, seg) && match_segments(rest, tail)),
+    }
+}
+
+fn match_wildcard(pattern: &str, value: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == value,
+        Some((prefix, rest)) => {
+            let Some(value) = value.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=value.len())
+                .filter(|i| value.is_char_boundary(*i))
+                .any(|i| match_wildcard(rest, &value[i..]))
+        }
+    }
+}
+
+fn split_path(path: &str) -> Vec<&str> {
+    path.split('/').filter(|s| !s.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_glob() {
+        let matches = |pattern: &str, path: &str| PathGlob::new(pattern).matches(path);
+        assert!(matches("/assets/**", "/assets/js/app.js"));
+        assert!(matches("/assets/**", "/assets"));
+        assert!(matches("/assets/**/*.js", "/assets/js/app.js"));
+        assert!(!matches("/assets/**/*.js", "/assets/js/app.css"));
+        assert!(matches("/users/*", "/users/42"));
+        assert!(!matches("/users/*", "/users/42/posts"));
+        assert!(matches("/", "/"));
+    }
+}
@@ -0,0 +1,477 @@
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use http::header::RETRY_AFTER;
+use http::{HeaderMap, HeaderName};
+
+use crate::extractor::{ClientIp, FromRequestParts};
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, StatusCode};
+
+/// 限流配额：令牌补充速率与突发容量
+///
+/// ```rust
+/// use silent::middlewares::Quota;
+///
+/// // 每分钟 60 次，允许瞬时突发 10 次
+/// let quota = Quota::per_minute(60).burst(10);
+/// assert_eq!(quota.burst_size(), 10);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quota {
+    rate: f64,
+    burst: u32,
+}
+
+impl Quota {
+    /// 每秒 `n` 次，突发容量为 `n`
+    pub fn per_second(n: u32) -> Self {
+        Self {
+            rate: n as f64,
+            burst: n.max(1),
+        }
+    }
+
+    /// 每分钟 `n` 次，突发容量为 `n`
+    pub fn per_minute(n: u32) -> Self {
+        Self {
+            rate: n as f64 / 60.0,
+            burst: n.max(1),
+        }
+    }
+
+    /// 设置突发容量，即令牌桶的大小
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// 每秒补充的令牌数
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// 突发容量
+    pub fn burst_size(&self) -> u32 {
+        self.burst
+    }
+}
+
+type KeyFn = Arc<dyn Fn(&Request) -> String + Send + Sync>;
+
+/// 限流键：决定哪些请求共享同一个令牌桶
+#[derive(Clone)]
+pub enum RateLimitKey {
+    /// 按客户端 IP，位于可信代理之后时按 [`ClientIp`] 的规则解析
+    ClientIp,
+    /// 按请求头的值，如 `X-Api-Key`；缺少该请求头的请求共享同一个令牌桶
+    Header(HeaderName),
+    /// 所有请求共享同一个令牌桶
+    Global,
+    /// 由闭包计算限流键，如按登录用户或租户限流，见 [`RateLimitKey::from_fn`]
+    Custom(KeyFn),
+}
+
+impl fmt::Debug for RateLimitKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimitKey::ClientIp => f.write_str("ClientIp"),
+            RateLimitKey::Header(name) => f.debug_tuple("Header").field(name).finish(),
+            RateLimitKey::Global => f.write_str("Global"),
+            RateLimitKey::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+impl RateLimitKey {
+    /// 由闭包计算限流键，返回相同字符串的请求共享同一个令牌桶
+    ///
+    /// ```rust
+    /// use silent::middlewares::RateLimitKey;
+    ///
+    /// let key = RateLimitKey::from_fn(|req| req.uri().path().to_string());
+    /// ```
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(&Request) -> String + Send + Sync + 'static,
+    {
+        RateLimitKey::Custom(Arc::new(f))
+    }
+
+    async fn resolve(&self, req: &mut Request) -> String {
+        match self {
+            RateLimitKey::ClientIp => ClientIp::from_request_parts(req)
+                .await
+                .map(|ClientIp(ip)| ip.to_string())
+                .unwrap_or_default(),
+            RateLimitKey::Header(name) => req
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default()
+                .to_string(),
+            RateLimitKey::Global => String::new(),
+            RateLimitKey::Custom(f) => f(req),
+        }
+    }
+}
+
+/// 一次限流判定的结果
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    /// 是否放行
+    pub allowed: bool,
+    /// 判定后桶内剩余的令牌数
+    pub remaining: u32,
+    /// 被拒绝时距离下一个令牌可用的时间
+    pub retry_after: Duration,
+}
+
+/// 限流状态存储
+///
+/// 默认使用进程内的 [`MemoryStore`]；多实例部署时可基于 Redis 等实现该 trait，使配额在实例间共享。
+#[async_trait]
+pub trait RateLimitStore: Send + Sync + 'static {
+    /// 为 `key` 尝试消耗一个令牌
+    async fn acquire(&self, key: &str, quota: &Quota) -> Result<RateLimitDecision>;
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// 进程内令牌桶存储
+///
+/// 令牌桶按键的哈希分散到多个分片，每个分片独立加锁，降低高并发下的锁竞争。
+/// 分片内桶的数量超过阈值时清理已回满的桶，避免按客户端 IP 限流时内存无限增长。
+pub struct MemoryStore {
+    shards: Box<[Mutex<HashMap<String, Bucket>>]>,
+    hasher: RandomState,
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::with_shards(Self::DEFAULT_SHARDS)
+    }
+}
+
+impl MemoryStore {
+    const CLEANUP_THRESHOLD: usize = 10_000;
+    const DEFAULT_SHARDS: usize = 16;
+
+    /// 创建空的存储，默认 16 个分片
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 创建指定分片数的存储，分片数至少为 1
+    pub fn with_shards(shards: usize) -> Self {
+        Self {
+            shards: (0..shards.max(1))
+                .map(|_| Mutex::new(HashMap::new()))
+                .collect(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    fn shard(&self, key: &str) -> &Mutex<HashMap<String, Bucket>> {
+        let index = self.hasher.hash_one(key) as usize % self.shards.len();
+        &self.shards[index]
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for MemoryStore {
+    async fn acquire(&self, key: &str, quota: &Quota) -> Result<RateLimitDecision> {
+        let mut buckets = self.shard(key).lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let capacity = quota.burst as f64;
+        let threshold = (Self::CLEANUP_THRESHOLD / self.shards.len()).max(1);
+        if buckets.len() >= threshold && !buckets.contains_key(key) {
+            buckets.retain(|_, bucket| {
+                let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+                bucket.tokens + elapsed * quota.rate < capacity
+            });
+        }
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: capacity,
+            last_refill: now,
+        });
+
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * quota.rate).min(capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(RateLimitDecision {
+                allowed: true,
+                remaining: bucket.tokens as u32,
+                retry_after: Duration::ZERO,
+            });
+        }
+        let retry_after = if quota.rate > 0.0 {
+            Duration::from_secs_f64((1.0 - bucket.tokens) / quota.rate)
+        } else {
+            Duration::MAX
+        };
+        Ok(RateLimitDecision {
+            allowed: false,
+            remaining: 0,
+            retry_after,
+        })
+    }
+}
+
+/// RateLimit 中间件
+///
+/// 按 [`RateLimitKey`] 将请求分组，每组独立使用一个令牌桶，配额耗尽时返回 `429 Too Many Requests`
+/// 并设置 `Retry-After` 头。放行与拒绝的响应都带有 `X-RateLimit-Limit`（突发容量）、
+/// `X-RateLimit-Remaining`（剩余令牌数）与 `X-RateLimit-Reset`（令牌桶回满的秒数）头。与所有请求共享一个令牌桶的 [`RateLimiter`](super::RateLimiter) 不同，
+/// 适合按客户端或 API Key 分别限流；也可通过 `Route::with_rate_limit` 直接挂载到路由上。
+///
+/// 存储出错时放行请求并记录警告，避免限流后端故障导致服务不可用。
+///
+/// ```rust
+/// use http::HeaderName;
+/// use silent::prelude::*;
+/// use silent::middlewares::{Quota, RateLimit, RateLimitKey};
+///
+/// let route = Route::new("api")
+///     .hook(
+///         RateLimit::new(Quota::per_minute(100))
+///             .key(RateLimitKey::Header(HeaderName::from_static("x-api-key"))),
+///     )
+///     .get(|_req: Request| async { Ok("ok") });
+/// ```
+#[derive(Clone)]
+pub struct RateLimit {
+    quota: Quota,
+    key: RateLimitKey,
+    store: Arc<dyn RateLimitStore>,
+}
+
+const X_RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const X_RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+const X_RATELIMIT_RESET: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+impl RateLimit {
+    /// 创建按客户端 IP 限流、使用进程内存储的中间件
+    pub fn new(quota: Quota) -> Self {
+        Self {
+            quota,
+            key: RateLimitKey::ClientIp,
+            store: Arc::new(MemoryStore::new()),
+        }
+    }
+
+    /// 设置限流键
+    pub fn key(mut self, key: RateLimitKey) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// 使用自定义存储
+    pub fn store(mut self, store: impl RateLimitStore) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
+    /// 写入 `X-RateLimit-*` 头
+    fn set_headers(&self, headers: &mut HeaderMap, decision: &RateLimitDecision) {
+        let missing = self.quota.burst.saturating_sub(decision.remaining) as f64;
+        let reset = if missing == 0.0 {
+            0
+        } else if self.quota.rate > 0.0 {
+            (missing / self.quota.rate).ceil() as u64
+        } else {
+            decision.retry_after.as_secs()
+        };
+        headers.insert(X_RATELIMIT_LIMIT, self.quota.burst.into());
+        headers.insert(X_RATELIMIT_REMAINING, decision.remaining.into());
+        headers.insert(X_RATELIMIT_RESET, reset.into());
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for RateLimit {
+    async fn handle(&self, mut req: Request, next: &Next) -> Result<Response> {
+        let key = self.key.resolve(&mut req).await;
+        let decision = match self.store.acquire(&key, &self.quota).await {
+            Ok(decision) => decision,
+            Err(e) => {
+                tracing::warn!(error = %e, "rate limit store unavailable, request allowed");
+                return next.call(req).await;
+            }
+        };
+        if decision.allowed {
+            let mut res = next.call(req).await?;
+            self.set_headers(res.headers_mut(), &decision);
+            return Ok(res);
+        }
+
+        let retry_after = decision.retry_after.as_secs_f64().ceil().max(1.0) as u64;
+        tracing::debug!(key, retry_after, "rate limit exceeded");
+        let mut res = Response::empty();
+        res.set_status(StatusCode::TOO_MANY_REQUESTS);
+        res.headers_mut().insert(RETRY_AFTER, retry_after.into());
+        self.set_headers(res.headers_mut(), &decision);
+        res.set_body(crate::core::res_body::full("Too Many Requests"));
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== MemoryStore 测试 ====================
+
+    #[tokio::test]
+    async fn test_memory_store_buckets_per_key() {
+        let store = MemoryStore::new();
+        let quota = Quota::per_minute(1).burst(2);
+
+        let first = store.acquire("a", &quota).await.unwrap();
+        assert!(first.allowed);
+        assert_eq!(first.remaining, 1);
+        assert!(store.acquire("a", &quota).await.unwrap().allowed);
+
+        let denied = store.acquire("a", &quota).await.unwrap();
+        assert!(!denied.allowed);
+        assert!(denied.retry_after > Duration::from_secs(50));
+
+        // 其他键不受影响
+        assert!(store.acquire("b", &quota).await.unwrap().allowed);
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_single_shard() {
+        let store = MemoryStore::with_shards(0);
+        assert_eq!(store.shards.len(), 1);
+        let quota = Quota::per_minute(1);
+        for key in ["a", "b", "c"] {
+            assert!(store.acquire(key, &quota).await.unwrap().allowed);
+            assert!(!store.acquire(key, &quota).await.unwrap().allowed);
+        }
+    }
+
+    // ==================== 集成测试 ====================
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_rate_limit_by_header() {
+        use crate::route::Route;
+
+        let route = Route::new("/")
+            .hook(
+                RateLimit::new(Quota::per_minute(1))
+                    .key(RateLimitKey::Header(HeaderName::from_static("x-api-key"))),
+            )
+            .get(|_req: Request| async { Ok("ok") });
+        let route = Route::new_root().append(route);
+
+        let request = |key: &'static str| {
+            let mut req = Request::empty();
+            req.headers_mut()
+                .insert("x-api-key", http::HeaderValue::from_static(key));
+            req
+        };
+
+        let res = crate::Handler::call(&route, request("alice"))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let res = crate::Handler::call(&route, request("alice"))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+        let retry_after: u64 = res.headers()[RETRY_AFTER]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((1..=60).contains(&retry_after));
+
+        let res = crate::Handler::call(&route, request("bob")).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_rate_limit_custom_key_and_headers() {
+        use crate::route::Route;
+
+        let route = Route::new("<tenant>")
+            .hook(
+                RateLimit::new(Quota::per_minute(60).burst(2)).key(RateLimitKey::from_fn(|req| {
+                    req.uri().path().trim_start_matches('/').to_string()
+                })),
+            )
+            .get(|_req: Request| async { Ok("ok") });
+        let route = Route::new_root().append(route);
+
+        let call = |path: &'static str| {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            crate::Handler::call(&route, req)
+        };
+
+        let res = call("/acme").await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[X_RATELIMIT_LIMIT], "2");
+        assert_eq!(res.headers()[X_RATELIMIT_REMAINING], "1");
+        assert_eq!(res.headers()[X_RATELIMIT_RESET], "1");
+        let res = call("/acme").await.unwrap();
+        assert_eq!(res.headers()[X_RATELIMIT_REMAINING], "0");
+        assert_eq!(res.headers()[X_RATELIMIT_RESET], "2");
+
+        let res = call("/acme").await.unwrap();
+        assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(res.headers()[X_RATELIMIT_REMAINING], "0");
+        assert!(res.headers().contains_key(RETRY_AFTER));
+
+        // 其他租户不受影响
+        let res = call("/globex").await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_rate_limit_store_error_allows_request() {
+        use crate::SilentError;
+        use crate::route::Route;
+
+        struct Broken;
+
+        #[async_trait]
+        impl RateLimitStore for Broken {
+            async fn acquire(&self, _key: &str, _quota: &Quota) -> Result<RateLimitDecision> {
+                Err(SilentError::business_error(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "store down",
+                ))
+            }
+        }
+
+        let route = Route::new("/")
+            .hook(
+                RateLimit::new(Quota::per_second(1))
+                    .key(RateLimitKey::Global)
+                    .store(Broken),
+            )
+            .get(|_req: Request| async { Ok("ok") });
+        let route = Route::new_root().append(route);
+        for _ in 0..3 {
+            let res = crate::Handler::call(&route, Request::empty())
+                .await
+                .unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+
+use bytes::Bytes;
+use http::header::{AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION, SET_COOKIE};
+use http::{HeaderMap, HeaderName, HeaderValue};
+use serde_json::Value;
+
+/// 敏感数据脱敏规则，供 [`Logger`](super::Logger) 与 [`AuditLog`](super::AuditLog) 在输出前使用
+///
+/// - 头部：默认脱敏 `Authorization`、`Proxy-Authorization`、`Cookie`、`Set-Cookie`
+/// - 字段：默认脱敏 `password`、`token`，作用于 JSON 消息体（任意嵌套层级）与查询参数，名称不区分大小写
+///
+/// 命中的值整体替换为掩码（默认 `[REDACTED]`）。无法解析为 JSON 的消息体（包括被截断的 JSON）原样保留。
+///
+/// ```rust
+/// use silent::middlewares::Redactor;
+///
+/// let redactor = Redactor::new().header("x-api-key").field("secret");
+/// assert_eq!(
+///     redactor.query("user=alice&password=123"),
+///     "user=alice&password=[REDACTED]"
+/// );
+/// assert_eq!(
+///     &redactor.body(br#"{"auth":{"Token":"t"}}"#)[..],
+///     br#"{"auth":{"Token":"[REDACTED]"}}"#
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Redactor {
+    headers: HashSet<HeaderName>,
+    fields: HashSet<String>,
+    mask: String,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Redactor {
+    /// 使用默认规则创建
+    pub fn new() -> Self {
+        Self::empty()
+            .header(AUTHORIZATION.as_str())
+            .header(PROXY_AUTHORIZATION.as_str())
+            .header(COOKIE.as_str())
+            .header(SET_COOKIE.as_str())
+            .field("password")
+            .field("token")
+    }
+
+    /// 创建不含任何规则的脱敏器
+    pub fn empty() -> Self {
+        Self {
+            headers: HashSet::new(),
+            fields: HashSet::new(),
+            mask: "[REDACTED]".to_string(),
+        }
+    }
+
+    /// 追加需要脱敏的头部
+    ///
+    /// # Panics
+    ///
+    /// 头部名称不合法时 panic。
+    pub fn header(mut self, name: &str) -> Self {
+        let header = HeaderName::try_from(name)
+            .unwrap_or_else(|_| panic!("Redactor: invalid header name \"{name}\""));
+        self.headers.insert(header);
+        self
+    }
+
+    /// 追加需要脱敏的 JSON 字段或查询参数名
+    pub fn field(mut self, name: &str) -> Self {
+        self.fields.insert(name.to_ascii_lowercase());
+        self
+    }
+
+    /// 设置替换敏感值的掩码
+    pub fn mask(mut self, mask: impl Into<String>) -> Self {
+        self.mask = mask.into();
+        self
+    }
+
+    /// 头部是否需要脱敏
+    pub fn is_sensitive_header(&self, name: &HeaderName) -> bool {
+        self.headers.contains(name)
+    }
+
+    /// 字段名是否需要脱敏
+    pub fn is_sensitive_field(&self, name: &str) -> bool {
+        self.fields.contains(&name.to_ascii_lowercase())
+    }
+
+    /// 单个头部值脱敏后的文本
+    pub fn header_value(&self, name: &HeaderName, value: &HeaderValue) -> String {
+        if self.is_sensitive_header(name) {
+            self.mask.clone()
+        } else {
+            String::from_utf8_lossy(value.as_bytes()).into_owned()
+        }
+    }
+
+    /// 按出现顺序输出全部头部，敏感头部的值已替换为掩码
+    pub fn headers(&self, headers: &HeaderMap) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| (name.to_string(), self.header_value(name, value)))
+            .collect()
+    }
+
+    /// 原地替换 JSON 中的敏感字段
+    pub fn json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.is_sensitive_field(key) {
+                        *value = Value::String(self.mask.clone());
+                    } else {
+                        self.json(value);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.json(item)),
+            _ => {}
+        }
+    }
+
+    /// 脱敏 JSON 消息体，不是合法 JSON 或不含敏感字段时原样返回
+    pub fn body(&self, body: &[u8]) -> Bytes {
+        if self.fields.is_empty() {
+            return Bytes::copy_from_slice(body);
+        }
+        let Ok(mut value) = serde_json::from_slice::<Value>(body) else {
+            return Bytes::copy_from_slice(body);
+        };
+        let original = value.clone();
+        self.json(&mut value);
+        if value == original {
+            return Bytes::copy_from_slice(body);
+        }
+        serde_json::to_vec(&value)
+            .map(Bytes::from)
+            .unwrap_or_else(|_| Bytes::copy_from_slice(body))
+    }
+
+    /// 脱敏查询字符串中的敏感参数，其余部分保持原样
+    pub fn query(&self, query: &str) -> String {
+        query
+            .split('&')
+            .map(|pair| match pair.split_once('=') {
+                Some((key, _)) if self.is_sensitive_field(key) => format!("{key}={}", self.mask),
+                _ => pair.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redactor_headers() {
+        let redactor = Redactor::new().header("x-api-key");
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, "Bearer t".parse().unwrap());
+        headers.insert("x-api-key", "key".parse().unwrap());
+        headers.insert("x-trace", "trace".parse().unwrap());
+        assert_eq!(
+            redactor.headers(&headers),
+            vec![
+                ("authorization".to_string(), "[REDACTED]".to_string()),
+                ("x-api-key".to_string(), "[REDACTED]".to_string()),
+                ("x-trace".to_string(), "trace".to_string()),
+            ]
+        );
+        assert_eq!(
+            Redactor::empty().headers(&headers)[0].1,
+            "Bearer t".to_string()
+        );
+    }
+
+    #[test]
+    fn test_redactor_body_and_query() {
+        let redactor = Redactor::new().mask("***");
+        let body = redactor.body(br#"{"users":[{"name":"a","PASSWORD":"p"}],"token":{"v":1}}"#);
+        let value: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(value["users"][0]["PASSWORD"], "***");
+        assert_eq!(value["users"][0]["name"], "a");
+        assert_eq!(value["token"], "***");
+
+        // 非 JSON 与不含敏感字段的消息体原样返回
+        assert_eq!(&redactor.body(b"password=p")[..], b"password=p");
+        assert_eq!(&redactor.body(b"{ \"a\": 1 }")[..], b"{ \"a\": 1 }");
+
+        assert_eq!(
+            redactor.query("token=abc&page=1&flag"),
+            "token=***&page=1&flag"
+        );
+    }
+}
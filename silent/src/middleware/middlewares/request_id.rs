@@ -1,45 +1,87 @@
+use std::fmt;
+use std::sync::Arc;
+
 use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result};
 use async_trait::async_trait;
-use http::HeaderName;
+use http::{HeaderName, HeaderValue};
+use tracing::Instrument;
 
 const DEFAULT_HEADER: &str = "x-request-id";
 
+/// 透传的请求 ID 最大长度，超出时视为无效并重新生成
+const MAX_INCOMING_LEN: usize = 128;
+
+/// 当前请求的 ID，由 [`RequestId`] 中间件写入请求扩展
+///
+/// 处理函数可通过 `req.extensions().get::<RequestIdValue>()` 或
+/// [`Extension<RequestIdValue>`](crate::extractor::Extension) 获取。
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RequestIdValue(String);
+
+impl RequestIdValue {
+    /// 请求 ID 字符串
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for RequestIdValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for RequestIdValue {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+type Generator = Arc<dyn Fn() -> String + Send + Sync>;
+
 /// RequestId 中间件
 ///
-/// 为每个请求生成或透传唯一请求 ID，并注入到请求头、响应头和 tracing span 中。
+/// 为每个请求生成或透传唯一请求 ID，并注入到请求头、请求扩展、响应头和 tracing span 中。
 ///
 /// # 行为
 ///
-/// 1. 如果请求头中已包含指定的 ID 头（默认 `x-request-id`），则复用该值（上游代理透传场景）
-/// 2. 否则使用 scru128 生成一个新的有序唯一 ID
+/// 1. 如果请求头中已包含指定的 ID 头（默认 `x-request-id`）且值合法，则复用该值（上游代理透传场景）
+/// 2. 否则使用生成器生成新的 ID，默认为 UUIDv7（按时间有序）
 /// 3. 将请求 ID 设置到请求头中，下游 handler 可通过 `req.headers().get("x-request-id")` 获取
-/// 4. 将请求 ID 设置到响应头中，方便客户端关联
-/// 5. 将请求 ID 注入 tracing span，便于日志追踪
+/// 4. 将请求 ID 以 [`RequestIdValue`] 写入请求扩展
+/// 5. 将请求 ID 设置到响应头中，方便客户端关联
+/// 6. 在携带 `request_id` 字段的 tracing span 中执行后续处理，便于日志追踪
+///
+/// 透传的值为空、超过 128 字节或含有非可见 ASCII 字符时视为无效，改为生成新的 ID。
 ///
 /// # 示例
 ///
 /// ```rust
 /// use silent::prelude::*;
-/// use silent::middlewares::RequestId;
+/// use silent::middlewares::{RequestId, RequestIdValue};
 ///
 /// let route = Route::new("/")
 ///     .hook(RequestId::new())
-///     .get(|_req: Request| async { Ok("hello") });
+///     .get(|req: Request| async move {
+///         let id = req.extensions().get::<RequestIdValue>().unwrap();
+///         Ok(id.to_string())
+///     });
 /// ```
 ///
-/// 自定义头名称：
+/// 自定义头名称与 ID 生成器：
 ///
 /// ```rust
 /// use silent::prelude::*;
 /// use silent::middlewares::RequestId;
 ///
 /// let route = Route::new("/")
-///     .hook(RequestId::with_header("x-trace-id"))
+///     .hook(RequestId::with_header("x-trace-id").generator(|| uuid::Uuid::now_v7().simple().to_string()))
 ///     .get(|_req: Request| async { Ok("hello") });
 /// ```
 #[derive(Clone)]
 pub struct RequestId {
     header_name: HeaderName,
+    generator: Generator,
 }
 
 impl Default for RequestId {
@@ -48,11 +90,16 @@ impl Default for RequestId {
     }
 }
 
+fn uuid_v7() -> String {
+    uuid::Uuid::now_v7().to_string()
+}
+
 impl RequestId {
     /// 使用默认头名称 `x-request-id` 创建中间件。
     pub fn new() -> Self {
         Self {
             header_name: HeaderName::from_static(DEFAULT_HEADER),
+            generator: Arc::new(uuid_v7),
         }
     }
 
@@ -64,6 +111,36 @@ impl RequestId {
     pub fn with_header(name: &'static str) -> Self {
         Self {
             header_name: HeaderName::from_static(name),
+            ..Self::new()
+        }
+    }
+
+    /// 使用自定义 ID 生成器，生成的值不是合法的头部值时回退到 UUIDv7。
+    pub fn generator<F>(mut self, generator: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        self.generator = Arc::new(generator);
+        self
+    }
+
+    /// 读取请求中合法的 ID，或生成新的 ID
+    fn resolve(&self, req: &Request) -> (String, HeaderValue) {
+        if let Some(value) = req.headers().get(&self.header_name)
+            && !value.is_empty()
+            && value.len() <= MAX_INCOMING_LEN
+            && let Ok(id) = value.to_str()
+        {
+            return (id.to_owned(), value.clone());
+        }
+        let id = (self.generator)();
+        match HeaderValue::from_str(&id) {
+            Ok(value) if !id.is_empty() => (id, value),
+            _ => {
+                let id = uuid_v7();
+                let value = HeaderValue::from_str(&id).expect("uuid is a valid header value");
+                (id, value)
+            }
         }
     }
 }
@@ -72,25 +149,20 @@ impl RequestId {
 impl MiddleWareHandler for RequestId {
     async fn handle(&self, mut req: Request, next: &Next) -> Result<Response> {
         // 优先使用请求中已有的 ID（上游代理透传），否则生成新的
-        let request_id = req
-            .headers()
-            .get(&self.header_name)
-            .and_then(|v| v.to_str().ok())
-            .map(|s| s.to_owned())
-            .unwrap_or_else(scru128::new_string);
-
-        // 将 ID 注入请求头，下游 handler 可获取
-        if let Ok(val) = request_id.parse() {
-            req.headers_mut().insert(self.header_name.clone(), val);
-        }
+        let (request_id, value) = self.resolve(&req);
+
+        // 将 ID 注入请求头与请求扩展，下游 handler 可获取
+        req.headers_mut()
+            .insert(self.header_name.clone(), value.clone());
+        req.extensions_mut()
+            .insert(RequestIdValue(request_id.clone()));
 
-        tracing::debug!(request_id = %request_id, "request started");
-        let mut res = next.call(req).await?;
+        let span = tracing::info_span!("request", request_id = %request_id);
+        tracing::debug!(parent: &span, "request started");
+        let mut res = next.call(req).instrument(span).await?;
 
         // 将 ID 注入响应头
-        if let Ok(val) = request_id.parse() {
-            res.headers_mut().insert(self.header_name.clone(), val);
-        }
+        res.headers_mut().insert(self.header_name.clone(), value);
 
         Ok(res)
     }
@@ -155,9 +227,10 @@ mod tests {
         // 响应中应包含自动生成的 x-request-id
         let id = resp.headers().get("x-request-id");
         assert!(id.is_some());
-        // 应该是有效的 scru128 ID（25 字符）
+        // 默认生成 UUIDv7
         let id_str = id.unwrap().to_str().unwrap();
-        assert_eq!(id_str.len(), 25);
+        let uuid = uuid::Uuid::parse_str(id_str).unwrap();
+        assert_eq!(uuid.get_version_num(), 7);
     }
 
     #[cfg(feature = "server")]
@@ -346,4 +419,38 @@ mod tests {
         // 错误情况下中间件返回 Err，不会设置响应头
         assert!(res.is_err());
     }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_request_id_extension_and_generator() {
+        use crate::route::Route;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        let next_id = counter.clone();
+        let mid = RequestId::new()
+            .generator(move || format!("req-{}", next_id.fetch_add(1, Ordering::SeqCst)));
+        let route = Route::new("/").hook(mid).get(|req: Request| async move {
+            let id = req.extensions().get::<RequestIdValue>().unwrap();
+            Ok(id.to_string())
+        });
+        let route = Route::new_root().append(route);
+
+        let res = crate::Handler::call(&route, Request::empty())
+            .await
+            .unwrap();
+        assert_eq!(res.headers()["x-request-id"], "req-0");
+        let body = http_body_util::BodyExt::collect(res.body)
+            .await
+            .unwrap()
+            .to_bytes();
+        assert_eq!(&body[..], b"req-0");
+
+        // 过长的透传值被替换为新生成的 ID
+        let mut req = Request::empty();
+        req.headers_mut()
+            .insert("x-request-id", "a".repeat(200).parse().unwrap());
+        let res = crate::Handler::call(&route, req).await.unwrap();
+        assert_eq!(res.headers()["x-request-id"], "req-1");
+    }
 }
@@ -0,0 +1,753 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::header::{AGE, AUTHORIZATION, CACHE_CONTROL, COOKIE, SET_COOKIE, VARY};
+use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use http_body::Body;
+use http_body_util::BodyExt;
+
+use crate::core::res_body::full;
+use crate::extractor::OriginalUri;
+use crate::route::request_host;
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, SilentError};
+
+const X_CACHE: HeaderName = HeaderName::from_static("x-cache");
+
+/// 资源标识：请求方法、主机名、路径与查询
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct ResourceKey {
+    method: Method,
+    /// 小写且不含端口的主机名，与 `Route::host` 的匹配规则一致
+    host: Option<String>,
+    /// `Route::mount` 去除挂载前缀之前的完整路径
+    path: String,
+    query: Option<String>,
+}
+
+impl ResourceKey {
+    fn new(req: &Request) -> Self {
+        let uri = match req.extensions().get::<OriginalUri>() {
+            Some(OriginalUri(uri)) => uri,
+            None => req.uri(),
+        };
+        Self {
+            method: req.method().clone(),
+            host: request_host(req).map(str::to_ascii_lowercase),
+            path: uri.path().to_string(),
+            query: uri.query().map(str::to_string),
+        }
+    }
+}
+
+/// 缓存键：资源标识与 Vary 请求头的值
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct CacheKey {
+    resource: ResourceKey,
+    vary: Vec<(HeaderName, Option<HeaderValue>)>,
+}
+
+impl CacheKey {
+    fn new(resource: ResourceKey, names: &[HeaderName], req: &Request) -> Self {
+        let vary = names
+            .iter()
+            .map(|name| (name.clone(), req.headers().get(name).cloned()))
+            .collect();
+        Self { resource, vary }
+    }
+}
+
+struct CacheEntry {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    stored_at: Instant,
+    expires_at: Instant,
+    /// 响应带有 `Cache-Control: public`，可用于携带凭据的请求
+    public: bool,
+    tick: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<CacheKey, CacheEntry>,
+    /// 最近使用顺序：tick -> key，tick 越小越久未使用
+    order: BTreeMap<u64, CacheKey>,
+    /// 每个资源参与缓存键的请求头（配置项与响应 `Vary` 的并集）及其条目数
+    vary: HashMap<ResourceKey, (Vec<HeaderName>, usize)>,
+    tick: u64,
+}
+
+impl CacheState {
+    /// 资源参与缓存键的请求头，资源尚无缓存时返回 `None`
+    fn vary_names(&self, resource: &ResourceKey) -> Option<&[HeaderName]> {
+        self.vary.get(resource).map(|(names, _)| names.as_slice())
+    }
+
+    fn unlink(&mut self, key: &CacheKey) {
+        if let Some((_, count)) = self.vary.get_mut(&key.resource) {
+            *count -= 1;
+            if *count == 0 {
+                self.vary.remove(&key.resource);
+            }
+        }
+    }
+
+    fn touch(&mut self, key: &CacheKey) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.order.remove(&entry.tick);
+            entry.tick = tick;
+            self.order.insert(tick, key.clone());
+        }
+    }
+
+    fn remove(&mut self, key: &CacheKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.tick);
+            self.unlink(key);
+        }
+    }
+
+    fn insert(&mut self, key: CacheKey, mut entry: CacheEntry, capacity: usize) {
+        self.remove(&key);
+        while self.entries.len() >= capacity {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+            self.unlink(&oldest);
+        }
+        // 响应的 Vary 变化后，旧缓存键已无法命中
+        let names: Vec<_> = key.vary.iter().map(|(name, _)| name.clone()).collect();
+        if self
+            .vary_names(&key.resource)
+            .is_some_and(|current| current != names)
+        {
+            let stale: Vec<_> = self
+                .entries
+                .keys()
+                .filter(|k| k.resource == key.resource)
+                .cloned()
+                .collect();
+            for stale in &stale {
+                self.remove(stale);
+            }
+        }
+        self.vary
+            .entry(key.resource.clone())
+            .or_insert((names, 0))
+            .1 += 1;
+        self.tick += 1;
+        entry.tick = self.tick;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, entry);
+    }
+}
+
+/// 响应缓存的命中统计
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// 命中次数
+    pub hits: u64,
+    /// 未命中次数（含被 `Cache-Control` 绕过的请求）
+    pub misses: u64,
+    /// 当前缓存的条目数
+    pub entries: usize,
+}
+
+struct Inner {
+    capacity: usize,
+    ttl: Duration,
+    max_body_size: usize,
+    vary: Vec<HeaderName>,
+    state: Mutex<CacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// ResponseCache 中间件
+///
+/// 在进程内缓存只读接口的响应，按 LRU 淘汰并在 TTL 到期后失效。
+///
+/// # 行为
+///
+/// 1. 只缓存 `GET` / `HEAD` 请求的 `200 OK` 响应，缓存键由方法、主机名、路径、查询字符串、[`vary`](Self::vary)
+///    指定的请求头以及响应 `Vary` 头列出的请求头组成；路径为 `Route::mount` 去除挂载前缀之前的完整路径
+/// 2. 请求携带 `Cache-Control: no-cache` 时跳过缓存读取，`no-store` 时既不读取也不写入
+/// 3. 响应带有 `Cache-Control: no-store` / `no-cache` / `private`、`Set-Cookie` 或 `Vary: *` 时不缓存；
+///    带有 `s-maxage` 或 `max-age` 时以其作为 TTL（不超过配置的 TTL）
+/// 4. 请求携带 `Authorization` 或 `Cookie` 时，只缓存并命中带有 `Cache-Control: public` 的响应
+/// 5. 只缓存大小已知且不超过上限（默认 1 MiB）的响应体
+/// 6. 响应带有 `X-Cache: HIT` / `MISS` 头，命中的响应带有 `Age` 头
+///
+/// 克隆的中间件共享同一份缓存，可保留一份用于 [`invalidate`](Self::invalidate) 与 [`stats`](Self::stats)。
+/// 启用 `metrics` 特性时同时上报 `silent.response_cache.hit` / `silent.response_cache.miss` 计数。
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::ResponseCache;
+/// use std::time::Duration;
+///
+/// let cache = ResponseCache::new(1024)
+///     .ttl(Duration::from_secs(30))
+///     .vary(header::ACCEPT_LANGUAGE);
+/// let route = Route::new("products")
+///     .hook(cache.clone())
+///     .get(|_req: Request| async { Ok("products") });
+///
+/// // 数据变更后使缓存失效
+/// cache.invalidate("/products");
+/// ```
+#[derive(Clone)]
+pub struct ResponseCache {
+    inner: Arc<Inner>,
+}
+
+impl ResponseCache {
+    /// 创建最多缓存 `capacity` 个响应的中间件，默认 TTL 为 60 秒
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                capacity: capacity.max(1),
+                ttl: Duration::from_secs(60),
+                max_body_size: 1024 * 1024,
+                vary: Vec::new(),
+                state: Mutex::default(),
+                hits: AtomicU64::new(0),
+                misses: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    fn configure(mut self, f: impl FnOnce(&mut Inner)) -> Self {
+        let inner = Arc::get_mut(&mut self.inner).expect("ResponseCache 需在克隆前完成配置");
+        f(inner);
+        self
+    }
+
+    /// 设置缓存有效期
+    pub fn ttl(self, ttl: Duration) -> Self {
+        self.configure(|inner| inner.ttl = ttl)
+    }
+
+    /// 设置可缓存的响应体大小上限（字节）
+    pub fn max_body_size(self, size: usize) -> Self {
+        self.configure(|inner| inner.max_body_size = size)
+    }
+
+    /// 将请求头加入缓存键，如 `Accept-Language`、`Accept-Encoding`，可多次调用
+    pub fn vary(self, name: HeaderName) -> Self {
+        self.configure(|inner| inner.vary.push(name))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        self.inner.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn remove_where(&self, f: impl Fn(&ResourceKey) -> bool) {
+        let mut state = self.lock();
+        let keys: Vec<_> = state
+            .entries
+            .keys()
+            .filter(|key| f(&key.resource))
+            .cloned()
+            .collect();
+        for key in &keys {
+            state.remove(key);
+        }
+    }
+
+    /// 使所有主机下路径为 `path`（完整请求路径，不含查询字符串）的缓存失效
+    pub fn invalidate(&self, path: &str) {
+        self.remove_where(|resource| resource.path == path)
+    }
+
+    /// 使主机名为 `host`、路径为 `path` 的缓存失效，用于 `Route::host` 划分的虚拟主机
+    pub fn invalidate_host(&self, host: &str, path: &str) {
+        let host = host.trim().trim_end_matches('.').to_ascii_lowercase();
+        self.remove_where(|resource| {
+            resource.path == path && resource.host.as_deref() == Some(host.as_str())
+        })
+    }
+
+    /// 使所有主机下路径以 `prefix` 开头的缓存失效
+    pub fn invalidate_prefix(&self, prefix: &str) {
+        self.remove_where(|resource| resource.path.starts_with(prefix))
+    }
+
+    /// 清空缓存
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.entries.clear();
+        state.order.clear();
+        state.vary.clear();
+    }
+
+    /// 命中统计
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.inner.hits.load(Ordering::Relaxed),
+            misses: self.inner.misses.load(Ordering::Relaxed),
+            entries: self.lock().entries.len(),
+        }
+    }
+
+    fn record(&self, hit: bool) {
+        let counter = if hit {
+            &self.inner.hits
+        } else {
+            &self.inner.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "metrics")]
+        if hit {
+            metrics::counter!("silent.response_cache.hit").increment(1);
+        } else {
+            metrics::counter!("silent.response_cache.miss").increment(1);
+        }
+    }
+
+    fn lookup(
+        &self,
+        resource: &ResourceKey,
+        req: &Request,
+        credentialed: bool,
+    ) -> Option<Response> {
+        let mut state = self.lock();
+        let key = CacheKey::new(resource.clone(), state.vary_names(resource)?, req);
+        let now = Instant::now();
+        let entry = state.entries.get(&key)?;
+        if entry.expires_at <= now {
+            state.remove(&key);
+            return None;
+        }
+        if credentialed && !entry.public {
+            return None;
+        }
+        let mut res = Response::empty();
+        res.set_status(entry.status);
+        *res.headers_mut() = entry.headers.clone();
+        res.headers_mut()
+            .insert(AGE, now.duration_since(entry.stored_at).as_secs().into());
+        res.set_body(full(entry.body.clone()));
+        state.touch(&key);
+        Some(res)
+    }
+
+    /// 参与缓存键的请求头：配置的请求头与响应 `Vary` 列出的请求头，排序去重
+    fn vary_names(&self, headers: &HeaderMap) -> Vec<HeaderName> {
+        let mut names = self.inner.vary.clone();
+        names.extend(
+            headers
+                .get_all(VARY)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .flat_map(|v| v.split(','))
+                .filter_map(|v| HeaderName::from_bytes(v.trim().as_bytes()).ok()),
+        );
+        names.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        names.dedup();
+        names
+    }
+
+    /// 根据响应的 `Cache-Control` 计算 TTL，不可缓存时返回 `None`
+    fn response_ttl(&self, headers: &HeaderMap) -> Option<Duration> {
+        if headers.contains_key(SET_COOKIE) || header_has(headers, &VARY, "*") {
+            return None;
+        }
+        let directives = cache_directives(headers);
+        let mut max_age = None;
+        let mut s_maxage = None;
+        for directive in &directives {
+            match directive.split_once('=') {
+                Some(("max-age", v)) => max_age = v.trim_matches('"').parse::<u64>().ok(),
+                Some(("s-maxage", v)) => s_maxage = v.trim_matches('"').parse::<u64>().ok(),
+                None if matches!(directive.as_str(), "no-store" | "no-cache" | "private") => {
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        let ttl = match s_maxage.or(max_age) {
+            Some(seconds) => Duration::from_secs(seconds).min(self.inner.ttl),
+            None => self.inner.ttl,
+        };
+        (!ttl.is_zero()).then_some(ttl)
+    }
+}
+
+/// 解析 `Cache-Control` 指令，统一为小写
+fn cache_directives(headers: &HeaderMap) -> Vec<String> {
+    headers
+        .get_all(CACHE_CONTROL)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|d| d.trim().to_ascii_lowercase())
+        .filter(|d| !d.is_empty())
+        .collect()
+}
+
+fn header_has(headers: &HeaderMap, name: &HeaderName, token: &str) -> bool {
+    headers
+        .get_all(name)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|v| v.trim() == token)
+}
+
+#[async_trait]
+impl MiddleWareHandler for ResponseCache {
+    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+        if !matches!(*req.method(), Method::GET | Method::HEAD) {
+            return next.call(req).await;
+        }
+        let resource = ResourceKey::new(&req);
+        let directives = cache_directives(req.headers());
+        let no_store = directives.iter().any(|d| d == "no-store");
+        let no_cache = no_store || directives.iter().any(|d| d == "no-cache");
+        let credentialed =
+            req.headers().contains_key(AUTHORIZATION) || req.headers().contains_key(COOKIE);
+
+        if !no_cache && let Some(mut res) = self.lookup(&resource, &req, credentialed) {
+            self.record(true);
+            res.headers_mut()
+                .insert(X_CACHE, HeaderValue::from_static("HIT"));
+            return Ok(res);
+        }
+        self.record(false);
+
+        // 响应生成前保留 Vary 可能引用的请求头
+        let req_headers = req.headers().clone();
+        let mut res = next.call(req).await?;
+        let size = res.body.size_hint().exact();
+        let ttl = self.response_ttl(res.headers());
+        let public = cache_directives(res.headers())
+            .iter()
+            .any(|d| d == "public");
+        if !no_store
+            && (public || !credentialed)
+            && res.status() == StatusCode::OK
+            && let Some(ttl) = ttl
+            && let Some(_) = size.filter(|size| *size as usize <= self.inner.max_body_size)
+        {
+            let body = res
+                .take_body()
+                .collect()
+                .await
+                .map_err(|e| {
+                    SilentError::business_error(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        format!("ResponseCache: failed to read response body: {e}"),
+                    )
+                })?
+                .to_bytes();
+            let now = Instant::now();
+            let entry = CacheEntry {
+                status: res.status(),
+                headers: res.headers().clone(),
+                body: body.clone(),
+                stored_at: now,
+                expires_at: now + ttl,
+                public,
+                tick: 0,
+            };
+            let vary = self
+                .vary_names(res.headers())
+                .into_iter()
+                .map(|name| {
+                    let value = req_headers.get(&name).cloned();
+                    (name, value)
+                })
+                .collect();
+            let key = CacheKey { resource, vary };
+            self.lock().insert(key, entry, self.inner.capacity);
+            res.set_body(full(body));
+        }
+        res.headers_mut()
+            .insert(X_CACHE, HeaderValue::from_static("MISS"));
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+    use std::sync::atomic::AtomicUsize;
+
+    fn route(cache: ResponseCache, calls: Arc<AtomicUsize>) -> Route {
+        let counter = calls.clone();
+        let route = Route::new("")
+            .hook(cache)
+            .append(Route::new("items").get(move |_req: Request| {
+                let calls = counter.clone();
+                async move {
+                    let n = calls.fetch_add(1, Ordering::SeqCst);
+                    Ok(format!("items {n}"))
+                }
+            }))
+            .append(Route::new("private").get(|_req: Request| async {
+                let mut res = Response::text("private");
+                res.headers_mut()
+                    .insert(CACHE_CONTROL, HeaderValue::from_static("private"));
+                Ok(res)
+            }));
+        Route::new_root().append(route)
+    }
+
+    async fn get(route: &Route, path: &str, headers: &[(HeaderName, &'static str)]) -> Response {
+        let mut req = Request::empty();
+        *req.uri_mut() = path.parse().unwrap();
+        for (name, value) in headers {
+            req.headers_mut()
+                .insert(name.clone(), HeaderValue::from_static(value));
+        }
+        crate::Handler::call(route, req).await.unwrap()
+    }
+
+    /// 每次调用返回递增计数并附带给定响应头的路由
+    fn counting_route(
+        cache: ResponseCache,
+        path: &str,
+        headers: &'static [(HeaderName, &'static str)],
+    ) -> Route {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let handler = Route::new(path).get(move |_req: Request| {
+            let calls = calls.clone();
+            async move {
+                let mut res = Response::text(&calls.fetch_add(1, Ordering::SeqCst).to_string());
+                for (name, value) in headers {
+                    res.headers_mut()
+                        .insert(name.clone(), HeaderValue::from_static(value));
+                }
+                Ok(res)
+            }
+        });
+        Route::new_root().append(Route::new("").hook(cache).append(handler))
+    }
+
+    async fn body(res: Response) -> String {
+        let bytes = res.body.collect().await.unwrap().to_bytes();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_cache_state_lru() {
+        let mut state = CacheState::default();
+        let key = |path: &str| CacheKey {
+            resource: ResourceKey {
+                method: Method::GET,
+                host: None,
+                path: path.to_string(),
+                query: None,
+            },
+            vary: Vec::new(),
+        };
+        let entry = || CacheEntry {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: Bytes::new(),
+            stored_at: Instant::now(),
+            expires_at: Instant::now(),
+            public: false,
+            tick: 0,
+        };
+        state.insert(key("/a"), entry(), 2);
+        state.insert(key("/b"), entry(), 2);
+        state.touch(&key("/a"));
+        state.insert(key("/c"), entry(), 2);
+        assert!(state.entries.contains_key(&key("/a")));
+        assert!(!state.entries.contains_key(&key("/b")));
+        assert!(state.entries.contains_key(&key("/c")));
+        assert_eq!(state.order.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_hit_and_invalidate() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cache = ResponseCache::new(16).vary(http::header::ACCEPT_LANGUAGE);
+        let route = route(cache.clone(), calls.clone());
+
+        let res = get(&route, "/items", &[]).await;
+        assert_eq!(res.headers()[X_CACHE], "MISS");
+        assert_eq!(body(res).await, "items 0");
+        let res = get(&route, "/items", &[]).await;
+        assert_eq!(res.headers()[X_CACHE], "HIT");
+        assert!(res.headers().contains_key(AGE));
+        assert_eq!(body(res).await, "items 0");
+
+        // 查询字符串与 Vary 请求头区分缓存
+        assert_eq!(
+            body(get(&route, "/items?page=2", &[]).await).await,
+            "items 1"
+        );
+        let zh = [(http::header::ACCEPT_LANGUAGE, "zh")];
+        assert_eq!(body(get(&route, "/items", &zh).await).await, "items 2");
+
+        // 请求端 no-cache 跳过读取
+        let no_cache = [(CACHE_CONTROL, "no-cache")];
+        assert_eq!(
+            body(get(&route, "/items", &no_cache).await).await,
+            "items 3"
+        );
+        assert_eq!(body(get(&route, "/items", &[]).await).await, "items 3");
+
+        cache.invalidate("/items");
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(body(get(&route, "/items", &[]).await).await, "items 4");
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 5);
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_respects_response_cache_control() {
+        let cache = ResponseCache::new(16);
+        let route = route(cache.clone(), Arc::new(AtomicUsize::new(0)));
+        get(&route, "/private", &[]).await;
+        let res = get(&route, "/private", &[]).await;
+        assert_eq!(res.headers()[X_CACHE], "MISS");
+        assert_eq!(cache.stats().entries, 0);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("public, max-age=5"));
+        assert_eq!(cache.response_ttl(&headers), Some(Duration::from_secs(5)));
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("max-age=0"));
+        assert_eq!(cache.response_ttl(&headers), None);
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("s-maxage=600"));
+        assert_eq!(cache.response_ttl(&headers), Some(Duration::from_secs(60)));
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_skips_no_store_response() {
+        let cache = ResponseCache::new(16);
+        let route = counting_route(cache.clone(), "nostore", &[(CACHE_CONTROL, "no-store")]);
+        assert_eq!(body(get(&route, "/nostore", &[]).await).await, "0");
+        let res = get(&route, "/nostore", &[]).await;
+        assert_eq!(res.headers()[X_CACHE], "MISS");
+        assert_eq!(body(res).await, "1");
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_credentialed_requests() {
+        let cache = ResponseCache::new(16);
+        let route = counting_route(cache.clone(), "items", &[]);
+        for credential in [(AUTHORIZATION, "Bearer token"), (COOKIE, "sid=1")] {
+            let headers = [credential];
+            get(&route, "/items", &headers).await;
+            let res = get(&route, "/items", &headers).await;
+            assert_eq!(res.headers()[X_CACHE], "MISS");
+            assert_eq!(cache.stats().entries, 0);
+        }
+
+        // 匿名请求缓存的响应不用于携带凭据的请求
+        assert_eq!(body(get(&route, "/items", &[]).await).await, "4");
+        assert_eq!(get(&route, "/items", &[]).await.headers()[X_CACHE], "HIT");
+        let auth = [(AUTHORIZATION, "Bearer token")];
+        assert_eq!(body(get(&route, "/items", &auth).await).await, "5");
+
+        // 显式 public 的响应可以缓存并命中
+        let cache = ResponseCache::new(16);
+        let route = counting_route(cache.clone(), "public", &[(CACHE_CONTROL, "public")]);
+        assert_eq!(body(get(&route, "/public", &auth).await).await, "0");
+        let res = get(&route, "/public", &auth).await;
+        assert_eq!(res.headers()[X_CACHE], "HIT");
+        assert_eq!(body(res).await, "0");
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_response_vary() {
+        let cache = ResponseCache::new(16);
+        let route = counting_route(cache.clone(), "tenant", &[(VARY, "X-Tenant, Accept")]);
+        let a = [(HeaderName::from_static("x-tenant"), "a")];
+        let b = [(HeaderName::from_static("x-tenant"), "b")];
+        assert_eq!(body(get(&route, "/tenant", &a).await).await, "0");
+        assert_eq!(body(get(&route, "/tenant", &b).await).await, "1");
+        let res = get(&route, "/tenant", &a).await;
+        assert_eq!(res.headers()[X_CACHE], "HIT");
+        assert_eq!(body(res).await, "0");
+        let res = get(&route, "/tenant", &b).await;
+        assert_eq!(res.headers()[X_CACHE], "HIT");
+        assert_eq!(body(res).await, "1");
+
+        // Vary 中的其他请求头同样区分缓存
+        let json = [
+            (HeaderName::from_static("x-tenant"), "a"),
+            (http::header::ACCEPT, "application/json"),
+        ];
+        assert_eq!(body(get(&route, "/tenant", &json).await).await, "2");
+        assert_eq!(cache.stats().entries, 3);
+
+        cache.invalidate("/tenant");
+        assert_eq!(cache.stats().entries, 0);
+        assert!(cache.lock().vary.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_response_cache_separates_hosts_and_mounts() {
+        let cache = ResponseCache::new(16);
+        let vhost = |host: &'static str| {
+            let calls = Arc::new(AtomicUsize::new(0));
+            Route::new("")
+                .host(host)
+                .append(Route::new("items").get(move |_req: Request| {
+                    let calls = calls.clone();
+                    async move { Ok(format!("{host} {}", calls.fetch_add(1, Ordering::SeqCst))) }
+                }))
+        };
+        let route = Route::new_root().append(
+            Route::new("")
+                .hook(cache.clone())
+                .append(vhost("a.example.com"))
+                .append(vhost("b.example.com"))
+                .mount("v1", vhost("c.example.com"))
+                .mount("v2", vhost("c.example.com")),
+        );
+
+        let a = [(http::header::HOST, "a.example.com")];
+        let b = [(http::header::HOST, "B.example.com:8080")];
+        assert_eq!(
+            body(get(&route, "/items", &a).await).await,
+            "a.example.com 0"
+        );
+        assert_eq!(
+            body(get(&route, "/items", &b).await).await,
+            "b.example.com 0"
+        );
+        let res = get(&route, "/items", &b).await;
+        assert_eq!(res.headers()[X_CACHE], "HIT");
+        assert_eq!(body(res).await, "b.example.com 0");
+
+        // 挂载后的子应用看到相同的路径，缓存键使用挂载前的完整路径
+        let c = [(http::header::HOST, "c.example.com")];
+        assert_eq!(
+            body(get(&route, "/v1/items", &c).await).await,
+            "c.example.com 0"
+        );
+        assert_eq!(
+            body(get(&route, "/v2/items", &c).await).await,
+            "c.example.com 0"
+        );
+        assert_eq!(cache.stats().entries, 4);
+
+        cache.invalidate_host("b.example.com.", "/items");
+        assert_eq!(cache.stats().entries, 3);
+        assert_eq!(get(&route, "/items", &a).await.headers()[X_CACHE], "HIT");
+        cache.invalidate("/v1/items");
+        assert_eq!(cache.stats().entries, 2);
+        cache.invalidate("/items");
+        assert_eq!(cache.stats().entries, 1);
+    }
+}
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use http::header::Entry;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result};
+
+/// ResponseHeaders 中间件
+///
+/// 为经过的每个成功响应补充固定的响应头，如 `Cache-Control`、`X-Powered-By` 等。
+/// 处理函数或内层中间件已设置的同名响应头保持不变，因此子路由可以覆盖上层的默认值；
+/// 同一名称配置多次时全部写入。通常通过 `Route::with_headers` 挂载。
+///
+/// ```rust
+/// use http::{HeaderName, HeaderValue};
+/// use silent::middlewares::ResponseHeaders;
+/// use silent::prelude::*;
+///
+/// let route = Route::new("static")
+///     .hook(ResponseHeaders::new().header(
+///         HeaderName::from_static("cache-control"),
+///         HeaderValue::from_static("max-age=3600"),
+///     ))
+///     .get(|_req: Request| async { Ok("ok") });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ResponseHeaders {
+    headers: HeaderMap,
+}
+
+impl ResponseHeaders {
+    /// 创建不含响应头的中间件
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加一个响应头
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    /// 已配置的响应头
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+}
+
+impl From<HeaderMap> for ResponseHeaders {
+    fn from(headers: HeaderMap) -> Self {
+        Self { headers }
+    }
+}
+
+#[async_trait]
+impl MiddleWareHandler for ResponseHeaders {
+    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+        let mut res = next.call(req).await?;
+        let target = res.headers_mut();
+        for name in self.headers.keys() {
+            if let Entry::Vacant(entry) = target.entry(name) {
+                let mut values = self.headers.get_all(name).iter();
+                if let Some(first) = values.next() {
+                    let mut entry = entry.insert_entry(first.clone());
+                    for value in values {
+                        entry.append(value.clone());
+                    }
+                }
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+    use http::header::{CACHE_CONTROL, VARY};
+
+    #[tokio::test]
+    async fn test_response_headers_fill_missing() {
+        let route = Route::new("/")
+            .hook(
+                ResponseHeaders::new()
+                    .header(CACHE_CONTROL, HeaderValue::from_static("no-store"))
+                    .header(VARY, HeaderValue::from_static("accept"))
+                    .header(VARY, HeaderValue::from_static("origin")),
+            )
+            .get(|_req: Request| async { Ok("ok") })
+            .append(Route::new("cached").get(|_req: Request| async {
+                let mut res = Response::text("cached");
+                res.headers_mut()
+                    .insert(CACHE_CONTROL, HeaderValue::from_static("max-age=60"));
+                Ok(res)
+            }));
+        let route = Route::new_root().append(route);
+
+        let res = crate::Handler::call(&route, Request::empty())
+            .await
+            .unwrap();
+        assert_eq!(res.headers()[CACHE_CONTROL], "no-store");
+        let vary: Vec<_> = res.headers().get_all(VARY).iter().collect();
+        assert_eq!(vary, ["accept", "origin"]);
+
+        // 处理函数设置的响应头优先
+        let mut req = Request::empty();
+        *req.uri_mut() = "/cached".parse().unwrap();
+        let res = crate::Handler::call(&route, req).await.unwrap();
+        let cache: Vec<_> = res.headers().get_all(CACHE_CONTROL).iter().collect();
+        assert_eq!(cache, ["max-age=60"]);
+    }
+}
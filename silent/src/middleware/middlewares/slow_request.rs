@@ -0,0 +1,161 @@
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use crate::route::MatchedRoute;
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result};
+
+/// 日志中单个路由参数值的最大字符数
+const MAX_PARAM_CHARS: usize = 64;
+
+/// SlowRequest 中间件
+///
+/// 记录处理耗时超过阈值的请求，以 `WARN` 级别输出方法、路径、命中的路由模板、路由参数摘要、状态码与耗时，
+/// 便于在生产环境中发现延迟退化。启用 `metrics` 特性并调用 [`with_metric`](Self::with_metric) 后，
+/// 还会按 `method`、`route` 标签累加 `slow_requests_total` 计数。
+///
+/// 耗时只统计处理函数返回响应之前的部分，流式响应体的发送不计入。
+///
+/// # 示例
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::middlewares::SlowRequest;
+/// use std::time::Duration;
+///
+/// let route = Route::new_root()
+///     .hook(SlowRequest::new(Duration::from_millis(500)))
+///     .append(Route::new("users/<id:u64>").get(|_req: Request| async { Ok("user") }));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SlowRequest {
+    threshold: Duration,
+    #[cfg(feature = "metrics")]
+    metric: bool,
+}
+
+impl SlowRequest {
+    /// 创建中间件，耗时超过 `threshold` 的请求视为慢请求
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            #[cfg(feature = "metrics")]
+            metric: false,
+        }
+    }
+
+    /// 慢请求同时累加 `slow_requests_total` 指标
+    #[cfg(feature = "metrics")]
+    pub fn with_metric(mut self) -> Self {
+        self.metric = true;
+        self
+    }
+}
+
+/// 按参数名排序的 `key=value` 摘要，过长的值被截断
+fn params_summary(matched: &MatchedRoute) -> String {
+    let Some(params) = matched.params() else {
+        return String::new();
+    };
+    let mut params: Vec<_> = params.iter().collect();
+    params.sort();
+    params
+        .into_iter()
+        .map(
+            |(key, value)| match value.char_indices().nth(MAX_PARAM_CHARS) {
+                Some((end, _)) => format!("{key}={}...", &value[..end]),
+                None => format!("{key}={value}"),
+            },
+        )
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[async_trait]
+impl MiddleWareHandler for SlowRequest {
+    async fn handle(&self, mut req: Request, next: &Next) -> Result<Response> {
+        let method = req.method().clone();
+        let path = req.uri().path().to_string();
+        let matched = MatchedRoute::with_params();
+        req.extensions_mut().insert(matched.clone());
+
+        let start = Instant::now();
+        let res = next.call(req).await;
+        let elapsed = start.elapsed();
+        if elapsed < self.threshold {
+            return res;
+        }
+
+        let route = matched.pattern().unwrap_or("-");
+        let status = match &res {
+            Ok(res) => res.status(),
+            Err(err) => err.status(),
+        };
+        tracing::warn!(
+            %method,
+            %path,
+            route,
+            params = %params_summary(&matched),
+            status = status.as_u16(),
+            elapsed_ms = elapsed.as_secs_f64() * 1000.0,
+            threshold_ms = self.threshold.as_secs_f64() * 1000.0,
+            "slow request"
+        );
+        #[cfg(feature = "metrics")]
+        if self.metric {
+            metrics::counter!(
+                "slow_requests_total",
+                "method" => method.to_string(),
+                "route" => route.to_string()
+            )
+            .increment(1);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+
+    #[tokio::test]
+    async fn test_params_summary() {
+        let matched = MatchedRoute::with_params();
+        let route = Route::new_root()
+            .append(Route::new("users/<id:u64>/<name>").get(|_req: Request| async { Ok("ok") }));
+        assert_eq!(params_summary(&matched), "");
+
+        let long = "x".repeat(80);
+        let mut req = Request::empty();
+        *req.uri_mut() = format!("/users/7/{long}").parse().unwrap();
+        req.extensions_mut().insert(matched.clone());
+        crate::Handler::call(&route, req).await.unwrap();
+        assert_eq!(matched.pattern(), Some("/users/<id:u64>/<name>"));
+        assert_eq!(
+            params_summary(&matched),
+            format!("id=7, name={}...", "x".repeat(MAX_PARAM_CHARS))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_slow_request_passthrough() {
+        let route = Route::new_root()
+            .hook(SlowRequest::new(Duration::from_millis(10)))
+            .append(Route::new("fast").get(|_req: Request| async { Ok("fast") }))
+            .append(Route::new("slow").get(|_req: Request| async {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                Ok("slow")
+            }));
+
+        for path in ["/fast", "/slow"] {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            let res = crate::Handler::call(&route, req).await.unwrap();
+            assert_eq!(res.status(), http::StatusCode::OK);
+        }
+        let mut req = Request::empty();
+        *req.uri_mut() = "/missing".parse().unwrap();
+        assert!(crate::Handler::call(&route, req).await.is_err());
+    }
+}
@@ -2,42 +2,101 @@ use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, SilentE
 use async_trait::async_trait;
 use http::StatusCode;
 use std::time::Duration;
+#[cfg(feature = "server")]
+use std::{
+    sync::{Arc, Mutex},
+    time::Instant,
+};
 
 #[cfg(feature = "server")]
 /// Timeout 中间件 - 在server模式下提供请求超时控制
+///
+/// 后续中间件与处理函数未在期限内完成时返回 `504 Gateway Timeout`，响应体可通过 [`Timeout::body`] 设置。
+/// 命中的路由通过 `Route::with_timeout` 设置了超时时间时，以路由的设置为准，即使比中间件的期限更长。
+///
 /// ```rust
 /// use silent::prelude::*;
 /// use silent::middlewares::Timeout;
 /// use std::time::Duration;
 /// // Define a timeout middleware
 /// let _ = Timeout::new(Duration::from_secs(30));
+///
+/// let route = Route::new("api")
+///     .hook(Timeout::new(Duration::from_secs(5)).body("upstream timed out"))
+///     .append(
+///         Route::new("export")
+///             .with_timeout(Duration::from_secs(60))
+///             .get(|_req: Request| async { Ok("done") }),
+///     );
+/// ```
 #[derive(Default, Clone)]
 pub struct Timeout {
     timeout: Duration,
+    body: Option<String>,
 }
 
 #[cfg(feature = "server")]
 impl Timeout {
     pub fn new(timeout: Duration) -> Self {
-        Self { timeout }
+        Self {
+            timeout,
+            body: None,
+        }
+    }
+
+    /// 设置超时响应的响应体，默认为 `Request timed out`
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+/// [`Timeout`] 中间件的截止时间，命中设置了超时时间的路由时由路由树更新；`None` 表示不限时
+#[cfg(feature = "server")]
+#[derive(Clone)]
+pub(crate) struct TimeoutDeadline(Arc<Mutex<Option<Instant>>>);
+
+#[cfg(feature = "server")]
+impl TimeoutDeadline {
+    fn new(timeout: Duration) -> Self {
+        Self(Arc::new(Mutex::new(Instant::now().checked_add(timeout))))
+    }
+
+    fn get(&self) -> Option<Instant> {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 以路由的超时时间覆盖中间件的截止时间
+    pub(crate) fn reset(&self, timeout: Duration) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now().checked_add(timeout);
     }
 }
 
 #[cfg(feature = "server")]
 #[async_trait]
 impl MiddleWareHandler for Timeout {
-    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
-        match tokio::time::timeout(self.timeout, next.call(req))
-            .await
-            .map_err(|_| {
-                SilentError::business_error(
-                    StatusCode::REQUEST_TIMEOUT,
-                    "Request timed out".to_string(),
-                )
-            }) {
-            Ok(res) => res,
-            Err(err) => Err(err),
+    async fn handle(&self, mut req: Request, next: &Next) -> Result<Response> {
+        let deadline = TimeoutDeadline::new(self.timeout);
+        req.extensions_mut().insert(deadline.clone());
+
+        let fut = next.call(req);
+        tokio::pin!(fut);
+        while let Some(until) = deadline.get() {
+            tokio::select! {
+                res = &mut fut => return res,
+                _ = tokio::time::sleep_until(until.into()) => {
+                    // 截止时间被路由延长时继续等待
+                    if deadline.get().is_none_or(|current| current > until) {
+                        continue;
+                    }
+                    return Err(SilentError::business_error(
+                        StatusCode::GATEWAY_TIMEOUT,
+                        self.body.clone().unwrap_or_else(|| "Request timed out".to_string()),
+                    ));
+                }
+            }
         }
+        fut.await
     }
 }
 
@@ -199,7 +258,7 @@ mod tests {
         let result: Result<Response> = route.call(req).await;
         assert!(result.is_err());
         if let Err(e) = result {
-            assert_eq!(e.status(), StatusCode::REQUEST_TIMEOUT);
+            assert_eq!(e.status(), StatusCode::GATEWAY_TIMEOUT);
         }
     }
 
@@ -263,4 +322,36 @@ mod tests {
         // 非server模式下，is_available 应该返回 false
         assert!(!timeout.is_available());
     }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_timeout_body_and_route_override() {
+        use crate::route::Route;
+
+        async fn slow(_req: Request) -> Result<&'static str> {
+            tokio::time::sleep(Duration::from_millis(150)).await;
+            Ok("slow")
+        }
+
+        let route = Route::new("api")
+            .hook(Timeout::new(Duration::from_millis(50)).body("upstream timed out"))
+            .append(Route::new("slow").get(slow))
+            .append(
+                Route::new("export")
+                    .with_timeout(Duration::from_secs(5))
+                    .get(slow),
+            );
+        let route = Route::new_root().append(route);
+
+        let call = |path: &'static str| {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            crate::Handler::call(&route, req)
+        };
+        let err = call("/api/slow").await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(err.message(), "upstream timed out");
+        // 路由的超时时间覆盖中间件的期限
+        assert!(call("/api/export").await.is_ok());
+    }
 }
@@ -0,0 +1,260 @@
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use http::{HeaderName, HeaderValue, Method};
+
+use super::path_glob::PathGlob;
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result};
+
+type MatchFn = Arc<dyn Fn(&Request) -> bool + Send + Sync>;
+
+/// 请求条件，用于 [`when`] 与 [`unless`]
+///
+/// 可由方法、路径模式、请求头构造，并通过 [`and`](Self::and)、[`or`](Self::or)、[`not`](Self::not) 组合；
+/// 任意 `Fn(&Request) -> bool` 闭包也可以直接转换为条件。
+///
+/// ```rust
+/// use silent::middlewares::RequestMatcher;
+/// use silent::prelude::*;
+///
+/// // 非 GET 的 /api/** 请求，或携带 X-Debug 头的请求
+/// let matcher = RequestMatcher::path("/api/**")
+///     .and(RequestMatcher::method(Method::GET).not())
+///     .or(RequestMatcher::header("x-debug"));
+/// ```
+#[derive(Clone)]
+pub struct RequestMatcher(MatchFn);
+
+impl RequestMatcher {
+    /// 由闭包构造
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(&Request) -> bool + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// 请求方法相同
+    pub fn method(method: Method) -> Self {
+        Self::from_fn(move |req| *req.method() == method)
+    }
+
+    /// 请求路径匹配模式：`**` 匹配任意多段，段内 `*` 匹配任意字符，如 `/api/**`、`/assets/*.js`
+    pub fn path(pattern: &str) -> Self {
+        let glob = PathGlob::new(pattern);
+        Self::from_fn(move |req| glob.matches(req.uri().path()))
+    }
+
+    /// 携带指定请求头
+    ///
+    /// # Panics
+    ///
+    /// 请求头名称不合法时 panic。
+    pub fn header(name: &str) -> Self {
+        let name = HeaderName::try_from(name)
+            .unwrap_or_else(|_| panic!("RequestMatcher: invalid header name \"{name}\""));
+        Self::from_fn(move |req| req.headers().contains_key(&name))
+    }
+
+    /// 携带指定请求头，且任一值与 `value` 相同
+    ///
+    /// # Panics
+    ///
+    /// 请求头名称或值不合法时 panic。
+    pub fn header_value(name: &str, value: &str) -> Self {
+        let name = HeaderName::try_from(name)
+            .unwrap_or_else(|_| panic!("RequestMatcher: invalid header name \"{name}\""));
+        let value = HeaderValue::try_from(value)
+            .unwrap_or_else(|_| panic!("RequestMatcher: invalid header value \"{value}\""));
+        Self::from_fn(move |req| req.headers().get_all(&name).iter().any(|v| *v == value))
+    }
+
+    /// 两个条件同时满足
+    pub fn and(self, other: impl Into<RequestMatcher>) -> Self {
+        let other = other.into();
+        Self::from_fn(move |req| self.matches(req) && other.matches(req))
+    }
+
+    /// 任一条件满足
+    pub fn or(self, other: impl Into<RequestMatcher>) -> Self {
+        let other = other.into();
+        Self::from_fn(move |req| self.matches(req) || other.matches(req))
+    }
+
+    /// 条件取反
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Self::from_fn(move |req| !self.matches(req))
+    }
+
+    /// 请求是否满足条件
+    pub fn matches(&self, req: &Request) -> bool {
+        (self.0)(req)
+    }
+}
+
+impl<F> From<F> for RequestMatcher
+where
+    F: Fn(&Request) -> bool + Send + Sync + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::from_fn(f)
+    }
+}
+
+impl fmt::Debug for RequestMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RequestMatcher")
+    }
+}
+
+/// 条件中间件，由 [`when`] 与 [`unless`] 创建
+#[derive(Clone)]
+pub struct When<M> {
+    matcher: RequestMatcher,
+    middleware: M,
+}
+
+/// 仅当请求满足条件时执行 `middleware`，否则直接调用后续处理
+///
+/// 适合在同一棵路由树中为部分请求豁免鉴权、压缩等中间件，而无需拆分路由。
+///
+/// ```rust
+/// use silent::middlewares::{RequestId, RequestMatcher, when};
+/// use silent::prelude::*;
+///
+/// let route = Route::new_root()
+///     // 只为 /api/** 生成请求 ID
+///     .hook(when(RequestMatcher::path("/api/**"), RequestId::new()))
+///     // 闭包形式的条件
+///     .hook(when(|req: &Request| req.headers().contains_key("x-trace"), RequestId::new()))
+///     .append(Route::new("api/users").get(|_req: Request| async { Ok("users") }));
+/// ```
+pub fn when<M>(matcher: impl Into<RequestMatcher>, middleware: M) -> When<M>
+where
+    M: MiddleWareHandler,
+{
+    When {
+        matcher: matcher.into(),
+        middleware,
+    }
+}
+
+/// 仅当请求不满足条件时执行 `middleware`，等价于 `when(matcher.not(), middleware)`
+///
+/// ```rust
+/// use silent::middlewares::{RequestMatcher, unless};
+/// use silent::prelude::*;
+///
+/// # #[derive(Clone)]
+/// # struct Auth;
+/// # #[async_trait::async_trait]
+/// # impl MiddleWareHandler for Auth {
+/// #     async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+/// #         next.call(req).await
+/// #     }
+/// # }
+/// // 登录与健康检查接口无需鉴权
+/// let route = Route::new_root()
+///     .hook(unless(
+///         RequestMatcher::path("/login").or(RequestMatcher::path("/healthz")),
+///         Auth,
+///     ))
+///     .append(Route::new("login").post(|_req: Request| async { Ok("token") }));
+/// ```
+pub fn unless<M>(matcher: impl Into<RequestMatcher>, middleware: M) -> When<M>
+where
+    M: MiddleWareHandler,
+{
+    when(matcher.into().not(), middleware)
+}
+
+#[async_trait]
+impl<M> MiddleWareHandler for When<M>
+where
+    M: MiddleWareHandler,
+{
+    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+        if self.matcher.matches(&req) {
+            self.middleware.handle(req, next).await
+        } else {
+            next.call(req).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+
+    /// 为响应添加 `x-hit` 头
+    #[derive(Clone)]
+    struct Mark;
+
+    #[async_trait]
+    impl MiddleWareHandler for Mark {
+        async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+            let mut res = next.call(req).await?;
+            res.headers_mut()
+                .insert("x-hit", HeaderValue::from_static("1"));
+            Ok(res)
+        }
+    }
+
+    fn request(method: Method, path: &str, headers: &[(&'static str, &'static str)]) -> Request {
+        let mut req = Request::empty();
+        *req.method_mut() = method;
+        *req.uri_mut() = path.parse().unwrap();
+        for (name, value) in headers {
+            req.headers_mut()
+                .append(*name, HeaderValue::from_static(value));
+        }
+        req
+    }
+
+    #[test]
+    fn test_request_matcher() {
+        let matcher = RequestMatcher::path("/api/**")
+            .and(RequestMatcher::method(Method::GET).not())
+            .or(RequestMatcher::header_value("x-debug", "1"));
+        assert!(matcher.matches(&request(Method::POST, "/api/users", &[])));
+        assert!(!matcher.matches(&request(Method::GET, "/api/users", &[])));
+        assert!(!matcher.matches(&request(Method::POST, "/web", &[])));
+        assert!(matcher.matches(&request(Method::GET, "/web", &[("x-debug", "1")])));
+        assert!(!matcher.matches(&request(Method::GET, "/web", &[("x-debug", "0")])));
+        assert!(RequestMatcher::header("x-debug").matches(&request(
+            Method::GET,
+            "/",
+            &[("x-debug", "0")]
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_when_and_unless() {
+        let route = Route::new_root()
+            .hook(when(RequestMatcher::path("/api/**"), Mark))
+            .append(Route::new("api/users").get(|_req: Request| async { Ok("users") }))
+            .append(Route::new("web").get(|_req: Request| async { Ok("web") }));
+        let res = route
+            .call(request(Method::GET, "/api/users", &[]))
+            .await
+            .unwrap();
+        assert_eq!(res.headers()["x-hit"], "1");
+        let res = route.call(request(Method::GET, "/web", &[])).await.unwrap();
+        assert!(!res.headers().contains_key("x-hit"));
+
+        let route = Route::new_root()
+            .hook(unless(|req: &Request| req.uri().path() == "/web", Mark))
+            .append(Route::new("api/users").get(|_req: Request| async { Ok("users") }))
+            .append(Route::new("web").get(|_req: Request| async { Ok("web") }));
+        let res = route
+            .call(request(Method::GET, "/api/users", &[]))
+            .await
+            .unwrap();
+        assert_eq!(res.headers()["x-hit"], "1");
+        let res = route.call(request(Method::GET, "/web", &[])).await.unwrap();
+        assert!(!res.headers().contains_key("x-hit"));
+    }
+}
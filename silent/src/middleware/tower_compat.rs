@@ -1,28 +1,33 @@
-//! Tower Layer → Silent MiddleWareHandler 适配器
+//! Tower Layer / Service → Silent MiddleWareHandler 适配器
 //!
-//! 通过 `Route::hook_layer()` 方法，将任意 `tower::Layer` 隐式适配为 Silent 中间件。
+//! 通过 [`TowerLayerCompat`] 或 `Route::hook_layer()` 方法，将任意 `tower::Layer` 适配为 Silent 中间件，
+//! 在路由树中复用 tower-http 等生态中的中间件（trace、cors、limit 等）。
 //!
 //! # 示例
 //!
 //! ```rust,ignore
-//! use tower::layer::layer_fn;
+//! use silent::middlewares::TowerLayerCompat;
+//! use tower_http::trace::TraceLayer;
 //!
 //! let route = Route::new("api")
-//!     .hook_layer(some_tower_layer)
+//!     .hook(TowerLayerCompat::new(TraceLayer::new_for_http()))
 //!     .get(handler);
 //! ```
 
+use std::any::Any;
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures_util::TryStreamExt;
 use http::Response as HttpResponse;
 use http_body::Body;
 use tower::{Service, ServiceExt};
 
 use crate::core::next::Next;
+use crate::core::remote_addr::RemoteAddr;
 use crate::core::req_body::ReqBody;
 use crate::core::res_body::ResBody;
 use crate::error::BoxedError;
@@ -34,26 +39,69 @@ use crate::{Handler, MiddleWareHandler, Request, Response, SilentError, StatusCo
 struct SilentExtras {
     state: crate::State,
     path_params: std::collections::HashMap<String, crate::core::path_param::PathParam>,
+    path_source: Option<std::sync::Arc<str>>,
+    peer_addr: Option<RemoteAddr>,
 }
 
-/// 将 Tower Layer 适配为 Silent MiddleWareHandler。
+/// TowerLayerCompat 中间件适配器
 ///
-/// 用户不需要直接使用此类型，通过 `Route::hook_layer()` 自动创建。
-#[doc(hidden)]
-pub struct TowerLayerAdapter<L> {
+/// 将任意 `tower::Layer` 适配为 [`MiddleWareHandler`]：Layer 包装的内层 Service 即 Silent 中间件链的后续部分，
+/// 因此 Layer 可以改写请求与响应、提前返回，或替换请求体类型（如 tower-http 的 `RequestBodyLimitLayer`）。
+/// 通过 [`from_service`](Self::from_service) 也可以直接挂载一个 `tower::Service`，由它处理请求而不再调用后续中间件与处理函数。
+///
+/// 请求经过 Tower 中间件时，状态、路由参数与对端地址会被保留；内层返回的 [`SilentError`] 原样传回，
+/// 不会被改写为 `500`。
+///
+/// # 示例
+///
+/// ```rust,ignore
+/// use silent::middlewares::TowerLayerCompat;
+/// use tower_http::cors::CorsLayer;
+///
+/// let route = Route::new("api")
+///     .hook(TowerLayerCompat::new(CorsLayer::permissive()))
+///     .get(handler);
+/// ```
+#[derive(Clone)]
+pub struct TowerLayerCompat<L> {
     layer: L,
 }
 
-impl<L> TowerLayerAdapter<L> {
-    #[doc(hidden)]
+/// `TowerLayerCompat` 的旧名称
+#[doc(hidden)]
+pub type TowerLayerAdapter<L> = TowerLayerCompat<L>;
+
+impl<L> TowerLayerCompat<L> {
+    /// 适配 `tower::Layer`
     pub fn new(layer: L) -> Self {
         Self { layer }
     }
 }
 
+impl<S> TowerLayerCompat<ServiceLayer<S>> {
+    /// 适配 `tower::Service`，由该 Service 处理请求
+    pub fn from_service(service: S) -> Self {
+        Self::new(ServiceLayer(service))
+    }
+}
+
+/// 忽略内层 Service、始终返回给定 Service 的 Layer，用于 [`TowerLayerCompat::from_service`]
+#[derive(Clone)]
+#[doc(hidden)]
+pub struct ServiceLayer<S>(S);
+
+impl<S: Clone, Inner> tower::Layer<Inner> for ServiceLayer<S> {
+    type Service = S;
+
+    fn layer(&self, _inner: Inner) -> S {
+        self.0.clone()
+    }
+}
+
 /// 将 Silent 的 Next 包装为 tower::Service，
 /// 供 Tower 中间件作为内层 Service 调用。
 ///
+/// 接受任意 `Data = Bytes` 的请求体，以便兼容替换了请求体类型的 Layer。
 /// 用户不需要直接使用此类型。
 #[derive(Clone)]
 #[doc(hidden)]
@@ -61,7 +109,11 @@ pub struct NextServicePublic {
     pub(crate) next: Next,
 }
 
-impl Service<http::Request<ReqBody>> for NextServicePublic {
+impl<B> Service<http::Request<B>> for NextServicePublic
+where
+    B: Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxedError>,
+{
     type Response = HttpResponse<ResBody>;
     type Error = BoxedError;
     type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
@@ -70,8 +122,10 @@ impl Service<http::Request<ReqBody>> for NextServicePublic {
         Poll::Ready(Ok(()))
     }
 
-    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
         let next = self.next.clone();
+        let (parts, body) = req.into_parts();
+        let req = http::Request::from_parts(parts, into_req_body(body));
         Box::pin(async move {
             // 从 http::Request 恢复 Silent Request
             let silent_req = from_http_request(req);
@@ -89,7 +143,7 @@ impl Service<http::Request<ReqBody>> for NextServicePublic {
 }
 
 #[async_trait]
-impl<L> MiddleWareHandler for TowerLayerAdapter<L>
+impl<L> MiddleWareHandler for TowerLayerCompat<L>
 where
     L: tower::Layer<NextServicePublic> + Clone + Send + Sync + 'static,
     L::Service: Service<http::Request<ReqBody>> + Clone + Send + 'static,
@@ -106,8 +160,14 @@ where
 
         // 通过 oneshot 调用 Tower Service（自动处理 poll_ready）
         let tower_res = svc.oneshot(http_req).await.map_err(|e| {
+            // 内层中间件链返回的 SilentError 原样传回
             let err: BoxedError = e.into();
-            SilentError::business_error(StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+            match err.downcast::<SilentError>() {
+                Ok(err) => *err,
+                Err(err) => {
+                    SilentError::business_error(StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+                }
+            }
         })?;
 
         // 将结果转回 Silent Response
@@ -119,15 +179,17 @@ where
 
 /// 将 Silent Request 转为 http::Request，将 Silent 特有数据存入 Extensions
 fn into_http_request(req: Request) -> http::Request<ReqBody> {
-    let state = req.state();
-    let path_params = req.path_params().clone();
+    let extras = SilentExtras {
+        state: req.state(),
+        path_params: req.path_params().clone(),
+        path_source: req.path_source().cloned(),
+        peer_addr: req.peer_addr().cloned(),
+    };
 
     let mut http_req = req.into_http();
 
     // 将 Silent 特有数据存入 Extensions
-    http_req
-        .extensions_mut()
-        .insert(SilentExtras { state, path_params });
+    http_req.extensions_mut().insert(extras);
 
     http_req
 }
@@ -144,11 +206,35 @@ fn from_http_request(mut req: http::Request<ReqBody>) -> Request {
         for (key, value) in extras.path_params {
             silent_req.set_path_params(key, value);
         }
+        if let Some(source) = extras.path_source {
+            silent_req.set_path_source(source);
+        }
+        if let Some(peer_addr) = extras.peer_addr {
+            silent_req.set_remote(peer_addr);
+        }
     }
 
     silent_req
 }
 
+/// 将任意请求体转为 ReqBody，已是 ReqBody 时不做包装
+fn into_req_body<B>(body: B) -> ReqBody
+where
+    B: Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxedError>,
+{
+    let body: Box<dyn Any> = Box::new(body);
+    match body.downcast::<ReqBody>() {
+        Ok(body) => *body,
+        Err(body) => {
+            let body = *body.downcast::<B>().expect("body type checked above");
+            let stream = http_body_util::BodyDataStream::new(body)
+                .map_err(|e| std::io::Error::other(e.into()));
+            ReqBody::Streaming(Box::pin(stream))
+        }
+    }
+}
+
 /// 将 Silent Response 转为 http::Response<ResBody>
 fn into_http_response(mut res: Response) -> HttpResponse<ResBody> {
     let body = res.take_body();
@@ -320,4 +406,112 @@ mod tests {
             "2"
         );
     }
+
+    /// 将请求体替换为 `Limited<ReqBody>` 的 Layer，模拟 tower-http 的 `RequestBodyLimitLayer`
+    #[derive(Clone)]
+    struct LimitLayer(usize);
+
+    impl<S> tower::Layer<S> for LimitLayer {
+        type Service = LimitService<S>;
+        fn layer(&self, inner: S) -> Self::Service {
+            LimitService(inner, self.0)
+        }
+    }
+
+    #[derive(Clone)]
+    struct LimitService<S>(S, usize);
+
+    impl<S> Service<http::Request<ReqBody>> for LimitService<S>
+    where
+        S: Service<http::Request<http_body_util::Limited<ReqBody>>>,
+    {
+        type Response = S::Response;
+        type Error = S::Error;
+        type Future = S::Future;
+
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            self.0.poll_ready(cx)
+        }
+
+        fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+            let limit = self.1;
+            self.0
+                .call(req.map(|body| http_body_util::Limited::new(body, limit)))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tower_layer_compat_replaces_body() {
+        let route = Route::new("")
+            .hook(TowerLayerCompat::new(LimitLayer(4)))
+            .post(|mut req: Request| async move {
+                let body = req.take_body();
+                let bytes = http_body_util::BodyExt::collect(body)
+                    .await
+                    .map_err(|e| {
+                        SilentError::business_error(StatusCode::PAYLOAD_TOO_LARGE, e.to_string())
+                    })?
+                    .to_bytes();
+                Ok(String::from_utf8_lossy(&bytes).to_string())
+            });
+        let route = Route::new_root().append(route);
+
+        for (body, expected) in [
+            ("abc", StatusCode::OK),
+            ("abcdef", StatusCode::PAYLOAD_TOO_LARGE),
+        ] {
+            let mut req = Request::empty();
+            *req.method_mut() = http::Method::POST;
+            req.replace_body(ReqBody::Once(Bytes::from_static(body.as_bytes())));
+            let status = match route.call(req).await {
+                Ok(res) => res.status(),
+                Err(err) => err.status(),
+            };
+            assert_eq!(status, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tower_layer_compat_preserves_error_and_peer() {
+        let route = Route::new("")
+            .hook(TowerLayerCompat::new(AddHeaderLayer {
+                name: "x-tower",
+                value: "1",
+            }))
+            .get(|req: Request| async move {
+                match req.peer_addr() {
+                    Some(addr) => Err(SilentError::business_error(
+                        StatusCode::FORBIDDEN,
+                        addr.to_string(),
+                    )),
+                    None => Ok("no peer"),
+                }
+            });
+        let route = Route::new_root().append(route);
+
+        let mut req = Request::empty();
+        req.set_remote("10.0.0.1:8080".parse().unwrap());
+        let err = route.call(req).await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::FORBIDDEN);
+        assert_eq!(err.message(), "10.0.0.1:8080");
+    }
+
+    #[tokio::test]
+    async fn test_tower_service_compat() {
+        let service = tower::service_fn(|req: http::Request<ReqBody>| async move {
+            let body = format!("tower {}", req.uri().path());
+            Ok::<_, std::convert::Infallible>(HttpResponse::new(ResBody::from(body)))
+        });
+        let route = Route::new("")
+            .hook(TowerLayerCompat::from_service(service))
+            .get(|_req: Request| async { Ok("silent") });
+        let route = Route::new_root().append(route);
+
+        let res = route.call(Request::empty()).await.unwrap();
+        let body = http_body_util::BodyExt::collect(res.body)
+            .await
+            .unwrap()
+            .to_bytes();
+        assert_eq!(&body[..], b"tower /");
+    }
 }
@@ -1,5 +1,6 @@
 #[allow(deprecated)]
 pub use crate::configs::Configs;
+pub use crate::configs::JsonConfig;
 pub use crate::configs::State;
 #[cfg(feature = "cookie")]
 pub use crate::cookie::cookie_ext::CookieExt;
@@ -19,10 +20,14 @@ pub use crate::handler::HandlerWrapper;
 pub use crate::handler::{StaticOptions, static_handler, static_handler_with_options};
 pub use crate::log::*;
 pub use crate::middleware::MiddleWareHandler;
+pub use crate::response::{Html, Redirect};
 pub use crate::route::handler_append::{HandlerAppend, HandlerGetter, IntoRouteHandler};
 #[cfg(all(feature = "worker", target_arch = "wasm32"))]
 pub use crate::route::worker::WorkRoute;
-pub use crate::route::{Route, RouteService, RouterAdapt};
+pub use crate::route::{
+    Dispatcher, Route, RouteInfo, RouteService, RouteTree, RouterAdapt, Scope, TrailingSlash,
+    VersionSource, Versioned,
+};
 #[cfg(feature = "scheduler")]
 pub use crate::scheduler::{SCHEDULER, SchedulerExt, Task};
 #[cfg(feature = "security")]
@@ -0,0 +1,228 @@
+#[cfg(feature = "server")]
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use headers::ContentType;
+use http::{HeaderValue, StatusCode, header};
+use mime::Mime;
+
+use crate::core::res_body::full;
+use crate::{Response, SilentError};
+
+enum Source {
+    Bytes(Bytes),
+    #[cfg(feature = "server")]
+    Path(PathBuf),
+}
+
+/// 文件下载响应，设置 `Content-Disposition: attachment`
+///
+/// - `Content-Type` 未显式指定时按文件名（或文件路径）的扩展名推断，无法推断时为 `application/octet-stream`
+/// - `Content-Length` 取内存数据长度或文件大小
+/// - 文件内容以流式读取，不会一次性载入内存；文件不存在时返回 `404`
+///
+/// ```rust
+/// use silent::response::Attachment;
+/// use silent::{Request, Result};
+///
+/// async fn export(_req: Request) -> Result<Attachment> {
+///     let csv = "id,name\n1,silent\n";
+///     Ok(Attachment::new(csv).filename("report.csv"))
+/// }
+///
+/// let route = silent::prelude::Route::new("export").get(export);
+/// ```
+pub struct Attachment {
+    source: Source,
+    filename: Option<String>,
+    content_type: Option<Mime>,
+}
+
+impl Attachment {
+    /// 以内存中的数据作为下载内容，适合动态生成的文件
+    pub fn new(body: impl Into<Bytes>) -> Self {
+        Self {
+            source: Source::Bytes(body.into()),
+            filename: None,
+            content_type: None,
+        }
+    }
+
+    /// 以磁盘文件作为下载内容，默认使用路径中的文件名
+    #[cfg(feature = "server")]
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        Self {
+            source: Source::Path(path.into()),
+            filename: None,
+            content_type: None,
+        }
+    }
+
+    /// 设置下载时保存的文件名，支持非 ASCII 字符（RFC 6266 `filename*`）
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// 显式设置 `Content-Type`
+    pub fn content_type(mut self, content_type: Mime) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    fn resolved_filename(&self) -> Option<String> {
+        match (&self.filename, &self.source) {
+            (Some(name), _) => Some(name.clone()),
+            #[cfg(feature = "server")]
+            (None, Source::Path(path)) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+            _ => None,
+        }
+    }
+}
+
+/// 生成 `Content-Disposition` 头：ASCII 回退文件名 + UTF-8 编码的 `filename*`
+fn content_disposition(filename: Option<&str>) -> HeaderValue {
+    let Some(filename) = filename else {
+        return HeaderValue::from_static("attachment");
+    };
+    let fallback: String = filename
+        .chars()
+        .map(|c| match c {
+            '"' | '\\' => '_',
+            c if c.is_ascii() && !c.is_ascii_control() => c,
+            _ => '_',
+        })
+        .collect();
+    let mut value = format!("attachment; filename=\"{fallback}\"");
+    if fallback != filename {
+        value.push_str("; filename*=UTF-8''");
+        for byte in filename.bytes() {
+            // RFC 5987 attr-char 原样保留，其余字节百分号编码
+            if byte.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&byte) {
+                value.push(byte as char);
+            } else {
+                value.push_str(&format!("%{byte:02X}"));
+            }
+        }
+    }
+    HeaderValue::try_from(value).unwrap_or_else(|_| HeaderValue::from_static("attachment"))
+}
+
+#[cfg(feature = "server")]
+fn file_body(path: PathBuf) -> crate::core::res_body::ResBody {
+    use futures::stream::{self, TryStreamExt};
+    use tokio::io::AsyncReadExt;
+
+    const CHUNK_SIZE: usize = 16 * 1024;
+    // 首次读取时再打开文件，响应未被发送时不会占用文件句柄
+    let stream = stream::once(tokio::fs::File::open(path))
+        .map_ok(|file| {
+            stream::try_unfold(
+                (file, vec![0u8; CHUNK_SIZE]),
+                |(mut file, mut buf)| async move {
+                    let n = file.read(&mut buf).await?;
+                    if n == 0 {
+                        Ok::<_, std::io::Error>(None)
+                    } else {
+                        Ok(Some((Bytes::copy_from_slice(&buf[..n]), (file, buf))))
+                    }
+                },
+            )
+        })
+        .try_flatten();
+    crate::core::res_body::stream_body(stream)
+}
+
+impl From<Attachment> for Response {
+    fn from(attachment: Attachment) -> Self {
+        let filename = attachment.resolved_filename();
+        let content_type = attachment.content_type.clone().unwrap_or_else(|| {
+            filename
+                .as_deref()
+                .and_then(|name| mime_guess::from_path(name).first())
+                .unwrap_or(mime::APPLICATION_OCTET_STREAM)
+        });
+        let (body, len) = match attachment.source {
+            Source::Bytes(bytes) => {
+                let len = bytes.len() as u64;
+                (full(bytes), len)
+            }
+            #[cfg(feature = "server")]
+            Source::Path(path) => match std::fs::metadata(&path) {
+                Ok(meta) if meta.is_file() => (file_body(path), meta.len()),
+                _ => {
+                    return SilentError::business_error(StatusCode::NOT_FOUND, "file not found")
+                        .into();
+                }
+            },
+        };
+        Response::empty()
+            .with_typed_header(ContentType::from(content_type))
+            .with_header(
+                header::CONTENT_DISPOSITION,
+                content_disposition(filename.as_deref()),
+            )
+            .with_header(header::CONTENT_LENGTH, HeaderValue::from(len))
+            .with_body(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::header::{CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE};
+    use http_body_util::BodyExt;
+
+    #[test]
+    fn test_attachment_from_bytes() {
+        let res: Response = Attachment::new("id\n1\n").filename("report.csv").into();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "text/csv");
+        assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "5");
+        assert_eq!(
+            res.headers().get(CONTENT_DISPOSITION).unwrap(),
+            "attachment; filename=\"report.csv\""
+        );
+
+        let res: Response = Attachment::new(vec![0u8; 3]).into();
+        assert_eq!(
+            res.headers().get(CONTENT_TYPE).unwrap(),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            res.headers().get(CONTENT_DISPOSITION).unwrap(),
+            "attachment"
+        );
+    }
+
+    #[test]
+    fn test_content_disposition_encoding() {
+        assert_eq!(
+            content_disposition(Some("报表 \"1\".csv")),
+            "attachment; filename=\"__ _1_.csv\"; filename*=UTF-8''%E6%8A%A5%E8%A1%A8%20%221%22.csv"
+        );
+    }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_attachment_from_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.json");
+        std::fs::write(&path, br#"{"ok":true}"#).unwrap();
+
+        let res: Response = Attachment::from_path(&path).into();
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+        assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "11");
+        assert_eq!(
+            res.headers().get(CONTENT_DISPOSITION).unwrap(),
+            "attachment; filename=\"data.json\""
+        );
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], br#"{"ok":true}"#);
+
+        let res: Response = Attachment::from_path(dir.path().join("missing.txt")).into();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+}
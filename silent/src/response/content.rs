@@ -0,0 +1,68 @@
+use bytes::Bytes;
+use headers::ContentType;
+
+use crate::Response;
+use crate::core::res_body::full;
+
+/// HTML 响应，`Content-Type` 为 `text/html; charset=utf-8`
+///
+/// 直接返回 `String` 会被序列化为 JSON，需要输出 HTML 时用 `Html` 包装即可。
+///
+/// ```rust
+/// use silent::response::Html;
+/// use silent::{Request, Result};
+///
+/// async fn index(_req: Request) -> Result<Html<String>> {
+///     Ok(Html(format!("<h1>{}</h1>", "hello")))
+/// }
+///
+/// let route = silent::prelude::Route::new("").get(index);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Html<T>(pub T);
+
+impl<T: Into<Bytes>> From<Html<T>> for Response {
+    fn from(Html(body): Html<T>) -> Self {
+        Response::empty()
+            .with_typed_header(ContentType::from(mime::TEXT_HTML_UTF_8))
+            .with_body(full(body))
+    }
+}
+
+/// 纯文本响应，`Content-Type` 为 `text/plain; charset=utf-8`
+///
+/// 与 [`extractor::Text`](crate::extractor::Text) 萃取器不同，此类型用于构造响应。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Text<T>(pub T);
+
+impl<T: Into<Bytes>> From<Text<T>> for Response {
+    fn from(Text(body): Text<T>) -> Self {
+        Response::empty()
+            .with_typed_header(ContentType::text_utf8())
+            .with_body(full(body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::header::CONTENT_TYPE;
+    use http_body_util::BodyExt;
+
+    #[tokio::test]
+    async fn test_html_and_text_response() {
+        let mut res: Response = Html("<p>hi</p>".to_string()).into();
+        assert_eq!(
+            res.headers().get(CONTENT_TYPE).unwrap(),
+            "text/html; charset=utf-8"
+        );
+        let body = res.body.frame().await.unwrap().unwrap();
+        assert_eq!(body.data_ref().unwrap(), &Bytes::from("<p>hi</p>"));
+
+        let res: Response = Text("plain").into();
+        assert_eq!(
+            res.headers().get(CONTENT_TYPE).unwrap(),
+            "text/plain; charset=utf-8"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use headers::ContentType;
+use serde::Serialize;
+
+use crate::core::res_body::full;
+use crate::extractor::Json;
+use crate::{JsonConfig, Response, SilentError};
+
+/// 带缩进格式化的 JSON 响应，由 [`Json::pretty`] 构造
+///
+/// 适合面向人工阅读的调试、诊断接口；常规接口请直接返回 [`Json`]。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PrettyJson<T>(pub T);
+
+impl<T> Json<T> {
+    /// 构造格式化输出的 JSON 响应
+    ///
+    /// ```rust
+    /// use silent::extractor::Json;
+    /// use silent::response::PrettyJson;
+    /// use silent::{Request, Result};
+    ///
+    /// async fn debug(_req: Request) -> Result<PrettyJson<serde_json::Value>> {
+    ///     Ok(Json::pretty(serde_json::json!({"uptime": 42})))
+    /// }
+    ///
+    /// let route = silent::prelude::Route::new("debug").get(debug);
+    /// ```
+    pub fn pretty(value: T) -> PrettyJson<T> {
+        PrettyJson(value)
+    }
+}
+
+fn json_response(body: Result<Vec<u8>, SilentError>) -> Response {
+    match body {
+        Ok(body) => Response::empty()
+            .with_typed_header(ContentType::json())
+            .with_body(full(body)),
+        Err(e) => e.into(),
+    }
+}
+
+impl<T: Serialize> From<Json<T>> for Response {
+    fn from(Json(value): Json<T>) -> Self {
+        json_response(JsonConfig::with_current(|config| config.to_vec(&value)))
+    }
+}
+
+impl<T: Serialize> From<PrettyJson<T>> for Response {
+    fn from(PrettyJson(value): PrettyJson<T>) -> Self {
+        json_response(JsonConfig::with_current(|config| {
+            config.serialize(&value, true)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::StatusCode;
+    use http::header::CONTENT_TYPE;
+    use http_body_util::BodyExt;
+
+    async fn body(mut res: Response) -> Bytes {
+        res.body
+            .frame()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_data()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_json_response() {
+        let res: Response = Json(serde_json::json!({"id": 1})).into();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+        assert_eq!(body(res).await, Bytes::from(r#"{"id":1}"#));
+
+        // 与直接返回 Serialize 类型不同，Json 包装的 null 仍返回 200
+        let res: Response = Json(()).into();
+        assert_eq!(res.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_pretty_json_response() {
+        let res: Response = Json::pretty(serde_json::json!({"id": 1})).into();
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+        assert_eq!(body(res).await, Bytes::from("{\n  \"id\": 1\n}"));
+    }
+
+    #[tokio::test]
+    async fn test_json_serialize_error() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(vec![1u8], 1);
+        let res: Response = Json(map).into();
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_json_response_respects_config() {
+        use crate::configs::JsonScope;
+
+        let fut = Box::pin(async {
+            let res: Response = Json(serde_json::json!({"b": 1, "a": [2]})).into();
+            body(res).await
+        });
+        let config = JsonConfig::new().pretty(true).preserve_order(false);
+        assert_eq!(
+            JsonScope::new(config, fut).await,
+            Bytes::from("{\n  \"a\": [\n    2\n  ],\n  \"b\": 1\n}")
+        );
+    }
+}
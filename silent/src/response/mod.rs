@@ -0,0 +1,26 @@
+//! # 响应辅助类型
+//!
+//! 常用响应的包装类型，均实现了 `Into<Response>`，可直接作为处理函数的返回值：
+//!
+//! - **Redirect**：`303 See Other`、`307 Temporary Redirect`、`308 Permanent Redirect` 重定向
+//! - **NoContent、Created<T>**：`204 No Content`，以及带 `Location` 头的 `201 Created`
+//! - **Json<T>、PrettyJson<T>**：JSON 响应，`Json::pretty(value)` 输出带缩进的格式化 JSON
+//! - **Html<T>、Text<T>**：以 `text/html` 或 `text/plain` 返回字符串，避免被序列化为 JSON
+//! - **Attachment**：带 `Content-Disposition: attachment` 的文件下载，支持内存数据与流式读取磁盘文件
+//! - **Either、Either3、Either4**：按分支返回不同类型的响应，各分支需实现 `Into<Response>`
+//! - **StreamBody**：将任意字节流作为响应体流式发送，可选 `Content-Length`，否则分块传输
+
+mod attachment;
+mod content;
+mod json;
+mod redirect;
+mod status;
+mod stream;
+
+pub use crate::extractor::{Either, Either3, Either4, Json};
+pub use attachment::Attachment;
+pub use content::{Html, Text};
+pub use json::PrettyJson;
+pub use redirect::Redirect;
+pub use status::{Created, NoContent};
+pub use stream::StreamBody;
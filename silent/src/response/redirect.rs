@@ -0,0 +1,99 @@
+use http::{HeaderValue, StatusCode, header};
+
+use crate::{Response, SilentError};
+
+/// 重定向响应
+///
+/// 目标地址在转换为 [`Response`] 时校验，无法作为 `Location` 头时返回 `500`。
+///
+/// ```rust
+/// use silent::response::Redirect;
+/// use silent::{Request, Result};
+///
+/// // 登录成功后跳转到首页，浏览器会以 GET 请求新地址
+/// async fn login(_req: Request) -> Result<Redirect> {
+///     Ok(Redirect::see_other("/"))
+/// }
+///
+/// let route = silent::prelude::Route::new("login").post(login);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Redirect {
+    status: StatusCode,
+    location: String,
+}
+
+impl Redirect {
+    /// `303 See Other`：要求客户端以 GET 请求新地址，适用于表单提交后的跳转
+    pub fn see_other(uri: impl Into<String>) -> Self {
+        Self::with_status(StatusCode::SEE_OTHER, uri)
+    }
+
+    /// `307 Temporary Redirect`：临时重定向，客户端保持原请求方法与请求体
+    pub fn temporary(uri: impl Into<String>) -> Self {
+        Self::with_status(StatusCode::TEMPORARY_REDIRECT, uri)
+    }
+
+    /// `308 Permanent Redirect`：永久重定向，客户端保持原请求方法与请求体
+    pub fn permanent(uri: impl Into<String>) -> Self {
+        Self::with_status(StatusCode::PERMANENT_REDIRECT, uri)
+    }
+
+    pub(crate) fn with_status(status: StatusCode, uri: impl Into<String>) -> Self {
+        Self {
+            status,
+            location: uri.into(),
+        }
+    }
+
+    /// 重定向状态码
+    pub fn status_code(&self) -> StatusCode {
+        self.status
+    }
+
+    /// 重定向目标地址
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+}
+
+impl From<Redirect> for Response {
+    fn from(redirect: Redirect) -> Self {
+        match HeaderValue::try_from(redirect.location) {
+            Ok(location) => Response::empty()
+                .with_status(redirect.status)
+                .with_header(header::LOCATION, location),
+            Err(e) => SilentError::business_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("redirect error: {e}"),
+            )
+            .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redirect_status() {
+        for (redirect, status) in [
+            (Redirect::see_other("/home"), StatusCode::SEE_OTHER),
+            (Redirect::temporary("/home"), StatusCode::TEMPORARY_REDIRECT),
+            (Redirect::permanent("/home"), StatusCode::PERMANENT_REDIRECT),
+        ] {
+            assert_eq!(redirect.location(), "/home");
+            let res: Response = redirect.into();
+            assert_eq!(res.status(), status);
+            assert_eq!(res.headers().get(header::LOCATION).unwrap(), "/home");
+        }
+    }
+
+    #[test]
+    fn test_redirect_invalid_location() {
+        let res: Response = Redirect::see_other("/bad\nheader").into();
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(res.headers().get(header::LOCATION).is_none());
+    }
+}
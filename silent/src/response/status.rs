@@ -0,0 +1,117 @@
+use http::{HeaderValue, StatusCode, header};
+
+use crate::{Response, SilentError};
+
+/// `204 No Content` 响应，适用于删除或无返回内容的更新操作
+///
+/// ```rust
+/// use silent::response::NoContent;
+/// use silent::{Request, Result};
+///
+/// async fn delete_user(_req: Request) -> Result<NoContent> {
+///     Ok(NoContent)
+/// }
+///
+/// let route = silent::prelude::Route::new("users/<id>").delete(delete_user);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoContent;
+
+impl From<NoContent> for Response {
+    fn from(_: NoContent) -> Self {
+        Response::empty().with_status(StatusCode::NO_CONTENT)
+    }
+}
+
+/// `201 Created` 响应：设置 `Location` 头指向新资源，响应体按 `T` 的常规方式转换
+///
+/// `location` 在转换为 [`Response`] 时校验，无法作为响应头时返回 `500`。
+///
+/// ```rust
+/// use serde::Serialize;
+/// use silent::response::Created;
+/// use silent::{Request, Result};
+///
+/// #[derive(Serialize)]
+/// struct User {
+///     id: u64,
+/// }
+///
+/// async fn create_user(_req: Request) -> Result<Created<User>> {
+///     let user = User { id: 7 };
+///     Ok(Created::new(format!("/users/{}", user.id), user))
+/// }
+///
+/// let route = silent::prelude::Route::new("users").post(create_user);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Created<T = ()> {
+    /// 新资源的地址
+    pub location: String,
+    /// 响应体
+    pub body: T,
+}
+
+impl<T> Created<T> {
+    /// 创建带响应体的 `201` 响应
+    pub fn new(location: impl Into<String>, body: T) -> Self {
+        Self {
+            location: location.into(),
+            body,
+        }
+    }
+}
+
+impl Created {
+    /// 创建不带响应体的 `201` 响应
+    pub fn at(location: impl Into<String>) -> Self {
+        Self::new(location, ())
+    }
+}
+
+impl<T: Into<Response>> From<Created<T>> for Response {
+    fn from(created: Created<T>) -> Self {
+        let location = match HeaderValue::try_from(created.location) {
+            Ok(location) => location,
+            Err(e) => {
+                return SilentError::business_error(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("invalid location header: {e}"),
+                )
+                .into();
+            }
+        };
+        let mut res: Response = created.body.into();
+        res.set_status(StatusCode::CREATED);
+        res.set_header(header::LOCATION, location);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::header::CONTENT_TYPE;
+
+    #[test]
+    fn test_no_content() {
+        let res: Response = NoContent.into();
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[test]
+    fn test_created() {
+        let res: Response = Created::new("/users/1", serde_json::json!({"id": 1})).into();
+        assert_eq!(res.status(), StatusCode::CREATED);
+        assert_eq!(res.headers().get(header::LOCATION).unwrap(), "/users/1");
+        assert_eq!(res.headers().get(CONTENT_TYPE).unwrap(), "application/json");
+
+        // 无响应体时仍为 201，而不是 `()` 默认对应的 204
+        let res: Response = Created::at("/users/2").into();
+        assert_eq!(res.status(), StatusCode::CREATED);
+        assert_eq!(res.headers().get(header::LOCATION).unwrap(), "/users/2");
+
+        let res: Response = Created::at("/bad\nlocation").into();
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
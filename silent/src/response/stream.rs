@@ -0,0 +1,130 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+use bytes::Bytes;
+use futures_util::Stream;
+use headers::ContentType;
+use http::{HeaderValue, header};
+use mime::Mime;
+
+use crate::Response;
+use crate::core::res_body::{ResBody, stream_body};
+
+/// 流式响应：将任意字节流直接作为响应体发送，无需先缓冲到内存
+///
+/// - 设置了 [`StreamBody::content_length`] 时写入 `Content-Length` 头
+/// - 未设置时 HTTP/1.1 使用 `Transfer-Encoding: chunked` 分块发送
+/// - `Content-Type` 默认为 `application/octet-stream`
+///
+/// ```rust
+/// use bytes::Bytes;
+/// use futures::stream;
+/// use silent::response::StreamBody;
+/// use silent::{Request, Result};
+///
+/// async fn export(_req: Request) -> Result<StreamBody> {
+///     let rows = (0..3).map(|i| Ok::<_, std::io::Error>(Bytes::from(format!("{i}\n"))));
+///     Ok(StreamBody::new(stream::iter(rows)).content_type(mime::TEXT_PLAIN_UTF_8))
+/// }
+///
+/// let route = silent::prelude::Route::new("export").get(export);
+/// ```
+pub struct StreamBody {
+    body: ResBody,
+    content_length: Option<u64>,
+    content_type: Option<Mime>,
+}
+
+impl fmt::Debug for StreamBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamBody")
+            .field("content_length", &self.content_length)
+            .field("content_type", &self.content_type)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StreamBody {
+    /// 由字节流创建流式响应
+    pub fn new<S, O, E>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<O, E>> + Send + 'static,
+        O: Into<Bytes> + 'static,
+        E: Into<Box<dyn StdError + Send + Sync>> + 'static,
+    {
+        Self {
+            body: stream_body(stream),
+            content_length: None,
+            content_type: None,
+        }
+    }
+
+    /// 声明响应体总长度，须与流实际产出的字节数一致
+    pub fn content_length(mut self, len: u64) -> Self {
+        self.content_length = Some(len);
+        self
+    }
+
+    /// 设置 `Content-Type`
+    pub fn content_type(mut self, content_type: Mime) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+}
+
+impl From<StreamBody> for Response {
+    fn from(stream: StreamBody) -> Self {
+        let content_type = stream
+            .content_type
+            .unwrap_or(mime::APPLICATION_OCTET_STREAM);
+        let mut res = Response::empty()
+            .with_typed_header(ContentType::from(content_type))
+            .with_body(stream.body);
+        if let Some(len) = stream.content_length {
+            res.headers_mut()
+                .insert(header::CONTENT_LENGTH, HeaderValue::from(len));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+    use http::header::{CONTENT_LENGTH, CONTENT_TYPE};
+    use http_body_util::BodyExt;
+
+    #[tokio::test]
+    async fn test_stream_body() {
+        let chunks = vec![Ok::<_, std::io::Error>("hello "), Ok("world")];
+        let res: Response = StreamBody::new(stream::iter(chunks)).into();
+        assert_eq!(
+            res.headers().get(CONTENT_TYPE).unwrap(),
+            "application/octet-stream"
+        );
+        assert!(res.headers().get(CONTENT_LENGTH).is_none());
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"hello world");
+    }
+
+    #[tokio::test]
+    async fn test_stream_body_with_length_and_error() {
+        let res: Response = StreamBody::new(stream::iter(vec![Ok::<_, std::io::Error>("abc")]))
+            .content_length(3)
+            .content_type(mime::TEXT_PLAIN_UTF_8)
+            .into();
+        assert_eq!(res.headers().get(CONTENT_LENGTH).unwrap(), "3");
+        assert_eq!(
+            res.headers().get(CONTENT_TYPE).unwrap(),
+            "text/plain; charset=utf-8"
+        );
+
+        let chunks = vec![
+            Ok("partial".to_string()),
+            Err(std::io::Error::other("upstream closed")),
+        ];
+        let res: Response = StreamBody::new(stream::iter(chunks)).into();
+        assert!(res.body.collect().await.is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::Write;
+
+use crate::route::Route;
+
+/// 路由树中的一个节点，按深度优先顺序编号
+struct DiagramNode {
+    id: usize,
+    parent: Option<usize>,
+    lines: Vec<String>,
+}
+
+impl Route {
+    /// 以 Mermaid 流程图渲染路由树，节点标注路径段、请求方法与中间件数量
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// async fn hello(_req: Request) -> Result<&'static str> {
+    ///     Ok("hello")
+    /// }
+    ///
+    /// let route = Route::new("api").append(Route::new("users/<id:u64>").get(hello));
+    /// // 可直接嵌入 Markdown 的 ```mermaid 代码块
+    /// println!("{}", route.to_mermaid());
+    /// ```
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("graph LR\n");
+        let nodes = diagram_nodes(self);
+        for node in &nodes {
+            let label = node
+                .lines
+                .iter()
+                .map(|line| escape_mermaid(line))
+                .collect::<Vec<_>>()
+                .join("<br/>");
+            let _ = writeln!(out, "    n{}[\"{}\"]", node.id, label);
+        }
+        for node in &nodes {
+            if let Some(parent) = node.parent {
+                let _ = writeln!(out, "    n{parent} --> n{}", node.id);
+            }
+        }
+        out
+    }
+
+    /// 以 Graphviz DOT 格式渲染路由树，内容与 [`to_mermaid`](Self::to_mermaid) 一致
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph routes {\n    rankdir=LR;\n    node [shape=box];\n");
+        let nodes = diagram_nodes(self);
+        for node in &nodes {
+            let label = node
+                .lines
+                .iter()
+                .map(|line| escape_dot(line))
+                .collect::<Vec<_>>()
+                .join("\\n");
+            let _ = writeln!(out, "    n{} [label=\"{}\"];", node.id, label);
+        }
+        for node in &nodes {
+            if let Some(parent) = node.parent {
+                let _ = writeln!(out, "    n{parent} -> n{};", node.id);
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+fn diagram_nodes(route: &Route) -> Vec<DiagramNode> {
+    let mut nodes = Vec::new();
+    collect_nodes(route, None, &mut nodes);
+    nodes
+}
+
+fn collect_nodes(route: &Route, parent: Option<usize>, nodes: &mut Vec<DiagramNode>) {
+    let id = nodes.len();
+    let mut lines = vec![format!("/{}", route.path)];
+    if let Some(host) = &route.host {
+        lines.push(format!("host: {host}"));
+    }
+    if route.mount_point {
+        lines.push("mount".to_string());
+    }
+    if !route.handler.is_empty() {
+        let mut methods: Vec<&str> = route.handler.keys().map(|method| method.as_str()).collect();
+        methods.sort_unstable();
+        lines.push(methods.join(", "));
+    }
+    if !route.middlewares.is_empty() {
+        lines.push(format!("middlewares: {}", route.middlewares.len()));
+    }
+    nodes.push(DiagramNode { id, parent, lines });
+    for child in &route.children {
+        collect_nodes(child, Some(id), nodes);
+    }
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('&', "#amp;")
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Handler, Request, Response, Result};
+
+    async fn hello(_req: Request) -> Result<&'static str> {
+        Ok("hello")
+    }
+
+    #[derive(Clone)]
+    struct Noop;
+
+    #[async_trait::async_trait]
+    impl crate::MiddleWareHandler for Noop {
+        async fn handle(&self, req: Request, next: &crate::Next) -> Result<Response> {
+            next.call(req).await
+        }
+    }
+
+    fn route() -> Route {
+        Route::new("")
+            .hook(Noop)
+            .append(Route::new("users").get(hello).post(hello))
+            .append(Route::new("users/<id:u64>").delete(hello))
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        assert_eq!(
+            route().to_mermaid(),
+            "graph LR
+    n0[\"/<br/>middlewares: 1\"]
+    n1[\"/users<br/>GET, POST\"]
+    n2[\"/#lt;id:u64#gt;<br/>DELETE\"]
+    n0 --> n1
+    n1 --> n2
+"
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        assert_eq!(
+            route().to_dot(),
+            "digraph routes {
+    rankdir=LR;
+    node [shape=box];
+    n0 [label=\"/\\nmiddlewares: 1\"];
+    n1 [label=\"/users\\nGET, POST\"];
+    n2 [label=\"/<id:u64>\\nDELETE\"];
+    n0 -> n1;
+    n1 -> n2;
+}
+"
+        );
+    }
+}
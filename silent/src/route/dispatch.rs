@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use crate::handler::Handler;
+use crate::route::RouteTree;
+use crate::{Request, Response, Result, SilentError, StatusCode};
+
+/// 子请求的最大嵌套层数，超过后返回 `508 Loop Detected`
+const MAX_DISPATCH_DEPTH: usize = 8;
+
+/// 进程内子请求分发器
+///
+/// 路由树在处理请求时将其放入请求扩展，处理函数与中间件可通过 `Extension<Dispatcher>` 萃取，
+/// 或从 `req.extensions()` 中读取，用于服务端包含、批量接口或 ESI 式的页面组合，
+/// 子请求直接在当前进程内走完整的路由匹配与中间件链，不经过网络。
+///
+/// 子请求可以继续分发，嵌套超过 8 层时返回 `508 Loop Detected`，避免路由间互相调用造成死循环。
+///
+/// ```rust
+/// use silent::extractor::Extension;
+/// use silent::prelude::*;
+///
+/// async fn batch(Extension(dispatcher): Extension<Dispatcher>) -> Result<String> {
+///     let mut bodies = Vec::new();
+///     for path in ["/users/1", "/users/2"] {
+///         let mut req = Request::empty();
+///         *req.uri_mut() = path.parse().unwrap();
+///         let res = dispatcher.dispatch(req).await?;
+///         bodies.push(res.status().to_string());
+///     }
+///     Ok(bodies.join(","))
+/// }
+///
+/// let route = Route::new_root()
+///     .append(Route::new("users/<id:u64>").get(|_req: Request| async { Ok("user") }))
+///     .append(Route::new("batch").get(batch));
+/// ```
+#[derive(Clone)]
+pub struct Dispatcher {
+    tree: Arc<RouteTree>,
+    depth: usize,
+}
+
+impl Dispatcher {
+    pub(crate) fn new(tree: Arc<RouteTree>) -> Self {
+        Self { tree, depth: 0 }
+    }
+
+    /// 当前请求的嵌套层数，顶层请求为 0
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// 在进程内分发子请求
+    ///
+    /// 子请求从根路由开始匹配，经过完整的中间件链；请求头、远端地址等需由调用方按需从原请求复制。
+    pub async fn dispatch(&self, mut req: Request) -> Result<Response> {
+        if self.depth >= MAX_DISPATCH_DEPTH {
+            return Err(SilentError::business_error(
+                StatusCode::LOOP_DETECTED,
+                "sub-request nesting too deep".to_string(),
+            ));
+        }
+        req.extensions_mut().insert(Self {
+            tree: Arc::clone(&self.tree),
+            depth: self.depth + 1,
+        });
+        self.tree.dispatch(req).await
+    }
+
+    /// 请求将命中的路由的 `Allow` 头，只做路由匹配，不执行中间件与处理函数
+    pub(crate) fn allow_header(&self, req: &Request) -> Option<http::HeaderValue> {
+        self.tree.allow_header(req)
+    }
+}
+
+impl RouteTree {
+    /// 在进程内执行请求，与服务器收到该请求时的处理流程相同
+    ///
+    /// 处理函数中通常通过请求扩展中的 [`Dispatcher`] 调用，以获得嵌套层数保护。
+    pub async fn dispatch(&self, req: Request) -> Result<Response> {
+        self.call(req).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::Extension;
+    use crate::route::Route;
+    use http_body_util::BodyExt;
+
+    fn request(path: &str) -> Request {
+        let mut req = Request::empty();
+        *req.uri_mut() = path.parse().unwrap();
+        req
+    }
+
+    async fn body(res: Response) -> String {
+        let body = res.body.collect().await.unwrap().to_bytes();
+        String::from_utf8_lossy(&body).into_owned()
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_sub_requests() {
+        let route = Route::new_root()
+            .append(Route::new("users/<id:u64>").get(|req: Request| async move {
+                let id: u64 = req.get_path_params("id")?;
+                Ok(format!("user{id}"))
+            }))
+            .append(Route::new("batch").get(
+                |Extension(dispatcher): Extension<Dispatcher>| async move {
+                    assert_eq!(dispatcher.depth(), 0);
+                    let mut bodies = Vec::new();
+                    for path in ["/users/1", "/users/2"] {
+                        let res = dispatcher.dispatch(request(path)).await?;
+                        bodies.push(body(res).await);
+                    }
+                    Ok(bodies.join(","))
+                },
+            ));
+        let tree = route.convert_to_route_tree();
+
+        let res = tree.dispatch(request("/batch")).await.unwrap();
+        assert_eq!(body(res).await, "user1,user2");
+        assert!(matches!(
+            tree.dispatch(request("/missing")).await,
+            Err(SilentError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_loop_detected() {
+        let route = Route::new_root().append(Route::new("loop").get(
+            |Extension(dispatcher): Extension<Dispatcher>| async move {
+                dispatcher.dispatch(request("/loop")).await
+            },
+        ));
+        let err = route
+            .convert_to_route_tree()
+            .dispatch(request("/loop"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::LOOP_DETECTED);
+    }
+}
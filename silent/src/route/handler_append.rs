@@ -1,9 +1,10 @@
 use super::Route;
 use crate::core::into_response::IntoResponse;
-use crate::extractor::{FromRequest, handler_from_extractor, handler_from_extractor_with_request};
+use crate::extractor::{
+    FromRequest, extractor_handler, extractor_handler_with_request, handler_from_extractor,
+};
 use crate::handler::HandlerFn;
 use crate::{Handler, HandlerWrapper, Method, Request, Response, Result};
-use async_trait::async_trait;
 use std::collections::HashMap;
 use std::future::Future;
 use std::sync::Arc;
@@ -98,18 +99,20 @@ where
 }
 
 /// 将不同形态的处理函数（基于 Request 或基于萃取器 Args）统一适配为 `Arc<dyn Handler>`
-pub trait IntoRouteHandler<Args> {
+///
+/// `M` 为返回值 [`IntoResponse`] 实现的标记类型，由编译器推断。
+pub trait IntoRouteHandler<Args, M = ()> {
     fn into_handler(self) -> std::sync::Arc<dyn Handler>;
 }
 
-trait RouteDispatch: Sized {
+trait RouteDispatch<M>: Sized {
     fn into_arc_handler<F, Fut>(handler: F) -> std::sync::Arc<dyn Handler>
     where
         F: Fn(Request) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Self> + Send + 'static;
 }
 
-impl RouteDispatch for Response {
+impl RouteDispatch<()> for Response {
     fn into_arc_handler<F, Fut>(handler: F) -> std::sync::Arc<dyn Handler>
     where
         F: Fn(Request) -> Fut + Send + Sync + 'static,
@@ -119,9 +122,10 @@ impl RouteDispatch for Response {
     }
 }
 
-impl<T> RouteDispatch for crate::error::SilentResult<T>
+impl<T, M> RouteDispatch<M> for crate::error::SilentResult<T>
 where
-    T: IntoResponse + Send + 'static,
+    T: IntoResponse<M> + Send + 'static,
+    M: 'static,
 {
     fn into_arc_handler<F, Fut>(handler: F) -> std::sync::Arc<dyn Handler>
     where
@@ -132,116 +136,175 @@ where
     }
 }
 
-/// 将返回 `Result<T, E>` 的 handler 包装为 Handler trait 实现。
-/// Ok(T) 和 Err(E) 都通过 `IntoResponse` 转为 Response。
-#[allow(dead_code)]
-pub(crate) struct IntoResponseResultHandler<F> {
-    pub(crate) handler: F,
-}
-
-#[async_trait]
-impl<F, T, E, Fut> Handler for IntoResponseResultHandler<F>
+impl<F, T, E, Fut, M> IntoRouteHandler<HandlerWrapper<F, M>> for HandlerWrapper<F, M>
 where
-    T: IntoResponse + Send + 'static,
-    E: IntoResponse + Send + 'static,
     Fut: Future<Output = std::result::Result<T, E>> + Send + 'static,
     F: Fn(Request) -> Fut + Send + Sync + 'static,
+    T: IntoResponse<M>,
+    E: Into<Response> + 'static,
+    M: 'static,
 {
-    async fn call(&self, req: Request) -> Result<Response> {
-        match (self.handler)(req).await {
-            Ok(v) => Ok(v.into_response()),
-            Err(e) => Ok(e.into_response()),
-        }
+    fn into_handler(self) -> std::sync::Arc<dyn Handler> {
+        std::sync::Arc::new(self)
     }
 }
 
-impl<F, Fut> IntoRouteHandler<crate::Request> for F
+impl<F, Fut, M> IntoRouteHandler<crate::Request, M> for F
 where
     F: Fn(Request) -> Fut + Send + Sync + 'static,
     Fut: Future + Send + 'static,
-    Fut::Output: RouteDispatch,
+    Fut::Output: RouteDispatch<M>,
 {
     fn into_handler(self) -> std::sync::Arc<dyn Handler> {
-        <Fut::Output as RouteDispatch>::into_arc_handler(self)
+        <Fut::Output as RouteDispatch<M>>::into_arc_handler(self)
     }
 }
 
-impl<Args, F, Fut, T> IntoRouteHandler<Args> for F
+impl<Args, F, Fut, T, M> IntoRouteHandler<Args, M> for F
 where
     Args: FromRequest + Send + 'static,
     <Args as FromRequest>::Rejection: Into<Response> + Send + 'static,
     F: Fn(Args) -> Fut + Send + Sync + 'static,
     Fut: core::future::Future<Output = Result<T>> + Send + 'static,
-    T: IntoResponse + Send + 'static,
+    T: IntoResponse<M> + Send + 'static,
+    M: 'static,
 {
     fn into_handler(self) -> std::sync::Arc<dyn Handler> {
-        let adapted = handler_from_extractor::<Args, F, Fut, T>(self);
+        let adapted = extractor_handler::<Args, F, Fut, T, M>(self);
         std::sync::Arc::new(HandlerWrapper::new(adapted))
     }
 }
 
-impl<Args, F, Fut, T> IntoRouteHandler<(Request, Args)> for F
+impl<Args, F, Fut, T, M> IntoRouteHandler<(Request, Args), M> for F
 where
     Args: FromRequest + Send + 'static,
     <Args as FromRequest>::Rejection: Into<Response> + Send + 'static,
     F: Fn(Request, Args) -> Fut + Send + Sync + 'static,
     Fut: core::future::Future<Output = Result<T>> + Send + 'static,
-    T: IntoResponse + Send + 'static,
+    T: IntoResponse<M> + Send + 'static,
+    M: 'static,
 {
     fn into_handler(self) -> std::sync::Arc<dyn Handler> {
-        let adapted = handler_from_extractor_with_request::<Args, F, Fut, T>(self);
+        let adapted = extractor_handler_with_request::<Args, F, Fut, T, M>(self);
         std::sync::Arc::new(HandlerWrapper::new(adapted))
     }
 }
 
 impl Route {
-    pub fn get<H, Args>(self, handler: H) -> Self
+    pub fn get<H, Args, M>(self, handler: H) -> Self
     where
-        H: IntoRouteHandler<Args>,
+        H: IntoRouteHandler<Args, M>,
     {
         let handler = handler.into_handler();
         <Route as HandlerGetter>::handler(self, Method::GET, handler)
     }
 
-    pub fn post<H, Args>(self, handler: H) -> Self
+    pub fn post<H, Args, M>(self, handler: H) -> Self
     where
-        H: IntoRouteHandler<Args>,
+        H: IntoRouteHandler<Args, M>,
     {
         let handler = handler.into_handler();
         <Route as HandlerGetter>::handler(self, Method::POST, handler)
     }
 
-    pub fn put<H, Args>(self, handler: H) -> Self
+    pub fn put<H, Args, M>(self, handler: H) -> Self
     where
-        H: IntoRouteHandler<Args>,
+        H: IntoRouteHandler<Args, M>,
     {
         let handler = handler.into_handler();
         <Route as HandlerGetter>::handler(self, Method::PUT, handler)
     }
 
-    pub fn delete<H, Args>(self, handler: H) -> Self
+    pub fn delete<H, Args, M>(self, handler: H) -> Self
     where
-        H: IntoRouteHandler<Args>,
+        H: IntoRouteHandler<Args, M>,
     {
         let handler = handler.into_handler();
         <Route as HandlerGetter>::handler(self, Method::DELETE, handler)
     }
 
-    pub fn patch<H, Args>(self, handler: H) -> Self
+    pub fn patch<H, Args, M>(self, handler: H) -> Self
     where
-        H: IntoRouteHandler<Args>,
+        H: IntoRouteHandler<Args, M>,
     {
         let handler = handler.into_handler();
         <Route as HandlerGetter>::handler(self, Method::PATCH, handler)
     }
 
-    pub fn options<H, Args>(self, handler: H) -> Self
+    pub fn options<H, Args, M>(self, handler: H) -> Self
     where
-        H: IntoRouteHandler<Args>,
+        H: IntoRouteHandler<Args, M>,
     {
         let handler = handler.into_handler();
         <Route as HandlerGetter>::handler(self, Method::OPTIONS, handler)
     }
+
+    /// 为多个方法注册同一个处理函数，覆盖这些方法已注册的处理函数
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// async fn webhook(_req: Request) -> Result<&'static str> {
+    ///     Ok("received")
+    /// }
+    ///
+    /// let route = Route::new("webhook").on(&[Method::GET, Method::POST], webhook);
+    /// ```
+    pub fn on<H, Args, M>(mut self, methods: &[Method], handler: H) -> Self
+    where
+        H: IntoRouteHandler<Args, M>,
+    {
+        let handler = handler.into_handler();
+        let handler_map = self.get_handler_mut();
+        for method in methods {
+            handler_map.insert(method.clone(), Arc::clone(&handler));
+        }
+        self
+    }
+
+    /// 为所有标准方法注册同一个处理函数，已单独注册的方法保持不变
+    ///
+    /// 包含 `GET`、`HEAD`、`POST`、`PUT`、`DELETE`、`PATCH`、`OPTIONS`、`TRACE` 与 `CONNECT`，
+    /// 因此 `OPTIONS` 与 `HEAD` 也交由该处理函数处理，适合反向代理等需要原样转发的端点。
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// async fn proxy(req: Request) -> Result<String> {
+    ///     Ok(format!("forward {}", req.method()))
+    /// }
+    ///
+    /// async fn health(_req: Request) -> Result<&'static str> {
+    ///     Ok("ok")
+    /// }
+    ///
+    /// // GET 使用单独的处理函数，其余方法转发
+    /// let route = Route::new("upstream").get(health).any(proxy);
+    /// ```
+    pub fn any<H, Args, M>(mut self, handler: H) -> Self
+    where
+        H: IntoRouteHandler<Args, M>,
+    {
+        const METHODS: [Method; 9] = [
+            Method::GET,
+            Method::HEAD,
+            Method::POST,
+            Method::PUT,
+            Method::DELETE,
+            Method::PATCH,
+            Method::OPTIONS,
+            Method::TRACE,
+            Method::CONNECT,
+        ];
+        let handler = handler.into_handler();
+        let handler_map = self.get_handler_mut();
+        for method in METHODS {
+            handler_map
+                .entry(method)
+                .or_insert_with(|| Arc::clone(&handler));
+        }
+        self
+    }
 }
 
 // 扩展：支持基于萃取器签名的处理函数
@@ -891,4 +954,89 @@ mod tests {
             panic!("Handler not found");
         }
     }
+
+    // ==================== on / any 测试 ====================
+
+    #[tokio::test]
+    async fn test_on_and_any() {
+        use crate::Handler;
+
+        async fn method(req: Request) -> Result<String> {
+            Ok(req.method().to_string())
+        }
+
+        let route = Route::new("hook").on(&[Method::GET, Method::POST], method);
+        assert_eq!(route.handler.len(), 2);
+        assert!(route.handler.contains_key(&Method::POST));
+
+        let route = Route::new("proxy")
+            .get(|_req: Request| async { Ok("health") })
+            .any(method);
+        assert_eq!(route.handler.len(), 9);
+        let tree = Route::new("").append(route).convert_to_route_tree();
+        for (method, expected) in [(Method::GET, "health"), (Method::DELETE, "DELETE")] {
+            let mut req = Request::empty();
+            *req.method_mut() = method;
+            *req.uri_mut() = "/proxy".parse().unwrap();
+            let res = tree.call(req).await.unwrap();
+            let body = http_body_util::BodyExt::collect(res.body)
+                .await
+                .unwrap()
+                .to_bytes();
+            assert_eq!(&body[..], expected.as_bytes());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_status_tuple_handlers() {
+        use crate::extractor::Query;
+        use crate::{Handler, StatusCode};
+        use http::HeaderMap;
+        use http::header::LOCATION;
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        struct Item {
+            id: u64,
+        }
+
+        async fn create(Query(item): Query<Item>) -> Result<(StatusCode, HeaderMap, String)> {
+            let mut headers = HeaderMap::new();
+            headers.insert(LOCATION, format!("/items/{}", item.id).parse().unwrap());
+            Ok((StatusCode::CREATED, headers, format!("item {}", item.id)))
+        }
+
+        let route = Route::new("items")
+            .get(|_req: Request| async { Ok((StatusCode::ACCEPTED, "queued")) })
+            .post(create)
+            .delete(|_req: Request| async { Ok(StatusCode::NO_CONTENT) });
+        let tree = Route::new("").append(route).convert_to_route_tree();
+
+        let call = |method: Method, uri: &str| {
+            let mut req = Request::empty();
+            *req.method_mut() = method;
+            *req.uri_mut() = uri.parse().unwrap();
+            tree.call(req)
+        };
+
+        let res = call(Method::GET, "/items").await.unwrap();
+        assert_eq!(res.status(), StatusCode::ACCEPTED);
+        let body = http_body_util::BodyExt::collect(res.body)
+            .await
+            .unwrap()
+            .to_bytes();
+        assert_eq!(&body[..], b"queued");
+
+        let res = call(Method::POST, "/items?id=7").await.unwrap();
+        assert_eq!(res.status(), StatusCode::CREATED);
+        assert_eq!(res.headers().get(LOCATION).unwrap(), "/items/7");
+        let body = http_body_util::BodyExt::collect(res.body)
+            .await
+            .unwrap()
+            .to_bytes();
+        assert_eq!(&body[..], b"item 7");
+
+        let res = call(Method::DELETE, "/items").await.unwrap();
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    }
 }
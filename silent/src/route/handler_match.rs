@@ -1,4 +1,4 @@
-// 仅保留路由特殊段解析（例如 <id:i64>、<path:**>）。
+// 仅保留路由特殊段解析（例如 <id:i64>、<ts:datetime>、<path:**>）。
 // 具体匹配逻辑已迁移至 RouteTree。
 
 pub(crate) enum SpecialPath {
@@ -9,6 +9,7 @@ pub(crate) enum SpecialPath {
     U64(String),
     U32(String),
     UUid(String),
+    DateTime(String),
     Path(String),
     FullPath(String),
 }
@@ -32,6 +33,7 @@ impl From<&str> for SpecialPath {
             "u64" => SpecialPath::U64(key.to_string()),
             "u32" => SpecialPath::U32(key.to_string()),
             "uuid" => SpecialPath::UUid(key.to_string()),
+            "datetime" => SpecialPath::DateTime(key.to_string()),
             _ => SpecialPath::String(key.to_string()),
         }
     }
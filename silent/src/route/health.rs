@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use futures::future::{BoxFuture, join_all};
+use serde::Serialize;
+
+use crate::route::Route;
+use crate::{Request, Response, StatusCode};
+
+type Check = Arc<dyn Fn() -> BoxFuture<'static, Result<(), String>> + Send + Sync>;
+
+#[derive(Clone)]
+struct NamedCheck {
+    name: String,
+    liveness: bool,
+    check: Check,
+}
+
+/// 健康检查注册表，配合 [`Route::with_health_probes`] 提供 `/healthz` 与 `/readyz` 探针
+///
+/// - [`register`](Self::register) 注册就绪检查（如数据库连通性），只在 `/readyz` 中执行
+/// - [`register_liveness`](Self::register_liveness) 注册存活检查（如调度器仍在运行），`/healthz` 与 `/readyz` 均会执行
+///
+/// 各检查并发执行，超过 [`timeout`](Self::timeout)（默认 5 秒）视为失败。克隆的注册表共享同一组检查，
+/// 挂载路由后仍可继续注册。
+///
+/// ```rust
+/// use silent::prelude::*;
+/// use silent::HealthRegistry;
+///
+/// let health = HealthRegistry::new();
+/// health.register("database", || async {
+///     // 例如执行 `SELECT 1`
+///     Ok::<_, std::io::Error>(())
+/// });
+/// let route = Route::new_root().with_health_probes(health.clone());
+/// ```
+#[derive(Clone)]
+pub struct HealthRegistry {
+    checks: Arc<RwLock<Vec<NamedCheck>>>,
+    timeout: Duration,
+}
+
+impl Default for HealthRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HealthRegistry {
+    /// 创建空的注册表，单个检查的超时为 5 秒
+    pub fn new() -> Self {
+        Self {
+            checks: Arc::default(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// 设置单个检查的超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 注册就绪检查，返回 `Err` 时 `/readyz` 报告失败
+    pub fn register<F, Fut, E>(&self, name: impl Into<String>, check: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Display,
+    {
+        self.insert(name.into(), false, check);
+    }
+
+    /// 注册存活检查，返回 `Err` 时 `/healthz` 与 `/readyz` 均报告失败
+    pub fn register_liveness<F, Fut, E>(&self, name: impl Into<String>, check: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Display,
+    {
+        self.insert(name.into(), true, check);
+    }
+
+    fn insert<F, Fut, E>(&self, name: String, liveness: bool, check: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Display,
+    {
+        let check: Check = Arc::new(move || {
+            let fut = check();
+            Box::pin(async move { fut.await.map_err(|e| e.to_string()) })
+        });
+        let mut checks = self.checks.write().expect("health registry poisoned");
+        checks.retain(|c| c.name != name);
+        checks.push(NamedCheck {
+            name,
+            liveness,
+            check,
+        });
+    }
+
+    /// 执行存活检查
+    pub async fn liveness(&self) -> HealthReport {
+        self.run(true).await
+    }
+
+    /// 执行全部检查
+    pub async fn readiness(&self) -> HealthReport {
+        self.run(false).await
+    }
+
+    async fn run(&self, liveness_only: bool) -> HealthReport {
+        let checks: Vec<NamedCheck> = self
+            .checks
+            .read()
+            .expect("health registry poisoned")
+            .iter()
+            .filter(|c| c.liveness || !liveness_only)
+            .cloned()
+            .collect();
+        let timeout = self.timeout;
+        let results = join_all(checks.into_iter().map(|c| async move {
+            let start = Instant::now();
+            let result = match tokio::time::timeout(timeout, (c.check)()).await {
+                Ok(result) => result,
+                Err(_) => Err("timed out".to_string()),
+            };
+            let report = CheckReport {
+                status: if result.is_ok() {
+                    HealthStatus::Up
+                } else {
+                    HealthStatus::Down
+                },
+                error: result.err(),
+                duration_ms: start.elapsed().as_millis() as u64,
+            };
+            (c.name, report)
+        }))
+        .await;
+        let checks: BTreeMap<_, _> = results.into_iter().collect();
+        let status = if checks.values().all(|c| c.status == HealthStatus::Up) {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+        HealthReport { status, checks }
+    }
+}
+
+/// 健康状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+/// 单个检查的结果
+#[derive(Clone, Debug, Serialize)]
+pub struct CheckReport {
+    pub status: HealthStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// 探针返回的汇总结果，所有检查通过时为 `up`
+#[derive(Clone, Debug, Serialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checks: BTreeMap<String, CheckReport>,
+}
+
+impl HealthReport {
+    fn into_response(self) -> Response {
+        let status = match self.status {
+            HealthStatus::Up => StatusCode::OK,
+            HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+        };
+        let mut res = Response::json(&self);
+        res.set_status(status);
+        res
+    }
+}
+
+impl Route {
+    /// 追加 `/healthz`（存活）与 `/readyz`（就绪）探针
+    ///
+    /// 所有检查通过时返回 `200`，否则返回 `503`，响应体为各检查结果的 JSON 汇总：
+    ///
+    /// ```json
+    /// {"status":"down","checks":{"database":{"status":"down","error":"connection refused","duration_ms":3}}}
+    /// ```
+    ///
+    /// 检查的注册方式见 [`HealthRegistry`]。
+    pub fn with_health_probes(self, registry: HealthRegistry) -> Self {
+        let readiness = registry.clone();
+        self.append(Route::new("healthz").get(move |_req: Request| {
+            let registry = registry.clone();
+            async move { Ok(registry.liveness().await.into_response()) }
+        }))
+        .append(Route::new("readyz").get(move |_req: Request| {
+            let registry = readiness.clone();
+            async move { Ok(registry.readiness().await.into_response()) }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    async fn probe(route: &Route, path: &str) -> (StatusCode, serde_json::Value) {
+        let mut req = Request::empty();
+        *req.uri_mut() = path.parse().unwrap();
+        let res = crate::Handler::call(route, req).await.unwrap();
+        let status = res.status();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_health_probes() {
+        let db_up = Arc::new(AtomicBool::new(true));
+        let health = HealthRegistry::new().timeout(Duration::from_millis(50));
+        let route = Route::new_root().with_health_probes(health.clone());
+
+        let (status, body) = probe(&route, "/readyz").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "up");
+
+        let flag = db_up.clone();
+        health.register("database", move || {
+            let up = flag.load(Ordering::SeqCst);
+            async move {
+                if up {
+                    Ok(())
+                } else {
+                    Err("connection refused")
+                }
+            }
+        });
+        health.register_liveness("scheduler", || async { Ok::<_, String>(()) });
+
+        let (status, body) = probe(&route, "/readyz").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["checks"]["database"]["status"], "up");
+        assert_eq!(body["checks"]["scheduler"]["status"], "up");
+
+        db_up.store(false, Ordering::SeqCst);
+        let (status, body) = probe(&route, "/readyz").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "down");
+        assert_eq!(body["checks"]["database"]["error"], "connection refused");
+
+        // 存活探针不执行就绪检查
+        let (status, body) = probe(&route, "/healthz").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["checks"].get("database").is_none());
+        assert_eq!(body["checks"]["scheduler"]["status"], "up");
+    }
+
+    #[tokio::test]
+    async fn test_health_check_timeout() {
+        let health = HealthRegistry::new().timeout(Duration::from_millis(10));
+        health.register_liveness("stuck", || async {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            Ok::<_, String>(())
+        });
+        let report = health.liveness().await;
+        assert_eq!(report.status, HealthStatus::Down);
+        assert_eq!(report.checks["stuck"].error.as_deref(), Some("timed out"));
+    }
+}
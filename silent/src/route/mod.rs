@@ -4,6 +4,7 @@ pub use route_service::RouteService;
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::handler::Handler;
 #[cfg(feature = "static")]
@@ -13,17 +14,48 @@ use crate::middleware::MiddleWareHandler;
 use crate::prelude::HandlerGetter;
 use crate::{Method, Request, Response};
 
+mod diagram;
+mod dispatch;
 pub(crate) mod handler_append;
 mod handler_match;
+#[cfg(feature = "server")]
+mod health;
+mod route_info;
 mod route_service;
 mod route_tree;
+mod scope;
+mod typed_path;
+mod versioned;
+pub use dispatch::Dispatcher;
+#[cfg(feature = "server")]
+pub use health::{CheckReport, HealthRegistry, HealthReport, HealthStatus};
+pub use route_info::RouteInfo;
 pub use route_tree::RouteTree;
+pub(crate) use route_tree::{MatchedRoute, request_host};
+pub use scope::Scope;
+pub use typed_path::encode_path_segment;
+pub use versioned::{VersionSource, Versioned};
 #[cfg(all(feature = "worker", target_arch = "wasm32"))]
 pub mod worker;
 pub trait RouterAdapt {
     fn into_router(self) -> Route;
 }
 
+/// 路径末尾斜杠的处理策略，在根路由上通过 [`Route::trailing_slash`] 设置
+///
+/// 注册路由时末尾的 `/` 会被忽略，因此 `/users` 是规范形式，该策略决定 `/users/` 如何处理。
+/// 策略在路由匹配时生效，重定向与 404 均经过中间件。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TrailingSlash {
+    /// `/users/` 与 `/users` 匹配同一路由（默认）
+    #[default]
+    MergeBoth,
+    /// `/users/` 重定向到 `/users`：GET、HEAD 使用 `301`，其他方法使用 `308` 以保留方法与请求体
+    Redirect,
+    /// `/users/` 不匹配 `/users`，交给兜底处理器，未设置时返回 404；`<path:**>` 等通配整段路径的路由不受影响
+    Strict,
+}
+
 #[derive(Clone)]
 pub struct Route {
     pub path: String,
@@ -34,6 +66,22 @@ pub struct Route {
     create_path: String,
     // 状态管理字段（有此字段表示是服务入口点）
     state: Option<crate::State>,
+    // 子树内未匹配请求的兜底处理器
+    fallback: Option<Arc<dyn Handler>>,
+    // 末尾斜杠策略，仅在根路由上生效
+    trailing_slash: TrailingSlash,
+    // 主机名约束，仅匹配 Host 与之相符的请求
+    host: Option<String>,
+    // 子应用挂载点：不与同级路由合并，进入时去除已匹配的路径前缀
+    mount_point: bool,
+    // 处理函数超时时间，子路由未设置时沿用
+    timeout: Option<Duration>,
+    // 匹配优先级，同级路由中数值大者先尝试
+    priority: i32,
+    // 合并时发现的重复注册方法，构建路由树时报错
+    duplicate_methods: Vec<Method>,
+    // API 版本约束，由 Versioned 设置，不与同级路由合并
+    version: Option<Arc<versioned::VersionGuard>>,
     #[cfg(feature = "session")]
     session_set: bool,
 }
@@ -87,6 +135,14 @@ impl Route {
             special_match: false,
             create_path: String::new(),
             state: Some(crate::State::new()), // 服务入口点需要状态管理
+            fallback: None,
+            trailing_slash: TrailingSlash::default(),
+            host: None,
+            mount_point: false,
+            timeout: None,
+            priority: 0,
+            duplicate_methods: Vec::new(),
+            version: None,
             #[cfg(feature = "session")]
             session_set: false,
         }
@@ -105,6 +161,14 @@ impl Route {
             special_match: first_path.starts_with('<') && first_path.ends_with('>'),
             create_path: path.to_string(),
             state: None,
+            fallback: None,
+            trailing_slash: TrailingSlash::default(),
+            host: None,
+            mount_point: false,
+            timeout: None,
+            priority: 0,
+            duplicate_methods: Vec::new(),
+            version: None,
             #[cfg(feature = "session")]
             session_set: false,
         };
@@ -148,6 +212,86 @@ impl Route {
             Self::merge_child(&mut real_route.children, route);
         }
     }
+    /// 将完整构建的路由（如其他 crate 提供的子应用）挂载到 `prefix` 下
+    ///
+    /// 与 `append` 不同，子应用作为独立子树挂载，不会与同路径的已有路由合并，其中间件、状态与兜底处理器保持不变。
+    /// 进入子应用后 `req.uri()` 的路径去除了挂载前缀，子应用按挂载前的路径编写即可；
+    /// [`MatchedPath`](crate::extractor::MatchedPath) 为带前缀的完整模板，
+    /// 原始 URI 可通过 [`OriginalUri`](crate::extractor::OriginalUri) 获取。
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// async fn path(req: Request) -> Result<String> {
+    ///     Ok(req.uri().path().to_string())
+    /// }
+    ///
+    /// // 可复用的子应用，自身路由为 /users
+    /// fn admin_app() -> Route {
+    ///     Route::new("").append(Route::new("users").get(path))
+    /// }
+    ///
+    /// // GET /admin/users 由子应用处理，处理函数看到的路径为 /users
+    /// let route = Route::new("").mount("admin", admin_app());
+    /// ```
+    pub fn mount<R: RouterAdapt>(self, prefix: &str, app: R) -> Self {
+        let mut mount_point = Route::new("");
+        mount_point.mount_point = true;
+        mount_point.children.push(app.into_router());
+        let prefix = prefix.trim_matches('/');
+        if prefix.is_empty() {
+            self.append(mount_point)
+        } else {
+            self.append(Route::new(prefix).append(mount_point))
+        }
+    }
+
+    /// 将 `from` 重定向到 `to`，适用于旧地址迁移，所有请求方法均重定向
+    ///
+    /// `to` 不含查询字符串时沿用请求的查询字符串。`status` 须为 3xx，`to` 须可作为 `Location` 头，否则 panic。
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// let route = Route::new("")
+    ///     .redirect("/old", "/new", StatusCode::MOVED_PERMANENTLY)
+    ///     .redirects(
+    ///         [("/blog/2019", "/archive/2019"), ("/about-us", "/about")],
+    ///         StatusCode::PERMANENT_REDIRECT,
+    ///     );
+    /// ```
+    pub fn redirect(self, from: &str, to: &str, status: crate::StatusCode) -> Self {
+        assert!(
+            status.is_redirection(),
+            "重定向状态码必须为 3xx，实际为 {status}"
+        );
+        assert!(
+            http::HeaderValue::from_str(to).is_ok(),
+            "无效的重定向地址：{to}"
+        );
+        let to: Arc<str> = Arc::from(to);
+        let keep_query = !to.contains('?');
+        self.append(Route::new(from).any(move |req: Request| {
+            let location = match req.uri().query() {
+                Some(query) if keep_query => format!("{to}?{query}"),
+                _ => to.to_string(),
+            };
+            async move { Ok(crate::response::Redirect::with_status(status, location)) }
+        }))
+    }
+
+    /// 批量注册重定向，见 [`Route::redirect`]
+    pub fn redirects<I, F, T>(self, redirects: I, status: crate::StatusCode) -> Self
+    where
+        I: IntoIterator<Item = (F, T)>,
+        F: AsRef<str>,
+        T: AsRef<str>,
+    {
+        redirects.into_iter().fold(self, |route, (from, to)| {
+            route.redirect(from.as_ref(), to.as_ref(), status)
+        })
+    }
+
     pub fn hook(mut self, handler: impl MiddleWareHandler + 'static) -> Self {
         self.middlewares.push(Arc::new(handler));
         self
@@ -234,7 +378,7 @@ impl Route {
             Into<crate::error::BoxedError> + Send,
         <L::Service as tower::Service<http::Request<crate::core::req_body::ReqBody>>>::Future: Send,
     {
-        self.hook(crate::middleware::tower_compat::TowerLayerAdapter::new(
+        self.hook(crate::middleware::tower_compat::TowerLayerCompat::new(
             layer,
         ))
     }
@@ -257,6 +401,216 @@ impl Route {
         self
     }
 
+    /// 注入以 `Arc` 共享的状态，`T` 无需实现 `Clone`
+    ///
+    /// 适合连接池、模板引擎等克隆代价高或无法克隆的值，处理器可通过
+    /// `StateArc<T>` 或 `State<T>`（需 `T: Clone`）获取。
+    pub fn with_state_arc<T: Send + Sync + 'static>(mut self, val: Arc<T>) -> Self {
+        self.state
+            .get_or_insert_with(crate::State::new)
+            .insert_arc(val);
+        self
+    }
+
+    /// 设置子树的兜底处理器
+    ///
+    /// 该路由下路径未匹配的请求（原本返回 404）交由 `handler` 处理，
+    /// 嵌套的子树可设置各自的兜底处理器，就近生效；方法不匹配（405）不受影响。
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    /// use silent::response::Html;
+    ///
+    /// async fn not_found_page(_req: Request) -> Result<Html<&'static str>> {
+    ///     Ok(Html("<h1>404</h1>"))
+    /// }
+    ///
+    /// async fn api_not_found(req: Request) -> Result<Response> {
+    ///     let body = serde_json::json!({ "error": "not found", "path": req.uri().path() });
+    ///     Ok(Response::json(&body).with_status(StatusCode::NOT_FOUND))
+    /// }
+    ///
+    /// let route = Route::new("")
+    ///     .fallback(not_found_page)
+    ///     .append(Route::new("api").fallback(api_not_found));
+    /// ```
+    pub fn fallback<H, Args, M>(mut self, handler: H) -> Self
+    where
+        H: handler_append::IntoRouteHandler<Args, M>,
+    {
+        let real_route = self.get_append_real_route(&self.create_path.clone());
+        real_route.fallback = Some(handler.into_handler());
+        self
+    }
+
+    /// 设置末尾斜杠策略，需在根路由上调用
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// async fn users(_req: Request) -> Result<&'static str> {
+    ///     Ok("users")
+    /// }
+    ///
+    /// // GET /users/ 将以 301 重定向到 /users
+    /// let route = Route::new("")
+    ///     .trailing_slash(TrailingSlash::Redirect)
+    ///     .append(Route::new("users").get(users));
+    /// ```
+    pub fn trailing_slash(mut self, policy: TrailingSlash) -> Self {
+        self.trailing_slash = policy;
+        self
+    }
+
+    /// 限定当前路由（含子路由）只响应指定主机名的请求
+    ///
+    /// 主机名取自 `Host` 请求头（HTTP/2 为 `:authority`），比较时忽略大小写与端口；
+    /// 以 `*.` 开头时匹配任意子域名，如 `*.example.com` 匹配 `a.example.com`，
+    /// 但不匹配 `example.com`。主机名不符的子树视为未匹配，可为不同域名挂载各自的中间件：
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// async fn users(_req: Request) -> Result<&'static str> {
+    ///     Ok("users")
+    /// }
+    ///
+    /// async fn dashboard(_req: Request) -> Result<&'static str> {
+    ///     Ok("dashboard")
+    /// }
+    ///
+    /// let route = Route::new("")
+    ///     .append(
+    ///         Route::new("")
+    ///             .host("api.example.com")
+    ///             .append(Route::new("users").get(users)),
+    ///     )
+    ///     .append(
+    ///         Route::new("")
+    ///             .host("admin.example.com")
+    ///             .append(Route::new("dashboard").get(dashboard)),
+    ///     );
+    /// ```
+    pub fn host(mut self, host: &str) -> Self {
+        let host = host.trim().trim_end_matches('.').to_ascii_lowercase();
+        self.host = Some(host);
+        self
+    }
+
+    /// 设置当前路由（含子路由）处理函数的超时时间，超时返回 `504 Gateway Timeout`
+    ///
+    /// 子路由可再次调用以覆盖继承的值，适合个别调用慢速上游的处理函数，而无需设置全局的连接超时。
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// async fn report(_req: Request) -> Result<&'static str> {
+    ///     Ok("report")
+    /// }
+    ///
+    /// let route = Route::new("api")
+    ///     .with_timeout(Duration::from_secs(5))
+    ///     .append(Route::new("reports").with_timeout(Duration::from_secs(60)).get(report));
+    /// ```
+    #[cfg(feature = "server")]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 为当前路由（含子路由）按 `key` 分组限流，配额耗尽时返回 `429` 与 `Retry-After`
+    ///
+    /// 等价于挂载 [`RateLimit`](crate::middlewares::RateLimit) 中间件，需要自定义存储时直接使用该中间件。
+    ///
+    /// ```rust
+    /// use silent::middlewares::{Quota, RateLimitKey};
+    /// use silent::prelude::*;
+    ///
+    /// let route = Route::new("login")
+    ///     .with_rate_limit(Quota::per_minute(5), RateLimitKey::ClientIp)
+    ///     .post(|_req: Request| async { Ok("ok") });
+    /// ```
+    pub fn with_rate_limit(
+        self,
+        quota: crate::middlewares::Quota,
+        key: crate::middlewares::RateLimitKey,
+    ) -> Self {
+        self.hook(crate::middlewares::RateLimit::new(quota).key(key))
+    }
+
+    /// 为当前路由（含子路由）的每个成功响应补充响应头，处理函数已设置的同名响应头保持不变
+    ///
+    /// 等价于挂载 [`ResponseHeaders`](crate::middlewares::ResponseHeaders) 中间件。
+    /// 响应头名称或值不合法时 panic。
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// let route = Route::new("account")
+    ///     .with_headers([("Cache-Control", "no-store"), ("X-Frame-Options", "DENY")])
+    ///     .get(|_req: Request| async { Ok("ok") });
+    /// ```
+    pub fn with_headers<I, K, V>(self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let headers =
+            headers
+                .into_iter()
+                .fold(crate::middlewares::ResponseHeaders::new(), |acc, (k, v)| {
+                    let (k, v) = (k.as_ref(), v.as_ref());
+                    let name = http::HeaderName::try_from(k)
+                        .unwrap_or_else(|_| panic!("响应头名称不合法：{k}"));
+                    let value = http::HeaderValue::try_from(v)
+                        .unwrap_or_else(|_| panic!("响应头 {k} 的值不合法：{v}"));
+                    acc.header(name, value)
+                });
+        self.hook(headers)
+    }
+
+    /// 设置匹配优先级，默认为 0
+    ///
+    /// 同一层级的路由按以下顺序尝试，先能完整匹配者生效，与注册顺序无关：
+    /// 优先级高者在前；同优先级时依次为静态段、空路径分组、类型化参数（如 `<id:u64>`）、
+    /// 字符串参数（`<name>`）、路径参数（`<p:path>`）与通配符（`<p:**>`）。
+    /// 优先级作用于该路由路径上的每一段。
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// async fn by_slug(_req: Request) -> Result<&'static str> {
+    ///     Ok("slug")
+    /// }
+    ///
+    /// async fn by_id(_req: Request) -> Result<&'static str> {
+    ///     Ok("id")
+    /// }
+    ///
+    /// // 默认 /posts/42 命中 <id:u64>，提高优先级后改由 <slug> 处理
+    /// let route = Route::new("")
+    ///     .append(Route::new("posts/<id:u64>").get(by_id))
+    ///     .append(Route::new("posts/<slug>").priority(1).get(by_slug));
+    /// ```
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.set_priority(&self.create_path.clone(), priority);
+        self
+    }
+
+    fn set_priority(&mut self, create_path: &str, priority: i32) {
+        self.priority = priority;
+        if let Some((_, last_path)) = create_path.split_once('/')
+            && let Some(route) = self
+                .children
+                .iter_mut()
+                .find(|r| r.create_path == last_path)
+        {
+            route.set_priority(last_path, priority);
+        }
+    }
+
     /// 设置完整的状态容器
     pub fn set_state(&mut self, state: Option<crate::State>) {
         self.state = state;
@@ -308,10 +662,13 @@ impl Route {
 
 impl Route {
     fn merge_child(children: &mut Vec<Route>, route: Route) {
-        if let Some(existing) = children
-            .iter_mut()
-            .find(|child| child.path == route.path && child.special_match == route.special_match)
-        {
+        if let Some(existing) = children.iter_mut().find(|child| {
+            child.path == route.path
+                && child.special_match == route.special_match
+                && child.host == route.host
+                && child.version.is_none()
+                && route.version.is_none()
+        }) {
             existing.merge_from(route);
         } else {
             children.push(route);
@@ -320,14 +677,30 @@ impl Route {
 
     fn merge_from(&mut self, mut other: Route) {
         for (method, handler) in other.handler.drain() {
-            self.handler.entry(method).or_insert(handler);
+            match self.handler.get(&method) {
+                // 同一个处理函数被重复合并（如克隆的路由）不视为冲突
+                Some(existing) if Arc::ptr_eq(existing, &handler) => {}
+                Some(_) => self.duplicate_methods.push(method),
+                None => {
+                    self.handler.insert(method, handler);
+                }
+            }
         }
+        self.duplicate_methods.append(&mut other.duplicate_methods);
 
         let middlewares = std::mem::take(&mut other.middlewares);
         if !middlewares.is_empty() {
             self.middlewares.extend(middlewares);
         }
 
+        if self.fallback.is_none() {
+            self.fallback = other.fallback.take();
+        }
+        if self.timeout.is_none() {
+            self.timeout = other.timeout;
+        }
+        self.priority = self.priority.max(other.priority);
+
         let children = std::mem::take(&mut other.children);
         for child in children {
             Self::merge_child(&mut self.children, child);
@@ -759,4 +1132,34 @@ mod tests {
         let debug_str = format!("{:?}", route);
         assert!(debug_str.contains("api"));
     }
+
+    // ==================== 重定向测试 ====================
+
+    #[tokio::test]
+    async fn test_route_redirect() {
+        let route = Route::new("")
+            .redirect("/old", "/new", crate::StatusCode::MOVED_PERMANENTLY)
+            .redirects(
+                [("docs/v1", "/docs?version=1")],
+                crate::StatusCode::PERMANENT_REDIRECT,
+            );
+
+        for (method, uri, status, location) in [
+            (Method::GET, "/old?page=2", 301, "/new?page=2"),
+            (Method::POST, "/docs/v1?page=2", 308, "/docs?version=1"),
+        ] {
+            let mut req = Request::empty();
+            *req.method_mut() = method;
+            *req.uri_mut() = uri.parse().unwrap();
+            let res = Handler::call(&route, req).await.unwrap();
+            assert_eq!(res.status().as_u16(), status);
+            assert_eq!(res.headers()[http::header::LOCATION], location);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "重定向状态码必须为 3xx")]
+    fn test_route_redirect_requires_redirect_status() {
+        let _ = Route::new("").redirect("/old", "/new", crate::StatusCode::OK);
+    }
 }
@@ -0,0 +1,132 @@
+use crate::Method;
+use crate::route::Route;
+use crate::route::route_service::join_pattern;
+
+/// 路由表中的一条记录，由 [`Route::iter_routes`] 产生
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RouteInfo {
+    /// 请求方法
+    pub method: Method,
+    /// 完整路由模板，如 `/users/<id:u64>`，与 [`MatchedPath`](crate::extractor::MatchedPath) 一致
+    pub pattern: String,
+    /// 请求到达该处理器前是否经过中间件（含祖先路由上的中间件）
+    pub has_middleware: bool,
+    /// 所在子树的主机名约束，见 [`Route::host`]
+    pub host: Option<String>,
+}
+
+impl Route {
+    /// 遍历已注册的路由，每个路径上的每个方法对应一条记录
+    ///
+    /// 按注册顺序深度优先遍历，同一路径的方法按名称排序，可用于启动时打印路由表、生成站点地图或对接权限系统。
+    ///
+    /// ```rust
+    /// use silent::prelude::*;
+    ///
+    /// async fn hello(_req: Request) -> Result<&'static str> {
+    ///     Ok("hello")
+    /// }
+    ///
+    /// let route = Route::new("api")
+    ///     .append(Route::new("users").get(hello).post(hello))
+    ///     .append(Route::new("users/<id:u64>").get(hello));
+    /// for info in route.iter_routes() {
+    ///     println!("{:<6} {}", info.method, info.pattern);
+    /// }
+    /// ```
+    pub fn iter_routes(&self) -> impl Iterator<Item = RouteInfo> {
+        let mut routes = Vec::new();
+        collect_routes(self, "", false, None, &mut routes);
+        routes.into_iter()
+    }
+}
+
+fn collect_routes(
+    route: &Route,
+    parent_pattern: &str,
+    inherited_middleware: bool,
+    inherited_host: Option<&str>,
+    routes: &mut Vec<RouteInfo>,
+) {
+    let pattern = join_pattern(parent_pattern, &route.path);
+    let has_middleware = inherited_middleware || !route.middlewares.is_empty();
+    let host = route.host.as_deref().or(inherited_host);
+
+    let mut methods: Vec<&Method> = route.handler.keys().collect();
+    methods.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    routes.extend(methods.into_iter().map(|method| RouteInfo {
+        method: method.clone(),
+        pattern: pattern.clone(),
+        has_middleware,
+        host: host.map(str::to_string),
+    }));
+
+    for child in &route.children {
+        collect_routes(child, &pattern, has_middleware, host, routes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Handler, Request, Response, Result};
+
+    async fn hello(_req: Request) -> Result<&'static str> {
+        Ok("hello")
+    }
+
+    #[derive(Clone)]
+    struct Noop;
+
+    #[async_trait::async_trait]
+    impl crate::MiddleWareHandler for Noop {
+        async fn handle(&self, req: Request, next: &crate::Next) -> Result<Response> {
+            next.call(req).await
+        }
+    }
+
+    #[test]
+    fn test_iter_routes() {
+        let route = Route::new("")
+            .get(hello)
+            .append(Route::new("users").post(hello).get(hello))
+            .append(
+                Route::new("admin")
+                    .hook(Noop)
+                    .append(Route::new("users/<id:u64>").delete(hello)),
+            )
+            .append(
+                Route::new("")
+                    .host("api.example.com")
+                    .append(Route::new("status").get(hello)),
+            );
+
+        let routes: Vec<_> = route
+            .iter_routes()
+            .map(|info| {
+                (
+                    info.method.to_string(),
+                    info.pattern,
+                    info.has_middleware,
+                    info.host,
+                )
+            })
+            .collect();
+        assert_eq!(
+            routes,
+            vec![
+                ("GET".into(), "/".into(), false, None),
+                ("GET".into(), "/users".into(), false, None),
+                ("POST".into(), "/users".into(), false, None),
+                ("DELETE".into(), "/admin/users/<id:u64>".into(), true, None),
+                (
+                    "GET".into(),
+                    "/status".into(),
+                    false,
+                    Some("api.example.com".into())
+                ),
+            ]
+        );
+    }
+}
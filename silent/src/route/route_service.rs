@@ -1,9 +1,11 @@
+use crate::error::SilentResult;
 use crate::middleware::MiddleWareHandler;
 use crate::route::route_tree::parse_special_seg;
 use crate::route::{Route, RouteTree};
 use smallvec::SmallVec;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub trait RouteService {
     fn route(self) -> Route;
@@ -17,24 +19,47 @@ impl RouteService for Route {
 
 impl Route {
     /// 递归将Route转换为RouteTree，并调用 freeze() 预构建 Arc 引用
+    ///
+    /// 路由冲突时 panic，需要自行处理冲突时使用 [`try_convert_to_route_tree`](Self::try_convert_to_route_tree)。
     pub(crate) fn convert_to_route_tree(self) -> RouteTree {
+        self.try_convert_to_route_tree()
+            .unwrap_or_else(|e| panic!("{}", e.message()))
+    }
+
+    /// 同 [`convert_to_route_tree`](Self::convert_to_route_tree)，路由冲突时返回错误
+    pub(crate) fn try_convert_to_route_tree(self) -> SilentResult<RouteTree> {
         let empty: Arc<[Arc<dyn MiddleWareHandler>]> = Arc::from(Vec::new());
-        self.into_route_tree_with_chain(empty).freeze()
+        Ok(self.into_route_tree_with_chain(empty, "", None)?.freeze())
     }
 
     fn into_route_tree_with_chain(
         self,
         inherited_middlewares: Arc<[Arc<dyn MiddleWareHandler>]>,
-    ) -> RouteTree {
+        parent_pattern: &str,
+        inherited_timeout: Option<Duration>,
+    ) -> SilentResult<RouteTree> {
         let Route {
             path,
             handler,
             children,
             middlewares,
             state,
+            fallback,
+            trailing_slash,
+            host,
+            mount_point,
+            timeout,
+            priority,
+            duplicate_methods,
+            version,
             ..
         } = self;
+        let timeout = timeout.or(inherited_timeout);
 
+        let pattern = join_pattern(parent_pattern, &path);
+        if let Some(method) = duplicate_methods.first() {
+            return Err(format!("路由冲突：{method} {pattern} 被重复注册").into());
+        }
         let segment = parse_special_seg(path);
         let has_handler = !handler.is_empty();
 
@@ -49,22 +74,39 @@ impl Route {
             Arc::from(merged)
         };
 
-        let children: Vec<RouteTree> = children
+        let children = children
             .into_iter()
-            .map(|child| child.into_route_tree_with_chain(current_middlewares.clone()))
-            .collect();
+            .map(|child| {
+                child.into_route_tree_with_chain(current_middlewares.clone(), &pattern, timeout)
+            })
+            .collect::<SilentResult<Vec<RouteTree>>>()?;
+        for (i, a) in children.iter().enumerate() {
+            for b in &children[i + 1..] {
+                if let Some((method, a, b)) = a.find_conflict(b) {
+                    return Err(format!(
+                        "路由冲突：{method} {a} 与 {method} {b} 可能匹配同一请求，\
+                         请合并为一个路由或通过 Route::priority 指定匹配顺序"
+                    )
+                    .into());
+                }
+            }
+        }
 
+        // 同名的静态段（如限定了不同主机名）只有一个进入索引，其余与动态段一起按匹配顺序尝试
         let mut static_children = HashMap::new();
         let mut dynamic_children = SmallVec::<[usize; 4]>::new();
         for (idx, child) in children.iter().enumerate() {
-            if let Some(key) = child.segment.as_static_key() {
-                static_children.insert(key.into(), idx);
-            } else {
-                dynamic_children.push(idx);
+            match child.segment.as_static_key() {
+                Some(key) if child.host.is_none() && !static_children.contains_key(key) => {
+                    static_children.insert(key.into(), idx);
+                }
+                _ => dynamic_children.push(idx),
             }
         }
+        dynamic_children
+            .sort_by(|&a, &b| (children[a].order_key(), a).cmp(&(children[b].order_key(), b)));
 
-        RouteTree {
+        Ok(RouteTree {
             children,
             handler,
             middlewares: current_middlewares,
@@ -74,11 +116,32 @@ impl Route {
             state,
             segment,
             has_handler,
+            fallback,
+            trailing_slash,
+            host: host.map(Into::into),
+            version,
+            mount_point,
+            timeout,
+            priority,
+            pattern: Arc::from(pattern),
             self_arc: None,
-        }
+        })
     }
 
     pub fn into_route_tree(self) -> RouteTree {
         self.convert_to_route_tree()
     }
 }
+
+/// 拼接父级路由模板与当前路径段，根路由为 `/`
+pub(super) fn join_pattern(parent: &str, path: &str) -> String {
+    let parent = parent.trim_end_matches('/');
+    if path.is_empty() {
+        return if parent.is_empty() {
+            "/".to_string()
+        } else {
+            parent.to_string()
+        };
+    }
+    format!("{parent}/{}", path.trim_start_matches('/'))
+}
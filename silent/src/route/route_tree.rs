@@ -3,14 +3,59 @@ use memchr::memchr;
 use smallvec::SmallVec;
 use std::collections::HashMap;
 use std::ops::Range;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
+use crate::configs::{JsonConfig, JsonScope};
 use crate::core::path_param::PathParam;
+use crate::extractor::{MatchedPath, OriginalUri};
 use crate::handler::Handler;
 use crate::middleware::MiddleWareHandler;
 use crate::route::handler_match::SpecialPath;
+use crate::route::versioned::VersionGuard;
+use crate::route::{Dispatcher, TrailingSlash};
 use crate::{Method, Next, Request, Response, SilentError};
 
+/// 命中的路由模板与路由参数，由外层中间件放入请求扩展，路由树在调用处理函数前回填
+///
+/// 处理函数收到的是移动后的请求，外层中间件无法读取其中的 [`MatchedPath`] 与路由参数，
+/// 因此通过共享的槽位回传。路由参数仅在以 [`with_params`](Self::with_params) 创建时记录。
+#[derive(Clone, Default)]
+pub(crate) struct MatchedRoute {
+    pattern: Arc<OnceLock<Arc<str>>>,
+    params: Option<Arc<OnceLock<HashMap<String, String>>>>,
+}
+
+/// 请求路径带末尾斜杠且策略不是 [`TrailingSlash::MergeBoth`] 时，由根节点放入请求扩展
+#[derive(Clone, Copy)]
+struct TrailingSlashPolicy(TrailingSlash);
+
+impl MatchedRoute {
+    /// 同时记录路由参数
+    pub(crate) fn with_params() -> Self {
+        Self {
+            pattern: Arc::default(),
+            params: Some(Arc::default()),
+        }
+    }
+
+    fn set(&self, pattern: &Arc<str>, req: &Request) {
+        let _ = self.pattern.set(Arc::clone(pattern));
+        if let Some(params) = &self.params {
+            let _ = params.set(req.path_params_map());
+        }
+    }
+
+    /// 命中的路由模板，未命中任何路由时为 `None`
+    pub(crate) fn pattern(&self) -> Option<&str> {
+        self.pattern.get().map(|pattern| &**pattern)
+    }
+
+    /// 命中路由的路由参数
+    pub(crate) fn params(&self) -> Option<&HashMap<String, String>> {
+        self.params.as_ref().and_then(|params| params.get())
+    }
+}
+
 /// 零分配的 not found 错误
 #[inline]
 fn not_found_error() -> SilentError {
@@ -21,15 +66,37 @@ fn not_found_error() -> SilentError {
 pub(crate) enum SpecialSeg {
     Root,
     Static(Box<str>),
-    String { key: Box<str> },
-    Int { key: Box<str> },
-    I64 { key: Box<str> },
-    I32 { key: Box<str> },
-    U64 { key: Box<str> },
-    U32 { key: Box<str> },
-    Uuid { key: Box<str> },
-    Path { key: Box<str> },
-    FullPath { key: Box<str> },
+    String {
+        key: Box<str>,
+    },
+    Int {
+        key: Box<str>,
+    },
+    I64 {
+        key: Box<str>,
+    },
+    I32 {
+        key: Box<str>,
+    },
+    U64 {
+        key: Box<str>,
+    },
+    U32 {
+        key: Box<str>,
+    },
+    Uuid {
+        key: Box<str>,
+    },
+    /// RFC 3339 时间，统一转换为 UTC
+    DateTime {
+        key: Box<str>,
+    },
+    Path {
+        key: Box<str>,
+    },
+    FullPath {
+        key: Box<str>,
+    },
 }
 
 impl SpecialSeg {
@@ -37,6 +104,43 @@ impl SpecialSeg {
         matches!(self, SpecialSeg::FullPath { .. })
     }
 
+    /// 同优先级下的匹配顺序，越小越先尝试
+    fn rank(&self) -> u8 {
+        match self {
+            SpecialSeg::Static(_) => 0,
+            SpecialSeg::Root => 1,
+            SpecialSeg::Int { .. }
+            | SpecialSeg::I64 { .. }
+            | SpecialSeg::I32 { .. }
+            | SpecialSeg::U64 { .. }
+            | SpecialSeg::U32 { .. }
+            | SpecialSeg::Uuid { .. }
+            | SpecialSeg::DateTime { .. } => 2,
+            SpecialSeg::String { .. } => 3,
+            SpecialSeg::Path { .. } => 4,
+            SpecialSeg::FullPath { .. } => 5,
+        }
+    }
+
+    /// 两个同级段是否可能匹配同一路径段，仅比较同一匹配顺序的段，
+    /// 不同顺序的段（如 `<id:u64>` 与 `<name>`）由匹配优先级决定，不视为重叠
+    fn overlaps(&self, other: &SpecialSeg) -> bool {
+        use SpecialSeg::*;
+        match (self, other) {
+            (Static(a), Static(b)) => a == b,
+            (
+                Int { .. } | I64 { .. } | I32 { .. } | U64 { .. } | U32 { .. },
+                Int { .. } | I64 { .. } | I32 { .. } | U64 { .. } | U32 { .. },
+            )
+            | (Uuid { .. }, Uuid { .. })
+            | (DateTime { .. }, DateTime { .. })
+            | (String { .. }, String { .. })
+            | (Path { .. }, Path { .. })
+            | (FullPath { .. }, FullPath { .. }) => true,
+            _ => false,
+        }
+    }
+
     pub(crate) fn as_static_key(&self) -> Option<&str> {
         if let SpecialSeg::Static(value) = self {
             Some(value)
@@ -74,6 +178,9 @@ pub(crate) fn parse_special_seg(raw: String) -> SpecialSeg {
             SpecialPath::UUid(key) => SpecialSeg::Uuid {
                 key: key.into_boxed_str(),
             },
+            SpecialPath::DateTime(key) => SpecialSeg::DateTime {
+                key: key.into_boxed_str(),
+            },
             SpecialPath::Path(key) => SpecialSeg::Path {
                 key: key.into_boxed_str(),
             },
@@ -118,6 +225,7 @@ enum PathMatchCapture {
     U64(u64),
     U32(u32),
     Uuid(uuid::Uuid),
+    DateTime(chrono::DateTime<chrono::Utc>),
 }
 
 #[derive(Clone)]
@@ -131,6 +239,22 @@ pub struct RouteTree {
     pub(crate) state: Option<crate::State>,
     pub(crate) segment: SpecialSeg,
     pub(crate) has_handler: bool,
+    /// 子树内未匹配请求的兜底处理器
+    pub(crate) fallback: Option<Arc<dyn Handler>>,
+    /// 末尾斜杠策略，仅根节点使用
+    pub(crate) trailing_slash: TrailingSlash,
+    /// 主机名约束，已转为小写
+    pub(crate) host: Option<Box<str>>,
+    /// API 版本约束，见 [`Versioned`](crate::route::Versioned)
+    pub(crate) version: Option<Arc<VersionGuard>>,
+    /// 子应用挂载点，进入时去除已匹配的路径前缀
+    pub(crate) mount_point: bool,
+    /// 处理函数超时时间，已合并祖先路由的设置
+    pub(crate) timeout: Option<std::time::Duration>,
+    /// 匹配优先级，同级节点中数值大者先尝试
+    pub(crate) priority: i32,
+    /// 从根路由到当前节点的完整路由模板，如 `/users/<id:u64>`
+    pub(crate) pattern: Arc<str>,
     /// 预构建的 Arc 自引用，避免 call_with_path 中每次请求深拷贝
     pub(crate) self_arc: Option<Arc<RouteTree>>,
 }
@@ -158,6 +282,14 @@ impl RouteTree {
             state: self.state.clone(),
             segment: self.segment.clone(),
             has_handler: self.has_handler,
+            fallback: self.fallback.clone(),
+            trailing_slash: self.trailing_slash,
+            host: self.host.clone(),
+            version: self.version.clone(),
+            mount_point: self.mount_point,
+            timeout: self.timeout,
+            priority: self.priority,
+            pattern: Arc::clone(&self.pattern),
             self_arc: None, // Arc 内部不需要再持有 self_arc
         });
         self.self_arc = Some(arc);
@@ -242,6 +374,19 @@ impl RouteTree {
                     Err(_) => None,
                 }
             }
+            SpecialSeg::DateTime { .. } => {
+                let (segment, remain) = strip_one_segment(path);
+                if segment.is_empty() {
+                    return None;
+                }
+                match chrono::DateTime::parse_from_rfc3339(segment) {
+                    Ok(v) => Some(PathMatch::new(
+                        remain,
+                        Some(PathMatchCapture::DateTime(v.with_timezone(&chrono::Utc))),
+                    )),
+                    Err(_) => None,
+                }
+            }
             SpecialSeg::Path { .. } => {
                 let (segment, remain) = strip_one_segment(path);
                 Some(PathMatch::new(
@@ -291,6 +436,10 @@ impl RouteTree {
                 req.set_path_params(key.to_string(), (*value).into());
                 true
             }
+            (SpecialSeg::DateTime { key }, Some(PathMatchCapture::DateTime(value))) => {
+                req.set_path_params(key.to_string(), (*value).into());
+                true
+            }
             (SpecialSeg::Path { key }, Some(PathMatchCapture::Path(captured))) => {
                 req.set_path_params(
                     key.to_string(),
@@ -318,6 +467,9 @@ impl RouteTree {
         if let Some(state) = self.get_state() {
             req.state_mut().extend_from(state);
         }
+        if self.mount_point {
+            strip_mount_prefix(&mut req, &path[offset..]);
+        }
 
         let middleware_slice = &self.middlewares[self.middleware_start..];
 
@@ -342,6 +494,66 @@ impl RouteTree {
         next.call(req).await
     }
 
+    /// 匹配顺序的排序键：优先级高者在前，同优先级时依次为静态段、分组、类型化参数、
+    /// 字符串参数、路径参数与通配符，仍相同时按路由模板排序，与注册顺序无关
+    pub(crate) fn order_key(&self) -> (std::cmp::Reverse<i32>, u8, &str) {
+        (
+            std::cmp::Reverse(self.priority),
+            self.segment.rank(),
+            &self.pattern,
+        )
+    }
+
+    /// 查找两个同级节点之间的路由冲突：同一方法下可能匹配同一请求、且匹配顺序无法区分的两个处理函数
+    ///
+    /// 返回冲突的方法与双方的路由模板。主机名或优先级不同的节点视为有意区分，不做检查。
+    pub(crate) fn find_conflict(&self, other: &RouteTree) -> Option<(Method, Arc<str>, Arc<str>)> {
+        if self.host != other.host
+            || self.priority != other.priority
+            || !self.segment.overlaps(&other.segment)
+        {
+            return None;
+        }
+        let mut methods: Vec<&Method> = self
+            .handler
+            .keys()
+            .filter(|method| other.handler.contains_key(*method))
+            .collect();
+        methods.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        if let Some(method) = methods.first() {
+            return Some((
+                (*method).clone(),
+                Arc::clone(&self.pattern),
+                Arc::clone(&other.pattern),
+            ));
+        }
+        self.children
+            .iter()
+            .find_map(|a| other.children.iter().find_map(|b| a.find_conflict(b)))
+    }
+
+    /// 按匹配顺序列出可能匹配 `remain` 的子节点
+    fn candidates(&self, remain: &str) -> SmallVec<[usize; 8]> {
+        let mut candidates: SmallVec<[usize; 8]> = self.dynamic_children.iter().copied().collect();
+        let mut insert = |idx: usize| {
+            let key = (self.children[idx].order_key(), idx);
+            let pos = candidates
+                .iter()
+                .position(|&other| (self.children[other].order_key(), other) > key)
+                .unwrap_or(candidates.len());
+            candidates.insert(pos, idx);
+        };
+        if remain.is_empty() {
+            self.static_children.values().copied().for_each(&mut insert);
+        } else {
+            let (segment, _) = strip_one_segment(remain);
+            if let Some(&idx) = self.static_children.get(segment) {
+                insert(idx);
+            }
+        }
+        candidates
+    }
+
     async fn call_children(
         &self,
         req: Request,
@@ -351,23 +563,14 @@ impl RouteTree {
         let full_path = path.as_ref();
         let remain_slice = &full_path[offset..];
 
-        let mut candidate_indices: SmallVec<[usize; 8]> = SmallVec::new();
-        if remain_slice.is_empty() {
-            candidate_indices.extend(self.static_children.values().copied());
-            candidate_indices.extend(self.dynamic_children.iter().copied());
-        } else {
-            let (segment, _) = strip_one_segment(remain_slice);
-            if let Some(&idx) = self.static_children.get(segment) {
-                candidate_indices.push(idx);
-            }
-            candidate_indices.extend(self.dynamic_children.iter().copied());
-        }
-
-        for idx in candidate_indices {
+        for idx in self.candidates(remain_slice) {
             let child = &self.children[idx];
+            if !child.accepts(&req) {
+                continue;
+            }
             if let Some(candidate) = child.call_path_only(remain_slice, full_path) {
                 let next_offset = remain_offset(full_path, candidate.remain);
-                if !child.path_can_resolve(next_offset, full_path) {
+                if !child.path_can_resolve(next_offset, full_path, &req) {
                     continue;
                 }
                 let mut real_req = req;
@@ -380,46 +583,177 @@ impl RouteTree {
             }
         }
 
-        if remain_slice.is_empty() {
-            return if self.has_handler {
-                self.handler.call(req).await
-            } else {
-                Err(not_found_error())
-            };
+        if remain_slice.is_empty() && self.has_handler {
+            match self.trailing_slash_policy(&req) {
+                TrailingSlash::MergeBoth => return self.call_handler(req).await,
+                TrailingSlash::Redirect => return self.redirect_trailing_slash(&req, full_path),
+                TrailingSlash::Strict => {}
+            }
         }
 
         if self.segment.is_full_path() && self.has_handler {
-            return self.handler.call(req).await;
+            return self.call_handler(req).await;
         }
 
-        Err(not_found_error())
+        match &self.fallback {
+            Some(fallback) => fallback.call(req).await,
+            None => Err(not_found_error()),
+        }
     }
 
-    fn path_can_resolve(&self, offset: usize, full_path: &str) -> bool {
-        let remain = &full_path[offset..];
-        let mut candidate_indices: SmallVec<[usize; 8]> = SmallVec::new();
-        if remain.is_empty() {
-            candidate_indices.extend(self.static_children.values().copied());
-            candidate_indices.extend(self.dynamic_children.iter().copied());
+    /// 调用当前节点的处理器，并记录命中的路由模板
+    async fn call_handler(&self, mut req: Request) -> crate::error::SilentResult<Response> {
+        req.extensions_mut()
+            .insert(MatchedPath::new(Arc::clone(&self.pattern)));
+        if let Some(matched) = req.extensions().get::<MatchedRoute>() {
+            matched.set(&self.pattern, &req);
+        }
+        #[cfg(feature = "server")]
+        if let Some(timeout) = self.timeout {
+            if let Some(deadline) = req
+                .extensions()
+                .get::<crate::middlewares::TimeoutDeadline>()
+            {
+                deadline.reset(timeout);
+            }
+            return tokio::time::timeout(timeout, self.invoke_handler(req))
+                .await
+                .unwrap_or_else(|_| {
+                    Err(SilentError::business_error(
+                        http::StatusCode::GATEWAY_TIMEOUT,
+                        "handler timed out",
+                    ))
+                });
+        }
+        self.invoke_handler(req).await
+    }
+
+    async fn invoke_handler(&self, req: Request) -> crate::error::SilentResult<Response> {
+        // 注入了 JsonConfig 时，处理函数内的 JSON 序列化按该配置进行
+        if let Ok(config) = req.get_state::<JsonConfig>().cloned() {
+            return JsonScope::new(config, self.handler.call(req)).await;
+        }
+        self.handler.call(req).await
+    }
+
+    /// 当前节点对带末尾斜杠请求的处理策略，通配整段路径的节点总是按原样匹配
+    fn trailing_slash_policy(&self, req: &Request) -> TrailingSlash {
+        if self.segment.is_full_path() {
+            return TrailingSlash::MergeBoth;
+        }
+        req.extensions()
+            .get::<TrailingSlashPolicy>()
+            .map_or(TrailingSlash::MergeBoth, |policy| policy.0)
+    }
+
+    /// 将带末尾斜杠的请求重定向到规范路径
+    ///
+    /// 路径开头连续的 `/` 与 `\` 合并为一个 `/`，避免 `//evil.com/` 被浏览器当作协议相对地址跳转到外站。
+    fn redirect_trailing_slash(
+        &self,
+        req: &Request,
+        full_path: &str,
+    ) -> crate::error::SilentResult<Response> {
+        let path = full_path
+            .trim_start_matches(['/', '\\'])
+            .trim_end_matches('/');
+        let location = match req.uri().query() {
+            Some(query) => format!("/{path}?{query}"),
+            None => format!("/{path}"),
+        };
+        let status = if matches!(*req.method(), Method::GET | Method::HEAD) {
+            http::StatusCode::MOVED_PERMANENTLY
         } else {
-            let (segment, _) = strip_one_segment(remain);
-            if let Some(&idx) = self.static_children.get(segment) {
-                candidate_indices.push(idx);
+            http::StatusCode::PERMANENT_REDIRECT
+        };
+        let location = http::HeaderValue::try_from(location).map_err(|_| not_found_error())?;
+        Ok(Response::empty()
+            .with_status(status)
+            .with_header(http::header::LOCATION, location))
+    }
+
+    /// 当前节点的主机名与版本约束是否与请求相符，无约束时总是相符
+    fn accepts(&self, req: &Request) -> bool {
+        self.host_matches(req)
+            && self
+                .version
+                .as_ref()
+                .is_none_or(|version| version.matches(req))
+    }
+
+    /// 当前节点的主机名约束是否与请求相符，无约束时总是相符
+    fn host_matches(&self, req: &Request) -> bool {
+        let Some(pattern) = self.host.as_deref() else {
+            return true;
+        };
+        let Some(host) = request_host(req) else {
+            return false;
+        };
+        match pattern.strip_prefix("*.") {
+            Some(suffix) => {
+                host.len() > suffix.len() + 1
+                    && host.as_bytes()[host.len() - suffix.len() - 1] == b'.'
+                    && host[host.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
             }
-            candidate_indices.extend(self.dynamic_children.iter().copied());
+            None => host.eq_ignore_ascii_case(pattern),
         }
+    }
 
-        for idx in candidate_indices {
+    fn path_can_resolve(&self, offset: usize, full_path: &str, req: &Request) -> bool {
+        let remain = &full_path[offset..];
+        for idx in self.candidates(remain) {
             let child = &self.children[idx];
+            if !child.accepts(req) {
+                continue;
+            }
             if let Some(candidate) = child.call_path_only(remain, full_path) {
                 let next_offset = remain_offset(full_path, candidate.remain);
-                if child.path_can_resolve(next_offset, full_path) {
+                if child.path_can_resolve(next_offset, full_path, req) {
                     return true;
                 }
             }
         }
 
-        remain.is_empty() || self.segment.is_full_path()
+        (remain.is_empty() && self.trailing_slash_policy(req) != TrailingSlash::Strict)
+            || self.segment.is_full_path()
+            || self.fallback.is_some()
+    }
+
+    /// 按与 [`call_children`](Self::call_children) 相同的规则查找将处理请求的节点，
+    /// 只做路径、主机名与版本匹配，不执行中间件与处理函数；落入兜底处理器或未命中时返回 `None`
+    fn resolve(&self, offset: usize, full_path: &str, req: &Request) -> Option<&RouteTree> {
+        let remain = &full_path[offset..];
+        for idx in self.candidates(remain) {
+            let child = &self.children[idx];
+            if !child.accepts(req) {
+                continue;
+            }
+            if let Some(candidate) = child.call_path_only(remain, full_path) {
+                let next_offset = remain_offset(full_path, candidate.remain);
+                if child.path_can_resolve(next_offset, full_path, req) {
+                    return child.resolve(next_offset, full_path, req);
+                }
+            }
+        }
+
+        let matched = (remain.is_empty()
+            && self.trailing_slash_policy(req) == TrailingSlash::MergeBoth)
+            || self.segment.is_full_path();
+        (matched && self.has_handler).then_some(self)
+    }
+
+    /// 请求路径命中的路由自动生成的 `Allow` 头，从根节点匹配且不执行处理函数，未命中时返回 `None`
+    pub(crate) fn allow_header(&self, req: &Request) -> Option<http::HeaderValue> {
+        if !self.accepts(req) {
+            return None;
+        }
+        let path = match req.extensions().get::<OriginalUri>() {
+            Some(OriginalUri(uri)) => uri.path(),
+            None => req.uri().path(),
+        };
+        let candidate = self.call_path_only(path, path)?;
+        let node = self.resolve(remain_offset(path, candidate.remain), path, req)?;
+        Some(crate::handler::allow_header(&node.handler))
     }
 }
 
@@ -430,8 +764,25 @@ impl Handler for RouteTree {
         if let Some(state) = self.get_state().cloned() {
             *req.state_mut() = state;
         }
+        if !self.accepts(&req) {
+            return Err(not_found_error());
+        }
+        if req.extensions().get::<Dispatcher>().is_none()
+            && let Some(tree) = &self.self_arc
+        {
+            req.extensions_mut()
+                .insert(Dispatcher::new(Arc::clone(tree)));
+        }
 
-        let path_source = Arc::<str>::from(req.uri().path().to_string());
+        let path = req.uri().path();
+        if path.len() > 1 && path.ends_with('/') && self.trailing_slash != TrailingSlash::MergeBoth
+        {
+            let policy = TrailingSlashPolicy(self.trailing_slash);
+            req.extensions_mut().insert(policy);
+        }
+
+        let path = req.uri().path();
+        let path_source = Arc::<str>::from(path.to_string());
         let full_path = &*path_source;
         req.set_path_source(path_source.clone());
 
@@ -477,6 +828,47 @@ impl Handler for ContinuationHandler {
     }
 }
 
+/// 将请求 URI 的路径改写为挂载点之后的剩余部分，并保留最初的 URI
+fn strip_mount_prefix(req: &mut Request, remain: &str) {
+    let path = if remain.starts_with('/') {
+        remain.to_string()
+    } else {
+        format!("/{remain}")
+    };
+    let path_and_query = match req.uri().query() {
+        Some(query) => format!("{path}?{query}"),
+        None => path,
+    };
+    let mut parts = req.uri().clone().into_parts();
+    let Ok(path_and_query) = path_and_query.parse() else {
+        return;
+    };
+    parts.path_and_query = Some(path_and_query);
+    let Ok(uri) = http::Uri::from_parts(parts) else {
+        return;
+    };
+    if req.extensions().get::<OriginalUri>().is_none() {
+        let original = OriginalUri(req.uri().clone());
+        req.extensions_mut().insert(original);
+    }
+    *req.uri_mut() = uri;
+}
+
+/// 请求的主机名：优先取 `Host` 头，其次取 URI 中的 authority，去除端口与末尾的 `.`
+pub(crate) fn request_host(req: &Request) -> Option<&str> {
+    let authority = match req.headers().get(http::header::HOST) {
+        Some(value) => value.to_str().ok()?,
+        None => req.uri().authority()?.as_str(),
+    };
+    let host = if authority.starts_with('[') {
+        // IPv6 字面量，如 `[::1]:8080`
+        &authority[..=authority.find(']')?]
+    } else {
+        authority.split(':').next().unwrap_or(authority)
+    };
+    Some(host.trim_end_matches('.'))
+}
+
 fn strip_leading_slash(path: &str) -> &str {
     path.strip_prefix('/').unwrap_or(path)
 }
@@ -879,6 +1271,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_special_seg_datetime_param() {
+        match parse_special_seg("<ts:datetime>".to_string()) {
+            SpecialSeg::DateTime { key } => assert_eq!(&*key, "ts"),
+            _ => panic!("Expected DateTime segment"),
+        }
+    }
+
     #[test]
     fn test_parse_special_seg_path_param() {
         match parse_special_seg("<path:path>".to_string()) {
@@ -1116,6 +1516,20 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_route_tree_call_path_only_datetime_param() {
+        let route = Route::new("<ts:datetime>").get(hello);
+        let tree = route.convert_to_route_tree();
+
+        for path in ["/2024-05-01T08:30:00Z", "/2024-05-01T16:30:00+08:00"] {
+            assert!(tree.call_path_only(path, path).is_some());
+        }
+        // 非 RFC 3339 时间不匹配，最终返回 404
+        for path in ["/2024-05-01", "/yesterday"] {
+            assert!(tree.call_path_only(path, path).is_none());
+        }
+    }
+
     #[test]
     fn test_route_tree_call_path_only_path_param() {
         let route = Route::new("<path:path>").get(hello);
@@ -1186,7 +1600,7 @@ mod tests {
         let tree = route.convert_to_route_tree();
 
         // 叶子节点，没有子节点，应该可以解析
-        let result = tree.path_can_resolve(4, "/api");
+        let result = tree.path_can_resolve(4, "/api", &Request::empty());
         assert!(result);
     }
 
@@ -1198,7 +1612,7 @@ mod tests {
         let tree = route.convert_to_route_tree();
 
         // 有子节点，但路径为空，有 handler，应该可以解析
-        let result = tree.path_can_resolve(4, "/api");
+        let result = tree.path_can_resolve(4, "/api", &Request::empty());
         assert!(result);
     }
 
@@ -1208,7 +1622,7 @@ mod tests {
         let tree = route.convert_to_route_tree();
 
         // FullPath 节点，应该可以解析
-        let result = tree.path_can_resolve(0, "/api/users/123");
+        let result = tree.path_can_resolve(0, "/api/users/123", &Request::empty());
         assert!(result);
     }
 
@@ -1247,6 +1661,448 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_route_tree_auto_options() {
+        let route = Route::new("").append(
+            Route::new("users/<id:u64>")
+                .get(hello)
+                .delete(hello)
+                .append(Route::new("avatar").put(hello).options(hello)),
+        );
+        let tree = route.convert_to_route_tree();
+
+        let mut req = Request::empty();
+        *req.method_mut() = Method::OPTIONS;
+        *req.uri_mut() = "/users/1".parse().unwrap();
+        let res = tree.call(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            res.headers().get(http::header::ALLOW).unwrap(),
+            "DELETE, GET, HEAD, OPTIONS"
+        );
+
+        // 显式注册的 OPTIONS 处理器优先
+        let mut req = Request::empty();
+        *req.method_mut() = Method::OPTIONS;
+        *req.uri_mut() = "/users/1/avatar".parse().unwrap();
+        let res = tree.call(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert!(res.headers().get(http::header::ALLOW).is_none());
+
+        // 未匹配的路径仍返回 404
+        let mut req = Request::empty();
+        *req.method_mut() = Method::OPTIONS;
+        *req.uri_mut() = "/posts".parse().unwrap();
+        let err = tree.call(req).await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_route_tree_fallback() {
+        async fn site_404(_req: Request) -> Result<String, SilentError> {
+            Ok("site 404".into())
+        }
+        async fn api_404(req: Request) -> Result<String, SilentError> {
+            Ok(format!("api 404: {}", req.uri().path()))
+        }
+
+        let route = Route::new("")
+            .fallback(site_404)
+            .append(Route::new("about").get(hello))
+            .append(
+                Route::new("api")
+                    .fallback(api_404)
+                    .append(Route::new("users/<id:u64>").get(hello)),
+            );
+        let tree = route.convert_to_route_tree();
+
+        async fn body_of(tree: &RouteTree, path: &str) -> String {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            let res = tree.call(req).await.unwrap();
+            let body = res.body.collect().await.unwrap().to_bytes();
+            String::from_utf8(body.to_vec()).unwrap()
+        }
+
+        assert_eq!(body_of(&tree, "/about").await, "hello");
+        assert_eq!(body_of(&tree, "/missing").await, "site 404");
+        assert_eq!(body_of(&tree, "/about/more").await, "site 404");
+        assert_eq!(body_of(&tree, "/api").await, "api 404: /api");
+        assert_eq!(body_of(&tree, "/api/posts").await, "api 404: /api/posts");
+        assert_eq!(
+            body_of(&tree, "/api/users/x").await,
+            "api 404: /api/users/x"
+        );
+        assert_eq!(body_of(&tree, "/api/users/1").await, "hello");
+
+        // 方法不匹配仍返回 405
+        let mut req = Request::empty();
+        *req.method_mut() = Method::POST;
+        *req.uri_mut() = "/about".parse().unwrap();
+        let err = tree.call(req).await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn test_route_tree_trailing_slash() {
+        fn tree(policy: TrailingSlash) -> RouteTree {
+            Route::new("")
+                .trailing_slash(policy)
+                .append(Route::new("users").get(hello).post(hello))
+                .convert_to_route_tree()
+        }
+
+        fn request(method: Method, uri: &str) -> Request {
+            let mut req = Request::empty();
+            *req.method_mut() = method;
+            *req.uri_mut() = uri.parse().unwrap();
+            req
+        }
+
+        // 默认两种形式都能匹配
+        let merge = tree(TrailingSlash::MergeBoth);
+        assert!(merge.call(request(Method::GET, "/users/")).await.is_ok());
+        assert!(merge.call(request(Method::GET, "/users")).await.is_ok());
+
+        let strict = tree(TrailingSlash::Strict);
+        let err = strict
+            .call(request(Method::GET, "/users/"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::NOT_FOUND);
+        assert!(strict.call(request(Method::GET, "/")).await.is_err());
+        assert!(strict.call(request(Method::GET, "/users")).await.is_ok());
+
+        let redirect = tree(TrailingSlash::Redirect);
+        let res = redirect
+            .call(request(Method::GET, "/users/?page=2"))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            res.headers().get(http::header::LOCATION).unwrap(),
+            "/users?page=2"
+        );
+        let res = redirect
+            .call(request(Method::POST, "/users/"))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::PERMANENT_REDIRECT);
+        let err = redirect
+            .call(request(Method::GET, "/posts/"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_route_tree_trailing_slash_dispatch() {
+        #[derive(Clone)]
+        struct CounterMw(Arc<std::sync::atomic::AtomicUsize>);
+        #[async_trait::async_trait]
+        impl MiddleWareHandler for CounterMw {
+            async fn handle(
+                &self,
+                req: Request,
+                next: &Next,
+            ) -> crate::error::SilentResult<Response> {
+                self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                next.call(req).await
+            }
+        }
+
+        async fn missing(_: Request) -> Result<String, SilentError> {
+            Ok("fallback".into())
+        }
+
+        let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let tree = |policy: TrailingSlash| {
+            Route::new("")
+                .trailing_slash(policy)
+                .hook(CounterMw(hits.clone()))
+                .fallback(missing)
+                .append(Route::new("users").get(hello))
+                .append(Route::new("evil.com").get(hello))
+                .append(Route::new("files/<path:**>").get(hello))
+                .convert_to_route_tree()
+        };
+        fn request(uri: &str) -> Request {
+            let mut req = Request::empty();
+            *req.uri_mut() = uri.parse().unwrap();
+            req
+        }
+        async fn body(res: Response) -> String {
+            let bytes = res.body.collect().await.unwrap().to_bytes();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        }
+
+        // 严格模式下目录形式的通配路径照常匹配，其余路径交给兜底处理器，且经过中间件
+        let strict = tree(TrailingSlash::Strict);
+        let res = strict.call(request("/files/docs/")).await.unwrap();
+        assert_eq!(body(res).await, "hello");
+        let res = strict.call(request("/users/")).await.unwrap();
+        assert_eq!(body(res).await, "fallback");
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        // 重定向同样经过中间件，且开头的多个斜杠被合并，不会生成协议相对地址
+        let redirect = tree(TrailingSlash::Redirect);
+        let res = redirect.call(request("/users/")).await.unwrap();
+        assert_eq!(res.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 3);
+        let res = redirect.call(request("//evil.com/")).await.unwrap();
+        assert!(
+            res.headers()
+                .get(http::header::LOCATION)
+                .is_none_or(|location| !location.as_bytes().starts_with(b"//"))
+        );
+        let res = redirect
+            .redirect_trailing_slash(&request("//evil.com/"), "//\\evil.com/")
+            .unwrap();
+        assert_eq!(
+            res.headers().get(http::header::LOCATION).unwrap(),
+            "/evil.com"
+        );
+        let res = redirect.call(request("/files/docs/")).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let res = redirect.call(request("/posts/")).await.unwrap();
+        assert_eq!(body(res).await, "fallback");
+    }
+
+    #[tokio::test]
+    async fn test_route_tree_host() {
+        #[derive(Clone)]
+        struct CounterMw(Arc<std::sync::atomic::AtomicUsize>);
+        #[async_trait::async_trait]
+        impl MiddleWareHandler for CounterMw {
+            async fn handle(
+                &self,
+                req: Request,
+                next: &Next,
+            ) -> crate::error::SilentResult<Response> {
+                self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                next.call(req).await
+            }
+        }
+
+        async fn api(_: Request) -> Result<String, SilentError> {
+            Ok("api".into())
+        }
+        async fn admin(_: Request) -> Result<String, SilentError> {
+            Ok("admin".into())
+        }
+        async fn tenant(_: Request) -> Result<String, SilentError> {
+            Ok("tenant".into())
+        }
+
+        let api_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let tree = Route::new("")
+            .append(
+                Route::new("")
+                    .host("API.example.com")
+                    .hook(CounterMw(api_count.clone()))
+                    .append(Route::new("index").get(api)),
+            )
+            .append(
+                Route::new("")
+                    .host("admin.example.com")
+                    .append(Route::new("index").get(admin)),
+            )
+            .append(Route::new("index").host("*.tenant.io").get(tenant))
+            .convert_to_route_tree();
+
+        async fn call(tree: &RouteTree, host: &str) -> crate::error::SilentResult<String> {
+            let mut req = Request::empty();
+            *req.uri_mut() = "/index".parse().unwrap();
+            req.headers_mut()
+                .insert(http::header::HOST, host.parse().unwrap());
+            let res = tree.call(req).await?;
+            let body = res.body.collect().await.unwrap().to_bytes();
+            Ok(String::from_utf8(body.to_vec()).unwrap())
+        }
+
+        assert_eq!(call(&tree, "api.example.com").await.unwrap(), "api");
+        assert_eq!(
+            call(&tree, "admin.example.com:8080").await.unwrap(),
+            "admin"
+        );
+        assert_eq!(call(&tree, "a.tenant.io").await.unwrap(), "tenant");
+        assert_eq!(
+            api_count.load(std::sync::atomic::Ordering::SeqCst),
+            1,
+            "中间件只作用于所属主机"
+        );
+        for host in ["tenant.io", "other.example.com", "[::1]:8080"] {
+            let err = call(&tree, host).await.unwrap_err();
+            assert_eq!(err.status(), StatusCode::NOT_FOUND);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_route_tree_mount() {
+        use crate::extractor::FromRequest;
+
+        #[derive(Clone)]
+        struct TagMw;
+        #[async_trait::async_trait]
+        impl MiddleWareHandler for TagMw {
+            async fn handle(
+                &self,
+                req: Request,
+                next: &Next,
+            ) -> crate::error::SilentResult<Response> {
+                let res = next.call(req).await?;
+                Ok(res.with_header(
+                    http::HeaderName::from_static("x-app"),
+                    http::HeaderValue::from_static("admin"),
+                ))
+            }
+        }
+
+        async fn inspect(mut req: Request) -> Result<String, SilentError> {
+            let OriginalUri(original) = OriginalUri::from_request(&mut req).await?;
+            let matched = req.extensions().get::<MatchedPath>().unwrap().to_string();
+            let name = req.get_state::<&'static str>()?;
+            Ok(format!("{} {} {} {}", req.uri(), original, matched, name))
+        }
+
+        let app = Route::new_root()
+            .with_state("admin-app")
+            .hook(TagMw)
+            .append(Route::new("users/<id:u64>").get(inspect));
+        let tree = Route::new("")
+            .append(Route::new("tenants/<tenant>/stats").get(hello))
+            .mount("tenants/<tenant>", app)
+            .convert_to_route_tree();
+
+        let mut req = Request::empty();
+        *req.uri_mut() = "/tenants/acme/users/7?full=1".parse().unwrap();
+        let res = tree.call(req).await.unwrap();
+        assert_eq!(res.headers().get("x-app").unwrap(), "admin");
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(
+            &body[..],
+            b"/users/7?full=1 /tenants/acme/users/7?full=1 /tenants/<tenant>/users/<id:u64> admin-app"
+        );
+
+        // 同前缀下的已有路由不受子应用中间件影响
+        let mut req = Request::empty();
+        *req.uri_mut() = "/tenants/acme/stats".parse().unwrap();
+        let res = tree.call(req).await.unwrap();
+        assert!(res.headers().get("x-app").is_none());
+    }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_route_tree_timeout() {
+        use std::time::Duration;
+
+        async fn slow(_: Request) -> Result<String, SilentError> {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            Ok("slow".into())
+        }
+
+        let tree = Route::new("")
+            .with_timeout(Duration::from_millis(20))
+            .append(Route::new("slow").get(slow))
+            .append(Route::new("fast").get(hello))
+            .append(
+                Route::new("report")
+                    .with_timeout(Duration::from_secs(5))
+                    .get(slow),
+            )
+            .convert_to_route_tree();
+
+        let call = |path: &'static str| {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            tree.call(req)
+        };
+        let err = call("/slow").await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert!(call("/fast").await.is_ok());
+        // 子路由覆盖继承的超时时间
+        assert!(call("/report").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_route_tree_match_priority() {
+        async fn name(req: Request) -> Result<String, SilentError> {
+            Ok(req.extensions().get::<MatchedPath>().unwrap().to_string())
+        }
+
+        async fn body(tree: &RouteTree, path: &str) -> String {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            let res = tree.call(req).await.unwrap();
+            let body = res.body.collect().await.unwrap().to_bytes();
+            String::from_utf8(body.to_vec()).unwrap()
+        }
+
+        let routes = || {
+            vec![
+                Route::new("files/<rest:**>").get(name),
+                Route::new("files/<name>").get(name),
+                Route::new("files/<id:u64>").get(name),
+                Route::new("files/latest").get(name),
+            ]
+        };
+        // 注册顺序不影响匹配结果
+        let mut forward = Route::new("");
+        forward.extend(routes());
+        let mut reversed = Route::new("");
+        reversed.extend(routes().into_iter().rev().collect());
+        for tree in [
+            forward.convert_to_route_tree(),
+            reversed.convert_to_route_tree(),
+        ] {
+            assert_eq!(body(&tree, "/files/latest").await, "/files/latest");
+            assert_eq!(body(&tree, "/files/42").await, "/files/<id:u64>");
+            assert_eq!(body(&tree, "/files/report").await, "/files/<name>");
+            assert_eq!(body(&tree, "/files/a/b").await, "/files/<rest:**>");
+        }
+
+        // 显式优先级覆盖默认顺序
+        let tree = Route::new("")
+            .append(Route::new("files/<id:u64>").get(name))
+            .append(Route::new("files/<name>").priority(1).get(name))
+            .convert_to_route_tree();
+        assert_eq!(body(&tree, "/files/42").await, "/files/<name>");
+    }
+
+    #[tokio::test]
+    async fn test_route_tree_handler_with_json_config() {
+        #[derive(serde::Serialize)]
+        struct User {
+            name: &'static str,
+            id: u64,
+        }
+
+        async fn user(_req: Request) -> Result<User, SilentError> {
+            Ok(User { name: "a", id: 1 })
+        }
+
+        let route = Route::new("")
+            .append(
+                Route::new("pretty")
+                    .with_state(JsonConfig::new().pretty(true))
+                    .get(user),
+            )
+            .append(Route::new("plain").get(user));
+        let tree = route.convert_to_route_tree();
+
+        let mut req = Request::empty();
+        *req.uri_mut() = "/pretty".parse().unwrap();
+        let res = tree.call(req).await.unwrap();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(body, "{\n  \"name\": \"a\",\n  \"id\": 1\n}");
+
+        let mut req = Request::empty();
+        *req.uri_mut() = "/plain".parse().unwrap();
+        let res = tree.call(req).await.unwrap();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(body, r#"{"name":"a","id":1}"#);
+    }
+
     #[tokio::test]
     async fn test_route_tree_handler_not_found() {
         let route = Route::new("api").get(hello);
@@ -1489,4 +2345,152 @@ mod tests {
         let body = frame.data_ref().unwrap();
         assert!(body.starts_with(b"uuid:"));
     }
+
+    #[tokio::test]
+    async fn test_route_tree_extract_typed_path() {
+        use crate::extractor::Path;
+        use chrono::{DateTime, Utc};
+
+        async fn get_by_uuid(Path(id): Path<uuid::Uuid>) -> Result<String, SilentError> {
+            Ok(id.to_string())
+        }
+        async fn get_by_time(Path(ts): Path<DateTime<Utc>>) -> Result<String, SilentError> {
+            Ok(ts.to_rfc3339())
+        }
+
+        let route = Route::new("")
+            .append(Route::new("items/<id:uuid>").get(get_by_uuid))
+            .append(Route::new("events/<ts:datetime>").get(get_by_time));
+        let tree = route.convert_to_route_tree();
+
+        for (uri, expected) in [
+            (
+                "/items/550e8400-e29b-41d4-a716-446655440000",
+                "550e8400-e29b-41d4-a716-446655440000",
+            ),
+            (
+                "/events/2024-05-01T16:30:00+08:00",
+                "2024-05-01T08:30:00+00:00",
+            ),
+        ] {
+            let mut req = Request::empty();
+            *req.uri_mut() = uri.parse().unwrap();
+            let mut res = tree.call(req).await.unwrap();
+            assert_eq!(
+                res.body.frame().await.unwrap().unwrap().data_ref().unwrap(),
+                &Bytes::from(expected)
+            );
+        }
+
+        for uri in ["/items/42", "/events/not-a-time"] {
+            let mut req = Request::empty();
+            *req.uri_mut() = uri.parse().unwrap();
+            let err = tree.call(req).await.unwrap_err();
+            assert_eq!(err.status(), StatusCode::NOT_FOUND);
+        }
+    }
+
+    // ==================== 路由模板测试 ====================
+
+    #[tokio::test]
+    async fn test_route_tree_matched_path() {
+        async fn matched(req: Request) -> Result<String, SilentError> {
+            let matched = req.extensions().get::<MatchedPath>().unwrap();
+            Ok(matched.to_string())
+        }
+
+        let route = Route::new("")
+            .get(matched)
+            .append(Route::new("users/<id:u64>").get(matched))
+            .append(Route::new("files/<path:**>").get(matched));
+        let tree = route.convert_to_route_tree();
+
+        for (uri, expected) in [
+            ("/", "/"),
+            ("/users/42", "/users/<id:u64>"),
+            ("/files/a/b.txt", "/files/<path:**>"),
+        ] {
+            let mut req = Request::empty();
+            *req.uri_mut() = uri.parse().unwrap();
+            let mut res = tree.call(req).await.unwrap();
+            assert_eq!(
+                res.body.frame().await.unwrap().unwrap().data_ref().unwrap(),
+                &Bytes::from(expected)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_matched_path_and_params_map() {
+        async fn describe(req: Request) -> Result<String, SilentError> {
+            let mut params: Vec<_> = req.path_params_map().into_iter().collect();
+            params.sort();
+            Ok(format!("{} {:?}", req.matched_path().unwrap(), params))
+        }
+
+        let tree = Route::new("api")
+            .append(Route::new("users/<id:u64>/posts/<slug>").get(describe))
+            .convert_to_route_tree();
+        let mut req = Request::empty();
+        *req.uri_mut() = "/api/users/7/posts/intro".parse().unwrap();
+        let mut res = tree.call(req).await.unwrap();
+        assert_eq!(
+            res.body.frame().await.unwrap().unwrap().data_ref().unwrap(),
+            &Bytes::from(r#"/api/users/<id:u64>/posts/<slug> [("id", "7"), ("slug", "intro")]"#)
+        );
+    }
+
+    // ==================== 路由冲突测试 ====================
+
+    async fn conflict_handler(_req: Request) -> Result<String, SilentError> {
+        Ok("ok".into())
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "路由冲突：GET /users/<id:int> 与 GET /users/<id:u64> 可能匹配同一请求"
+    )]
+    fn test_route_tree_conflicting_params() {
+        Route::new("")
+            .append(Route::new("users/<id:int>").get(conflict_handler))
+            .append(Route::new("users/<id:u64>").get(conflict_handler))
+            .convert_to_route_tree();
+    }
+
+    #[test]
+    #[should_panic(expected = "路由冲突：POST /users/<id>/posts 与 POST /users/<name>/posts")]
+    fn test_route_tree_conflicting_nested() {
+        Route::new("")
+            .append(Route::new("users/<id>/posts").post(conflict_handler))
+            .append(Route::new("users/<name>/posts").post(conflict_handler))
+            .convert_to_route_tree();
+    }
+
+    #[test]
+    #[should_panic(expected = "路由冲突：GET /users 被重复注册")]
+    fn test_route_tree_duplicate_registration() {
+        Route::new("")
+            .append(Route::new("users").get(conflict_handler))
+            .append(Route::new("users").get(conflict_handler))
+            .convert_to_route_tree();
+    }
+
+    #[test]
+    fn test_route_tree_no_conflict() {
+        let users = Route::new("users").get(conflict_handler);
+        Route::new("")
+            // 同一路由重复合并
+            .append(users.clone())
+            .append(users)
+            // 方法不同
+            .append(Route::new("items/<id:int>").get(conflict_handler))
+            .append(Route::new("items/<id:u64>").delete(conflict_handler))
+            // 匹配顺序不同
+            .append(Route::new("files/<id:u64>").get(conflict_handler))
+            .append(Route::new("files/<name>").get(conflict_handler))
+            // 显式优先级
+            .append(Route::new("posts/<id>").get(conflict_handler))
+            .append(Route::new("posts/<slug>").priority(1).get(conflict_handler))
+            .convert_to_route_tree();
+    }
 }
@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::{Route, RouterAdapt};
+use crate::{Handler, MiddleWareHandler, Next, Request, Response, Result, SilentError};
+
+/// 路由分组：为同一前缀下的一组路由统一挂载中间件、状态与错误映射
+///
+/// 与 `Route::new("api/v1").hook(..)` 不同，`Scope` 的中间件与状态挂在路径的最后一段（`v1`）上，
+/// 不会影响合并到 `api` 下的其他分组（如 `api/v2`）。通常通过 [`Route::scope`] 使用：
+///
+/// ```rust
+/// use silent::middlewares::RequestId;
+/// use silent::prelude::*;
+///
+/// async fn users(_req: Request) -> Result<&'static str> {
+///     Ok("users")
+/// }
+///
+/// let route = Route::new("").scope("api/v1", |scope| {
+///     scope
+///         .hook(RequestId::new())
+///         .with_state(42u32)
+///         .map_err(|err: SilentError| {
+///             let body = serde_json::json!({ "code": err.status().as_u16(), "message": err.message() });
+///             Response::json(&body).with_status(err.status())
+///         })
+///         .append(Route::new("users").get(users))
+/// });
+/// ```
+pub struct Scope {
+    route: Route,
+    error_mapper: Option<Arc<dyn MiddleWareHandler>>,
+}
+
+impl Scope {
+    /// 创建以 `path` 为前缀的分组
+    pub fn new(path: &str) -> Self {
+        Self {
+            route: Route::new(path),
+            error_mapper: None,
+        }
+    }
+
+    fn real_route(&mut self) -> &mut Route {
+        let create_path = self.route.create_path.clone();
+        self.route.get_append_real_route(&create_path)
+    }
+
+    /// 为分组挂载中间件，按调用顺序由外到内执行
+    pub fn hook(mut self, handler: impl MiddleWareHandler + 'static) -> Self {
+        self.real_route().middlewares.push(Arc::new(handler));
+        self
+    }
+
+    /// 为分组注入状态，仅分组内的处理函数可见
+    pub fn with_state<T: Send + Sync + Clone + 'static>(mut self, val: T) -> Self {
+        self.real_route()
+            .state
+            .get_or_insert_with(crate::State::new)
+            .insert(val);
+        self
+    }
+
+    /// 将分组内处理函数与中间件返回的错误映射为响应
+    ///
+    /// 映射位于分组中间件的最外层，因此也能处理中间件返回的错误；
+    /// 分组内未匹配的路径不会进入分组，需要自定义 404 时使用 [`Route::fallback`]。
+    pub fn map_err<F, R>(mut self, mapper: F) -> Self
+    where
+        F: Fn(SilentError) -> R + Send + Sync + 'static,
+        R: Into<Response>,
+    {
+        self.error_mapper = Some(Arc::new(ErrorMapper(mapper)));
+        self
+    }
+
+    /// 向分组添加子路由
+    pub fn append<R: RouterAdapt>(mut self, route: R) -> Self {
+        let route = route.into_router();
+        Route::merge_child(&mut self.real_route().children, route);
+        self
+    }
+}
+
+impl RouterAdapt for Scope {
+    fn into_router(mut self) -> Route {
+        if let Some(mapper) = self.error_mapper.take() {
+            self.real_route().middlewares.insert(0, mapper);
+        }
+        self.route
+    }
+}
+
+struct ErrorMapper<F>(F);
+
+#[async_trait]
+impl<F, R> MiddleWareHandler for ErrorMapper<F>
+where
+    F: Fn(SilentError) -> R + Send + Sync + 'static,
+    R: Into<Response>,
+{
+    async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+        match next.call(req).await {
+            Ok(res) => Ok(res),
+            Err(err) => Ok((self.0)(err).into()),
+        }
+    }
+}
+
+impl Route {
+    /// 在 `path` 下创建路由分组，统一挂载中间件、状态与错误映射，见 [`Scope`]
+    pub fn scope(self, path: &str, build: impl FnOnce(Scope) -> Scope) -> Self {
+        self.append(build(Scope::new(path)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StatusCode;
+    use http_body_util::BodyExt;
+
+    #[derive(Clone)]
+    struct Tag(&'static str);
+
+    #[async_trait]
+    impl MiddleWareHandler for Tag {
+        async fn handle(&self, req: Request, next: &Next) -> Result<Response> {
+            let mut res = next.call(req).await?;
+            res.headers_mut()
+                .insert("x-scope", http::HeaderValue::from_static(self.0));
+            Ok(res)
+        }
+    }
+
+    async fn version(req: Request) -> Result<String> {
+        Ok(req
+            .get_state::<&'static str>()
+            .copied()
+            .unwrap_or("none")
+            .to_string())
+    }
+
+    async fn fail(_req: Request) -> Result<String> {
+        Err(SilentError::business_error(
+            StatusCode::CONFLICT,
+            "conflict",
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_scope_isolates_middleware_and_state() {
+        let tree = Route::new("")
+            .scope("api/v1", |scope| {
+                scope
+                    .hook(Tag("v1"))
+                    .with_state("v1")
+                    .map_err(|err: SilentError| {
+                        Response::text(&format!("v1 error {}", err.status().as_u16()))
+                            .with_status(err.status())
+                    })
+                    .append(Route::new("version").get(version))
+                    .append(Route::new("fail").get(fail))
+            })
+            .append(Route::new("api/v2/version").get(version))
+            .append(Route::new("api/v2/fail").get(fail))
+            .convert_to_route_tree();
+
+        let call = |path: &'static str| {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            tree.call(req)
+        };
+
+        let res = call("/api/v1/version").await.unwrap();
+        assert_eq!(res.headers().get("x-scope").unwrap(), "v1");
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"v1");
+
+        // 同前缀下的其他分组不受影响
+        let res = call("/api/v2/version").await.unwrap();
+        assert!(res.headers().get("x-scope").is_none());
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"none");
+
+        // 分组内的错误经过错误映射
+        let res = call("/api/v1/fail").await.unwrap();
+        assert_eq!(res.status(), StatusCode::CONFLICT);
+        let body = res.body.collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"v1 error 409");
+        assert!(call("/api/v2/fail").await.is_err());
+    }
+}
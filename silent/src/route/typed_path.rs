@@ -0,0 +1,88 @@
+//! 类型化路由键 [`path!`](crate::path) 的运行时支持
+
+/// 按 RFC 3986 的 `pchar` 规则对路径段做百分号编码，`keep_slash` 为真时保留 `/`
+#[doc(hidden)]
+pub fn encode_path_segment(value: &str, keep_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        let keep = byte.is_ascii_alphanumeric()
+            || b"-._~!$&'()*+,;=:@".contains(&byte)
+            || (keep_slash && byte == b'/');
+        if keep {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extractor::{FromRequest, Path};
+    use crate::prelude::*;
+    use http_body_util::BodyExt;
+
+    crate::path!(pub(crate) PostPath = "/users/<id:u64>/posts/<slug>");
+    crate::path!(FilePath = "files/<path:**>");
+
+    #[test]
+    fn test_encode_path_segment() {
+        assert_eq!(encode_path_segment("a b/c", false), "a%20b%2Fc");
+        assert_eq!(encode_path_segment("a b/c", true), "a%20b/c");
+        assert_eq!(encode_path_segment("报表", false), "%E6%8A%A5%E8%A1%A8");
+    }
+
+    #[test]
+    fn test_path_macro_url() {
+        let post = crate::path!("/users/<id:u64>/posts/<slug>");
+        assert_eq!(post.pattern(), "/users/<id:u64>/posts/<slug>");
+        assert_eq!(post.url(7, "hello world"), "/users/7/posts/hello%20world");
+
+        let root = crate::path!("/");
+        assert_eq!(root.url(), "/");
+
+        let post = PostPath {
+            id: 7,
+            slug: "a/b".into(),
+        };
+        assert_eq!(post.url(), "/users/7/posts/a%2Fb");
+        let file = FilePath {
+            path: "docs/read me.md".into(),
+        };
+        assert_eq!(file.url(), "/files/docs/read%20me.md");
+    }
+
+    #[tokio::test]
+    async fn test_path_macro_route_and_extract() {
+        async fn show(mut req: Request) -> Result<String> {
+            let Path(post) = Path::<PostPath>::from_request(&mut req)
+                .await
+                .map_err(|_| SilentError::ParamsNotFound)?;
+            Ok(post.url())
+        }
+        async fn file(mut req: Request) -> Result<String> {
+            let Path(file) = Path::<FilePath>::from_request(&mut req)
+                .await
+                .map_err(|_| SilentError::ParamsNotFound)?;
+            Ok(file.path)
+        }
+
+        let tree = Route::new("")
+            .append(Route::new(PostPath::PATTERN).get(show))
+            .append(Route::new(FilePath::PATTERN).get(file))
+            .convert_to_route_tree();
+
+        for (path, expected) in [
+            ("/users/7/posts/intro", "/users/7/posts/intro"),
+            ("/files/a/b.txt", "a/b.txt"),
+        ] {
+            let mut req = Request::empty();
+            *req.uri_mut() = path.parse().unwrap();
+            let res = tree.call(req).await.unwrap();
+            let body = res.body.collect().await.unwrap().to_bytes();
+            assert_eq!(&body[..], expected.as_bytes());
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use http::HeaderName;
+
+use super::{Route, RouterAdapt};
+use crate::{Request, Response, Result, SilentError, StatusCode};
+
+/// API 版本的来源，见 [`Versioned`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VersionSource {
+    /// 路径前缀，如 `/v2/users`
+    PathPrefix,
+    /// 请求头，如 `Accept-Version: v2`
+    Header(HeaderName),
+    /// `Accept` 媒体类型中的参数，参数名如 `version`，对应 `Accept: application/json; version=v2`
+    MediaType(String),
+}
+
+impl VersionSource {
+    /// 从 `Accept-Version` 请求头读取版本
+    pub fn header() -> Self {
+        Self::Header(HeaderName::from_static("accept-version"))
+    }
+
+    /// 从 `Accept` 媒体类型的 `version` 参数读取版本
+    pub fn media_type() -> Self {
+        Self::MediaType("version".to_string())
+    }
+
+    /// 请求声明的版本，路径前缀方式由路由匹配完成，总是返回 `None`
+    fn resolve<'a>(&self, req: &'a Request) -> Option<&'a str> {
+        match self {
+            VersionSource::PathPrefix => None,
+            VersionSource::Header(name) => req
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+                .filter(|value| !value.is_empty()),
+            VersionSource::MediaType(param) => req
+                .headers()
+                .get_all(http::header::ACCEPT)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .flat_map(|media| media.split(';').skip(1))
+                .find_map(|pair| {
+                    let (name, value) = pair.split_once('=')?;
+                    name.trim()
+                        .eq_ignore_ascii_case(param)
+                        .then(|| value.trim().trim_matches('"'))
+                }),
+        }
+    }
+}
+
+/// 路由节点上的版本约束，仅匹配声明了该版本的请求；未声明版本时匹配默认版本
+pub(crate) struct VersionGuard {
+    source: VersionSource,
+    version: String,
+    is_default: bool,
+}
+
+impl VersionGuard {
+    pub(crate) fn matches(&self, req: &Request) -> bool {
+        match self.source.resolve(req) {
+            Some(version) => version == self.version,
+            None => self.is_default,
+        }
+    }
+}
+
+/// API 版本路由：按路径前缀、请求头或媒体类型参数将请求分发到不同版本的路由
+///
+/// 请求声明了未注册的版本时返回 `406 Not Acceptable`；未声明版本的请求交给默认版本，
+/// 未设置默认版本时同样返回 `406`。按请求头或媒体类型分发时，`Versioned` 会处理其所在路径下的所有请求，
+/// 通常挂载在 `api` 这样的前缀下。
+///
+/// 按路径前缀分发时，版本名即路径段，如 `v1` 对应 `/v1/...`；设置了默认版本时，
+/// 不带版本前缀的路径交给默认版本，此时无法区分未注册的版本与普通路径，未匹配的请求返回 `404`。
+///
+/// ```rust
+/// use silent::prelude::*;
+///
+/// async fn v1(_req: Request) -> Result<&'static str> {
+///     Ok("v1")
+/// }
+///
+/// async fn v2(_req: Request) -> Result<&'static str> {
+///     Ok("v2")
+/// }
+///
+/// // Accept-Version: v2 -> v2，未声明版本 -> v1，Accept-Version: v3 -> 406
+/// let route = Route::new("api").append(
+///     Versioned::new(VersionSource::header())
+///         .version("v1", Route::new("users").get(v1))
+///         .version("v2", Route::new("users").get(v2))
+///         .default_version("v1"),
+/// );
+/// ```
+pub struct Versioned {
+    source: VersionSource,
+    versions: Vec<(String, Route)>,
+    default: Option<String>,
+}
+
+impl Versioned {
+    /// 创建从 `source` 读取版本的版本路由
+    pub fn new(source: VersionSource) -> Self {
+        Self {
+            source,
+            versions: Vec::new(),
+            default: None,
+        }
+    }
+
+    /// 注册版本 `version` 的路由，重复注册时后者覆盖前者
+    pub fn version<R: RouterAdapt>(mut self, version: &str, route: R) -> Self {
+        let version = version.trim_matches('/').to_string();
+        let route = route.into_router();
+        match self.versions.iter_mut().find(|(name, _)| *name == version) {
+            Some((_, existing)) => *existing = route,
+            None => self.versions.push((version, route)),
+        }
+        self
+    }
+
+    /// 设置默认版本，用于未声明版本的请求
+    pub fn default_version(mut self, version: &str) -> Self {
+        self.default = Some(version.trim_matches('/').to_string());
+        self
+    }
+}
+
+/// 按路径前缀分发时捕获版本段的参数名
+const PATH_VERSION_KEY: &str = "__silent_api_version";
+
+/// 兜底处理器：拒绝声明了未注册版本、或未声明版本且没有默认版本的请求，其余未匹配的请求返回 404
+fn reject_unknown(
+    source: VersionSource,
+    known: Vec<String>,
+    has_default: bool,
+) -> impl Fn(Request) -> std::future::Ready<Result<Response>> + Send + Sync + 'static {
+    move |req: Request| {
+        let version = match &source {
+            VersionSource::PathPrefix => req
+                .path_params()
+                .get(PATH_VERSION_KEY)
+                .map(|value| value.to_string()),
+            source => source.resolve(&req).map(str::to_string),
+        };
+        let acceptable = match version {
+            Some(version) => known.contains(&version),
+            None => has_default,
+        };
+        std::future::ready(if acceptable {
+            Err(SilentError::NotFound)
+        } else {
+            Err(SilentError::business_error(
+                StatusCode::NOT_ACCEPTABLE,
+                "unsupported API version",
+            ))
+        })
+    }
+}
+
+impl RouterAdapt for Versioned {
+    fn into_router(self) -> Route {
+        let known: Vec<String> = self.versions.iter().map(|(name, _)| name.clone()).collect();
+        let has_default = self
+            .default
+            .as_ref()
+            .is_some_and(|default| known.contains(default));
+        let mut root = Route::new("");
+
+        if self.source == VersionSource::PathPrefix {
+            for (version, route) in &self.versions {
+                root = root.append(Route::new(version).append(route.clone()));
+                if self.default.as_ref() == Some(version) {
+                    root = root.append(route.clone());
+                }
+            }
+            if !has_default {
+                let reject = reject_unknown(VersionSource::PathPrefix, known, false);
+                root = root.append(Route::new(&format!("<{PATH_VERSION_KEY}>")).fallback(reject));
+            }
+            return root;
+        }
+
+        let source = self.source.clone();
+        for (version, route) in self.versions {
+            let mut node = Route::new("").append(route);
+            node.version = Some(Arc::new(VersionGuard {
+                source: source.clone(),
+                is_default: self.default.as_ref() == Some(&version),
+                version,
+            }));
+            root.children.push(node);
+        }
+        root.fallback(reject_unknown(source, known, has_default))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Handler;
+    use http_body_util::BodyExt;
+
+    async fn call(route: &Route, path: &str, headers: &[(&str, &str)]) -> (StatusCode, String) {
+        let mut req = Request::empty();
+        *req.uri_mut() = path.parse().unwrap();
+        for (name, value) in headers {
+            req.headers_mut().insert(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                value.parse().unwrap(),
+            );
+        }
+        let res = route.call(req).await.unwrap_or_else(Into::into);
+        let status = res.status();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn versioned(source: VersionSource) -> Versioned {
+        Versioned::new(source)
+            .version(
+                "v1",
+                Route::new("users").get(|_req: Request| async { Ok("users v1") }),
+            )
+            .version(
+                "v2",
+                Route::new("users").get(|req: Request| async move {
+                    Ok(format!("users v2 {}", req.matched_path().unwrap()))
+                }),
+            )
+    }
+
+    #[tokio::test]
+    async fn test_versioned_by_header() {
+        let route =
+            Route::new("api").append(versioned(VersionSource::header()).default_version("v1"));
+        let route = Route::new("").append(route);
+
+        assert_eq!(call(&route, "/api/users", &[]).await.1, "users v1");
+        assert_eq!(
+            call(&route, "/api/users", &[("accept-version", "v2")])
+                .await
+                .1,
+            "users v2 /api/users"
+        );
+        let (status, _) = call(&route, "/api/users", &[("accept-version", "v3")]).await;
+        assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+        let (status, _) = call(&route, "/api/posts", &[("accept-version", "v2")]).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_versioned_by_media_type() {
+        let route =
+            Route::new("").append(Route::new("api").append(versioned(VersionSource::media_type())));
+
+        let accept = [(
+            "accept",
+            "text/html, application/json; charset=utf-8; version=\"v2\"",
+        )];
+        assert_eq!(
+            call(&route, "/api/users", &accept).await.1,
+            "users v2 /api/users"
+        );
+        // 未设置默认版本时拒绝未声明版本的请求
+        let (status, _) = call(&route, "/api/users", &[("accept", "application/json")]).await;
+        assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+    }
+
+    #[tokio::test]
+    async fn test_versioned_by_path_prefix() {
+        let route = Route::new("").append(versioned(VersionSource::PathPrefix));
+        assert_eq!(call(&route, "/v1/users", &[]).await.1, "users v1");
+        assert_eq!(call(&route, "/v2/users", &[]).await.1, "users v2 /v2/users");
+        assert_eq!(
+            call(&route, "/v3/users", &[]).await.0,
+            StatusCode::NOT_ACCEPTABLE
+        );
+        assert_eq!(
+            call(&route, "/v1/posts", &[]).await.0,
+            StatusCode::NOT_FOUND
+        );
+
+        let route =
+            Route::new("").append(versioned(VersionSource::PathPrefix).default_version("v2"));
+        assert_eq!(call(&route, "/users", &[]).await.1, "users v2 /users");
+        assert_eq!(call(&route, "/v1/users", &[]).await.1, "users v1");
+    }
+}
@@ -0,0 +1,332 @@
+//! ACME 协议客户端（RFC 8555），请求均以 ES256 签名的 JWS 发送
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ring::rand::SystemRandom;
+use ring::signature::{ECDSA_P256_SHA256_FIXED_SIGNING, EcdsaKeyPair, KeyPair};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use super::{AcmeChallenge, Challenges, x509};
+
+const JOSE_JSON: &str = "application/jose+json";
+const BAD_NONCE: &str = "urn:ietf:params:acme:error:badNonce";
+/// 遇到 badNonce 时的最大重试次数
+const NONCE_RETRIES: usize = 3;
+/// 轮询授权与订单状态的间隔与次数
+const POLL_INTERVAL: Duration = if cfg!(test) {
+    Duration::from_millis(10)
+} else {
+    Duration::from_secs(2)
+};
+const POLL_ATTEMPTS: usize = 60;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Directory {
+    new_nonce: String,
+    new_account: String,
+    new_order: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Order {
+    status: String,
+    #[serde(default)]
+    authorizations: Vec<String>,
+    finalize: String,
+    certificate: Option<String>,
+    error: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Authorization {
+    status: String,
+    identifier: Identifier,
+    #[serde(default)]
+    challenges: Vec<Challenge>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Identifier {
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Challenge {
+    #[serde(rename = "type")]
+    kind: String,
+    url: String,
+    token: Option<String>,
+    error: Option<Value>,
+}
+
+/// 已注册的 ACME 账户
+pub(crate) struct Account {
+    client: reqwest::Client,
+    key: EcdsaKeyPair,
+    directory: Directory,
+    /// 账户 URL，注册前为空
+    kid: Option<String>,
+    nonce: Option<String>,
+}
+
+impl Account {
+    /// 注册账户；同一私钥重复注册时 ACME 服务返回已有账户
+    pub(crate) async fn register(
+        client: reqwest::Client,
+        directory_url: &str,
+        key_pkcs8: &[u8],
+        contact: &[String],
+    ) -> Result<Self> {
+        let key = EcdsaKeyPair::from_pkcs8(
+            &ECDSA_P256_SHA256_FIXED_SIGNING,
+            key_pkcs8,
+            &SystemRandom::new(),
+        )
+        .map_err(|e| anyhow!("加载 ACME 账户私钥失败: {e}"))?;
+        let directory = client
+            .get(directory_url)
+            .send()
+            .await
+            .and_then(|res| res.error_for_status())
+            .with_context(|| format!("获取 ACME 目录失败: {directory_url}"))?
+            .json::<Directory>()
+            .await
+            .context("解析 ACME 目录失败")?;
+        let mut account = Self {
+            client,
+            key,
+            directory,
+            kid: None,
+            nonce: None,
+        };
+        let url = account.directory.new_account.clone();
+        let res = account
+            .post(
+                &url,
+                Some(json!({"termsOfServiceAgreed": true, "contact": contact})),
+            )
+            .await?;
+        let kid = location(&res).context("ACME 账户响应缺少 Location")?;
+        tracing::debug!(account = %kid, "ACME account registered");
+        account.kid = Some(kid);
+        Ok(account)
+    }
+
+    fn jwk(&self) -> Value {
+        let public_key = self.key.public_key().as_ref();
+        json!({
+            "crv": "P-256",
+            "kty": "EC",
+            "x": URL_SAFE_NO_PAD.encode(&public_key[1..33]),
+            "y": URL_SAFE_NO_PAD.encode(&public_key[33..65]),
+        })
+    }
+
+    /// key authorization：`token.base64url(SHA-256(JWK 指纹))`（RFC 7638）
+    pub(crate) fn key_authorization(&self, token: &str) -> String {
+        let jwk = self.jwk();
+        // 指纹要求按字典序排列且无空白
+        let canonical = format!(
+            r#"{{"crv":"P-256","kty":"EC","x":"{}","y":"{}"}}"#,
+            jwk["x"].as_str().unwrap_or_default(),
+            jwk["y"].as_str().unwrap_or_default()
+        );
+        let thumbprint = ring::digest::digest(&ring::digest::SHA256, canonical.as_bytes());
+        format!("{token}.{}", URL_SAFE_NO_PAD.encode(thumbprint))
+    }
+
+    fn sign(&self, url: &str, nonce: String, payload: Option<&Value>) -> Result<Value> {
+        let mut protected = json!({"alg": "ES256", "nonce": nonce, "url": url});
+        match &self.kid {
+            Some(kid) => protected["kid"] = json!(kid),
+            None => protected["jwk"] = self.jwk(),
+        }
+        let protected = URL_SAFE_NO_PAD.encode(protected.to_string());
+        // POST-as-GET 的 payload 为空串
+        let payload = payload
+            .map(|payload| URL_SAFE_NO_PAD.encode(payload.to_string()))
+            .unwrap_or_default();
+        let signature = self
+            .key
+            .sign(
+                &SystemRandom::new(),
+                format!("{protected}.{payload}").as_bytes(),
+            )
+            .map_err(|e| anyhow!("JWS 签名失败: {e}"))?;
+        Ok(json!({
+            "protected": protected,
+            "payload": payload,
+            "signature": URL_SAFE_NO_PAD.encode(signature.as_ref()),
+        }))
+    }
+
+    async fn nonce(&mut self) -> Result<String> {
+        if let Some(nonce) = self.nonce.take() {
+            return Ok(nonce);
+        }
+        let res = self
+            .client
+            .head(&self.directory.new_nonce)
+            .send()
+            .await
+            .context("获取 ACME nonce 失败")?;
+        replay_nonce(&res).context("ACME 响应缺少 Replay-Nonce")
+    }
+
+    /// 发送签名请求，`payload` 为 `None` 时为 POST-as-GET
+    async fn post(&mut self, url: &str, payload: Option<Value>) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let nonce = self.nonce().await?;
+            let body = self.sign(url, nonce, payload.as_ref())?;
+            let res = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, JOSE_JSON)
+                .body(body.to_string())
+                .send()
+                .await
+                .with_context(|| format!("ACME 请求失败: {url}"))?;
+            self.nonce = replay_nonce(&res);
+            if res.status().is_success() {
+                return Ok(res);
+            }
+            let status = res.status();
+            let problem: Value = res.json().await.unwrap_or_default();
+            attempt += 1;
+            if problem["type"] == BAD_NONCE && attempt < NONCE_RETRIES {
+                continue;
+            }
+            bail!("ACME 请求失败: {url} {status} {problem}");
+        }
+    }
+
+    async fn fetch<T: DeserializeOwned>(&mut self, url: &str) -> Result<T> {
+        let res = self.post(url, None).await?;
+        res.json()
+            .await
+            .with_context(|| format!("解析 ACME 响应失败: {url}"))
+    }
+
+    /// 下单、完成验证并签发证书，返回 PEM 证书链
+    pub(crate) async fn issue(
+        &mut self,
+        domains: &[String],
+        challenge: AcmeChallenge,
+        challenges: &Challenges,
+        cert_key: &[u8],
+    ) -> Result<String> {
+        let identifiers: Vec<Value> = domains
+            .iter()
+            .map(|domain| json!({"type": "dns", "value": domain}))
+            .collect();
+        let new_order = self.directory.new_order.clone();
+        let res = self
+            .post(&new_order, Some(json!({"identifiers": identifiers})))
+            .await?;
+        let order_url = location(&res).context("ACME 订单响应缺少 Location")?;
+        let order: Order = res.json().await.context("解析 ACME 订单失败")?;
+
+        for url in &order.authorizations {
+            self.authorize(url, challenge, challenges).await?;
+        }
+
+        let order = self.poll_order(&order_url, &["ready", "valid"]).await?;
+        if order.status == "ready" {
+            let csr = x509::csr(cert_key, domains)?;
+            self.post(
+                &order.finalize,
+                Some(json!({"csr": URL_SAFE_NO_PAD.encode(csr)})),
+            )
+            .await?;
+        }
+        let order = self.poll_order(&order_url, &["valid"]).await?;
+        let url = order.certificate.context("ACME 订单缺少证书地址")?;
+        let chain = self.post(&url, None).await?.text().await?;
+        Ok(chain)
+    }
+
+    /// 完成单个授权的验证
+    async fn authorize(
+        &mut self,
+        url: &str,
+        challenge: AcmeChallenge,
+        challenges: &Challenges,
+    ) -> Result<()> {
+        let authorization: Authorization = self.fetch(url).await?;
+        if authorization.status == "valid" {
+            return Ok(());
+        }
+        let domain = authorization.identifier.value;
+        let Some(selected) = authorization
+            .challenges
+            .iter()
+            .find(|c| c.kind == challenge.as_str())
+        else {
+            bail!("ACME 服务未提供 {} 验证: {domain}", challenge.as_str());
+        };
+        let token = selected.token.as_deref().context("ACME 验证缺少 token")?;
+        let key_authorization = self.key_authorization(token);
+        let guard = challenges.install(challenge, &domain, token, &key_authorization)?;
+        tracing::info!(%domain, challenge = challenge.as_str(), "ACME challenge ready");
+        self.post(&selected.url, Some(json!({}))).await?;
+
+        let result = async {
+            for _ in 0..POLL_ATTEMPTS {
+                tokio::time::sleep(POLL_INTERVAL).await;
+                let authorization: Authorization = self.fetch(url).await?;
+                match authorization.status.as_str() {
+                    "valid" => return Ok(()),
+                    "pending" | "processing" => continue,
+                    status => {
+                        let error = authorization
+                            .challenges
+                            .iter()
+                            .find_map(|c| c.error.as_ref());
+                        bail!("ACME 验证失败: {domain} {status} {error:?}");
+                    }
+                }
+            }
+            bail!("ACME 验证超时: {domain}")
+        }
+        .await;
+        drop(guard);
+        result
+    }
+
+    async fn poll_order(&mut self, url: &str, expected: &[&str]) -> Result<Order> {
+        for attempt in 0..POLL_ATTEMPTS {
+            let order: Order = self.fetch(url).await?;
+            if expected.contains(&order.status.as_str()) {
+                return Ok(order);
+            }
+            if order.status == "invalid" {
+                bail!("ACME 订单无效: {:?}", order.error);
+            }
+            if attempt + 1 < POLL_ATTEMPTS {
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+        bail!("等待 ACME 订单超时: {url}")
+    }
+}
+
+fn replay_nonce(res: &reqwest::Response) -> Option<String> {
+    res.headers()
+        .get("replay-nonce")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+fn location(res: &reqwest::Response) -> Option<String> {
+    res.headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
@@ -0,0 +1,919 @@
+//! ACME（RFC 8555）证书自动签发与续期
+//!
+//! [`AcmeCertificateStore`] 向 Let's Encrypt 等 ACME 服务申请证书，完成 TLS-ALPN-01 或 HTTP-01 验证，
+//! 将账户私钥、证书与私钥保存在缓存目录，并在到期前自动续期。证书通过
+//! [`ReloadableCertificateStore`] 提供给 TLS 监听器，续期后无需重启即可生效。
+
+mod client;
+mod x509;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{DateTime, Utc};
+use http::StatusCode;
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use rustls_pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::TlsAcceptor;
+
+use super::tls::{
+    CertificateStore, ReloadableCertificateStore, apply_keylog, ensure_crypto_provider,
+};
+use crate::prelude::Route;
+use crate::{Request, Response, SilentError};
+
+/// Let's Encrypt 生产环境
+pub const LETS_ENCRYPT_PRODUCTION: &str = "https://acme-v02.api.letsencrypt.org/directory";
+/// Let's Encrypt 测试环境，频率限制宽松，签发的证书不受浏览器信任
+pub const LETS_ENCRYPT_STAGING: &str = "https://acme-staging-v02.api.letsencrypt.org/directory";
+
+/// TLS-ALPN-01 验证使用的 ALPN 协议
+const ACME_TLS_ALPN: &[u8] = b"acme-tls/1";
+const ACCOUNT_KEY_FILE: &str = "account.key";
+const CERT_FILE: &str = "cert.pem";
+const KEY_FILE: &str = "key.pem";
+/// 签发失败后的重试间隔
+const RETRY_INTERVAL: Duration = Duration::from_secs(3600);
+/// 连接 ACME 服务的超时
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// 单个 ACME 请求的超时，避免无响应的服务长期占用签发锁
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 域名验证方式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AcmeChallenge {
+    /// 在 TLS 监听器（443 端口）上以 `acme-tls/1` 协议响应验证，无需额外端口
+    #[default]
+    TlsAlpn01,
+    /// 通过 80 端口的 `/.well-known/acme-challenge/<token>` 响应验证，
+    /// 需挂载 [`AcmeCertificateStore::challenge_route`]
+    Http01,
+}
+
+impl AcmeChallenge {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AcmeChallenge::TlsAlpn01 => "tls-alpn-01",
+            AcmeChallenge::Http01 => "http-01",
+        }
+    }
+}
+
+/// 进行中的验证，由 TLS 监听器与 HTTP-01 路由读取
+#[derive(Clone, Default)]
+pub(crate) struct Challenges {
+    /// token -> key authorization
+    http: Arc<RwLock<HashMap<String, String>>>,
+    /// 域名 -> 验证证书
+    tls_alpn: Arc<RwLock<HashMap<String, Arc<CertifiedKey>>>>,
+}
+
+impl Challenges {
+    /// 登记验证响应，返回的守卫被丢弃时撤销
+    fn install(
+        &self,
+        challenge: AcmeChallenge,
+        domain: &str,
+        token: &str,
+        key_authorization: &str,
+    ) -> Result<ChallengeGuard> {
+        let domain = domain.to_ascii_lowercase();
+        match challenge {
+            AcmeChallenge::Http01 => {
+                self.http
+                    .write()
+                    .map_err(|_| anyhow!("challenge store poisoned"))?
+                    .insert(token.to_string(), key_authorization.to_string());
+            }
+            AcmeChallenge::TlsAlpn01 => {
+                let key = x509::generate_key()?;
+                let digest =
+                    ring::digest::digest(&ring::digest::SHA256, key_authorization.as_bytes());
+                let cert = x509::tls_alpn_certificate(&key, &domain, digest.as_ref())?;
+                ensure_crypto_provider();
+                let provider = rustls::crypto::CryptoProvider::get_default()
+                    .cloned()
+                    .unwrap_or_else(|| Arc::new(rustls::crypto::ring::default_provider()));
+                // acmeIdentifier 为关键扩展，webpki 无法解析，因此不经 from_der 校验
+                let signing_key = provider
+                    .key_provider
+                    .load_private_key(PrivateKeyDer::Pkcs8(key.into()))?;
+                let certified = CertifiedKey::new(vec![CertificateDer::from(cert)], signing_key);
+                self.tls_alpn
+                    .write()
+                    .map_err(|_| anyhow!("challenge store poisoned"))?
+                    .insert(domain.clone(), Arc::new(certified));
+            }
+        }
+        Ok(ChallengeGuard {
+            challenges: self.clone(),
+            challenge,
+            key: match challenge {
+                AcmeChallenge::Http01 => token.to_string(),
+                AcmeChallenge::TlsAlpn01 => domain,
+            },
+        })
+    }
+
+    fn key_authorization(&self, token: &str) -> Option<String> {
+        self.http.read().ok()?.get(token).cloned()
+    }
+
+    fn tls_alpn_certificate(&self, domain: &str) -> Option<Arc<CertifiedKey>> {
+        self.tls_alpn
+            .read()
+            .ok()?
+            .get(&domain.to_ascii_lowercase())
+            .cloned()
+    }
+}
+
+impl std::fmt::Debug for Challenges {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Challenges").finish_non_exhaustive()
+    }
+}
+
+pub(crate) struct ChallengeGuard {
+    challenges: Challenges,
+    challenge: AcmeChallenge,
+    key: String,
+}
+
+impl Drop for ChallengeGuard {
+    fn drop(&mut self) {
+        match self.challenge {
+            AcmeChallenge::Http01 => {
+                if let Ok(mut http) = self.challenges.http.write() {
+                    http.remove(&self.key);
+                }
+            }
+            AcmeChallenge::TlsAlpn01 => {
+                if let Ok(mut tls_alpn) = self.challenges.tls_alpn.write() {
+                    tls_alpn.remove(&self.key);
+                }
+            }
+        }
+    }
+}
+
+/// 客户端协商 `acme-tls/1` 时返回验证证书，否则返回已签发的证书
+#[derive(Debug)]
+struct ChallengeResolver {
+    challenges: Challenges,
+    certificate: Option<Arc<CertifiedKey>>,
+}
+
+impl ResolvesServerCert for ChallengeResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let acme = client_hello
+            .alpn()
+            .is_some_and(|mut protocols| protocols.any(|p| p == ACME_TLS_ALPN));
+        if acme {
+            return self
+                .challenges
+                .tls_alpn_certificate(client_hello.server_name()?);
+        }
+        self.certificate.clone()
+    }
+}
+
+/// 构建同时响应 TLS-ALPN-01 验证的 TLS acceptor；证书尚未签发时仅能完成验证握手
+pub(crate) fn tls_acceptor(
+    store: Option<&CertificateStore>,
+    challenges: &Challenges,
+    alpn: &[&[u8]],
+) -> Result<TlsAcceptor> {
+    ensure_crypto_provider();
+    let certificate = store
+        .map(|store| store.certified_key().map(Arc::new))
+        .transpose()?;
+    let mut config = rustls::ServerConfig::builder()
+        .with_no_client_auth()
+        .with_cert_resolver(Arc::new(ChallengeResolver {
+            challenges: challenges.clone(),
+            certificate,
+        }));
+    config.alpn_protocols = alpn.iter().map(|proto| proto.to_vec()).collect();
+    config.alpn_protocols.push(ACME_TLS_ALPN.to_vec());
+    apply_keylog(&mut config);
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// 自动签发与续期的证书存储
+///
+/// ```no_run
+/// use silent::prelude::*;
+/// use silent::{AcmeCertificateStore, Listener};
+///
+/// # async fn example() -> anyhow::Result<()> {
+/// let acme = AcmeCertificateStore::builder()
+///     .domains(["example.com", "www.example.com"])
+///     .contact("mailto:admin@example.com")
+///     .cache_dir("/var/lib/silent/acme")
+///     .build()?;
+/// // 首次签发前监听器已可响应 TLS-ALPN-01 验证
+/// let listener = Listener::from(tokio::net::TcpListener::bind("0.0.0.0:443").await?)
+///     .tls_with_reloadable(acme.reloadable());
+/// tokio::spawn(acme.clone().run());
+/// Server::new().listen(listener).serve(Route::new("")).await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AcmeCertificateStore {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    domains: Vec<String>,
+    contact: Vec<String>,
+    directory: String,
+    challenge: AcmeChallenge,
+    renew_before: Duration,
+    check_interval: Duration,
+    cache_dir: PathBuf,
+    client: reqwest::Client,
+    challenges: Challenges,
+    store: ReloadableCertificateStore,
+    /// 避免并发签发
+    issuing: tokio::sync::Mutex<()>,
+}
+
+impl AcmeCertificateStore {
+    pub fn builder() -> AcmeCertificateStoreBuilder {
+        AcmeCertificateStoreBuilder::default()
+    }
+
+    /// 供 TLS 监听器使用的证书存储，签发或续期后自动重新加载
+    pub fn reloadable(&self) -> ReloadableCertificateStore {
+        self.inner.store.clone()
+    }
+
+    /// HTTP-01 验证路由，需挂载在 80 端口服务的根路由上
+    pub fn challenge_route(&self) -> Route {
+        let challenges = self.inner.challenges.clone();
+        Route::new(".well-known/acme-challenge/<token>").get(move |req: Request| {
+            let challenges = challenges.clone();
+            async move {
+                let token: String = req.get_path_params("token")?;
+                match challenges.key_authorization(&token) {
+                    Some(key_authorization) => Ok(Response::text(&key_authorization)),
+                    None => Err(SilentError::business_error(
+                        StatusCode::NOT_FOUND,
+                        "challenge not found",
+                    )),
+                }
+            }
+        })
+    }
+
+    pub fn domains(&self) -> &[String] {
+        &self.inner.domains
+    }
+
+    pub fn cert_path(&self) -> PathBuf {
+        self.inner.cache_dir.join(CERT_FILE)
+    }
+
+    pub fn key_path(&self) -> PathBuf {
+        self.inner.cache_dir.join(KEY_FILE)
+    }
+
+    /// 缓存证书的到期时间，尚未签发时返回 `None`
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        let pem = std::fs::read(self.cert_path()).ok()?;
+        let cert = rustls_pemfile::certs(&mut pem.as_slice()).next()?.ok()?;
+        x509::not_after(&cert).ok()
+    }
+
+    /// 证书不存在或将在 `renew_before` 内到期
+    pub fn needs_renewal(&self) -> bool {
+        match self.expires_at() {
+            Some(expires_at) => {
+                let renew_before = chrono::Duration::from_std(self.inner.renew_before)
+                    .unwrap_or(chrono::Duration::MAX);
+                expires_at - Utc::now() <= renew_before
+            }
+            None => true,
+        }
+    }
+
+    /// 立即签发证书，写入缓存目录并重新加载
+    pub async fn renew(&self) -> Result<()> {
+        let inner = &self.inner;
+        let _issuing = inner.issuing.lock().await;
+        std::fs::create_dir_all(&inner.cache_dir)
+            .with_context(|| format!("创建 ACME 缓存目录失败: {}", inner.cache_dir.display()))?;
+        let account_key = self.account_key()?;
+        let mut account = client::Account::register(
+            inner.client.clone(),
+            &inner.directory,
+            &account_key,
+            &inner.contact,
+        )
+        .await?;
+        let cert_key = x509::generate_key()?;
+        let chain = account
+            .issue(
+                &inner.domains,
+                inner.challenge,
+                &inner.challenges,
+                &cert_key,
+            )
+            .await?;
+        // 证书与私钥先写入临时文件并校验二者匹配，再依次替换，重新加载时总是成对的新文件
+        let key_tmp = write_private_tmp(&self.key_path(), x509::key_pem(&cert_key)?.as_bytes())?;
+        let cert_tmp = write_private_tmp(&self.cert_path(), chain.as_bytes())?;
+        let verified = CertificateStore::builder()
+            .cert_path(&cert_tmp)
+            .key_path(&key_tmp)
+            .build()
+            .and_then(|store| store.certified_key())
+            .context("签发的证书与私钥不匹配");
+        if let Err(e) = verified {
+            let _ = std::fs::remove_file(&key_tmp);
+            let _ = std::fs::remove_file(&cert_tmp);
+            return Err(e);
+        }
+        rename(&key_tmp, &self.key_path())?;
+        rename(&cert_tmp, &self.cert_path())?;
+        inner.store.reload()?;
+        tracing::info!(
+            domains = ?inner.domains,
+            expires_at = ?self.expires_at(),
+            "ACME certificate issued"
+        );
+        Ok(())
+    }
+
+    /// 读取或生成账户私钥
+    fn account_key(&self) -> Result<Vec<u8>> {
+        let path = self.inner.cache_dir.join(ACCOUNT_KEY_FILE);
+        if path.exists() {
+            let pem = std::fs::read(&path)
+                .with_context(|| format!("读取 ACME 账户私钥失败: {}", path.display()))?;
+            let key = rustls_pemfile::pkcs8_private_keys(&mut pem.as_slice())
+                .next()
+                .ok_or_else(|| anyhow!("ACME 账户私钥文件无效: {}", path.display()))??;
+            return Ok(key.secret_pkcs8_der().to_vec());
+        }
+        let key = x509::generate_key()?;
+        write_private(&path, x509::key_pem(&key)?.as_bytes())?;
+        Ok(key)
+    }
+
+    /// 持续维护证书：需要时签发，失败后每小时重试，之后按 `check_interval` 检查到期时间
+    pub async fn run(self) {
+        loop {
+            let wait = if self.needs_renewal() {
+                match self.renew().await {
+                    Ok(()) => self.inner.check_interval,
+                    Err(e) => {
+                        tracing::error!(error = ?e, domains = ?self.inner.domains, "ACME certificate issuance failed");
+                        RETRY_INTERVAL.min(self.inner.check_interval)
+                    }
+                }
+            } else {
+                self.inner.check_interval
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// 以 0600 权限原子写入
+fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp = write_private_tmp(path, contents)?;
+    rename(&tmp, path)
+}
+
+/// 以 0600 权限写入 `path` 旁的临时文件并落盘，返回临时文件路径
+fn write_private_tmp(path: &Path, contents: &[u8]) -> Result<PathBuf> {
+    use std::io::Write;
+
+    let tmp = path.with_extension("tmp");
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options
+        .open(&tmp)
+        .with_context(|| format!("写入文件失败: {}", tmp.display()))?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    Ok(tmp)
+}
+
+fn rename(tmp: &Path, path: &Path) -> Result<()> {
+    std::fs::rename(tmp, path).with_context(|| format!("写入文件失败: {}", path.display()))
+}
+
+/// 带连接与请求超时的 HTTP 客户端
+fn http_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+}
+
+pub struct AcmeCertificateStoreBuilder {
+    domains: Vec<String>,
+    contact: Vec<String>,
+    directory: String,
+    challenge: AcmeChallenge,
+    renew_before: Duration,
+    check_interval: Duration,
+    cache_dir: Option<PathBuf>,
+}
+
+impl Default for AcmeCertificateStoreBuilder {
+    fn default() -> Self {
+        Self {
+            domains: vec![],
+            contact: vec![],
+            directory: LETS_ENCRYPT_PRODUCTION.to_string(),
+            challenge: AcmeChallenge::default(),
+            renew_before: Duration::from_secs(30 * 24 * 3600),
+            check_interval: Duration::from_secs(12 * 3600),
+            cache_dir: None,
+        }
+    }
+}
+
+impl AcmeCertificateStoreBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 证书包含的域名，第一个域名作为 CN
+    pub fn domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.domains
+            .extend(domains.into_iter().map(|d| d.into().to_ascii_lowercase()));
+        self
+    }
+
+    /// 账户联系方式，如 `mailto:admin@example.com`，可多次调用
+    pub fn contact<S: Into<String>>(mut self, contact: S) -> Self {
+        self.contact.push(contact.into());
+        self
+    }
+
+    /// ACME 目录地址，默认 [`LETS_ENCRYPT_PRODUCTION`]
+    pub fn directory<S: Into<String>>(mut self, url: S) -> Self {
+        self.directory = url.into();
+        self
+    }
+
+    /// 验证方式，默认 [`AcmeChallenge::TlsAlpn01`]
+    pub fn challenge(mut self, challenge: AcmeChallenge) -> Self {
+        self.challenge = challenge;
+        self
+    }
+
+    /// 到期前多久续期，默认 30 天
+    pub fn renew_before(mut self, duration: Duration) -> Self {
+        self.renew_before = duration;
+        self
+    }
+
+    /// 检查到期时间的间隔，默认 12 小时
+    pub fn check_interval(mut self, interval: Duration) -> Self {
+        self.check_interval = interval;
+        self
+    }
+
+    /// 保存账户私钥、证书与私钥的目录；不同域名组合应使用不同目录
+    pub fn cache_dir<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.cache_dir = Some(path.into());
+        self
+    }
+
+    /// 构建证书存储，缓存目录中已有证书时直接加载
+    pub fn build(self) -> Result<AcmeCertificateStore> {
+        if self.domains.is_empty() {
+            bail!("未设置域名，请调用 domains");
+        }
+        let cache_dir = self
+            .cache_dir
+            .ok_or_else(|| anyhow!("未设置缓存目录，请调用 cache_dir"))?;
+        let challenges = Challenges::default();
+        let store = ReloadableCertificateStore::acme(
+            cache_dir.join(CERT_FILE),
+            cache_dir.join(KEY_FILE),
+            challenges.clone(),
+        )?;
+        Ok(AcmeCertificateStore {
+            inner: Arc::new(Inner {
+                domains: self.domains,
+                contact: self.contact,
+                directory: self.directory,
+                challenge: self.challenge,
+                renew_before: self.renew_before,
+                check_interval: self.check_interval,
+                cache_dir,
+                client: http_client(),
+                challenges,
+                store,
+                issuing: tokio::sync::Mutex::new(()),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use bytes::Bytes;
+    use http_body_util::{BodyExt, Full};
+    use hyper::body::Incoming;
+    use hyper::service::service_fn;
+    use hyper_util::rt::TokioIo;
+    use serde_json::{Value, json};
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::net::TcpListener;
+
+    #[derive(Default)]
+    struct MockState {
+        validated: bool,
+        finalized: bool,
+        /// 各请求 JWS 头部中的认证方式
+        auth: Vec<(String, &'static str)>,
+        /// finalize 时提交的 CSR（DER）
+        csr: Vec<u8>,
+        /// 为另一把私钥签发证书，模拟与 CSR 不匹配的证书
+        foreign_key: bool,
+    }
+
+    /// CSR 中的 P-256 公钥，签发的证书与客户端生成的私钥匹配
+    struct CsrPublicKey(Vec<u8>);
+
+    impl rcgen::PublicKeyData for CsrPublicKey {
+        fn der_bytes(&self) -> &[u8] {
+            &self.0
+        }
+
+        fn algorithm(&self) -> &'static rcgen::SignatureAlgorithm {
+            &rcgen::PKCS_ECDSA_P256_SHA256
+        }
+    }
+
+    /// 最小的 ACME 服务：HTTP-01 验证时直接调用 `route` 检查 key authorization
+    async fn mock_acme(route: Route) -> (String, Arc<Mutex<MockState>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let nonce = Arc::new(AtomicUsize::new(0));
+        let route = Arc::new(route);
+        let url = base.clone();
+        let mock_state = state.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let (state, nonce, route, base) =
+                    (state.clone(), nonce.clone(), route.clone(), url.clone());
+                tokio::spawn(async move {
+                    let service = service_fn(move |req: http::Request<Incoming>| {
+                        let (state, nonce, route, base) =
+                            (state.clone(), nonce.clone(), route.clone(), base.clone());
+                        async move {
+                            let path = req.uri().path().to_string();
+                            let body = req.into_body().collect().await?.to_bytes();
+                            let (status, location, body) =
+                                handle(&state, &route, &base, &path, &body).await;
+                            let mut res = http::Response::new(Full::new(Bytes::from(body)));
+                            *res.status_mut() = status;
+                            let n = nonce.fetch_add(1, Ordering::Relaxed);
+                            res.headers_mut()
+                                .insert("replay-nonce", format!("nonce-{n}").parse().unwrap());
+                            if let Some(location) = location {
+                                res.headers_mut()
+                                    .insert("location", location.parse().unwrap());
+                            }
+                            Ok::<_, hyper::Error>(res)
+                        }
+                    });
+                    let _ = hyper::server::conn::http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await;
+                });
+            }
+        });
+        (format!("{base}/directory"), mock_state)
+    }
+
+    async fn handle(
+        state: &Mutex<MockState>,
+        route: &Route,
+        base: &str,
+        path: &str,
+        body: &[u8],
+    ) -> (StatusCode, Option<String>, Vec<u8>) {
+        let payload = if body.is_empty() {
+            Value::Null
+        } else {
+            let jws: Value = serde_json::from_slice(body).unwrap();
+            let protected: Value = serde_json::from_slice(
+                &URL_SAFE_NO_PAD
+                    .decode(jws["protected"].as_str().unwrap())
+                    .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(protected["alg"], "ES256");
+            assert_eq!(protected["url"], format!("{base}{path}"));
+            assert!(protected["nonce"].as_str().unwrap().starts_with("nonce-"));
+            let auth = if protected.get("kid").is_some() {
+                "kid"
+            } else {
+                "jwk"
+            };
+            state.lock().unwrap().auth.push((path.to_string(), auth));
+            let payload = jws["payload"].as_str().unwrap();
+            if payload.is_empty() {
+                Value::Null
+            } else {
+                serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap()
+            }
+        };
+        let order = |state: &MockState| {
+            let status = match (state.validated, state.finalized) {
+                (_, true) => "valid",
+                (true, false) => "ready",
+                _ => "pending",
+            };
+            json!({
+                "status": status,
+                "authorizations": [format!("{base}/authz/1")],
+                "finalize": format!("{base}/finalize/1"),
+                "certificate": state.finalized.then(|| format!("{base}/cert/1")),
+            })
+        };
+        let ok = |value: Value| (StatusCode::OK, None, value.to_string().into_bytes());
+        match path {
+            "/directory" => ok(json!({
+                "newNonce": format!("{base}/nonce"),
+                "newAccount": format!("{base}/account"),
+                "newOrder": format!("{base}/order"),
+            })),
+            "/nonce" => (StatusCode::OK, None, vec![]),
+            "/account" => {
+                assert_eq!(payload["termsOfServiceAgreed"], true);
+                (
+                    StatusCode::CREATED,
+                    Some(format!("{base}/acct/1")),
+                    b"{}".to_vec(),
+                )
+            }
+            "/order" => {
+                assert_eq!(payload["identifiers"][0]["value"], "example.com");
+                // 新订单需要重新提交 CSR
+                let mut state = state.lock().unwrap();
+                state.finalized = false;
+                let order = order(&state);
+                (
+                    StatusCode::CREATED,
+                    Some(format!("{base}/order/1")),
+                    order.to_string().into_bytes(),
+                )
+            }
+            "/order/1" => ok(order(&state.lock().unwrap())),
+            "/authz/1" => {
+                let status = if state.lock().unwrap().validated {
+                    "valid"
+                } else {
+                    "pending"
+                };
+                ok(json!({
+                    "status": status,
+                    "identifier": {"type": "dns", "value": "example.com"},
+                    "challenges": [
+                        {"type": "tls-alpn-01", "url": format!("{base}/chall/2"), "token": "other"},
+                        {"type": "http-01", "url": format!("{base}/chall/1"), "token": "tok"},
+                    ],
+                }))
+            }
+            "/chall/1" => {
+                let mut req = Request::empty();
+                *req.uri_mut() = "/.well-known/acme-challenge/tok".parse().unwrap();
+                let res = crate::Handler::call(route, req).await.unwrap();
+                let key_authorization = res.body.collect().await.unwrap().to_bytes();
+                assert!(key_authorization.starts_with(b"tok."));
+                state.lock().unwrap().validated = true;
+                ok(json!({"status": "processing"}))
+            }
+            "/finalize/1" => {
+                let csr = URL_SAFE_NO_PAD
+                    .decode(payload["csr"].as_str().unwrap())
+                    .unwrap();
+                let mut state = state.lock().unwrap();
+                state.csr = csr;
+                state.finalized = true;
+                ok(order(&state))
+            }
+            "/cert/1" => {
+                use x509_parser::prelude::FromDer;
+
+                let (mut csr, foreign_key) = {
+                    let state = state.lock().unwrap();
+                    (state.csr.clone(), state.foreign_key)
+                };
+                if foreign_key {
+                    let key = x509::generate_key().unwrap();
+                    csr = x509::csr(&key, &["example.com".to_string()]).unwrap();
+                }
+                let (_, request) =
+                    x509_parser::certification_request::X509CertificationRequest::from_der(&csr)
+                        .unwrap();
+                let public_key = CsrPublicKey(
+                    request
+                        .certification_request_info
+                        .subject_pki
+                        .subject_public_key
+                        .data
+                        .to_vec(),
+                );
+                let issuer = rcgen::Issuer::new(
+                    rcgen::CertificateParams::default(),
+                    rcgen::KeyPair::generate().unwrap(),
+                );
+                let mut params =
+                    rcgen::CertificateParams::new(["example.com".to_string()]).unwrap();
+                params.not_after = time::OffsetDateTime::now_utc() + time::Duration::days(7);
+                let cert = params.signed_by(&public_key, &issuer).unwrap();
+                (StatusCode::OK, None, cert.pem().into_bytes())
+            }
+            _ => (StatusCode::NOT_FOUND, None, vec![]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_acme_http01_issue() {
+        let dir = tempfile::tempdir().unwrap();
+        let acme = AcmeCertificateStore::builder()
+            .domains(["Example.com"])
+            .contact("mailto:admin@example.com")
+            .challenge(AcmeChallenge::Http01)
+            .cache_dir(dir.path())
+            .directory("http://127.0.0.1:1/directory")
+            .build()
+            .unwrap();
+        let (directory, mock) = mock_acme(Route::new_root().append(acme.challenge_route())).await;
+        let acme = AcmeCertificateStore {
+            inner: Arc::new(Inner {
+                directory,
+                domains: acme.inner.domains.clone(),
+                contact: acme.inner.contact.clone(),
+                challenge: acme.inner.challenge,
+                renew_before: acme.inner.renew_before,
+                check_interval: acme.inner.check_interval,
+                cache_dir: acme.inner.cache_dir.clone(),
+                client: http_client(),
+                challenges: acme.inner.challenges.clone(),
+                store: acme.inner.store.clone(),
+                issuing: tokio::sync::Mutex::new(()),
+            }),
+        };
+        assert!(acme.needs_renewal());
+        assert!(acme.reloadable().https_acceptor().is_ok());
+
+        acme.renew().await.unwrap();
+        assert!(dir.path().join(ACCOUNT_KEY_FILE).exists());
+        assert!(acme.key_path().exists());
+        let days = (acme.expires_at().unwrap() - Utc::now()).num_days();
+        assert!((6..=7).contains(&days));
+        // 有效期短于默认的 30 天续期窗口
+        assert!(acme.needs_renewal());
+        // 验证结束后撤销
+        assert!(acme.inner.challenges.key_authorization("tok").is_none());
+
+        // 再次签发复用账户私钥
+        let account_key = std::fs::read(dir.path().join(ACCOUNT_KEY_FILE)).unwrap();
+        acme.renew().await.unwrap();
+        assert_eq!(
+            std::fs::read(dir.path().join(ACCOUNT_KEY_FILE)).unwrap(),
+            account_key
+        );
+
+        // 证书与私钥不匹配时保留缓存中原有的一对
+        let cert = std::fs::read(acme.cert_path()).unwrap();
+        let key = std::fs::read(acme.key_path()).unwrap();
+        mock.lock().unwrap().foreign_key = true;
+        let err = acme.renew().await.unwrap_err();
+        assert!(format!("{err:#}").contains("签发的证书与私钥不匹配"));
+        assert_eq!(std::fs::read(acme.cert_path()).unwrap(), cert);
+        assert_eq!(std::fs::read(acme.key_path()).unwrap(), key);
+        assert!(!acme.cert_path().with_extension("tmp").exists());
+        assert!(acme.reloadable().https_acceptor().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_tls_alpn_challenge_handshake() {
+        #[derive(Debug)]
+        struct AcceptAny;
+
+        impl rustls::client::danger::ServerCertVerifier for AcceptAny {
+            fn verify_server_cert(
+                &self,
+                _: &CertificateDer<'_>,
+                _: &[CertificateDer<'_>],
+                _: &rustls_pki_types::ServerName<'_>,
+                _: &[u8],
+                _: rustls_pki_types::UnixTime,
+            ) -> std::result::Result<rustls::client::danger::ServerCertVerified, rustls::Error>
+            {
+                Ok(rustls::client::danger::ServerCertVerified::assertion())
+            }
+
+            fn verify_tls12_signature(
+                &self,
+                _: &[u8],
+                _: &CertificateDer<'_>,
+                _: &rustls::DigitallySignedStruct,
+            ) -> std::result::Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error>
+            {
+                Ok(rustls::client::danger::HandshakeSignatureValid::assertion())
+            }
+
+            fn verify_tls13_signature(
+                &self,
+                _: &[u8],
+                _: &CertificateDer<'_>,
+                _: &rustls::DigitallySignedStruct,
+            ) -> std::result::Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error>
+            {
+                Ok(rustls::client::danger::HandshakeSignatureValid::assertion())
+            }
+
+            fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+                rustls::crypto::ring::default_provider()
+                    .signature_verification_algorithms
+                    .supported_schemes()
+            }
+        }
+
+        let challenges = Challenges::default();
+        let guard = challenges
+            .install(AcmeChallenge::TlsAlpn01, "Example.com", "tok", "tok.thumb")
+            .unwrap();
+        let acceptor = tls_acceptor(None, &challenges, &[b"h2", b"http/1.1"]).unwrap();
+
+        let handshake = |alpn: &'static [u8]| {
+            let acceptor = acceptor.clone();
+            async move {
+                let (client, server) = tokio::io::duplex(64 * 1024);
+                tokio::spawn(async move {
+                    let _ = acceptor.accept(server).await;
+                });
+                let mut config = rustls::ClientConfig::builder()
+                    .dangerous()
+                    .with_custom_certificate_verifier(Arc::new(AcceptAny))
+                    .with_no_client_auth();
+                config.alpn_protocols = vec![alpn.to_vec()];
+                let name = rustls_pki_types::ServerName::try_from("example.com").unwrap();
+                let conn = tokio_rustls::TlsConnector::from(Arc::new(config))
+                    .connect(name, client)
+                    .await?;
+                Ok::<_, std::io::Error>(conn.get_ref().1.peer_certificates().unwrap()[0].to_vec())
+            }
+        };
+
+        let cert = handshake(ACME_TLS_ALPN).await.unwrap();
+        let digest = ring::digest::digest(&ring::digest::SHA256, b"tok.thumb");
+        assert!(cert.windows(32).any(|w| w == digest.as_ref()));
+        // 尚未签发证书时普通握手失败
+        assert!(handshake(b"h2").await.is_err());
+        // 验证结束后不再响应
+        drop(guard);
+        assert!(handshake(ACME_TLS_ALPN).await.is_err());
+    }
+
+    #[test]
+    fn test_builder_requires_domains_and_cache_dir() {
+        let err = AcmeCertificateStore::builder()
+            .cache_dir("/tmp")
+            .build()
+            .err()
+            .unwrap();
+        assert!(format!("{err:#}").contains("未设置域名"));
+        let err = AcmeCertificateStore::builder()
+            .domains(["example.com"])
+            .build()
+            .err()
+            .unwrap();
+        assert!(format!("{err:#}").contains("未设置缓存目录"));
+    }
+}
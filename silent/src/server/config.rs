@@ -2,12 +2,29 @@ use std::sync::{RwLock, RwLockReadGuard};
 use std::time::Duration;
 
 /// 连接级别的保护配置。
+///
+/// 后续版本可能新增字段，请通过 [`ConnectionLimits::new`] 与 `with_*` 方法构造：
+///
+/// ```rust
+/// use silent::ConnectionLimits;
+/// use std::time::Duration;
+///
+/// let limits = ConnectionLimits::new()
+///     .with_handler_timeout(Duration::from_secs(30))
+///     .with_max_body_size(1024 * 1024)
+///     .with_max_header_count(64);
+/// ```
 #[derive(Clone, Debug, Default)]
+#[non_exhaustive]
 pub struct ConnectionLimits {
     /// 处理单个连接（含 HTTP1/2/3）的超时时间，超时后任务将被取消。
     pub handler_timeout: Option<Duration>,
     /// HTTP 请求体大小上限（字节）。`None` 表示不限制。
     pub max_body_size: Option<usize>,
+    /// 请求头总大小上限（字节，按名称与值的长度累计），超出时返回 `431`。`None` 表示仅受 hyper 默认解析上限约束。
+    pub max_header_bytes: Option<usize>,
+    /// 请求头数量上限，超出时返回 `431`。`None` 表示仅受 hyper 默认解析上限约束（HTTP/1 为 100）。
+    pub max_header_count: Option<usize>,
     /// QUIC/HTTP3 请求体读取超时。
     pub h3_read_timeout: Option<Duration>,
     /// WebTransport 单帧/消息大小上限（字节）。
@@ -28,13 +45,255 @@ pub struct ConnectionLimits {
     pub h3_yield_bytes: Option<usize>,
 }
 
+impl ConnectionLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置处理单个连接的超时时间。
+    pub fn with_handler_timeout(mut self, timeout: Duration) -> Self {
+        self.handler_timeout = Some(timeout);
+        self
+    }
+
+    /// 设置 HTTP 请求体大小上限（字节）。
+    pub fn with_max_body_size(mut self, size: usize) -> Self {
+        self.max_body_size = Some(size);
+        self
+    }
+
+    /// 设置请求头总大小上限（字节）。
+    pub fn with_max_header_bytes(mut self, size: usize) -> Self {
+        self.max_header_bytes = Some(size);
+        self
+    }
+
+    /// 设置请求头数量上限。
+    pub fn with_max_header_count(mut self, count: usize) -> Self {
+        self.max_header_count = Some(count);
+        self
+    }
+
+    /// 设置 QUIC/HTTP3 请求体读取超时。
+    pub fn with_h3_read_timeout(mut self, timeout: Duration) -> Self {
+        self.h3_read_timeout = Some(timeout);
+        self
+    }
+
+    /// 设置 WebTransport 单帧/消息大小上限（字节）。
+    pub fn with_max_webtransport_frame_size(mut self, size: usize) -> Self {
+        self.max_webtransport_frame_size = Some(size);
+        self
+    }
+
+    /// 设置 WebTransport 读取超时。
+    pub fn with_webtransport_read_timeout(mut self, timeout: Duration) -> Self {
+        self.webtransport_read_timeout = Some(timeout);
+        self
+    }
+
+    /// 设置 WebTransport 会话并发上限。
+    pub fn with_max_webtransport_sessions(mut self, count: usize) -> Self {
+        self.max_webtransport_sessions = Some(count);
+        self
+    }
+
+    /// 设置 WebTransport 每帧大小上限（字节）。
+    pub fn with_webtransport_datagram_max_size(mut self, size: usize) -> Self {
+        self.webtransport_datagram_max_size = Some(size);
+        self
+    }
+
+    /// 设置 WebTransport 每连接 datagram 速率（每秒）上限。
+    pub fn with_webtransport_datagram_rate(mut self, rate: u64) -> Self {
+        self.webtransport_datagram_rate = Some(rate);
+        self
+    }
+
+    /// 设置是否统计 WebTransport datagram 丢弃计数。
+    pub fn with_webtransport_datagram_drop_metric(mut self, enabled: bool) -> Self {
+        self.webtransport_datagram_drop_metric = enabled;
+        self
+    }
+
+    /// 设置 HTTP/3 响应体分块大小（字节）。
+    pub fn with_h3_chunk_size(mut self, size: usize) -> Self {
+        self.h3_chunk_size = Some(size);
+        self
+    }
+
+    /// 设置 HTTP/3 响应体 yield 阈值（字节）。
+    pub fn with_h3_yield_bytes(mut self, size: usize) -> Self {
+        self.h3_yield_bytes = Some(size);
+        self
+    }
+}
+
+/// HTTP/2 连接参数。
+///
+/// 默认值偏向吞吐：1MB 流窗口、2MB 连接窗口、开启自适应窗口（开启后会覆盖初始窗口大小）、
+/// 每连接 256 个并发流；未设置的项使用 hyper 的默认值。
+///
+/// 后续版本可能新增字段，请通过 [`Http2Config::new`] 与 `with_*` 方法构造：
+///
+/// ```rust
+/// use silent::Http2Config;
+/// use std::time::Duration;
+///
+/// let http2 = Http2Config::new()
+///     .with_max_concurrent_streams(1024)
+///     .with_keep_alive_interval(Duration::from_secs(30))
+///     .with_keep_alive_timeout(Duration::from_secs(10));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Http2Config {
+    /// 每个连接的最大并发流数。
+    pub max_concurrent_streams: Option<u32>,
+    /// 流级初始窗口大小（字节）。
+    pub initial_stream_window_size: Option<u32>,
+    /// 连接级初始窗口大小（字节）。
+    pub initial_connection_window_size: Option<u32>,
+    /// 根据 BDP 自动调整窗口大小。
+    pub adaptive_window: bool,
+    /// 最大帧长度（字节），取值范围 16KB ~ 16MB。
+    pub max_frame_size: Option<u32>,
+    /// 发送 keepalive PING 的间隔，`None` 表示不发送。
+    pub keep_alive_interval: Option<Duration>,
+    /// 等待 PING 响应的超时时间，超时后关闭连接；仅在设置了间隔时生效。
+    pub keep_alive_timeout: Option<Duration>,
+}
+
+impl Http2Config {
+    pub const fn new() -> Self {
+        Self {
+            max_concurrent_streams: Some(256),
+            initial_stream_window_size: Some(1024 * 1024),
+            initial_connection_window_size: Some(2 * 1024 * 1024),
+            adaptive_window: true,
+            max_frame_size: None,
+            keep_alive_interval: None,
+            keep_alive_timeout: None,
+        }
+    }
+
+    /// 设置每个连接的最大并发流数。
+    pub fn with_max_concurrent_streams(mut self, max: u32) -> Self {
+        self.max_concurrent_streams = Some(max);
+        self
+    }
+
+    /// 设置流级初始窗口大小（字节）。
+    pub fn with_initial_stream_window_size(mut self, size: u32) -> Self {
+        self.initial_stream_window_size = Some(size);
+        self
+    }
+
+    /// 设置连接级初始窗口大小（字节）。
+    pub fn with_initial_connection_window_size(mut self, size: u32) -> Self {
+        self.initial_connection_window_size = Some(size);
+        self
+    }
+
+    /// 开启或关闭自适应窗口。
+    pub fn with_adaptive_window(mut self, enabled: bool) -> Self {
+        self.adaptive_window = enabled;
+        self
+    }
+
+    /// 设置最大帧长度（字节）。
+    pub fn with_max_frame_size(mut self, size: u32) -> Self {
+        self.max_frame_size = Some(size);
+        self
+    }
+
+    /// 设置发送 keepalive PING 的间隔。
+    pub fn with_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// 设置等待 PING 响应的超时时间。
+    pub fn with_keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.keep_alive_timeout = Some(timeout);
+        self
+    }
+}
+
+impl Default for Http2Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Server 级配置入口。
+///
+/// 后续版本可能新增字段，请通过 [`ServerConfig::new`] 与 `with_*` 方法构造：
+///
+/// ```rust
+/// use silent::{ConnectionLimits, ServerConfig};
+///
+/// let config = ServerConfig::new()
+///     .with_connection_limits(ConnectionLimits::default())
+///     .with_state(String::from("shared"));
+/// ```
 #[derive(Clone, Debug, Default)]
+#[non_exhaustive]
 pub struct ServerConfig {
     pub connection_limits: ConnectionLimits,
+    /// HTTP/2 连接参数。
+    pub http2: Http2Config,
+    /// Server 级共享状态，会注入每个请求；路由上通过 `with_state` 设置的同类型状态优先。
+    pub state: crate::State,
     /// QUIC 传输参数（仅在 `quic` 特性开启时生效）。
     #[cfg(feature = "quic")]
     pub quic_transport: Option<crate::server::quic::QuicTransportConfig>,
+    /// 将 TLS/QUIC 会话密钥写入 `SSLKEYLOGFILE` 指向的文件，供 Wireshark 解密抓包（仅在 `tls-keylog` 特性开启时生效）。
+    ///
+    /// 密钥泄露即可解密全部流量，只应在排查问题时临时开启。
+    #[cfg(feature = "tls-keylog")]
+    pub tls_keylog: bool,
+}
+
+impl ServerConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置连接级别超时/请求体大小限制。
+    pub fn with_connection_limits(mut self, limits: ConnectionLimits) -> Self {
+        self.connection_limits = limits;
+        self
+    }
+
+    /// 设置 HTTP/2 连接参数。
+    pub fn with_http2_config(mut self, http2: Http2Config) -> Self {
+        self.http2 = http2;
+        self
+    }
+
+    /// 注入 Server 级共享状态。
+    pub fn with_state<T: Send + Sync + Clone + 'static>(mut self, val: T) -> Self {
+        self.state.insert(val);
+        self
+    }
+
+    /// 设置 QUIC 传输参数。
+    #[cfg(feature = "quic")]
+    pub fn with_quic_transport(
+        mut self,
+        transport: crate::server::quic::QuicTransportConfig,
+    ) -> Self {
+        self.quic_transport = Some(transport);
+        self
+    }
+
+    /// 开启或关闭 TLS/QUIC 会话密钥导出。
+    #[cfg(feature = "tls-keylog")]
+    pub fn with_tls_keylog(mut self, enabled: bool) -> Self {
+        self.tls_keylog = enabled;
+        self
+    }
 }
 
 /// 运行时可查询的配置注册表，便于 RouteConnectionService 获取 Server 配置。
@@ -50,6 +309,8 @@ static CONFIG_REGISTRY: ServerConfigRegistry = ServerConfigRegistry {
         connection_limits: ConnectionLimits {
             handler_timeout: None,
             max_body_size: None,
+            max_header_bytes: None,
+            max_header_count: None,
             h3_read_timeout: None,
             max_webtransport_frame_size: None,
             webtransport_read_timeout: None,
@@ -60,8 +321,12 @@ static CONFIG_REGISTRY: ServerConfigRegistry = ServerConfigRegistry {
             h3_chunk_size: None,
             h3_yield_bytes: None,
         },
+        http2: Http2Config::new(),
+        state: crate::State::new(),
         #[cfg(feature = "quic")]
         quic_transport: None,
+        #[cfg(feature = "tls-keylog")]
+        tls_keylog: false,
     }),
 };
 
@@ -97,6 +362,8 @@ mod tests {
         let limits = ConnectionLimits::default();
         assert_eq!(limits.handler_timeout, None);
         assert_eq!(limits.max_body_size, None);
+        assert_eq!(limits.max_header_bytes, None);
+        assert_eq!(limits.max_header_count, None);
         assert_eq!(limits.h3_read_timeout, None);
         assert_eq!(limits.max_webtransport_frame_size, None);
         assert_eq!(limits.webtransport_read_timeout, None);
@@ -113,6 +380,8 @@ mod tests {
         let limits = ConnectionLimits {
             handler_timeout: Some(std::time::Duration::from_secs(30)),
             max_body_size: Some(1024),
+            max_header_bytes: Some(16 * 1024),
+            max_header_count: Some(64),
             h3_read_timeout: Some(std::time::Duration::from_secs(20)),
             max_webtransport_frame_size: Some(4096),
             webtransport_read_timeout: Some(std::time::Duration::from_secs(10)),
@@ -127,6 +396,8 @@ mod tests {
         let cloned = limits.clone();
         assert_eq!(cloned.handler_timeout, limits.handler_timeout);
         assert_eq!(cloned.max_body_size, limits.max_body_size);
+        assert_eq!(cloned.max_header_bytes, limits.max_header_bytes);
+        assert_eq!(cloned.max_header_count, limits.max_header_count);
         assert_eq!(cloned.h3_read_timeout, limits.h3_read_timeout);
         assert_eq!(
             cloned.max_webtransport_frame_size,
@@ -156,11 +427,79 @@ mod tests {
         assert_eq!(cloned.h3_yield_bytes, limits.h3_yield_bytes);
     }
 
+    #[test]
+    fn test_connection_limits_builder() {
+        let limits = ConnectionLimits::new()
+            .with_handler_timeout(std::time::Duration::from_secs(30))
+            .with_max_body_size(1024)
+            .with_max_header_bytes(16 * 1024)
+            .with_max_header_count(64)
+            .with_h3_read_timeout(std::time::Duration::from_secs(20))
+            .with_max_webtransport_frame_size(4096)
+            .with_webtransport_read_timeout(std::time::Duration::from_secs(10))
+            .with_max_webtransport_sessions(100)
+            .with_webtransport_datagram_max_size(1350)
+            .with_webtransport_datagram_rate(1000)
+            .with_webtransport_datagram_drop_metric(true)
+            .with_h3_chunk_size(32 * 1024)
+            .with_h3_yield_bytes(512 * 1024);
+        assert_eq!(
+            limits.handler_timeout,
+            Some(std::time::Duration::from_secs(30))
+        );
+        assert_eq!(limits.max_body_size, Some(1024));
+        assert_eq!(limits.max_header_bytes, Some(16 * 1024));
+        assert_eq!(limits.max_header_count, Some(64));
+        assert_eq!(
+            limits.h3_read_timeout,
+            Some(std::time::Duration::from_secs(20))
+        );
+        assert_eq!(limits.max_webtransport_frame_size, Some(4096));
+        assert_eq!(
+            limits.webtransport_read_timeout,
+            Some(std::time::Duration::from_secs(10))
+        );
+        assert_eq!(limits.max_webtransport_sessions, Some(100));
+        assert_eq!(limits.webtransport_datagram_max_size, Some(1350));
+        assert_eq!(limits.webtransport_datagram_rate, Some(1000));
+        assert!(limits.webtransport_datagram_drop_metric);
+        assert_eq!(limits.h3_chunk_size, Some(32 * 1024));
+        assert_eq!(limits.h3_yield_bytes, Some(512 * 1024));
+    }
+
     #[test]
     fn test_server_config_default() {
         let config = ServerConfig::default();
         assert_eq!(config.connection_limits.handler_timeout, None);
         assert_eq!(config.connection_limits.max_body_size, None);
+        assert_eq!(config.http2, Http2Config::new());
+    }
+
+    #[test]
+    fn test_server_config_builder() {
+        let http2 = Http2Config::new().with_max_concurrent_streams(16);
+        let config = ServerConfig::new()
+            .with_connection_limits(ConnectionLimits {
+                max_body_size: Some(1024),
+                ..Default::default()
+            })
+            .with_http2_config(http2)
+            .with_state(42u32);
+        assert_eq!(config.connection_limits.max_body_size, Some(1024));
+        assert_eq!(config.http2.max_concurrent_streams, Some(16));
+        assert_eq!(config.state.get::<u32>(), Some(&42));
+    }
+
+    #[test]
+    fn test_http2_config_default() {
+        let http2 = Http2Config::default();
+        assert_eq!(http2.max_concurrent_streams, Some(256));
+        assert_eq!(http2.initial_stream_window_size, Some(1024 * 1024));
+        assert_eq!(http2.initial_connection_window_size, Some(2 * 1024 * 1024));
+        assert!(http2.adaptive_window);
+        assert_eq!(http2.max_frame_size, None);
+        assert_eq!(http2.keep_alive_interval, None);
+        assert_eq!(http2.keep_alive_timeout, None);
     }
 
     #[test]
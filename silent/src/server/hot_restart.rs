@@ -0,0 +1,267 @@
+//! 零停机热重启
+//!
+//! 旧进程收到 `SIGUSR2`（或调用 [`UpgradeHandle::upgrade`]）后，以相同参数启动当前可执行文件，
+//! 按 systemd 套接字激活的约定把监听套接字放在文件描述符 3 起传给新进程。新进程按绑定地址复用这些套接字，
+//! 开始服务后通过 `SILENT_UPGRADE_NOTIFY` 指向的 Unix 数据报套接字回报 `READY=1`；
+//! 旧进程随即停止接受新连接，在优雅关停时间内排空已有连接后退出。
+
+use std::ffi::OsString;
+use std::io::{Error, ErrorKind, Result};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use tokio::net::UnixDatagram;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+
+use super::systemd;
+
+/// 旧进程 PID，新进程据此确认继承的套接字属于自己
+const UPGRADE_PPID_ENV: &str = "SILENT_UPGRADE_PPID";
+/// 新进程回报就绪的 Unix 数据报套接字路径
+const UPGRADE_NOTIFY_ENV: &str = "SILENT_UPGRADE_NOTIFY";
+/// 等待新进程就绪的最长时间
+const READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+static READY_SENT: AtomicBool = AtomicBool::new(false);
+static NOTIFY_SEQ: AtomicUsize = AtomicUsize::new(0);
+
+/// 热重启句柄
+///
+/// 通过 [`Server::upgrade_handle`](crate::Server::upgrade_handle) 或
+/// [`NetServer::upgrade_handle`](crate::NetServer::upgrade_handle) 获取后即启用热重启：
+/// 向进程发送 `SIGUSR2`，或调用 [`upgrade`](Self::upgrade)，都会以相同参数重新执行当前可执行文件。
+/// 新进程就绪后，旧进程停止接受连接，在 `with_shutdown` 配置的时间内排空已有连接，随后 `serve`/`run` 返回。
+///
+/// 只有通过 `bind`、`bind_with`、`bind_unix` 绑定的地址会被新进程按地址复用；
+/// 通过 `listen` 传入的自定义监听器需要新进程自行获取套接字。
+///
+/// ```no_run
+/// use silent::prelude::*;
+/// use std::time::Duration;
+///
+/// let mut server = Server::new()
+///     .bind("0.0.0.0:8080".parse().unwrap())
+///     .with_shutdown(Duration::from_secs(30));
+/// let upgrade = server.upgrade_handle();
+/// // 部署新版本后：kill -USR2 <pid>，或在管理接口中调用 upgrade.upgrade()
+/// server.run(Route::new(""));
+/// ```
+#[derive(Clone, Default)]
+pub struct UpgradeHandle {
+    trigger: Arc<Notify>,
+}
+
+impl UpgradeHandle {
+    /// 触发一次热重启，效果与 `SIGUSR2` 相同
+    pub fn upgrade(&self) {
+        self.trigger.notify_one();
+    }
+
+    /// 当前进程是否由热重启启动
+    pub fn is_upgraded(&self) -> bool {
+        is_upgraded()
+    }
+
+    /// 在后台等待热重启请求，新进程就绪后任务结束
+    pub(crate) fn spawn_watcher(self, fds: Vec<OwnedFd>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut sigusr2 =
+                tokio::signal::unix::signal(tokio::signal::unix::SignalKind::user_defined2())
+                    .map_err(|e| tracing::warn!(error = ?e, "failed to listen for SIGUSR2"))
+                    .ok();
+            loop {
+                tokio::select! {
+                    Some(_) = async { sigusr2.as_mut()?.recv().await } => {}
+                    _ = self.trigger.notified() => {}
+                }
+                tracing::info!(
+                    listeners = fds.len(),
+                    "upgrade requested, starting new process"
+                );
+                match upgrade(&fds).await {
+                    Ok(pid) => {
+                        tracing::info!(pid, "new process ready");
+                        return;
+                    }
+                    Err(e) => {
+                        tracing::error!(error = ?e, "upgrade failed, keep serving");
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// 当前进程是否由热重启启动
+pub(crate) fn is_upgraded() -> bool {
+    std::env::var(UPGRADE_PPID_ENV)
+        .ok()
+        .and_then(|pid| pid.trim().parse::<u32>().ok())
+        == Some(std::os::unix::process::parent_id())
+}
+
+/// 接管旧进程传入的监听套接字，非热重启启动时返回空列表
+pub(crate) fn take_inherited_fds() -> Result<Vec<OwnedFd>> {
+    if is_upgraded() {
+        systemd::take_listen_fds()
+    } else {
+        Ok(vec![])
+    }
+}
+
+/// 向旧进程回报就绪，仅首次调用生效
+pub(crate) fn notify_parent_ready() {
+    if !is_upgraded() || READY_SENT.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    if let Some(path) = std::env::var_os(UPGRADE_NOTIFY_ENV)
+        && let Err(e) = systemd::notify_to(&path, "READY=1")
+    {
+        tracing::warn!(error = ?e, "failed to notify parent process");
+    }
+}
+
+async fn upgrade(fds: &[OwnedFd]) -> Result<u32> {
+    let program = std::env::current_exe()?;
+    let mut command = Command::new(program);
+    command.args(std::env::args_os().skip(1));
+    let notify_path = std::env::temp_dir().join(format!(
+        "silent-upgrade-{}-{}.sock",
+        std::process::id(),
+        NOTIFY_SEQ.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = std::fs::remove_file(&notify_path);
+    let ready = UnixDatagram::bind(&notify_path)?;
+    let result = async {
+        let mut child = spawn_successor(command, fds, notify_path.as_os_str().to_owned())?;
+        wait_ready(&mut child, &ready, READY_TIMEOUT).await
+    }
+    .await;
+    let _ = std::fs::remove_file(&notify_path);
+    result
+}
+
+/// 启动新进程，监听套接字依次放在文件描述符 3 起
+fn spawn_successor(mut command: Command, fds: &[OwnedFd], notify: OsString) -> Result<Child> {
+    let start = systemd::SD_LISTEN_FDS_START;
+    // 先复制到目标范围之上，避免 dup2 覆盖尚未移动的套接字；副本带 CLOEXEC，不会泄漏给新进程
+    let high = fds
+        .iter()
+        .map(|fd| dup_above(fd.as_raw_fd(), start + fds.len() as RawFd))
+        .collect::<Result<Vec<_>>>()?;
+    let raw = high.iter().map(AsRawFd::as_raw_fd).collect::<Vec<_>>();
+    command
+        .env("LISTEN_FDS", fds.len().to_string())
+        .env_remove("LISTEN_PID")
+        .env(UPGRADE_PPID_ENV, std::process::id().to_string())
+        .env(UPGRADE_NOTIFY_ENV, notify);
+    // SAFETY: 闭包在 fork 之后、exec 之前执行，只调用异步信号安全的 dup2，且不分配内存。
+    unsafe {
+        command.pre_exec(move || {
+            for (i, fd) in raw.iter().enumerate() {
+                // dup2 产生的描述符不带 CLOEXEC，可被新进程继承
+                if libc::dup2(*fd, start + i as RawFd) < 0 {
+                    return Err(Error::last_os_error());
+                }
+            }
+            Ok(())
+        });
+    }
+    command.spawn()
+}
+
+fn dup_above(fd: RawFd, min: RawFd) -> Result<OwnedFd> {
+    // SAFETY: fd 在调用期间由调用方持有，F_DUPFD_CLOEXEC 返回新的独占描述符。
+    let new = unsafe { libc::fcntl(fd, libc::F_DUPFD_CLOEXEC, min) };
+    if new < 0 {
+        return Err(Error::last_os_error());
+    }
+    // SAFETY: new 为刚创建的有效描述符，所有权转移给 OwnedFd。
+    Ok(unsafe { OwnedFd::from_raw_fd(new) })
+}
+
+/// 等待新进程回报 `READY=1`；超时或新进程提前退出时终止新进程并返回错误
+async fn wait_ready(child: &mut Child, ready: &UnixDatagram, timeout: Duration) -> Result<u32> {
+    let pid = child.id();
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut buf = [0u8; 256];
+    loop {
+        tokio::select! {
+            received = ready.recv(&mut buf) => {
+                let n = received?;
+                if buf[..n].split(|b| *b == b'\n').any(|line| line == b"READY=1") {
+                    return Ok(pid);
+                }
+            }
+            _ = tokio::time::sleep(Duration::from_millis(100)) => {
+                if let Some(status) = child.try_wait()? {
+                    return Err(Error::other(format!("new process exited before ready: {status}")));
+                }
+                if tokio::time::Instant::now() >= deadline {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(Error::new(ErrorKind::TimedOut, "new process not ready in time"));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawn_successor_passes_fds() {
+        let first = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let second = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut command = Command::new("/bin/sh");
+        command.args([
+            "-c",
+            r#"[ "$LISTEN_FDS" = 2 ] && [ -S /dev/fd/3 ] && [ -S /dev/fd/4 ] && [ "$SILENT_UPGRADE_NOTIFY" = notify ]"#,
+        ]);
+        let fds = vec![OwnedFd::from(first), OwnedFd::from(second)];
+        let mut child = spawn_successor(command, &fds, "notify".into()).unwrap();
+        assert!(child.wait().unwrap().success());
+    }
+
+    #[tokio::test]
+    async fn test_wait_ready() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ready.sock");
+        let ready = UnixDatagram::bind(&path).unwrap();
+
+        let mut child = Command::new("/bin/sh")
+            .args(["-c", "sleep 5"])
+            .spawn()
+            .unwrap();
+        systemd::notify_to(path.as_os_str(), "STATUS=starting\nREADY=1").unwrap();
+        let pid = wait_ready(&mut child, &ready, READY_TIMEOUT).await.unwrap();
+        assert_eq!(pid, child.id());
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        // 新进程提前退出
+        let mut child = Command::new("/bin/sh")
+            .args(["-c", "exit 1"])
+            .spawn()
+            .unwrap();
+        assert!(wait_ready(&mut child, &ready, READY_TIMEOUT).await.is_err());
+
+        // 超时后终止新进程
+        let mut child = Command::new("/bin/sh")
+            .args(["-c", "sleep 5"])
+            .spawn()
+            .unwrap();
+        let err = wait_ready(&mut child, &ready, Duration::from_millis(200))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TimedOut);
+        assert!(child.try_wait().unwrap().is_some());
+    }
+}
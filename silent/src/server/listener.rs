@@ -1,13 +1,24 @@
-use super::connection::Connection;
+use super::ConnectionService;
+use super::connection::{Connection, HttpConnection};
 use super::stream::Stream;
 use crate::core::socket_addr::SocketAddr;
 #[cfg(feature = "tls")]
-use crate::{CertificateStore, ReloadableCertificateStore};
+use crate::{CertificateStore, ReloadableCertificateStore, TlsConfigSource};
 use std::future::Future;
 use std::io::Result;
 #[cfg(not(target_os = "windows"))]
+use std::os::fd::{AsFd, BorrowedFd, OwnedFd};
+#[cfg(not(target_os = "windows"))]
 use std::path::Path;
 use std::pin::Pin;
+use std::sync::Arc;
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+use tokio::sync::Mutex;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use tokio::time::{Duration, Instant, sleep_until};
 #[cfg(feature = "tls")]
 use tokio_rustls::TlsAcceptor;
@@ -26,10 +37,120 @@ pub type AcceptFuture<'a> = Pin<
 pub trait Listen: Send + Sync {
     fn accept(&self) -> AcceptFuture<'_>;
     fn local_addr(&self) -> Result<SocketAddr>;
+
+    /// 底层监听套接字，热重启时复制给新进程；返回 `None` 表示不参与交接
+    #[cfg(not(target_os = "windows"))]
+    fn as_fd(&self) -> Option<BorrowedFd<'_>> {
+        None
+    }
+}
+
+/// TCP 套接字调优选项
+///
+/// 通过 [`ListenersBuilder::bind_with`]、[`Listener::bind_with`] 或
+/// `Listener::from((listener, options))` 使用；未指定的缓冲区大小沿用系统默认值。
+///
+/// ```rust
+/// use silent::TcpOptions;
+///
+/// let options = TcpOptions {
+///     nodelay: false,
+///     keepalive: true,
+///     backlog: 4096,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TcpOptions {
+    /// 对接受的连接禁用 Nagle 算法，默认开启
+    pub nodelay: bool,
+    /// 启用 `SO_KEEPALIVE`，由接受的连接继承，默认关闭
+    pub keepalive: bool,
+    /// 接收缓冲区大小（`SO_RCVBUF`）
+    pub recv_buf: Option<u32>,
+    /// 发送缓冲区大小（`SO_SNDBUF`）
+    pub send_buf: Option<u32>,
+    /// 监听队列长度，默认 1024
+    pub backlog: u32,
+    /// IPv6 套接字是否仅接受 IPv6 连接（`IPV6_V6ONLY`），`None` 沿用系统默认值；
+    /// 仅对 IPv6 地址生效，非 Unix 平台忽略
+    pub v6only: Option<bool>,
+}
+
+impl Default for TcpOptions {
+    fn default() -> Self {
+        Self {
+            nodelay: true,
+            keepalive: false,
+            recv_buf: None,
+            send_buf: None,
+            backlog: 1024,
+            v6only: None,
+        }
+    }
+}
+
+impl TcpOptions {
+    /// 按选项创建并绑定监听套接字
+    ///
+    /// 必须在 tokio runtime 内调用。
+    fn bind(&self, addr: std::net::SocketAddr) -> Result<tokio::net::TcpListener> {
+        let socket = if addr.is_ipv4() {
+            tokio::net::TcpSocket::new_v4()?
+        } else {
+            tokio::net::TcpSocket::new_v6()?
+        };
+        // 与 std::net::TcpListener::bind 保持一致
+        #[cfg(not(target_os = "windows"))]
+        socket.set_reuseaddr(true)?;
+        if self.keepalive {
+            socket.set_keepalive(true)?;
+        }
+        if let Some(size) = self.recv_buf {
+            socket.set_recv_buffer_size(size)?;
+        }
+        if let Some(size) = self.send_buf {
+            socket.set_send_buffer_size(size)?;
+        }
+        if let Some(only_v6) = self.v6only
+            && addr.is_ipv6()
+        {
+            set_only_v6(&socket, only_v6)?;
+        }
+        socket.bind(addr)?;
+        socket.listen(self.backlog)
+    }
+}
+
+#[cfg(unix)]
+fn set_only_v6(socket: &tokio::net::TcpSocket, only_v6: bool) -> Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let value = libc::c_int::from(only_v6);
+    let ret = unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::IPPROTO_IPV6,
+            libc::IPV6_V6ONLY,
+            &value as *const libc::c_int as *const libc::c_void,
+            std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+        )
+    };
+    if ret < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_only_v6(_socket: &tokio::net::TcpSocket, _only_v6: bool) -> Result<()> {
+    Ok(())
 }
 
 pub enum Listener {
     TcpListener(tokio::net::TcpListener),
+    /// 使用自定义 [`TcpOptions`] 的 TCP 监听器
+    TcpListenerWithOptions(tokio::net::TcpListener, TcpOptions),
     #[cfg(not(target_os = "windows"))]
     UnixListener(tokio::net::UnixListener),
 }
@@ -51,6 +172,8 @@ impl TryFrom<std::os::unix::net::UnixListener> for Listener {
     type Error = std::io::Error;
 
     fn try_from(value: std::os::unix::net::UnixListener) -> Result<Self> {
+        // 与 TCP 一致，tokio 要求注册非阻塞套接字
+        value.set_nonblocking(true)?;
         let tokio_listener = tokio::net::UnixListener::from_std(value)?;
         Ok(Listener::UnixListener(tokio_listener))
     }
@@ -62,6 +185,129 @@ impl From<tokio::net::TcpListener> for Listener {
     }
 }
 
+impl From<(tokio::net::TcpListener, TcpOptions)> for Listener {
+    /// 套接字级选项需在绑定前设置，此处仅对接受的连接应用 `nodelay`
+    fn from((listener, options): (tokio::net::TcpListener, TcpOptions)) -> Self {
+        Listener::TcpListenerWithOptions(listener, options)
+    }
+}
+
+impl Listener {
+    /// 按 [`TcpOptions`] 绑定 TCP 地址
+    ///
+    /// 必须在 tokio runtime 内调用。
+    pub fn bind_with(addr: std::net::SocketAddr, options: TcpOptions) -> Result<Self> {
+        Ok(Listener::TcpListenerWithOptions(
+            options.bind(addr)?,
+            options,
+        ))
+    }
+
+    /// 按 [`UnixOptions`] 绑定 Unix Domain Socket
+    ///
+    /// 必须在 tokio runtime 内调用。`remove_on_shutdown` 需通过 [`ListenersBuilder::bind_unix_with`] 生效。
+    #[cfg(not(target_os = "windows"))]
+    pub fn bind_unix_with<P: AsRef<Path>>(path: P, options: UnixOptions) -> Result<Self> {
+        Listener::try_from(options.bind(path.as_ref())?)
+    }
+}
+
+/// Unix Domain Socket 选项
+///
+/// 通过 [`ListenersBuilder::bind_unix_with`] 或 [`Listener::bind_unix_with`] 使用；
+/// 默认值与 `bind_unix` 行为一致，不修改文件属性也不删除文件。
+///
+/// ```rust
+/// use silent::UnixOptions;
+///
+/// let options = UnixOptions {
+///     mode: Some(0o660),
+///     group: Some(33),
+///     remove_stale: true,
+///     remove_on_shutdown: true,
+///     ..Default::default()
+/// };
+/// ```
+#[cfg(not(target_os = "windows"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnixOptions {
+    /// 套接字文件权限，如 `0o660`
+    pub mode: Option<u32>,
+    /// 套接字文件属主（uid）
+    pub owner: Option<u32>,
+    /// 套接字文件属组（gid）
+    pub group: Option<u32>,
+    /// 绑定前删除已无进程监听的残留套接字文件
+    pub remove_stale: bool,
+    /// 服务关停后删除套接字文件，仅对 [`ListenersBuilder`] 绑定的路径生效；热重启交接时保留
+    pub remove_on_shutdown: bool,
+}
+
+#[cfg(not(target_os = "windows"))]
+impl UnixOptions {
+    /// 按选项绑定套接字并设置文件权限与属主
+    fn bind(&self, path: &Path) -> Result<std::os::unix::net::UnixListener> {
+        if self.remove_stale {
+            remove_stale_socket(path)?;
+        }
+        let listener = std::os::unix::net::UnixListener::bind(path)?;
+        let applied = (|| {
+            if let Some(mode) = self.mode {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode))?;
+            }
+            if self.owner.is_some() || self.group.is_some() {
+                std::os::unix::fs::chown(path, self.owner, self.group)?;
+            }
+            Ok(())
+        })();
+        if let Err(e) = applied {
+            let _ = std::fs::remove_file(path);
+            return Err(e);
+        }
+        Ok(listener)
+    }
+}
+
+/// 删除残留的套接字文件；仍有进程监听时保留，由随后的绑定报告地址占用
+#[cfg(not(target_os = "windows"))]
+fn remove_stale_socket(path: &Path) -> Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    match std::fs::symlink_metadata(path) {
+        Ok(meta) if meta.file_type().is_socket() => {}
+        Ok(_) => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!("{} exists and is not a socket", path.display()),
+            ));
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    }
+    match std::os::unix::net::UnixStream::connect(path) {
+        Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => {
+            tracing::debug!(path = ?path, "removing stale Unix socket");
+            std::fs::remove_file(path)
+        }
+        _ => Ok(()),
+    }
+}
+
+fn accept_tcp(listener: &tokio::net::TcpListener, nodelay: bool) -> AcceptFuture<'_> {
+    Box::pin(async move {
+        let (stream, addr) = listener.accept().await?;
+        // 禁用 Nagle 算法，减少小包延迟
+        if nodelay {
+            stream.set_nodelay(true)?;
+        }
+        Ok((
+            Box::new(Stream::TcpStream(stream)) as Box<dyn Connection + Send + Sync>,
+            SocketAddr::Tcp(addr),
+        ))
+    })
+}
+
 #[cfg(not(target_os = "windows"))]
 impl From<tokio::net::UnixListener> for Listener {
     fn from(value: tokio::net::UnixListener) -> Self {
@@ -72,17 +318,9 @@ impl From<tokio::net::UnixListener> for Listener {
 impl Listen for Listener {
     fn accept(&self) -> AcceptFuture<'_> {
         match self {
-            Listener::TcpListener(listener) => {
-                let accept_future = async move {
-                    let (stream, addr) = listener.accept().await?;
-                    // 禁用 Nagle 算法，减少小包延迟
-                    stream.set_nodelay(true)?;
-                    Ok((
-                        Box::new(Stream::TcpStream(stream)) as Box<dyn Connection + Send + Sync>,
-                        SocketAddr::Tcp(addr),
-                    ))
-                };
-                Box::pin(accept_future)
+            Listener::TcpListener(listener) => accept_tcp(listener, true),
+            Listener::TcpListenerWithOptions(listener, options) => {
+                accept_tcp(listener, options.nodelay)
             }
             #[cfg(not(target_os = "windows"))]
             Listener::UnixListener(listener) => {
@@ -102,22 +340,73 @@ impl Listen for Listener {
 
     fn local_addr(&self) -> Result<SocketAddr> {
         match self {
-            Listener::TcpListener(listener) => listener.local_addr().map(SocketAddr::Tcp),
+            Listener::TcpListener(listener) | Listener::TcpListenerWithOptions(listener, _) => {
+                listener.local_addr().map(SocketAddr::Tcp)
+            }
             #[cfg(not(target_os = "windows"))]
             Listener::UnixListener(listener) => Ok(SocketAddr::Unix(listener.local_addr()?.into())),
         }
     }
+
+    #[cfg(not(target_os = "windows"))]
+    fn as_fd(&self) -> Option<BorrowedFd<'_>> {
+        match self {
+            Listener::TcpListener(listener) | Listener::TcpListenerWithOptions(listener, _) => {
+                Some(listener.as_fd())
+            }
+            Listener::UnixListener(listener) => Some(listener.as_fd()),
+        }
+    }
 }
 
 #[cfg(feature = "tls")]
 impl Listener {
+    /// 使用给定的 acceptor 启用 TLS，未配置 ALPN 时使用 [`DEFAULT_ALPN`](crate::DEFAULT_ALPN)
     pub fn tls(self, acceptor: TlsAcceptor) -> TlsListener {
+        let acceptor = if acceptor.config().alpn_protocols.is_empty() {
+            let mut config = rustls::ServerConfig::clone(acceptor.config());
+            config.alpn_protocols = crate::DEFAULT_ALPN.iter().map(|p| p.to_vec()).collect();
+            TlsAcceptor::from(Arc::new(config))
+        } else {
+            acceptor
+        };
         TlsListener {
             listener: self,
             acceptor,
         }
     }
 
+    /// 使用指定的 ALPN 协议启用 TLS，`protocols` 为空时使用 [`DEFAULT_ALPN`](crate::DEFAULT_ALPN)
+    ///
+    /// `source` 可以是 [`CertificateStore`]、`TlsAcceptor` 或 rustls 的 `ServerConfig`。
+    ///
+    /// ```no_run
+    /// use silent::{CertificateStore, DEFAULT_ALPN, Listener};
+    ///
+    /// # async fn example() -> anyhow::Result<()> {
+    /// let store = CertificateStore::builder()
+    ///     .cert_path("certs/server.pem")
+    ///     .key_path("certs/server.key")
+    ///     .build()?;
+    /// let listener = Listener::from(tokio::net::TcpListener::bind("0.0.0.0:8443").await?)
+    ///     .tls_with_alpn(&store, DEFAULT_ALPN)?;
+    /// // 仅 HTTP/1.1
+    /// let legacy = Listener::from(tokio::net::TcpListener::bind("0.0.0.0:8444").await?)
+    ///     .tls_with_alpn(&store, &[b"http/1.1"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tls_with_alpn(
+        self,
+        source: &impl TlsConfigSource,
+        protocols: &[&[u8]],
+    ) -> Result<TlsListener> {
+        let config = source
+            .server_config(protocols)
+            .map_err(std::io::Error::other)?;
+        Ok(self.tls(TlsAcceptor::from(Arc::new(config))))
+    }
+
     pub fn tls_with_cert(self, cert: &CertificateStore) -> TlsListener {
         self.tls(TlsAcceptor::from(cert.https_config().unwrap()))
     }
@@ -142,15 +431,49 @@ pub struct ReloadableTlsListener {
     pub store: ReloadableCertificateStore,
 }
 
+/// 监听地址标签，取不到地址时为 `unknown`
+fn address_label(addr: Result<SocketAddr>) -> Arc<str> {
+    match addr {
+        Ok(addr) => Arc::from(addr.to_string()),
+        Err(_) => Arc::from("unknown"),
+    }
+}
+
+/// 完成 TLS 握手，启用 `metrics` 时记录握手耗时与失败次数
+#[cfg(feature = "tls")]
+async fn handshake<T>(
+    listener: &impl Listen,
+    accept: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    #[cfg(feature = "metrics")]
+    let started = Instant::now();
+    let res = accept.await;
+    #[cfg(feature = "metrics")]
+    {
+        let label = address_label(listener.local_addr());
+        match &res {
+            Ok(_) => super::metrics::record_tls_handshake_duration(
+                &label,
+                started.elapsed().as_nanos() as u64,
+            ),
+            Err(_) => super::metrics::record_tls_handshake_err(&label),
+        }
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = listener;
+    res
+}
+
 #[cfg(feature = "tls")]
 impl Listen for TlsListener {
     fn accept(&self) -> AcceptFuture<'_> {
         let accept_future = async move {
             let (stream, addr) = self.listener.accept().await?;
-            let tls_stream = self.acceptor.accept(stream).await?;
+            let tls_stream = handshake(self, self.acceptor.accept(stream)).await?;
+            // 对端地址标记为 TLS，供转发头等场景判断连接协议
             Ok((
                 Box::new(tls_stream) as Box<dyn Connection + Send + Sync>,
-                addr,
+                addr.tls()?,
             ))
         };
         Box::pin(accept_future)
@@ -159,6 +482,11 @@ impl Listen for TlsListener {
     fn local_addr(&self) -> Result<SocketAddr> {
         self.listener.local_addr()?.tls()
     }
+
+    #[cfg(not(target_os = "windows"))]
+    fn as_fd(&self) -> Option<BorrowedFd<'_>> {
+        self.listener.as_fd()
+    }
 }
 
 #[cfg(feature = "tls")]
@@ -170,10 +498,11 @@ impl Listen for ReloadableTlsListener {
             let acceptor = store
                 .https_acceptor()
                 .map_err(|e| std::io::Error::other(format!("reload tls acceptor failed: {e}")))?;
-            let tls_stream = acceptor.accept(stream).await?;
+            let tls_stream = handshake(self, acceptor.accept(stream)).await?;
+            // 对端地址标记为 TLS，供转发头等场景判断连接协议
             Ok((
                 Box::new(tls_stream) as Box<dyn Connection + Send + Sync>,
-                addr,
+                addr.tls()?,
             ))
         };
         Box::pin(accept_future)
@@ -182,23 +511,231 @@ impl Listen for ReloadableTlsListener {
     fn local_addr(&self) -> Result<SocketAddr> {
         self.listener.local_addr()?.tls()
     }
+
+    #[cfg(not(target_os = "windows"))]
+    fn as_fd(&self) -> Option<BorrowedFd<'_>> {
+        self.listener.as_fd()
+    }
+}
+
+/// 监听器接受的 HTTP 协议
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HttpProtocols {
+    /// 自动识别 HTTP/1.1 与 HTTP/2，明文连接上的 HTTP/2 即 h2c prior knowledge
+    #[default]
+    Auto,
+    /// 在 `Auto` 基础上接受 HTTP/1.1 `Upgrade: h2c` 升级
+    H2c,
+    /// 仅 HTTP/1.1
+    Http1Only,
+    /// 仅 HTTP/2，明文连接须使用 prior knowledge
+    Http2Only,
+}
+
+/// 为单个监听器指定 HTTP 协议
+///
+/// 未包装的监听器按 [`HttpProtocols::Auto`] 处理。适用于 gRPC 或内部服务间通信等不使用 TLS 的 HTTP/2 场景。
+///
+/// ```no_run
+/// use silent::{HttpListener, HttpProtocols, Listener};
+///
+/// # async fn example() -> std::io::Result<()> {
+/// let listener = tokio::net::TcpListener::bind("127.0.0.1:50051").await?;
+/// let listener = HttpListener::new(Listener::from(listener), HttpProtocols::H2c);
+/// # Ok(())
+/// # }
+/// ```
+pub struct HttpListener<L> {
+    listener: L,
+    protocols: HttpProtocols,
+}
+
+impl<L: Listen> HttpListener<L> {
+    pub fn new(listener: L, protocols: HttpProtocols) -> Self {
+        Self {
+            listener,
+            protocols,
+        }
+    }
+
+    pub fn protocols(&self) -> HttpProtocols {
+        self.protocols
+    }
+}
+
+impl<L: Listen> Listen for HttpListener<L> {
+    fn accept(&self) -> AcceptFuture<'_> {
+        Box::pin(async move {
+            let (conn, addr) = self.listener.accept().await?;
+            Ok((
+                Box::new(HttpConnection {
+                    inner: conn,
+                    protocols: self.protocols,
+                }) as Box<dyn Connection + Send + Sync>,
+                addr,
+            ))
+        })
+    }
+
+    fn local_addr(&self) -> Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn as_fd(&self) -> Option<BorrowedFd<'_>> {
+        self.listener.as_fd()
+    }
+}
+
+/// 接受结果，由各个 accept 循环发往主循环
+type Accepted = Result<(Box<dyn Connection + Send + Sync>, SocketAddr)>;
+
+/// 基于 `SO_REUSEPORT` 的多套接字监听器
+///
+/// 在同一地址上绑定多个启用 `SO_REUSEPORT` 的套接字，由内核在套接字间分配新连接；
+/// 每个套接字在独立的任务中循环 accept，由运行时调度到不同的工作线程，
+/// 连接频繁建立与断开时可避免单一 accept 循环成为瓶颈。
+///
+/// 通常通过 [`ListenersBuilder::bind_reuseport`] 创建，监听器被丢弃时各 accept 循环随之结束。
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+pub struct ReuseportListener {
+    accepted: Mutex<mpsc::Receiver<Accepted>>,
+    local_addr: std::net::SocketAddr,
+    loops: Vec<JoinHandle<()>>,
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+impl ReuseportListener {
+    /// 在 `addr` 上绑定 `n_loops` 个套接字并启动各自的 accept 循环
+    ///
+    /// `addr` 的端口为 0 时，其余套接字复用第一个套接字分配到的端口。必须在 tokio runtime 内调用。
+    pub fn bind(addr: std::net::SocketAddr, n_loops: usize) -> Result<Self> {
+        let n_loops = n_loops.max(1);
+        let mut addr = addr;
+        let mut sockets = Vec::with_capacity(n_loops);
+        for _ in 0..n_loops {
+            let socket = if addr.is_ipv4() {
+                tokio::net::TcpSocket::new_v4()?
+            } else {
+                tokio::net::TcpSocket::new_v6()?
+            };
+            socket.set_reuseaddr(true)?;
+            socket.set_reuseport(true)?;
+            socket.bind(addr)?;
+            let listener = socket.listen(1024)?;
+            addr = listener.local_addr()?;
+            sockets.push(Listener::TcpListener(listener));
+        }
+
+        let (tx, rx) = mpsc::channel(n_loops * 16);
+        let loops = sockets
+            .into_iter()
+            .map(|listener| {
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    loop {
+                        let accepted = listener.accept().await;
+                        let failed = accepted.is_err();
+                        if tx.send(accepted).await.is_err() {
+                            return;
+                        }
+                        // 单个循环出错（如文件描述符耗尽）时短暂退避，避免空转
+                        if failed {
+                            tokio::time::sleep(BackoffState::BASE).await;
+                        }
+                    }
+                })
+            })
+            .collect();
+        Ok(Self {
+            accepted: Mutex::new(rx),
+            local_addr: addr,
+            loops,
+        })
+    }
+
+    /// accept 循环（即套接字）数量
+    pub fn loops(&self) -> usize {
+        self.loops.len()
+    }
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+impl Listen for ReuseportListener {
+    fn accept(&self) -> AcceptFuture<'_> {
+        Box::pin(async move {
+            match self.accepted.lock().await.recv().await {
+                Some(accepted) => accepted,
+                None => Err(std::io::Error::other("reuseport accept loops stopped")),
+            }
+        })
+    }
+
+    fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(SocketAddr::Tcp(self.local_addr))
+    }
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+))]
+impl Drop for ReuseportListener {
+    fn drop(&mut self) {
+        for handle in &self.loops {
+            handle.abort();
+        }
+    }
 }
 
 #[derive(Default)]
 pub struct ListenersBuilder {
     listeners: Vec<Box<dyn Listen + Send + Sync + 'static>>,
-    tcp_addrs: Vec<std::net::SocketAddr>,
+    routed: Vec<(
+        Box<dyn Listen + Send + Sync + 'static>,
+        Arc<dyn ConnectionService>,
+    )>,
+    /// 第三项为 `true` 时绑定失败仅记录告警，用于 [`bind_all`](Self::bind_all) 补充的另一协议族地址
+    tcp_addrs: Vec<(std::net::SocketAddr, Option<TcpOptions>, bool)>,
+    #[cfg(not(target_os = "windows"))]
+    unix_paths: Vec<(std::path::PathBuf, Option<UnixOptions>)>,
     #[cfg(not(target_os = "windows"))]
-    unix_paths: Vec<std::path::PathBuf>,
+    env_fds: Vec<OwnedFd>,
+    #[cfg(not(target_os = "windows"))]
+    inherited_fds: Vec<OwnedFd>,
+    #[cfg(all(
+        unix,
+        not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+    ))]
+    reuseport_addrs: Vec<(std::net::SocketAddr, usize)>,
 }
 
 impl ListenersBuilder {
     pub fn new() -> Self {
         Self {
             listeners: vec![],
+            routed: vec![],
             tcp_addrs: vec![],
             #[cfg(not(target_os = "windows"))]
             unix_paths: vec![],
+            #[cfg(not(target_os = "windows"))]
+            env_fds: vec![],
+            #[cfg(not(target_os = "windows"))]
+            inherited_fds: vec![],
+            #[cfg(all(
+                unix,
+                not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+            ))]
+            reuseport_addrs: vec![],
         }
     }
 
@@ -206,23 +743,197 @@ impl ListenersBuilder {
         self.listeners.push(listener);
     }
 
+    /// 添加使用独立服务处理连接的监听器，其余监听器仍由 `serve` 传入的处理器处理
+    pub fn add_listener_with(
+        &mut self,
+        listener: Box<dyn Listen + Send + Sync>,
+        service: Arc<dyn ConnectionService>,
+    ) {
+        self.routed.push((listener, service));
+    }
+
     pub fn bind(&mut self, addr: std::net::SocketAddr) -> Result<()> {
-        self.tcp_addrs.push(addr);
+        self.tcp_addrs.push((addr, None, false));
+        Ok(())
+    }
+
+    /// 按 [`TcpOptions`] 绑定 TCP 地址，与 [`bind`](Self::bind) 一样延迟到 [`listen`](Self::listen) 时执行
+    pub fn bind_with(&mut self, addr: std::net::SocketAddr, options: TcpOptions) -> Result<()> {
+        self.tcp_addrs.push((addr, Some(options), false));
+        Ok(())
+    }
+
+    /// 绑定地址解析出的全部地址，未指定地址同时监听 IPv4 与 IPv6
+    ///
+    /// - 主机名解析出多个地址（如 `localhost` 对应 `127.0.0.1` 与 `::1`）时逐一绑定；
+    /// - `0.0.0.0` 与 `[::]` 会补充另一协议族的未指定地址，补充的地址绑定失败
+    ///   （如主机未启用 IPv6）时仅记录告警。
+    ///
+    /// 与 [`bind`](Self::bind) 一样延迟到 [`listen`](Self::listen) 时执行，
+    /// 绑定的全部地址通过 `on_listen` 回调报告。端口为 0 时各地址分别分配端口。
+    ///
+    /// ```no_run
+    /// use silent::ListenersBuilder;
+    ///
+    /// let mut builder = ListenersBuilder::new();
+    /// // 同时监听 0.0.0.0:8080 与 [::]:8080
+    /// builder.bind_all("0.0.0.0:8080").unwrap();
+    /// ```
+    pub fn bind_all<A: std::net::ToSocketAddrs>(&mut self, addr: A) -> Result<()> {
+        self.bind_all_with(addr, TcpOptions::default())
+    }
+
+    /// 按 [`TcpOptions`] 绑定地址解析出的全部地址，详见 [`bind_all`](Self::bind_all)
+    ///
+    /// `v6only` 未设置时 IPv6 套接字使用 `IPV6_V6ONLY`，使其与同端口的 IPv4 套接字共存。
+    pub fn bind_all_with<A: std::net::ToSocketAddrs>(
+        &mut self,
+        addr: A,
+        options: TcpOptions,
+    ) -> Result<()> {
+        let mut addrs: Vec<(std::net::SocketAddr, bool)> = vec![];
+        let mut push = |addr: std::net::SocketAddr, optional: bool| match addrs
+            .iter_mut()
+            .find(|(existing, _)| *existing == addr)
+        {
+            Some((_, existing)) => *existing &= optional,
+            None => addrs.push((addr, optional)),
+        };
+        for addr in addr.to_socket_addrs()? {
+            push(addr, false);
+            if addr.ip().is_unspecified() {
+                let other: std::net::IpAddr = match addr {
+                    std::net::SocketAddr::V4(_) => std::net::Ipv6Addr::UNSPECIFIED.into(),
+                    std::net::SocketAddr::V6(_) => std::net::Ipv4Addr::UNSPECIFIED.into(),
+                };
+                push(std::net::SocketAddr::new(other, addr.port()), true);
+            }
+        }
+        if addrs.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "address resolved to no socket addresses",
+            ));
+        }
+        let v6_options = TcpOptions {
+            v6only: options.v6only.or(Some(true)),
+            ..options
+        };
+        for (addr, optional) in addrs {
+            let options = if addr.is_ipv6() { v6_options } else { options };
+            self.tcp_addrs.push((addr, Some(options), optional));
+        }
         Ok(())
     }
 
     #[cfg(not(target_os = "windows"))]
     pub fn bind_unix<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
-        self.unix_paths.push(path.as_ref().to_path_buf());
+        self.unix_paths.push((path.as_ref().to_path_buf(), None));
+        Ok(())
+    }
+
+    /// 按 [`UnixOptions`] 绑定 Unix Domain Socket，与 [`bind_unix`](Self::bind_unix) 一样延迟到 [`listen`](Self::listen) 时执行
+    #[cfg(not(target_os = "windows"))]
+    pub fn bind_unix_with<P: AsRef<Path>>(&mut self, path: P, options: UnixOptions) -> Result<()> {
+        self.unix_paths
+            .push((path.as_ref().to_path_buf(), Some(options)));
+        Ok(())
+    }
+
+    /// 创建使用 systemd 套接字激活（`LISTEN_FDS`/`LISTEN_PID`）传入的监听套接字的构建器
+    ///
+    /// 未由 systemd 激活时构建器为空，可继续调用 [`bind`](Self::bind) 等方法作为回退；
+    /// 支持 TCP 与 Unix 流式套接字，其余类型在 [`listen`](Self::listen) 时报错。
+    ///
+    /// ```no_run
+    /// use silent::ListenersBuilder;
+    ///
+    /// let mut builder = ListenersBuilder::from_env_fds().unwrap();
+    /// if builder.env_fds() == 0 {
+    ///     builder.bind("127.0.0.1:8080".parse().unwrap()).unwrap();
+    /// }
+    /// ```
+    #[cfg(not(target_os = "windows"))]
+    pub fn from_env_fds() -> Result<Self> {
+        let mut builder = Self::new();
+        builder.add_env_fds()?;
+        Ok(builder)
+    }
+
+    /// 追加 systemd 套接字激活传入的监听套接字，返回追加的数量
+    ///
+    /// 继承的套接字在进程内只能接管一次，重复调用返回 0。
+    #[cfg(not(target_os = "windows"))]
+    pub fn add_env_fds(&mut self) -> Result<usize> {
+        let fds = super::systemd::take_listen_fds()?;
+        let count = fds.len();
+        self.env_fds.extend(fds);
+        Ok(count)
+    }
+
+    /// 已接管的 systemd 监听套接字数量
+    #[cfg(not(target_os = "windows"))]
+    pub fn env_fds(&self) -> usize {
+        self.env_fds.len()
+    }
+
+    /// 以 `SO_REUSEPORT` 绑定 `n_loops` 个套接字，每个套接字运行独立的 accept 循环
+    ///
+    /// 与 [`bind`](Self::bind) 一样延迟到 [`listen`](Self::listen) 时执行，详见 [`ReuseportListener`]。
+    #[cfg(all(
+        unix,
+        not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+    ))]
+    pub fn bind_reuseport(&mut self, addr: std::net::SocketAddr, n_loops: usize) -> Result<()> {
+        self.reuseport_addrs.push((addr, n_loops));
         Ok(())
     }
 
     /// 构建 Listeners，执行实际绑定。
     ///
     /// 必须在 tokio runtime 内调用（`tokio::net::TcpListener::from_std` 需要 reactor）。
+    /// 由热重启启动时，与绑定地址一致的继承套接字会被直接复用，其余继承套接字被关闭。
     pub fn listen(mut self) -> Result<Listeners> {
+        // 热重启继承的套接字仅按地址复用；systemd 传入的套接字未被复用时也会监听
+        #[cfg(not(target_os = "windows"))]
+        let mut inherited = {
+            self.inherited_fds
+                .extend(super::hot_restart::take_inherited_fds()?);
+            let mut inherited = adopt_fds(self.inherited_fds.drain(..), false)?;
+            inherited.extend(adopt_fds(self.env_fds.drain(..), true)?);
+            inherited
+        };
+
         // 绑定 TCP 地址
-        for addr in self.tcp_addrs.drain(..) {
+        for (addr, options, optional) in self.tcp_addrs.drain(..) {
+            #[cfg(not(target_os = "windows"))]
+            if let Some(listener) = take_inherited(
+                &mut inherited,
+                |a| matches!(a, SocketAddr::Tcp(a) if *a == addr),
+            ) {
+                self.listeners.push(Box::new(match (listener, options) {
+                    (Listener::TcpListener(listener), Some(options)) => {
+                        Listener::TcpListenerWithOptions(listener, options)
+                    }
+                    (listener, _) => listener,
+                }));
+                continue;
+            }
+            if let Some(options) = options {
+                match Listener::bind_with(addr, options) {
+                    Ok(listener) => {
+                        self.listeners.push(Box::new(listener));
+                    }
+                    Err(e) if optional => {
+                        tracing::warn!(addr = ?addr, error = ?e, "skipped dual-stack TCP listener");
+                    }
+                    Err(e) => {
+                        tracing::error!(addr = ?addr, error = ?e, "failed to bind TCP listener");
+                        return Err(e);
+                    }
+                }
+                continue;
+            }
             match std::net::TcpListener::bind(addr) {
                 Ok(listener) => match Listener::try_from(listener) {
                     Ok(listener) => {
@@ -240,9 +951,50 @@ impl ListenersBuilder {
             }
         }
 
+        // 绑定 SO_REUSEPORT 地址
+        #[cfg(all(
+            unix,
+            not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+        ))]
+        for (addr, n_loops) in self.reuseport_addrs.drain(..) {
+            match ReuseportListener::bind(addr, n_loops) {
+                Ok(listener) => {
+                    self.listeners.push(Box::new(listener));
+                }
+                Err(e) => {
+                    tracing::error!(addr = ?addr, error = ?e, "failed to bind reuseport listener");
+                    return Err(e);
+                }
+            }
+        }
+
         // 绑定 Unix Socket 地址
         #[cfg(not(target_os = "windows"))]
-        for path in self.unix_paths.drain(..) {
+        let mut socket_files = vec![];
+        #[cfg(not(target_os = "windows"))]
+        for (path, options) in self.unix_paths.drain(..) {
+            if options.is_some_and(|options| options.remove_on_shutdown) {
+                socket_files.push(path.clone());
+            }
+            if let Some(listener) = take_inherited(
+                &mut inherited,
+                |a| matches!(a, SocketAddr::Unix(a) if a.as_pathname() == Some(path.as_path())),
+            ) {
+                self.listeners.push(Box::new(listener));
+                continue;
+            }
+            if let Some(options) = options {
+                match Listener::bind_unix_with(&path, options) {
+                    Ok(listener) => {
+                        self.listeners.push(Box::new(listener));
+                    }
+                    Err(e) => {
+                        tracing::error!(path = ?path, error = ?e, "failed to bind Unix socket listener");
+                        return Err(e);
+                    }
+                }
+                continue;
+            }
             match std::os::unix::net::UnixListener::bind(&path) {
                 Ok(listener) => match Listener::try_from(listener) {
                     Ok(listener) => {
@@ -260,8 +1012,17 @@ impl ListenersBuilder {
             }
         }
 
+        #[cfg(not(target_os = "windows"))]
+        for (listener, activated) in inherited {
+            if activated {
+                self.listeners.push(Box::new(listener));
+            } else {
+                tracing::debug!(addr = ?listener.local_addr(), "inherited listener not bound again, closing");
+            }
+        }
+
         // 无任何 listener 时绑定默认地址
-        if self.listeners.is_empty() {
+        if self.listeners.is_empty() && self.routed.is_empty() {
             match std::net::TcpListener::bind("127.0.0.1:0") {
                 Ok(listener) => match Listener::try_from(listener) {
                     Ok(listener) => {
@@ -278,27 +1039,81 @@ impl ListenersBuilder {
                 }
             }
         }
+        let mut services = vec![None; self.listeners.len()];
+        for (listener, service) in self.routed {
+            self.listeners.push(listener);
+            services.push(Some(service));
+        }
         let local_addrs = self
             .listeners
             .iter()
             .flat_map(|listener| listener.local_addr())
             .collect();
-        let listeners = self.listeners;
-        let backoff_states = (0..listeners.len()).map(|_| BackoffState::new()).collect();
+        let labels = self
+            .listeners
+            .iter()
+            .map(|listener| address_label(listener.local_addr()))
+            .collect();
+        let listeners = self.listeners.into_iter().map(Arc::from).collect();
         Ok(Listeners {
             listeners,
+            services,
             local_addrs,
-            backoff_states,
-            next_index: 0,
+            labels,
+            accepted: None,
+            loops: Vec::new(),
+            #[cfg(not(target_os = "windows"))]
+            socket_files,
         })
     }
 }
 
+/// 将继承的文件描述符转换为监听器，`activated` 标记是否来自 systemd 套接字激活
+#[cfg(not(target_os = "windows"))]
+fn adopt_fds(fds: impl Iterator<Item = OwnedFd>, activated: bool) -> Result<Vec<(Listener, bool)>> {
+    fds.map(|fd| match super::systemd::listener_from_fd(fd) {
+        Ok(listener) => Ok((listener, activated)),
+        Err(e) => {
+            tracing::error!(error = ?e, "failed to adopt inherited listener");
+            Err(e)
+        }
+    })
+    .collect()
+}
+
+/// 取出地址匹配的继承监听器
+#[cfg(not(target_os = "windows"))]
+fn take_inherited(
+    inherited: &mut Vec<(Listener, bool)>,
+    matches: impl Fn(&SocketAddr) -> bool,
+) -> Option<Listener> {
+    let idx = inherited
+        .iter()
+        .position(|(listener, _)| listener.local_addr().is_ok_and(|addr| matches(&addr)))?;
+    Some(inherited.swap_remove(idx).0)
+}
+
+/// 接受结果、对应监听器的独立服务及监听地址标签
+pub(crate) type RoutedAccept = Result<(
+    Box<dyn Connection + Send + Sync>,
+    SocketAddr,
+    Option<Arc<dyn ConnectionService>>,
+    Arc<str>,
+)>;
+
 pub struct Listeners {
-    listeners: Vec<Box<dyn Listen + Send + Sync + 'static>>,
+    listeners: Vec<Arc<dyn Listen + Send + Sync + 'static>>,
+    /// 各监听器的独立服务，`None` 表示使用 `serve` 传入的处理器
+    services: Vec<Option<Arc<dyn ConnectionService>>>,
     local_addrs: Vec<SocketAddr>,
-    backoff_states: Vec<BackoffState>,
-    next_index: usize,
+    /// 各监听器的地址标签，用于日志与指标
+    labels: Vec<Arc<str>>,
+    /// 各监听器 accept 循环的结果（附带监听器下标），首次 accept 时启动
+    accepted: Option<mpsc::Receiver<(usize, Accepted)>>,
+    loops: Vec<JoinHandle<()>>,
+    /// 关停后需要删除的 Unix 套接字文件
+    #[cfg(not(target_os = "windows"))]
+    socket_files: Vec<std::path::PathBuf>,
 }
 
 impl Listeners {
@@ -311,64 +1126,104 @@ impl Listeners {
     pub async fn accept(
         &mut self,
     ) -> Option<Result<(Box<dyn Connection + Send + Sync>, SocketAddr)>> {
+        self.accept_routed()
+            .await
+            .map(|res| res.map(|(conn, addr, _, _)| (conn, addr)))
+    }
+
+    /// 与 [`accept`](Self::accept) 相同，并返回接受该连接的监听器的独立服务及地址标签
+    ///
+    /// 每个监听器在独立的任务中循环 accept（出错时按退避等待），结果汇入同一通道，
+    /// 任一监听器上的连接都不会因其他监听器空闲而被阻塞。
+    pub(crate) async fn accept_routed(&mut self) -> Option<RoutedAccept> {
         if self.listeners.is_empty() {
             return None;
         }
+        if self.accepted.is_none() {
+            self.spawn_accept_loops();
+        }
 
-        loop {
-            let now = Instant::now();
-            let len = self.listeners.len();
-            let mut earliest_ready: Option<Instant> = None;
-            let mut selected: Option<usize> = None;
-
-            for offset in 0..len {
-                let idx = (self.next_index + offset) % len;
-                let state = &self.backoff_states[idx];
-                if state.is_ready(now) {
-                    selected = Some(idx);
-                    break;
-                }
-                earliest_ready = match earliest_ready {
-                    Some(inst) => Some(inst.min(state.next_ready)),
-                    None => Some(state.next_ready),
-                };
-            }
+        let (idx, res) = self.accepted.as_mut()?.recv().await?;
+        Some(res.map(|(conn, addr)| {
+            (
+                conn,
+                addr,
+                self.services[idx].clone(),
+                self.labels[idx].clone(),
+            )
+        }))
+    }
 
-            if let Some(idx) = selected {
-                self.next_index = (idx + 1) % len;
-                let res = self.listeners[idx].accept().await;
-                match res {
-                    Ok(conn) => {
-                        self.backoff_states[idx].on_success();
-                        trace!(
-                            listener = ?self.local_addrs.get(idx),
-                            backoff = ?self.backoff_states[idx].current,
-                            "accept ok"
-                        );
-                        return Some(Ok(conn));
-                    }
-                    Err(e) => {
-                        self.backoff_states[idx].on_error();
-                        trace!(
-                            listener = ?self.local_addrs.get(idx),
-                            backoff = ?self.backoff_states[idx].current,
-                            "accept error, apply backoff"
-                        );
-                        return Some(Err(e));
+    fn spawn_accept_loops(&mut self) {
+        let (tx, rx) = mpsc::channel(self.listeners.len() * 16);
+        self.loops = self
+            .listeners
+            .iter()
+            .enumerate()
+            .map(|(idx, listener)| {
+                let listener = listener.clone();
+                let label = self.labels[idx].clone();
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    let mut backoff = BackoffState::new();
+                    loop {
+                        let res = listener.accept().await;
+                        let failed = res.is_err();
+                        if failed {
+                            backoff.on_error();
+                            trace!(
+                                listener = %label,
+                                backoff = ?backoff.current,
+                                "accept error, apply backoff"
+                            );
+                            #[cfg(feature = "metrics")]
+                            super::metrics::record_listener_accept_err(&label);
+                        } else {
+                            backoff.on_success();
+                            trace!(listener = %label, "accept ok");
+                            #[cfg(feature = "metrics")]
+                            super::metrics::record_listener_accept_ok(&label);
+                        }
+                        if tx.send((idx, res)).await.is_err() {
+                            return;
+                        }
+                        if failed {
+                            sleep_until(backoff.next_ready).await;
+                        }
                     }
-                }
-            } else if let Some(next_ready) = earliest_ready {
-                sleep_until(next_ready).await;
-                continue;
-            } else {
-                return None;
-            }
-        }
+                })
+            })
+            .collect();
+        self.accepted = Some(rx);
     }
 
     pub fn local_addrs(&self) -> &[SocketAddr] {
         &self.local_addrs
     }
+
+    /// 取出关停后需要删除的 Unix 套接字文件
+    #[cfg(not(target_os = "windows"))]
+    pub(crate) fn take_socket_files(&mut self) -> Vec<std::path::PathBuf> {
+        std::mem::take(&mut self.socket_files)
+    }
+
+    /// 复制可交接的监听套接字，供热重启传给新进程
+    #[cfg(not(target_os = "windows"))]
+    pub(crate) fn handover_fds(&self) -> Result<Vec<OwnedFd>> {
+        self.listeners
+            .iter()
+            .filter_map(|listener| listener.as_fd())
+            .map(|fd| fd.try_clone_to_owned())
+            .collect()
+    }
+}
+
+impl Drop for Listeners {
+    fn drop(&mut self) {
+        for handle in &self.loops {
+            handle.abort();
+        }
+    }
 }
 
 /// 监听错误的退避状态。
@@ -391,10 +1246,6 @@ impl BackoffState {
         }
     }
 
-    fn is_ready(&self, now: Instant) -> bool {
-        now >= self.next_ready
-    }
-
     fn on_success(&mut self) {
         self.current = Self::BASE;
         self.next_ready = Instant::now();
@@ -452,6 +1303,137 @@ mod tests {
         client_handle.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_listener_bind_with_options() {
+        let options = TcpOptions {
+            nodelay: false,
+            keepalive: true,
+            recv_buf: Some(64 * 1024),
+            send_buf: Some(64 * 1024),
+            backlog: 16,
+            v6only: None,
+        };
+        let listener = Listener::bind_with("127.0.0.1:0".parse().unwrap(), options).unwrap();
+        assert!(matches!(
+            listener,
+            Listener::TcpListenerWithOptions(_, opts) if opts == options
+        ));
+        let SocketAddr::Tcp(addr) = listener.local_addr().unwrap() else {
+            panic!("Expected TCP address");
+        };
+
+        let client = tokio::spawn(tokio::net::TcpStream::connect(addr));
+        let (_stream, peer_addr) = listener.accept().await.unwrap();
+        assert!(matches!(peer_addr, SocketAddr::Tcp(_)));
+        client.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_listeners_builder_bind_with() {
+        let mut builder = ListenersBuilder::new();
+        builder
+            .bind_with(
+                "127.0.0.1:0".parse().unwrap(),
+                TcpOptions {
+                    backlog: 8,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let listeners = builder.listen().unwrap();
+        assert_eq!(listeners.local_addrs().len(), 1);
+        assert!(TcpOptions::default().nodelay);
+    }
+
+    #[tokio::test]
+    async fn test_listeners_builder_bind_all() {
+        let ipv6 = std::net::TcpListener::bind("[::1]:0").is_ok();
+        let families = |builder: ListenersBuilder| {
+            let listeners = builder.listen().unwrap();
+            let addrs: Vec<std::net::SocketAddr> = listeners
+                .local_addrs()
+                .iter()
+                .map(|addr| match addr {
+                    SocketAddr::Tcp(addr) => *addr,
+                    other => panic!("Expected TCP address, got {other:?}"),
+                })
+                .collect();
+            (
+                addrs.iter().filter(|addr| addr.is_ipv4()).count(),
+                addrs.iter().filter(|addr| addr.is_ipv6()).count(),
+                addrs,
+            )
+        };
+
+        let mut builder = ListenersBuilder::new();
+        builder.bind_all("127.0.0.1:0").unwrap();
+        assert_eq!(families(builder).0, 1);
+
+        // 未指定地址补充另一协议族，v6only 使两者可共用同一端口
+        let port = std::net::TcpListener::bind("0.0.0.0:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let mut builder = ListenersBuilder::new();
+        builder.bind_all(("0.0.0.0", port)).unwrap();
+        let (v4, v6, addrs) = families(builder);
+        assert_eq!((v4, v6), (1, usize::from(ipv6)));
+        assert!(addrs.iter().all(|addr| addr.port() == port));
+
+        // 重复地址只绑定一次
+        let mut builder = ListenersBuilder::new();
+        builder
+            .bind_all(&["0.0.0.0:0".parse().unwrap(), "0.0.0.0:0".parse().unwrap()][..])
+            .unwrap();
+        assert_eq!(builder.tcp_addrs.len(), 2);
+        assert!(!builder.tcp_addrs[0].2);
+        assert!(builder.tcp_addrs[1].2);
+        assert_eq!(builder.tcp_addrs[1].1.unwrap().v6only, Some(true));
+
+        let mut builder = ListenersBuilder::new();
+        assert!(
+            builder
+                .bind_all(&[][..] as &[std::net::SocketAddr])
+                .is_err()
+        );
+        assert!(builder.bind_all("not a socket address").is_err());
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_tcp_options_v6only() {
+        if std::net::TcpListener::bind("[::1]:0").is_err() {
+            return;
+        }
+        // 关闭 v6only 的 [::] 套接字同时接受 IPv4 连接
+        let options = TcpOptions {
+            v6only: Some(false),
+            ..Default::default()
+        };
+        let listener = Listener::bind_with("[::]:0".parse().unwrap(), options).unwrap();
+        let SocketAddr::Tcp(addr) = listener.local_addr().unwrap() else {
+            panic!("Expected TCP address");
+        };
+        let client = tokio::spawn(tokio::net::TcpStream::connect(("127.0.0.1", addr.port())));
+        listener.accept().await.unwrap();
+        client.await.unwrap().unwrap();
+
+        let options = TcpOptions {
+            v6only: Some(true),
+            ..Default::default()
+        };
+        let listener = Listener::bind_with("[::]:0".parse().unwrap(), options).unwrap();
+        let SocketAddr::Tcp(addr) = listener.local_addr().unwrap() else {
+            panic!("Expected TCP address");
+        };
+        assert!(
+            tokio::net::TcpStream::connect(("127.0.0.1", addr.port()))
+                .await
+                .is_err()
+        );
+    }
+
     #[tokio::test]
     #[cfg(not(target_os = "windows"))]
     async fn test_unix_listener() {
@@ -535,6 +1517,92 @@ mod tests {
         let _: fn(Listener, tokio_rustls::TlsAcceptor) -> TlsListener = Listener::tls;
     }
 
+    #[tokio::test]
+    #[cfg(feature = "tls")]
+    async fn test_listener_tls_alpn() {
+        use crate::DEFAULT_ALPN;
+
+        crate::server::tls::ensure_crypto_provider();
+        let config = rustls::ServerConfig::builder()
+            .with_no_client_auth()
+            .with_cert_resolver(Arc::new(rustls::server::ResolvesServerCertUsingSni::new()));
+        let alpn = |listener: &TlsListener| listener.acceptor.config().alpn_protocols.clone();
+        let bind = || async {
+            Listener::from(tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap())
+        };
+
+        // 未配置 ALPN 的 acceptor 使用默认协议
+        let listener = bind()
+            .await
+            .tls(TlsAcceptor::from(Arc::new(config.clone())));
+        assert_eq!(alpn(&listener), [b"h2".to_vec(), b"http/1.1".to_vec()]);
+
+        let listener = bind().await.tls_with_alpn(&config, &[b"http/1.1"]).unwrap();
+        assert_eq!(alpn(&listener), [b"http/1.1".to_vec()]);
+
+        // 覆盖 acceptor 已有的 ALPN
+        let listener = bind()
+            .await
+            .tls_with_alpn(&listener.acceptor, &[b"h2"])
+            .unwrap();
+        assert_eq!(alpn(&listener), [b"h2".to_vec()]);
+
+        // 证书无效时返回错误而不是 panic
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("server.crt"), b"CERTBYTES").unwrap();
+        std::fs::write(dir.path().join("server.key"), b"KEYBYTES").unwrap();
+        let store = CertificateStore::builder()
+            .cert_path(dir.path().join("server.crt"))
+            .key_path(dir.path().join("server.key"))
+            .build()
+            .unwrap();
+        assert!(bind().await.tls_with_alpn(&store, DEFAULT_ALPN).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    #[cfg(all(
+        unix,
+        not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+    ))]
+    async fn test_listeners_builder_bind_reuseport() {
+        let mut builder = ListenersBuilder::new();
+        builder
+            .bind_reuseport("127.0.0.1:0".parse().unwrap(), 4)
+            .unwrap();
+        let mut listeners = builder.listen().unwrap();
+        let addr = match listeners.local_addrs() {
+            [SocketAddr::Tcp(addr)] => *addr,
+            other => panic!("Expected one TCP address, got {other:?}"),
+        };
+        assert_ne!(addr.port(), 0);
+
+        let clients: Vec<_> = (0..8)
+            .map(|_| tokio::spawn(tokio::net::TcpStream::connect(addr)))
+            .collect();
+        for _ in 0..8 {
+            let (_conn, peer) = listeners.accept().await.unwrap().unwrap();
+            assert!(matches!(peer, SocketAddr::Tcp(_)));
+        }
+        for client in clients {
+            client.await.unwrap().unwrap();
+        }
+    }
+
+    #[tokio::test]
+    #[cfg(all(
+        unix,
+        not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+    ))]
+    async fn test_reuseport_listener_loops() {
+        let listener = ReuseportListener::bind("127.0.0.1:0".parse().unwrap(), 3).unwrap();
+        assert_eq!(listener.loops(), 3);
+        // 未启用 SO_REUSEPORT 的套接字无法绑定同一端口
+        let SocketAddr::Tcp(addr) = listener.local_addr().unwrap() else {
+            panic!("Expected TCP address");
+        };
+        assert!(std::net::TcpListener::bind(addr).is_err());
+    }
+
     #[tokio::test]
     async fn test_bind_error_handling() {
         // 测试绑定冲突端口时 listen() 返回 Err 而不是 panic
@@ -552,4 +1620,90 @@ mod tests {
         // 避免被优化掉
         drop(occupied);
     }
+
+    #[tokio::test]
+    #[cfg(not(target_os = "windows"))]
+    async fn test_unix_options_bind() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("options.sock");
+        let options = UnixOptions {
+            mode: Some(0o600),
+            remove_stale: true,
+            ..Default::default()
+        };
+
+        // 残留文件（无进程监听）被删除后重新绑定
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+        let listener = Listener::bind_unix_with(&path, options).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        // 仍在监听的套接字不会被删除
+        let Err(err) = Listener::bind_unix_with(&path, options) else {
+            panic!("socket in use should not be removed");
+        };
+        assert_eq!(err.kind(), std::io::ErrorKind::AddrInUse);
+        let client = tokio::spawn(tokio::net::UnixStream::connect(path.clone()));
+        listener.accept().await.unwrap();
+        client.await.unwrap().unwrap();
+
+        // 非套接字文件不会被删除
+        let file = dir.path().join("plain");
+        std::fs::write(&file, b"").unwrap();
+        assert!(Listener::bind_unix_with(&file, options).is_err());
+        assert!(file.exists());
+    }
+
+    #[tokio::test]
+    #[cfg(not(target_os = "windows"))]
+    async fn test_listeners_builder_bind_unix_with() {
+        let dir = tempfile::tempdir().unwrap();
+        let kept = dir.path().join("kept.sock");
+        let removed = dir.path().join("removed.sock");
+
+        let mut builder = ListenersBuilder::new();
+        builder.bind_unix(&kept).unwrap();
+        builder
+            .bind_unix_with(
+                &removed,
+                UnixOptions {
+                    remove_on_shutdown: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let mut listeners = builder.listen().unwrap();
+        assert_eq!(listeners.local_addrs().len(), 2);
+        assert_eq!(listeners.take_socket_files(), vec![removed]);
+    }
+
+    #[tokio::test]
+    #[cfg(not(target_os = "windows"))]
+    async fn test_listeners_builder_reuses_inherited() {
+        let reused = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let reused_addr = reused.local_addr().unwrap();
+        let unused = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let unused_addr = unused.local_addr().unwrap();
+
+        let mut builder = ListenersBuilder::new();
+        builder.inherited_fds.push(OwnedFd::from(reused));
+        builder.inherited_fds.push(OwnedFd::from(unused));
+        // 地址已被继承的套接字占用，复用而非重新绑定
+        builder
+            .bind_with(reused_addr, TcpOptions::default())
+            .unwrap();
+        let mut listeners = builder.listen().unwrap();
+        assert_eq!(listeners.local_addrs().len(), 1);
+        assert!(matches!(listeners.local_addrs()[0], SocketAddr::Tcp(a) if a == reused_addr));
+        assert_eq!(listeners.handover_fds().unwrap().len(), 1);
+
+        // 未复用的继承套接字已关闭
+        assert!(std::net::TcpListener::bind(unused_addr).is_ok());
+
+        let client = tokio::spawn(tokio::net::TcpStream::connect(reused_addr));
+        listeners.accept().await.unwrap().unwrap();
+        client.await.unwrap().unwrap();
+    }
 }
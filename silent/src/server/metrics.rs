@@ -1,7 +1,7 @@
-use std::sync::OnceLock;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
 
-use metrics::{counter, histogram};
+use metrics::{counter, gauge, histogram};
 
 /// Server 运行时指标（进程内计数），便于对接外部导出或调试。
 ///
@@ -17,6 +17,9 @@ pub struct ServerMetrics {
     pub handler_timeout: AtomicU64,
     pub graceful_shutdowns: AtomicU64,
     pub forced_shutdowns: AtomicU64,
+    pub active_connections: AtomicU64,
+    #[cfg(feature = "tls")]
+    pub tls_handshake_err: AtomicU64,
     #[cfg(feature = "quic")]
     pub http3_body_oversize: AtomicU64,
     #[cfg(feature = "quic")]
@@ -156,6 +159,67 @@ pub fn record_shutdown_duration(tag: &'static str, dur_ns: u64) {
     .record(dur_ns as f64);
 }
 
+// 以下指标带 `listener` 标签（监听地址，如 `127.0.0.1:8080`、`/run/app.sock`），
+// 便于区分同一进程中的多个监听器。
+
+pub fn record_listener_accept_ok(listener: &Arc<str>) {
+    counter!("silent.server.listener.accepted", "listener" => listener.clone()).increment(1);
+}
+
+pub fn record_listener_accept_err(listener: &Arc<str>) {
+    counter!("silent.server.listener.accept_errors", "listener" => listener.clone()).increment(1);
+}
+
+/// 连接在限流器中等待许可的时长
+pub fn record_listener_rate_limiter_wait(listener: &Arc<str>, wait_ns: u64) {
+    histogram!("silent.server.listener.ratelimiter.wait_ns", "listener" => listener.clone())
+        .record(wait_ns as f64);
+}
+
+/// 限流器丢弃的连接，`reason` 为 `timeout` 或 `closed`
+pub fn record_listener_rate_limiter_drop(listener: &Arc<str>, reason: &'static str) {
+    counter!(
+        "silent.server.listener.ratelimiter.dropped",
+        "listener" => listener.clone(),
+        "reason" => reason
+    )
+    .increment(1);
+}
+
+#[cfg(feature = "tls")]
+pub fn record_tls_handshake_duration(listener: &Arc<str>, dur_ns: u64) {
+    histogram!("silent.server.listener.tls.handshake_ns", "listener" => listener.clone())
+        .record(dur_ns as f64);
+}
+
+#[cfg(feature = "tls")]
+pub fn record_tls_handshake_err(listener: &Arc<str>) {
+    inc(&server_metrics().tls_handshake_err);
+
+    counter!("silent.server.listener.tls.handshake_errors", "listener" => listener.clone())
+        .increment(1);
+}
+
+/// 活动连接计数：创建时加一，连接任务结束（包括关停时被取消）时减一
+pub(crate) struct ActiveConnection(Arc<str>);
+
+impl ActiveConnection {
+    pub(crate) fn new(listener: Arc<str>) -> Self {
+        inc(&server_metrics().active_connections);
+        gauge!("silent.server.listener.active", "listener" => listener.clone()).increment(1.0);
+        Self(listener)
+    }
+}
+
+impl Drop for ActiveConnection {
+    fn drop(&mut self) {
+        server_metrics()
+            .active_connections
+            .fetch_sub(1, Ordering::Relaxed);
+        gauge!("silent.server.listener.active", "listener" => self.0.clone()).decrement(1.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -539,6 +603,126 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_listener_metrics_labels() {
+        use metrics::{
+            Counter, CounterFn, Gauge, GaugeFn, Histogram, HistogramFn, Key, KeyName, Metadata,
+            Recorder, SharedString, Unit,
+        };
+        use std::collections::HashMap;
+        use std::sync::Mutex;
+
+        type Values = Arc<Mutex<HashMap<String, f64>>>;
+
+        #[derive(Default)]
+        struct TestRecorder(Values);
+
+        struct Handle(String, Values);
+
+        impl Handle {
+            fn add(&self, value: f64) {
+                *self.1.lock().unwrap().entry(self.0.clone()).or_default() += value;
+            }
+        }
+
+        impl CounterFn for Handle {
+            fn increment(&self, value: u64) {
+                self.add(value as f64);
+            }
+            fn absolute(&self, _value: u64) {}
+        }
+
+        impl GaugeFn for Handle {
+            fn increment(&self, value: f64) {
+                self.add(value);
+            }
+            fn decrement(&self, value: f64) {
+                self.add(-value);
+            }
+            fn set(&self, _value: f64) {}
+        }
+
+        impl HistogramFn for Handle {
+            fn record(&self, _value: f64) {
+                self.add(1.0);
+            }
+        }
+
+        impl TestRecorder {
+            fn handle(&self, key: &Key) -> Arc<Handle> {
+                let labels: Vec<String> = key
+                    .labels()
+                    .map(|label| format!("{}={}", label.key(), label.value()))
+                    .collect();
+                let name = format!("{}{{{}}}", key.name(), labels.join(","));
+                Arc::new(Handle(name, self.0.clone()))
+            }
+
+            fn get(&self, name: &str) -> Option<f64> {
+                self.0.lock().unwrap().get(name).copied()
+            }
+        }
+
+        impl Recorder for TestRecorder {
+            fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+                Counter::from_arc(self.handle(key))
+            }
+            fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+                Gauge::from_arc(self.handle(key))
+            }
+            fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+                Histogram::from_arc(self.handle(key))
+            }
+        }
+
+        let recorder = TestRecorder::default();
+        let listener: Arc<str> = Arc::from("127.0.0.1:8080");
+        metrics::with_local_recorder(&recorder, || {
+            record_listener_accept_ok(&listener);
+            record_listener_accept_ok(&listener);
+            record_listener_accept_err(&listener);
+            record_listener_rate_limiter_wait(&listener, 1000);
+            record_listener_rate_limiter_drop(&listener, "timeout");
+            #[cfg(feature = "tls")]
+            {
+                record_tls_handshake_duration(&listener, 1000);
+                record_tls_handshake_err(&listener);
+            }
+
+            let first = ActiveConnection::new(listener.clone());
+            let _second = ActiveConnection::new(listener.clone());
+            assert_eq!(
+                recorder.get("silent.server.listener.active{listener=127.0.0.1:8080}"),
+                Some(2.0)
+            );
+            drop(first);
+        });
+
+        let get = |name: &str| recorder.get(&format!("{name}{{listener=127.0.0.1:8080}}"));
+        assert_eq!(get("silent.server.listener.accepted"), Some(2.0));
+        assert_eq!(get("silent.server.listener.accept_errors"), Some(1.0));
+        assert_eq!(get("silent.server.listener.ratelimiter.wait_ns"), Some(1.0));
+        #[cfg(feature = "tls")]
+        {
+            assert_eq!(get("silent.server.listener.tls.handshake_ns"), Some(1.0));
+            assert_eq!(
+                get("silent.server.listener.tls.handshake_errors"),
+                Some(1.0)
+            );
+        }
+        // 连接结束后活动计数归零
+        assert_eq!(get("silent.server.listener.active"), Some(0.0));
+        assert_eq!(
+            recorder.get(
+                "silent.server.listener.ratelimiter.dropped{listener=127.0.0.1:8080,reason=timeout}"
+            ),
+            Some(1.0)
+        );
+    }
+
     #[test]
     fn test_metrics_debug() {
         let metrics = ServerMetrics::default();
@@ -1,28 +1,40 @@
+#[cfg(feature = "acme")]
+pub mod acme;
 pub mod connection;
 pub mod connection_service;
+#[cfg(not(target_os = "windows"))]
+mod hot_restart;
 pub mod listener;
 pub mod net_server;
 pub mod protocol;
 #[cfg(feature = "quic")]
 pub mod quic;
+mod reloadable_route;
 pub mod route_connection;
 pub mod stream;
+#[cfg(not(target_os = "windows"))]
+mod systemd;
 #[cfg(feature = "tls")]
 pub mod tls;
 #[cfg(feature = "tls")]
-pub use tls::{CertificateStore, CertificateStoreBuilder};
+pub use tls::{CertificateStore, CertificateStoreBuilder, DEFAULT_ALPN, TlsConfigSource};
 mod config;
 #[cfg(feature = "metrics")]
 pub mod metrics;
 
-pub use config::{ConnectionLimits, ServerConfig};
+pub use config::{ConnectionLimits, Http2Config, ServerConfig};
+#[cfg(not(target_os = "windows"))]
+pub use hot_restart::UpgradeHandle;
+pub use reloadable_route::ReloadableRoute;
 pub use route_connection::RouteConnectionService;
+#[cfg(not(target_os = "windows"))]
+pub use systemd::sd_notify;
 
 use crate::core::socket_addr::SocketAddr as CoreSocketAddr;
 use config::set_global_server_config;
 pub use connection_service::{BoxError, ConnectionFuture, ConnectionService};
 use listener::{Listen, ListenersBuilder};
-pub use net_server::RateLimiterConfig;
+pub use net_server::{RateLimiterConfig, ShutdownHandle};
 use std::net::SocketAddr;
 #[cfg(not(target_os = "windows"))]
 use std::path::Path;
@@ -35,7 +47,11 @@ pub struct Server {
     listen_callback: Option<ListenCallback>,
     rate_limiter_config: Option<RateLimiterConfig>,
     graceful_shutdown_duration: Option<Duration>,
+    shutdown_handle: ShutdownHandle,
+    shutdown_signal: Option<net_server::ShutdownSignal>,
     config: ServerConfig,
+    #[cfg(not(target_os = "windows"))]
+    upgrade: Option<UpgradeHandle>,
 }
 
 impl Default for Server {
@@ -52,7 +68,11 @@ impl Server {
             listen_callback: None,
             rate_limiter_config: None,
             graceful_shutdown_duration: None,
+            shutdown_handle: ShutdownHandle::default(),
+            shutdown_signal: None,
             config: ServerConfig::default(),
+            #[cfg(not(target_os = "windows"))]
+            upgrade: None,
         }
     }
 
@@ -64,6 +84,49 @@ impl Server {
         self
     }
 
+    #[inline]
+    pub fn bind_with(mut self, addr: SocketAddr, options: crate::TcpOptions) -> Self {
+        self.listeners_builder
+            .bind_with(addr, options)
+            .expect("Failed to bind to address");
+        self
+    }
+
+    /// 绑定地址解析出的全部地址，`0.0.0.0`/`[::]` 同时监听 IPv4 与 IPv6，详见 [`ListenersBuilder::bind_all`]
+    ///
+    /// ```no_run
+    /// use silent::prelude::*;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// Server::new()
+    ///     .bind_all("0.0.0.0:8080")
+    ///     .on_listen(|addrs| println!("listening on {addrs:?}"))
+    ///     .serve(Route::new(""))
+    ///     .await;
+    /// # }
+    /// ```
+    #[inline]
+    pub fn bind_all<A: std::net::ToSocketAddrs>(mut self, addr: A) -> Self {
+        self.listeners_builder
+            .bind_all(addr)
+            .expect("Failed to resolve address");
+        self
+    }
+
+    /// 按 [`TcpOptions`](crate::TcpOptions) 绑定地址解析出的全部地址
+    #[inline]
+    pub fn bind_all_with<A: std::net::ToSocketAddrs>(
+        mut self,
+        addr: A,
+        options: crate::TcpOptions,
+    ) -> Self {
+        self.listeners_builder
+            .bind_all_with(addr, options)
+            .expect("Failed to resolve address");
+        self
+    }
+
     #[cfg(not(target_os = "windows"))]
     #[inline]
     pub fn bind_unix<P: AsRef<Path>>(mut self, path: P) -> Self {
@@ -73,12 +136,70 @@ impl Server {
         self
     }
 
+    #[cfg(not(target_os = "windows"))]
+    #[inline]
+    pub fn bind_unix_with<P: AsRef<Path>>(mut self, path: P, options: crate::UnixOptions) -> Self {
+        self.listeners_builder
+            .bind_unix_with(&path, options)
+            .expect("Failed to bind to Unix socket");
+        self
+    }
+
+    /// 使用 systemd 套接字激活传入的监听套接字，未由 systemd 激活时不添加任何监听器。
+    #[cfg(not(target_os = "windows"))]
+    #[inline]
+    pub fn with_env_fds(mut self) -> Self {
+        self.listeners_builder
+            .add_env_fds()
+            .expect("Failed to adopt systemd sockets");
+        self
+    }
+
+    #[cfg(all(
+        unix,
+        not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+    ))]
+    #[inline]
+    pub fn bind_reuseport(mut self, addr: SocketAddr, n_loops: usize) -> Self {
+        self.listeners_builder
+            .bind_reuseport(addr, n_loops)
+            .expect("Failed to bind reuseport sockets");
+        self
+    }
+
     #[inline]
     pub fn listen<T: Listen + Send + Sync + 'static>(mut self, listener: T) -> Self {
         self.listeners_builder.add_listener(Box::new(listener));
         self
     }
 
+    /// 添加使用独立服务的监听器，其余监听器仍使用 `serve`/`run` 传入的处理器。
+    ///
+    /// ```no_run
+    /// use silent::prelude::*;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let admin = tokio::net::UnixListener::bind("/tmp/admin.sock").unwrap();
+    /// let admin_route = Route::new("metrics").get(|_req: Request| async { Ok("ok") });
+    /// Server::new()
+    ///     .bind("0.0.0.0:8080".parse().unwrap())
+    ///     .listen_with(Listener::from(admin), admin_route)
+    ///     .serve(Route::new("api"))
+    ///     .await;
+    /// # }
+    /// ```
+    #[inline]
+    pub fn listen_with<T, S>(mut self, listener: T, service: S) -> Self
+    where
+        T: Listen + Send + Sync + 'static,
+        S: ConnectionService + 'static,
+    {
+        self.listeners_builder
+            .add_listener_with(Box::new(listener), std::sync::Arc::new(service));
+        self
+    }
+
     pub fn set_shutdown_callback<F>(mut self, callback: F) -> Self
     where
         F: Fn() + Send + Sync + 'static,
@@ -146,6 +267,41 @@ impl Server {
         self
     }
 
+    /// 返回关停句柄，调用 [`ShutdownHandle::shutdown`] 触发与 Ctrl-C/SIGTERM 相同的优雅关停。
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown_handle.clone()
+    }
+
+    /// 设置自定义关停信号（如 Kubernetes preStop 通知），与 Ctrl-C/SIGTERM 一起生效。
+    ///
+    /// ```no_run
+    /// use silent::prelude::*;
+    ///
+    /// let (_tx, rx) = tokio::sync::oneshot::channel::<()>();
+    /// Server::new()
+    ///     .with_shutdown_signal(async move {
+    ///         let _ = rx.await;
+    ///     })
+    ///     .run(Route::new(""));
+    /// ```
+    pub fn with_shutdown_signal<F>(mut self, signal: F) -> Self
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        self.shutdown_signal = Some(Box::pin(signal));
+        self
+    }
+
+    /// 启用零停机热重启并返回句柄。
+    ///
+    /// 收到 `SIGUSR2` 或调用 [`UpgradeHandle::upgrade`] 时，以相同参数启动新进程并交接监听套接字；
+    /// 新进程就绪后旧进程停止接受连接，按 [`with_shutdown`](Self::with_shutdown) 的时间排空已有连接，
+    /// 随后 [`run`](Self::run) 返回。
+    #[cfg(not(target_os = "windows"))]
+    pub fn upgrade_handle(&mut self) -> UpgradeHandle {
+        self.upgrade.get_or_insert_with(Default::default).clone()
+    }
+
     /// 配置统一入口（连接限速、超时、请求体大小等）。
     #[inline]
     pub fn with_config(mut self, config: ServerConfig) -> Self {
@@ -153,6 +309,43 @@ impl Server {
         self
     }
 
+    /// 注入 Server 级共享状态。
+    ///
+    /// 状态会在每个请求进入路由前合并到请求中，可通过 `State<T>` 提取器或
+    /// `req.get_state::<T>()` 读取；路由上通过 `with_state` 设置的同类型状态优先。
+    /// 注意：`with_config` 会整体替换配置，应在其之后调用本方法。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::prelude::*;
+    /// use silent::extractor::State;
+    ///
+    /// #[derive(Clone)]
+    /// struct AppState {
+    ///     name: String,
+    /// }
+    ///
+    /// async fn hello(State(app): State<AppState>) -> Result<String> {
+    ///     Ok(app.name)
+    /// }
+    ///
+    /// let route = Route::new("").get(hello);
+    /// Server::new()
+    ///     .with_state(AppState { name: "silent".into() })
+    ///     .run(route);
+    /// ```
+    pub fn with_state<T: Send + Sync + Clone + 'static>(mut self, val: T) -> Self {
+        self.config.state.insert(val);
+        self
+    }
+
+    /// 注入以 `Arc` 共享的全局状态，`T` 无需实现 `Clone`，处理器可通过 `StateArc<T>` 获取。
+    pub fn with_state_arc<T: Send + Sync + 'static>(mut self, val: std::sync::Arc<T>) -> Self {
+        self.config.state.insert_arc(val);
+        self
+    }
+
     /// 设置连接级别超时/请求体大小限制。
     #[inline]
     pub fn with_connection_limits(mut self, limits: ConnectionLimits) -> Self {
@@ -160,6 +353,28 @@ impl Server {
         self
     }
 
+    /// 设置 HTTP/2 连接参数（并发流、窗口大小、帧长度、keepalive 等）。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::prelude::*;
+    /// use silent::Http2Config;
+    /// use std::time::Duration;
+    ///
+    /// let server = Server::new().with_http2_config(
+    ///     Http2Config::new()
+    ///         .with_max_concurrent_streams(1024)
+    ///         .with_keep_alive_interval(Duration::from_secs(30))
+    ///         .with_keep_alive_timeout(Duration::from_secs(10)),
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_http2_config(mut self, http2: Http2Config) -> Self {
+        self.config.http2 = http2;
+        self
+    }
+
     pub async fn serve<H>(self, handler: H)
     where
         H: ConnectionService + Clone,
@@ -183,6 +398,11 @@ impl Server {
         if let Some(duration) = self.graceful_shutdown_duration {
             net_server = net_server.with_shutdown(duration);
         }
+        net_server = net_server.with_shutdown_parts(self.shutdown_handle, self.shutdown_signal);
+        #[cfg(not(target_os = "windows"))]
+        {
+            net_server = net_server.with_upgrade(self.upgrade);
+        }
 
         net_server.serve(handler).await
     }
@@ -210,6 +430,11 @@ impl Server {
         if let Some(duration) = self.graceful_shutdown_duration {
             net_server = net_server.with_shutdown(duration);
         }
+        net_server = net_server.with_shutdown_parts(self.shutdown_handle, self.shutdown_signal);
+        #[cfg(not(target_os = "windows"))]
+        {
+            net_server = net_server.with_upgrade(self.upgrade);
+        }
 
         net_server.run(handler)
     }
@@ -325,6 +550,44 @@ mod tests {
         assert_eq!(server.graceful_shutdown_duration, Some(duration));
     }
 
+    #[tokio::test]
+    async fn test_server_shutdown_handle() {
+        let server = Server::new().bind("127.0.0.1:0".parse().unwrap());
+        let handle = server.shutdown_handle();
+        assert!(!handle.is_shutdown());
+        // 启动前触发，服务绑定后立即关停
+        handle.shutdown();
+        assert!(handle.is_shutdown());
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            server.serve(crate::prelude::Route::new("")),
+        )
+        .await
+        .expect("server should shut down");
+    }
+
+    #[tokio::test]
+    async fn test_server_with_shutdown_signal() {
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let called = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = called.clone();
+        let server = Server::new()
+            .bind("127.0.0.1:0".parse().unwrap())
+            .set_shutdown_callback(move || flag.store(true, std::sync::atomic::Ordering::SeqCst))
+            .with_shutdown_signal(async move {
+                let _ = rx.await;
+            });
+        let jh = tokio::spawn(server.serve(crate::prelude::Route::new("")));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!jh.is_finished());
+        tx.send(()).unwrap();
+        tokio::time::timeout(Duration::from_secs(5), jh)
+            .await
+            .expect("server should shut down")
+            .unwrap();
+        assert!(called.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
     #[test]
     fn test_server_with_config() {
         let config = ServerConfig::default();
@@ -334,11 +597,23 @@ mod tests {
         assert_eq!(server.config.connection_limits.max_body_size, None);
     }
 
+    #[test]
+    fn test_server_with_state() {
+        #[derive(Clone)]
+        struct AppName(&'static str);
+
+        let server = Server::new().with_state(AppName("silent")).with_state(1u8);
+        assert_eq!(server.config.state.len(), 2);
+        assert_eq!(server.config.state.get::<AppName>().unwrap().0, "silent");
+    }
+
     #[test]
     fn test_server_with_connection_limits() {
         let limits = ConnectionLimits {
             handler_timeout: Some(Duration::from_secs(30)),
             max_body_size: Some(1024 * 1024),
+            max_header_bytes: None,
+            max_header_count: None,
             h3_read_timeout: None,
             max_webtransport_frame_size: None,
             webtransport_read_timeout: None,
@@ -387,6 +662,8 @@ mod tests {
         let limits = ConnectionLimits {
             handler_timeout: Some(Duration::from_secs(60)),
             max_body_size: Some(512 * 1024),
+            max_header_bytes: None,
+            max_header_count: None,
             h3_read_timeout: None,
             max_webtransport_frame_size: None,
             webtransport_read_timeout: None,
@@ -438,6 +715,8 @@ mod tests {
         let limits = ConnectionLimits {
             handler_timeout: Some(Duration::from_secs(120)),
             max_body_size: Some(2048 * 1024),
+            max_header_bytes: None,
+            max_header_count: None,
             h3_read_timeout: Some(Duration::from_secs(30)),
             max_webtransport_frame_size: None,
             webtransport_read_timeout: None,
@@ -527,6 +806,8 @@ mod tests {
         let limits = ConnectionLimits {
             handler_timeout: Some(Duration::from_secs(30)),
             max_body_size: Some(1024 * 1024),
+            max_header_bytes: None,
+            max_header_count: None,
             h3_read_timeout: Some(Duration::from_secs(20)),
             max_webtransport_frame_size: Some(4096),
             webtransport_read_timeout: None,
@@ -551,6 +832,8 @@ mod tests {
         let limits = ConnectionLimits {
             handler_timeout: None,
             max_body_size: Some(512 * 1024),
+            max_header_bytes: None,
+            max_header_count: None,
             h3_read_timeout: None,
             max_webtransport_frame_size: None,
             webtransport_read_timeout: None,
@@ -624,6 +907,8 @@ mod tests {
             connection_limits: ConnectionLimits {
                 handler_timeout: Some(Duration::from_secs(100)),
                 max_body_size: Some(2048),
+                max_header_bytes: None,
+                max_header_count: None,
                 h3_read_timeout: Some(Duration::from_secs(50)),
                 max_webtransport_frame_size: None,
                 webtransport_read_timeout: None,
@@ -729,6 +1014,8 @@ mod tests {
         let limits = ConnectionLimits {
             handler_timeout: Some(Duration::from_secs(60)),
             max_body_size: Some(1048576),
+            max_header_bytes: None,
+            max_header_count: None,
             h3_read_timeout: Some(Duration::from_secs(30)),
             max_webtransport_frame_size: Some(16384),
             webtransport_read_timeout: Some(Duration::from_secs(20)),
@@ -824,6 +1111,8 @@ mod tests {
         let limits = ConnectionLimits {
             handler_timeout: Some(Duration::ZERO),
             max_body_size: Some(0),
+            max_header_bytes: None,
+            max_header_count: None,
             h3_read_timeout: Some(Duration::ZERO),
             max_webtransport_frame_size: Some(0),
             webtransport_read_timeout: Some(Duration::ZERO),
@@ -3,16 +3,19 @@ use super::config::ServerConfig;
 use super::listener::{Listen, ListenersBuilder};
 #[cfg(feature = "metrics")]
 use super::metrics::{
-    record_accept_err, record_accept_ok, record_forced_shutdown, record_graceful_shutdown,
-    record_handler_duration, record_handler_err, record_handler_ok, record_handler_timeout,
+    ActiveConnection, record_accept_err, record_accept_ok, record_forced_shutdown,
+    record_graceful_shutdown, record_handler_duration, record_handler_err, record_handler_ok,
+    record_handler_timeout, record_listener_rate_limiter_drop, record_listener_rate_limiter_wait,
     record_rate_limiter_closed, record_rate_limiter_timeout, record_shutdown_duration,
     record_wait_duration,
 };
 use crate::core::socket_addr::SocketAddr as CoreSocketAddr;
+use std::future::Future;
 use std::io;
 use std::net::SocketAddr;
 #[cfg(not(target_os = "windows"))]
 use std::path::Path;
+use std::pin::Pin;
 use std::sync::Arc;
 #[cfg(test)]
 use std::sync::OnceLock;
@@ -22,11 +25,28 @@ use std::time::{Duration, Instant};
 use tokio::signal;
 use tokio::sync::Semaphore;
 use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
 #[cfg(test)]
 static SHUTDOWN_NOTIFY: OnceLock<tokio::sync::Notify> = OnceLock::new();
 #[cfg(feature = "scheduler")]
 static SCHEDULER_RUNNING: AtomicBool = AtomicBool::new(false);
 
+tokio::task_local! {
+    /// 连接任务所属服务的排空信号
+    static DRAIN: CancellationToken;
+}
+
+/// 当前连接任务的排空信号，服务开始优雅关停时触发；不在 [`NetServer`] 的连接任务中时返回 `None`
+pub(crate) fn drain_signal() -> Option<CancellationToken> {
+    DRAIN.try_with(Clone::clone).ok()
+}
+
+/// 在给定排空信号下运行连接任务
+#[cfg(test)]
+pub(crate) async fn with_drain<F: Future>(drain: CancellationToken, fut: F) -> F::Output {
+    DRAIN.scope(drain, fut).await
+}
+
 #[cfg(test)]
 fn trigger_test_shutdown() {
     SHUTDOWN_NOTIFY
@@ -34,6 +54,16 @@ fn trigger_test_shutdown() {
         .notify_waiters();
 }
 
+/// 等待热重启交接完成，未启用时永不完成
+async fn upgrade_completed(
+    task: &mut Option<tokio::task::JoinHandle<()>>,
+) -> Result<(), tokio::task::JoinError> {
+    match task {
+        Some(task) => task.await,
+        None => futures_util::future::pending().await,
+    }
+}
+
 fn test_shutdown_future() -> impl std::future::Future<Output = ()> {
     #[cfg(test)]
     {
@@ -160,7 +190,11 @@ pub struct NetServer {
     listen_callback: Option<ListenCallback>,
     rate_limiter: Option<RateLimiter>,
     shutdown_cfg: ShutdownConfig,
+    shutdown_handle: ShutdownHandle,
+    shutdown_signal: Option<ShutdownSignal>,
     config: ServerConfig,
+    #[cfg(not(target_os = "windows"))]
+    upgrade: Option<super::UpgradeHandle>,
 }
 
 impl Default for NetServer {
@@ -191,7 +225,11 @@ impl NetServer {
             listen_callback: None,
             rate_limiter: None,
             shutdown_cfg: ShutdownConfig::default(),
+            shutdown_handle: ShutdownHandle::default(),
+            shutdown_signal: None,
             config: ServerConfig::default(),
+            #[cfg(not(target_os = "windows"))]
+            upgrade: None,
         }
     }
 
@@ -207,7 +245,11 @@ impl NetServer {
             listen_callback,
             rate_limiter: None,
             shutdown_cfg: ShutdownConfig::default(),
+            shutdown_handle: ShutdownHandle::default(),
+            shutdown_signal: None,
             config,
+            #[cfg(not(target_os = "windows"))]
+            upgrade: None,
         }
     }
 
@@ -230,6 +272,76 @@ impl NetServer {
         Ok(self)
     }
 
+    /// 按 [`TcpOptions`](crate::TcpOptions) 绑定 TCP 监听地址。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::{NetServer, TcpOptions};
+    ///
+    /// let options = TcpOptions {
+    ///     keepalive: true,
+    ///     backlog: 4096,
+    ///     ..Default::default()
+    /// };
+    /// let server = NetServer::new()
+    ///     .bind_with("127.0.0.1:8080".parse().unwrap(), options)
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn bind_with(
+        mut self,
+        addr: SocketAddr,
+        options: crate::TcpOptions,
+    ) -> Result<Self, io::Error> {
+        self.listeners_builder.bind_with(addr, options)?;
+        Ok(self)
+    }
+
+    /// 绑定地址解析出的全部地址，`0.0.0.0`/`[::]` 同时监听 IPv4 与 IPv6。
+    ///
+    /// 详见 [`ListenersBuilder::bind_all`](crate::ListenersBuilder::bind_all)。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::NetServer;
+    ///
+    /// let server = NetServer::new()
+    ///     .bind_all("localhost:8080")
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn bind_all<A: std::net::ToSocketAddrs>(mut self, addr: A) -> Result<Self, io::Error> {
+        self.listeners_builder.bind_all(addr)?;
+        Ok(self)
+    }
+
+    /// 按 [`TcpOptions`](crate::TcpOptions) 绑定地址解析出的全部地址。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::{NetServer, TcpOptions};
+    ///
+    /// let options = TcpOptions {
+    ///     backlog: 4096,
+    ///     ..Default::default()
+    /// };
+    /// let server = NetServer::new()
+    ///     .bind_all_with("0.0.0.0:8080", options)
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn bind_all_with<A: std::net::ToSocketAddrs>(
+        mut self,
+        addr: A,
+        options: crate::TcpOptions,
+    ) -> Result<Self, io::Error> {
+        self.listeners_builder.bind_all_with(addr, options)?;
+        Ok(self)
+    }
+
     /// 绑定 Unix Domain Socket 监听路径（仅非 Windows 平台）。
     ///
     /// # Examples
@@ -250,6 +362,75 @@ impl NetServer {
         Ok(self)
     }
 
+    /// 按 [`UnixOptions`](crate::UnixOptions) 绑定 Unix Domain Socket 监听路径（仅非 Windows 平台）。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::{NetServer, UnixOptions};
+    ///
+    /// let options = UnixOptions {
+    ///     mode: Some(0o660),
+    ///     remove_stale: true,
+    ///     remove_on_shutdown: true,
+    ///     ..Default::default()
+    /// };
+    /// let server = NetServer::new()
+    ///     .bind_unix_with("/tmp/my_service.sock", options)
+    ///     .unwrap();
+    /// ```
+    #[cfg(not(target_os = "windows"))]
+    #[inline]
+    pub fn bind_unix_with<P: AsRef<Path>>(
+        mut self,
+        path: P,
+        options: crate::UnixOptions,
+    ) -> Result<Self, io::Error> {
+        self.listeners_builder.bind_unix_with(path, options)?;
+        Ok(self)
+    }
+
+    /// 使用 systemd 套接字激活传入的监听套接字。
+    ///
+    /// 未由 systemd 激活时不添加任何监听器；服务在完成绑定后会自动通过 `sd_notify` 上报 `READY=1`。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::NetServer;
+    ///
+    /// let server = NetServer::new().with_env_fds().unwrap();
+    /// ```
+    #[cfg(not(target_os = "windows"))]
+    #[inline]
+    pub fn with_env_fds(mut self) -> Result<Self, io::Error> {
+        self.listeners_builder.add_env_fds()?;
+        Ok(self)
+    }
+
+    /// 以 `SO_REUSEPORT` 绑定多个套接字，每个套接字运行独立的 accept 循环。
+    ///
+    /// 适用于连接频繁建立与断开的场景，`n_loops` 通常取工作线程数。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::NetServer;
+    ///
+    /// let server = NetServer::new()
+    ///     .bind_reuseport("0.0.0.0:8080".parse().unwrap(), 4)
+    ///     .unwrap();
+    /// ```
+    #[cfg(all(
+        unix,
+        not(any(target_os = "solaris", target_os = "illumos", target_os = "cygwin"))
+    ))]
+    #[inline]
+    pub fn bind_reuseport(mut self, addr: SocketAddr, n_loops: usize) -> Result<Self, io::Error> {
+        self.listeners_builder.bind_reuseport(addr, n_loops)?;
+        Ok(self)
+    }
+
     /// 添加自定义监听器。
     ///
     /// 用于高级场景，允许使用自定义的监听器实现。
@@ -273,6 +454,40 @@ impl NetServer {
         self
     }
 
+    /// 添加使用独立服务的监听器。
+    ///
+    /// 该监听器接受的连接交给 `service` 处理，其余监听器仍使用 [`serve`](Self::serve) 传入的处理器，
+    /// 可在同一进程中分别提供公网 API 与内部管理接口。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::{BoxedConnection, NetServer, SocketAddr};
+    /// use tokio::net::TcpListener;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let admin = TcpListener::bind("127.0.0.1:9090").await.unwrap();
+    /// NetServer::new()
+    ///     .bind("0.0.0.0:8080".parse().unwrap()).unwrap()
+    ///     .listen_with(silent::Listener::from(admin), |_s: BoxedConnection, _p: SocketAddr| async move {
+    ///         Ok::<_, silent::BoxError>(())
+    ///     })
+    ///     .serve(|_s: BoxedConnection, _p: SocketAddr| async move { Ok::<_, silent::BoxError>(()) })
+    ///     .await;
+    /// # }
+    /// ```
+    #[inline]
+    pub fn listen_with<T, S>(mut self, listener: T, service: S) -> Self
+    where
+        T: Listen + Send + Sync + 'static,
+        S: ConnectionService + 'static,
+    {
+        self.listeners_builder
+            .add_listener_with(Box::new(listener), Arc::new(service));
+        self
+    }
+
     /// 设置监听成功后的回调函数。
     ///
     /// 回调函数会在所有监听器成功绑定后被调用，接收实际监听的地址列表。
@@ -374,6 +589,82 @@ impl NetServer {
         self
     }
 
+    /// 返回关停句柄，可在代码中触发优雅关停。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::NetServer;
+    ///
+    /// let server = NetServer::new();
+    /// let handle = server.shutdown_handle();
+    /// // 在测试或管理接口中：
+    /// handle.shutdown();
+    /// ```
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown_handle.clone()
+    }
+
+    /// 设置自定义关停信号，与 Ctrl-C/SIGTERM 一起生效，任一先完成即开始优雅关停。
+    ///
+    /// 重复调用时以最后一次设置为准。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::NetServer;
+    ///
+    /// let (_tx, rx) = tokio::sync::oneshot::channel::<()>();
+    /// let server = NetServer::new().with_shutdown_signal(async move {
+    ///     let _ = rx.await;
+    /// });
+    /// ```
+    pub fn with_shutdown_signal<F>(mut self, signal: F) -> Self
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.shutdown_signal = Some(Box::pin(signal));
+        self
+    }
+
+    pub(crate) fn with_shutdown_parts(
+        mut self,
+        handle: ShutdownHandle,
+        signal: Option<ShutdownSignal>,
+    ) -> Self {
+        self.shutdown_handle = handle;
+        self.shutdown_signal = signal;
+        self
+    }
+
+    /// 启用零停机热重启并返回句柄。
+    ///
+    /// 收到 `SIGUSR2` 或调用 [`UpgradeHandle::upgrade`](crate::UpgradeHandle::upgrade) 时，
+    /// 以相同参数启动新进程并交接监听套接字；新进程就绪后停止接受连接，
+    /// 按 [`with_shutdown`](Self::with_shutdown) 的时间排空已有连接，随后 [`serve`](Self::serve) 返回。
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use silent::NetServer;
+    /// use std::time::Duration;
+    ///
+    /// let mut server = NetServer::new()
+    ///     .bind("127.0.0.1:8080".parse().unwrap()).unwrap()
+    ///     .with_shutdown(Duration::from_secs(30));
+    /// let upgrade = server.upgrade_handle();
+    /// ```
+    #[cfg(not(target_os = "windows"))]
+    pub fn upgrade_handle(&mut self) -> super::UpgradeHandle {
+        self.upgrade.get_or_insert_with(Default::default).clone()
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    pub(crate) fn with_upgrade(mut self, upgrade: Option<super::UpgradeHandle>) -> Self {
+        self.upgrade = upgrade;
+        self
+    }
+
     /// 启动服务器（异步版本）。
     ///
     /// 此方法会阻塞当前任务，直到收到关停信号（Ctrl-C 或 SIGTERM）。
@@ -484,14 +775,46 @@ impl NetServer {
             }
         }
 
+        #[cfg(feature = "tls-keylog")]
+        if self.config.tls_keylog {
+            tracing::warn!(
+                path = ?std::env::var_os("SSLKEYLOGFILE"),
+                "TLS key logging enabled, do not use in production"
+            );
+        }
+
+        // 以 systemd Type=notify 启动时上报就绪，未设置 NOTIFY_SOCKET 时无操作
+        #[cfg(not(target_os = "windows"))]
+        super::systemd::notify_quietly("READY=1");
+        #[cfg(not(target_os = "windows"))]
+        super::hot_restart::notify_parent_ready();
+
+        // 热重启：新进程就绪后监视任务结束，主循环随之进入排空
+        #[cfg(not(target_os = "windows"))]
+        let mut upgrade_task = match self.upgrade.take() {
+            Some(upgrade) => Some(upgrade.spawn_watcher(listeners.handover_fds()?)),
+            None => None,
+        };
+        #[cfg(target_os = "windows")]
+        let mut upgrade_task: Option<tokio::task::JoinHandle<()>> = None;
+        #[cfg(not(target_os = "windows"))]
+        let mut socket_files = listeners.take_socket_files();
+
         #[cfg(feature = "scheduler")]
         ensure_scheduler_running();
 
         let mut join_set: JoinSet<()> = JoinSet::new();
-        let mut shutdown = ShutdownHandle::new(self.shutdown_callback.take(), self.shutdown_cfg);
+        let mut shutdown = ShutdownListener::new(
+            self.shutdown_callback.take(),
+            self.shutdown_cfg,
+            self.shutdown_handle.clone(),
+            self.shutdown_signal.take(),
+        );
         let rate = self_rate_limiter(self.rate_limiter.as_ref());
         // 启动限流器补充任务（若配置）
         let mut refill_handle = rate.as_ref().map(|r| r.spawn_refill_task());
+        // 关停时通知连接层尽快结束长连接
+        let drain = CancellationToken::new();
 
         loop {
             tokio::select! {
@@ -504,28 +827,32 @@ impl NetServer {
                     );
                     break;
                 }
-                accept_result = listeners.accept() => {
+                accept_result = listeners.accept_routed() => {
                     match accept_result {
                         None => {
                             tracing::info!(elapsed = ?loop_started.elapsed(), "listener closed, shutting down");
                             break;
                         }
-                        Some(Ok((stream, peer_addr))) => {
+                        Some(Ok((stream, peer_addr, service, listener))) => {
                             #[cfg(feature = "metrics")]
                             record_accept_ok();
                             if let Some(rate) = &rate {
                                 let semaphore = rate.semaphore.clone();
                                 let max_wait = rate.max_wait;
-                                let handler = handler.clone();
+                                let handler = service.unwrap_or_else(|| handler.clone());
                                 let peer = peer_addr.clone();
                                 let accepted_at = Instant::now();
-                                tracing::info!(%peer, "accepted connection");
-                                join_set.spawn(async move {
+                                tracing::info!(%peer, %listener, "accepted connection");
+                                join_set.spawn(DRAIN.scope(drain.clone(), async move {
+                                    #[cfg(feature = "metrics")]
+                                    let _active = ActiveConnection::new(listener.clone());
                                     match tokio::time::timeout(max_wait, semaphore.acquire_owned()).await {
                                         Ok(Ok(_permit)) => {
                                             let wait_cost = accepted_at.elapsed();
                                             #[cfg(feature = "metrics")]
                                             record_wait_duration(wait_cost.as_nanos() as u64);
+                                            #[cfg(feature = "metrics")]
+                                            record_listener_rate_limiter_wait(&listener, wait_cost.as_nanos() as u64);
                                             if let Some(timeout) = handler_timeout {
                                                 match tokio::time::timeout(timeout, handler.call(stream, peer.clone())).await {
                                                     Ok(res) => {
@@ -565,21 +892,27 @@ impl NetServer {
                                         Ok(Err(_)) => {
                                             #[cfg(feature = "metrics")]
                                             record_rate_limiter_closed();
+                                            #[cfg(feature = "metrics")]
+                                            record_listener_rate_limiter_drop(&listener, "closed");
                                             tracing::warn!(%peer, "Rate limiter closed, dropping connection");
                                         }
                                         Err(_) => {
                                             #[cfg(feature = "metrics")]
                                             record_rate_limiter_timeout();
+                                            #[cfg(feature = "metrics")]
+                                            record_listener_rate_limiter_drop(&listener, "timeout");
                                             tracing::warn!(%peer, "Rate limiter timeout, dropping connection");
                                         }
                                     }
-                                });
+                                }));
                             } else {
-                                let handler = handler.clone();
+                                let handler = service.unwrap_or_else(|| handler.clone());
                                 let peer = peer_addr.clone();
                                 let accepted_at = Instant::now();
-                                tracing::info!(%peer, "accepted connection");
-                                join_set.spawn(async move {
+                                tracing::info!(%peer, %listener, "accepted connection");
+                                join_set.spawn(DRAIN.scope(drain.clone(), async move {
+                                    #[cfg(feature = "metrics")]
+                                    let _active = ActiveConnection::new(listener);
                                     if let Some(timeout) = handler_timeout {
                                         match tokio::time::timeout(timeout, handler.call(stream, peer.clone())).await {
                                             Ok(res) => {
@@ -613,7 +946,7 @@ impl NetServer {
                                             tracing::debug!(%peer, handle = ?handle_started.elapsed(), "connection served");
                                         }
                                     }
-                                });
+                                }));
                             }
                         }
                         Some(Err(e)) => {
@@ -628,6 +961,17 @@ impl NetServer {
                         tracing::error!(error = ?err, "connection task panicked");
                     }
                 }
+                Ok(()) = upgrade_completed(&mut upgrade_task) => {
+                    tracing::info!(
+                        elapsed = ?loop_started.elapsed(),
+                        tasks = join_set.len(),
+                        "handed over to new process, draining connections"
+                    );
+                    // 套接字文件已由新进程使用
+                    #[cfg(not(target_os = "windows"))]
+                    socket_files.clear();
+                    break;
+                }
                 // 测试关停注入点（非测试构建为 pending，不影响选择其他分支）
                 _ = test_shutdown_future() => {
                     tracing::info!("test shutdown notify received");
@@ -636,6 +980,22 @@ impl NetServer {
             }
         }
 
+        #[cfg(not(target_os = "windows"))]
+        super::systemd::notify_quietly("STOPPING=1");
+        if let Some(task) = upgrade_task {
+            task.abort();
+        }
+        // 关闭监听套接字，新连接只由新进程（如有）接受
+        drop(listeners);
+        // HTTP/1 长连接在当前响应后关闭，HTTP/2、HTTP/3 发送 GOAWAY
+        drain.cancel();
+        #[cfg(not(target_os = "windows"))]
+        for path in socket_files {
+            if let Err(e) = std::fs::remove_file(&path) {
+                tracing::warn!(path = ?path, error = ?e, "failed to remove Unix socket file");
+            }
+        }
+
         // 优雅关停：等待活动任务在指定时间内完成
         if shutdown.shutdown_cfg.graceful_wait > Duration::from_millis(0) {
             let graceful_started = Instant::now();
@@ -739,21 +1099,80 @@ fn self_rate_limiter(rate: Option<&RateLimiter>) -> Option<RateLimiter> {
     rate.cloned()
 }
 
-struct ShutdownHandle {
+/// 自定义关停信号
+pub(crate) type ShutdownSignal = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// 关停句柄
+///
+/// 通过 [`NetServer::shutdown_handle`] 或 [`Server::shutdown_handle`](crate::Server::shutdown_handle) 获取，
+/// 可在代码或测试中触发与 Ctrl-C/SIGTERM 相同的优雅关停流程；在服务启动前触发时，服务绑定后立即进入关停。
+///
+/// ```no_run
+/// use silent::prelude::*;
+/// use std::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut server = Server::new()
+///     .bind("127.0.0.1:8080".parse().unwrap())
+///     .with_shutdown(Duration::from_secs(10));
+/// let handle = server.shutdown_handle();
+/// tokio::spawn(async move {
+///     tokio::time::sleep(Duration::from_secs(60)).await;
+///     handle.shutdown();
+/// });
+/// server.serve(Route::new("")).await;
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ShutdownHandle {
+    token: CancellationToken,
+}
+
+impl ShutdownHandle {
+    /// 触发关停，重复调用无副作用
+    pub fn shutdown(&self) {
+        self.token.cancel();
+    }
+
+    /// 是否已触发关停
+    pub fn is_shutdown(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
+
+struct ShutdownListener {
     shutdown_callback: Option<Box<dyn Fn() + Send + Sync>>,
     shutdown_cfg: ShutdownConfig,
+    handle: ShutdownHandle,
+    custom: Option<ShutdownSignal>,
 }
 
-impl ShutdownHandle {
-    fn new(callback: Option<Box<dyn Fn() + Send + Sync>>, shutdown_cfg: ShutdownConfig) -> Self {
+impl ShutdownListener {
+    fn new(
+        callback: Option<Box<dyn Fn() + Send + Sync>>,
+        shutdown_cfg: ShutdownConfig,
+        handle: ShutdownHandle,
+        custom: Option<ShutdownSignal>,
+    ) -> Self {
         let shutdown_callback = callback;
         Self {
             shutdown_callback,
             shutdown_cfg,
+            handle,
+            custom,
         }
     }
 
     async fn signal(&mut self) {
+        // 自定义信号在循环中保留，跨多次轮询持续等待
+        let custom = async {
+            match self.custom.as_mut() {
+                Some(custom) => custom.await,
+                None => futures_util::future::pending().await,
+            }
+        };
+
         #[cfg(unix)]
         {
             let mut term =
@@ -762,6 +1181,8 @@ impl ShutdownHandle {
             tokio::select! {
                 _ = signal::ctrl_c() => (),
                 _ = term.recv() => (),
+                _ = self.handle.token.cancelled() => (),
+                _ = custom => (),
             }
         }
 
@@ -769,6 +1190,8 @@ impl ShutdownHandle {
         {
             tokio::select! {
                 _ = signal::ctrl_c() => (),
+                _ = self.handle.token.cancelled() => (),
+                _ = custom => (),
             }
         }
 
@@ -911,6 +1334,98 @@ mod tests {
         let _ = jh.await;
     }
 
+    #[tokio::test]
+    async fn test_net_server_listen_with_routes_to_service() {
+        // 只有第二个监听器收到连接，空闲的第一个监听器不应阻塞它
+        let public = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let admin = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let admin_addr = admin.local_addr().unwrap();
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let public_tx = tx.clone();
+        let server = NetServer::new()
+            .listen(crate::server::listener::Listener::from(public))
+            .listen_with(
+                crate::server::listener::Listener::from(admin),
+                move |_s: BoxedConnection, _peer: CoreSocketAddr| {
+                    let tx = tx.clone();
+                    async move {
+                        let _ = tx.send("admin");
+                        Ok::<(), BoxError>(())
+                    }
+                },
+            );
+        let jh = tokio::spawn(async move {
+            server
+                .serve(move |_s: BoxedConnection, _peer: CoreSocketAddr| {
+                    let tx = public_tx.clone();
+                    async move {
+                        let _ = tx.send("public");
+                        Ok::<(), BoxError>(())
+                    }
+                })
+                .await;
+        });
+
+        for _ in 0..3 {
+            let _stream = tokio::net::TcpStream::connect(admin_addr).await.unwrap();
+            let served = tokio::time::timeout(Duration::from_secs(1), rx.recv())
+                .await
+                .expect("connection on the second listener was not served");
+            assert_eq!(served, Some("admin"));
+        }
+        jh.abort();
+        let _ = jh.await;
+    }
+
+    #[tokio::test]
+    async fn test_net_server_bind_all_serves_ipv4() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // 未指定地址同时监听两个协议族，仅经 IPv4 连接也应得到响应
+        let mut hosts = vec!["0.0.0.0:0"];
+        if std::net::TcpListener::bind("[::1]:0").is_ok() {
+            hosts.push("[::]:0");
+        }
+        for host in hosts {
+            let (addr_tx, addr_rx) = tokio::sync::oneshot::channel();
+            let addr_tx = std::sync::Mutex::new(Some(addr_tx));
+            let server = NetServer::new()
+                .bind_all(host)
+                .unwrap()
+                .on_listen(move |addrs| {
+                    let port = addrs.iter().find_map(|addr| match addr {
+                        CoreSocketAddr::Tcp(addr) if addr.is_ipv4() => Some(addr.port()),
+                        _ => None,
+                    });
+                    if let Some(tx) = addr_tx.lock().unwrap().take() {
+                        let _ = tx.send(port);
+                    }
+                });
+            let jh = tokio::spawn(async move {
+                server
+                    .serve(|mut s: BoxedConnection, _peer: CoreSocketAddr| async move {
+                        s.write_all(b"ok").await?;
+                        Ok::<(), BoxError>(())
+                    })
+                    .await;
+            });
+
+            let port = addr_rx.await.unwrap().expect("no IPv4 listener bound");
+            let mut stream = tokio::net::TcpStream::connect(("127.0.0.1", port))
+                .await
+                .unwrap();
+            let mut buf = [0u8; 2];
+            tokio::time::timeout(Duration::from_secs(1), stream.read_exact(&mut buf))
+                .await
+                .expect("IPv4 connection was not served")
+                .unwrap();
+            assert_eq!(&buf, b"ok", "bind_all({host})");
+            jh.abort();
+            let _ = jh.await;
+        }
+    }
+
     struct TestErrListener {
         addr: std::net::SocketAddr,
         sent_err: Arc<AtomicBool>,
@@ -1144,7 +1659,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_net_server_multi_listeners_race() {
-        // 快慢两个 listener，优先处理较快的连接一次
+        // 快慢两个 listener 同时 accept：较快的连接先处理，较慢的连接不会丢失
         let (_a1, b1) = tokio::io::duplex(8);
         let boxed1: BoxedConnection = Box::new(b1);
         let (_a2, b2) = tokio::io::duplex(8);
@@ -1166,11 +1681,17 @@ mod tests {
 
         let server = NetServer::new().listen(fast).listen(slow);
         let jh = tokio::spawn(async move { server.serve(handler).await });
-        tokio::time::sleep(Duration::from_millis(80)).await;
+        tokio::time::sleep(Duration::from_millis(25)).await;
         assert_eq!(
             calls.load(Ordering::SeqCst),
             1,
-            "only fast listener's connection handled"
+            "fast listener's connection handled first"
+        );
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            2,
+            "slow listener's connection is not dropped"
         );
         jh.abort();
         let _ = jh.await;
@@ -0,0 +1,439 @@
+//! HTTP/1.1 `Upgrade: h2c` 升级
+//!
+//! hyper 只支持 prior knowledge 方式的 h2c，这里在 HTTP/1.1 层处理升级请求：
+//! 返回 `101 Switching Protocols` 后，将升级请求编码为流 1 上的 HEADERS 帧，
+//! 插入到客户端连接前言与首个 SETTINGS 帧之后，再交给 hyper 的 HTTP/2 服务端处理，
+//! 升级请求的响应即在流 1 上返回。
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{Buf, Bytes};
+use http::header::{CONNECTION, CONTENT_LENGTH, HOST, TE, TRANSFER_ENCODING, UPGRADE};
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode, Version};
+use hyper::{Request as HyperRequest, Response as HyperResponse};
+use hyper_util::rt::TokioIo;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, ReadBuf};
+use tracing::debug;
+
+use super::HyperServiceHandler;
+use crate::Handler;
+use crate::core::req_body::ReqBody;
+use crate::core::res_body::ResBody;
+use crate::server::config::{ConnectionLimits, Http2Config};
+
+const PREFACE: &[u8; 24] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+const FRAME_HEADER_LEN: usize = 9;
+/// HTTP/2 默认的最大帧长度，在收到对端 SETTINGS 之前双方都须遵守
+const MAX_FRAME_SIZE: usize = 16_384;
+const FRAME_HEADERS: u8 = 0x1;
+const FRAME_SETTINGS: u8 = 0x4;
+const FRAME_CONTINUATION: u8 = 0x9;
+const FLAG_END_STREAM: u8 = 0x1;
+const FLAG_END_HEADERS: u8 = 0x4;
+
+static HTTP2_SETTINGS: HeaderName = HeaderName::from_static("http2-settings");
+
+/// 是否为可升级的 h2c 请求
+///
+/// 携带消息体的升级请求按 HTTP/1.1 处理（RFC 允许服务端忽略升级）。
+pub(crate) fn is_upgrade_request<B>(req: &HyperRequest<B>) -> bool {
+    let headers = req.headers();
+    req.version() == Version::HTTP_11
+        && has_token(headers, &UPGRADE, "h2c")
+        && has_token(headers, &CONNECTION, "upgrade")
+        && has_token(headers, &CONNECTION, "http2-settings")
+        && headers.get_all(&HTTP2_SETTINGS).iter().count() == 1
+        && !headers.contains_key(TRANSFER_ENCODING)
+        && headers.get(CONTENT_LENGTH).is_none_or(|len| len == "0")
+}
+
+/// 接受升级并在升级后的连接上提供 HTTP/2 服务，返回 `101` 响应
+pub(crate) fn upgrade<B, H>(
+    mut req: HyperRequest<B>,
+    service: HyperServiceHandler<H>,
+    http2: &Http2Config,
+) -> HyperResponse<ResBody>
+where
+    B: Into<ReqBody>,
+    H: Handler + Clone,
+{
+    let headers = headers_frames(&header_block(&req));
+    let on_upgrade = hyper::upgrade::on(&mut req);
+    let limits = ConnectionLimits {
+        max_header_bytes: service.max_header_bytes,
+        max_header_count: service.max_header_count,
+        ..Default::default()
+    };
+    let builder = super::connection_builder(http2, &limits).http2_only();
+    tokio::spawn(async move {
+        let upgraded = match on_upgrade.await {
+            Ok(upgraded) => upgraded,
+            Err(e) => {
+                debug!(error = ?e, "h2c upgrade failed");
+                return;
+            }
+        };
+        let mut io = TokioIo::new(upgraded);
+        let mut prefix = match read_client_preface(&mut io).await {
+            Ok(prefix) => prefix,
+            Err(e) => {
+                debug!(error = ?e, "invalid h2c client preface");
+                return;
+            }
+        };
+        prefix.extend_from_slice(&headers);
+        let io = Rewind {
+            prefix: Bytes::from(prefix),
+            inner: io,
+        };
+        if let Err(e) = builder
+            .serve_connection(TokioIo::new(io), service.with_h2c_upgrade(None))
+            .await
+        {
+            debug!(error = ?e, "h2c connection error");
+        }
+    });
+
+    let mut res = HyperResponse::new(ResBody::None);
+    *res.status_mut() = StatusCode::SWITCHING_PROTOCOLS;
+    res.headers_mut()
+        .insert(CONNECTION, HeaderValue::from_static("Upgrade"));
+    res.headers_mut()
+        .insert(UPGRADE, HeaderValue::from_static("h2c"));
+    res
+}
+
+fn has_token(headers: &HeaderMap, name: &HeaderName, token: &str) -> bool {
+    headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|item| item.trim().eq_ignore_ascii_case(token))
+}
+
+/// 将升级请求编码为 HPACK 头部块
+///
+/// 仅使用不索引的字面量表示，不改变动态表，后续请求的 HPACK 状态不受影响。
+fn header_block<B>(req: &HyperRequest<B>) -> Vec<u8> {
+    let mut block = Vec::new();
+    let path = req.uri().path_and_query().map_or("/", |p| p.as_str());
+    let authority = req
+        .uri()
+        .authority()
+        .map(|authority| authority.as_str().as_bytes())
+        .or_else(|| req.headers().get(HOST).map(HeaderValue::as_bytes));
+    encode_field(&mut block, b":method", req.method().as_str().as_bytes());
+    encode_field(&mut block, b":scheme", b"http");
+    if let Some(authority) = authority {
+        encode_field(&mut block, b":authority", authority);
+    }
+    encode_field(&mut block, b":path", path.as_bytes());
+    for (name, value) in req.headers() {
+        // HTTP/2 禁止连接级头部，TE 仅允许 trailers
+        let skip = matches!(
+            name.as_str(),
+            "connection"
+                | "upgrade"
+                | "http2-settings"
+                | "keep-alive"
+                | "proxy-connection"
+                | "transfer-encoding"
+                | "host"
+        ) || (name == TE && value != "trailers");
+        if !skip {
+            encode_field(&mut block, name.as_str().as_bytes(), value.as_bytes());
+        }
+    }
+    block
+}
+
+fn encode_field(block: &mut Vec<u8>, name: &[u8], value: &[u8]) {
+    // 0x00: 不索引的字面量，新名称
+    block.push(0x00);
+    encode_string(block, name);
+    encode_string(block, value);
+}
+
+fn encode_string(block: &mut Vec<u8>, value: &[u8]) {
+    // 7 位前缀整数表示长度，不使用 Huffman 编码
+    let len = value.len();
+    if len < 0x7f {
+        block.push(len as u8);
+    } else {
+        block.push(0x7f);
+        let mut rest = len - 0x7f;
+        while rest >= 0x80 {
+            block.push((rest & 0x7f) as u8 | 0x80);
+            rest >>= 7;
+        }
+        block.push(rest as u8);
+    }
+    block.extend_from_slice(value);
+}
+
+/// 流 1 上的 HEADERS 帧，超出最大帧长度时拆分为 CONTINUATION 帧
+fn headers_frames(block: &[u8]) -> Vec<u8> {
+    let chunks: Vec<&[u8]> = block.chunks(MAX_FRAME_SIZE).collect();
+    let mut frames = Vec::with_capacity(block.len() + chunks.len() * FRAME_HEADER_LEN);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let (kind, mut flags) = if i == 0 {
+            (FRAME_HEADERS, FLAG_END_STREAM)
+        } else {
+            (FRAME_CONTINUATION, 0)
+        };
+        if i + 1 == chunks.len() {
+            flags |= FLAG_END_HEADERS;
+        }
+        frames.extend_from_slice(&(chunk.len() as u32).to_be_bytes()[1..]);
+        frames.push(kind);
+        frames.push(flags);
+        frames.extend_from_slice(&1u32.to_be_bytes());
+        frames.extend_from_slice(chunk);
+    }
+    frames
+}
+
+/// 读取客户端连接前言及其后的 SETTINGS 帧
+async fn read_client_preface<T: AsyncRead + Unpin>(io: &mut T) -> io::Result<Vec<u8>> {
+    let mut buf = vec![0u8; PREFACE.len() + FRAME_HEADER_LEN];
+    io.read_exact(&mut buf).await?;
+    let header = &buf[PREFACE.len()..];
+    if &buf[..PREFACE.len()] != PREFACE || header[3] != FRAME_SETTINGS {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "expected HTTP/2 connection preface",
+        ));
+    }
+    let len = u32::from_be_bytes([0, header[0], header[1], header[2]]) as usize;
+    if len > MAX_FRAME_SIZE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "SETTINGS frame too large",
+        ));
+    }
+    let start = buf.len();
+    buf.resize(start + len, 0);
+    io.read_exact(&mut buf[start..]).await?;
+    Ok(buf)
+}
+
+/// 先读出预置数据，再读取底层连接
+struct Rewind<T> {
+    prefix: Bytes,
+    inner: T,
+}
+
+impl<T: AsyncRead + Unpin> AsyncRead for Rewind<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if self.prefix.is_empty() {
+            return Pin::new(&mut self.inner).poll_read(cx, buf);
+        }
+        let n = self.prefix.len().min(buf.remaining());
+        buf.put_slice(&self.prefix[..n]);
+        self.prefix.advance(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T: AsyncWrite + Unpin> AsyncWrite for Rewind<T> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HttpProtocols;
+    use crate::Request;
+    use crate::route::Route;
+    use crate::server::connection::HttpConnection;
+    use crate::server::connection_service::ConnectionService;
+    use crate::server::route_connection::RouteConnectionService;
+    use http_body_util::BodyExt;
+    use hyper_util::rt::TokioExecutor;
+    use tokio::io::AsyncWriteExt;
+
+    /// 启动只接受一个连接的服务端
+    async fn serve(protocols: HttpProtocols) -> std::net::SocketAddr {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let route = Route::new("hello")
+            .get(|req: Request| async move { Ok(format!("{:?}", req.version())) });
+        tokio::spawn(async move {
+            let (stream, peer) = listener.accept().await.unwrap();
+            let conn = HttpConnection {
+                inner: Box::new(stream),
+                protocols,
+            };
+            let _ = RouteConnectionService::new(route)
+                .call(Box::new(conn), peer.into())
+                .await;
+        });
+        addr
+    }
+
+    async fn read_frame(stream: &mut tokio::net::TcpStream) -> (u8, u8, u32, Vec<u8>) {
+        let mut header = [0u8; FRAME_HEADER_LEN];
+        stream.read_exact(&mut header).await.unwrap();
+        let len = u32::from_be_bytes([0, header[0], header[1], header[2]]) as usize;
+        let stream_id = u32::from_be_bytes([header[5], header[6], header[7], header[8]]);
+        let mut payload = vec![0u8; len];
+        stream.read_exact(&mut payload).await.unwrap();
+        (header[3], header[4], stream_id, payload)
+    }
+
+    #[test]
+    fn test_is_upgrade_request() {
+        let request = |connection: &str, content_length: Option<&str>| {
+            let mut builder = HyperRequest::builder()
+                .uri("/")
+                .header(UPGRADE, "h2c")
+                .header(CONNECTION, connection)
+                .header(&HTTP2_SETTINGS, "AAMAAABkAAQAoAAAAAIAAAAA");
+            if let Some(len) = content_length {
+                builder = builder.header(CONTENT_LENGTH, len);
+            }
+            builder.body(()).unwrap()
+        };
+        assert!(is_upgrade_request(&request(
+            "Upgrade, HTTP2-Settings",
+            None
+        )));
+        assert!(is_upgrade_request(&request(
+            "upgrade,http2-settings",
+            Some("0")
+        )));
+        assert!(!is_upgrade_request(&request("Upgrade", None)));
+        assert!(!is_upgrade_request(&request(
+            "Upgrade, HTTP2-Settings",
+            Some("5")
+        )));
+    }
+
+    #[test]
+    fn test_headers_frames_continuation() {
+        let block = vec![0u8; MAX_FRAME_SIZE + 10];
+        let frames = headers_frames(&block);
+        assert_eq!(frames.len(), block.len() + 2 * FRAME_HEADER_LEN);
+        assert_eq!(frames[3], FRAME_HEADERS);
+        assert_eq!(frames[4], FLAG_END_STREAM);
+        let second = &frames[FRAME_HEADER_LEN + MAX_FRAME_SIZE..];
+        assert_eq!(&second[..3], &[0, 0, 10]);
+        assert_eq!(second[3], FRAME_CONTINUATION);
+        assert_eq!(second[4], FLAG_END_HEADERS);
+    }
+
+    #[tokio::test]
+    async fn test_h2c_upgrade() {
+        let addr = serve(HttpProtocols::H2c).await;
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(
+                b"GET /hello HTTP/1.1\r\nHost: localhost\r\nConnection: Upgrade, HTTP2-Settings\r\n\
+                  Upgrade: h2c\r\nHTTP2-Settings: AAMAAABkAAQAoAAAAAIAAAAA\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        let mut response = Vec::new();
+        while !response.ends_with(b"\r\n\r\n") {
+            response.push(stream.read_u8().await.unwrap());
+        }
+        let response = String::from_utf8(response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 101"), "{response}");
+        assert!(response.to_ascii_lowercase().contains("upgrade: h2c"));
+
+        // 客户端连接前言与空 SETTINGS 帧
+        stream.write_all(PREFACE).await.unwrap();
+        stream
+            .write_all(&[0, 0, 0, FRAME_SETTINGS, 0, 0, 0, 0, 0])
+            .await
+            .unwrap();
+
+        let mut status = None;
+        let mut body = Vec::new();
+        loop {
+            let (kind, flags, stream_id, payload) = read_frame(&mut stream).await;
+            if stream_id != 1 {
+                continue;
+            }
+            match kind {
+                // 0x88: 静态表索引 8，即 `:status: 200`
+                FRAME_HEADERS => status = Some(payload[0]),
+                0x0 => body.extend_from_slice(&payload),
+                _ => {}
+            }
+            if flags & FLAG_END_STREAM != 0 {
+                break;
+            }
+        }
+        assert_eq!(status, Some(0x88));
+        assert_eq!(body, b"HTTP/2.0");
+    }
+
+    #[tokio::test]
+    async fn test_h2c_upgrade_disabled_by_default() {
+        let addr = serve(HttpProtocols::Auto).await;
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(
+                b"GET /hello HTTP/1.1\r\nHost: localhost\r\nConnection: Upgrade, HTTP2-Settings\r\n\
+                  Upgrade: h2c\r\nHTTP2-Settings: AAMAAABkAAQAoAAAAAIAAAAA\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        let mut response = [0u8; 12];
+        stream.read_exact(&mut response).await.unwrap();
+        assert_eq!(&response, b"HTTP/1.1 200");
+    }
+
+    #[tokio::test]
+    async fn test_h2c_prior_knowledge() {
+        let addr = serve(HttpProtocols::Http2Only).await;
+        let stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let (mut sender, conn) =
+            hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(stream))
+                .await
+                .unwrap();
+        tokio::spawn(conn);
+        let req = HyperRequest::builder()
+            .uri("http://localhost/hello")
+            .body(http_body_util::Empty::<Bytes>::new())
+            .unwrap();
+        let res = sender.send_request(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = res.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"HTTP/2.0");
+    }
+}
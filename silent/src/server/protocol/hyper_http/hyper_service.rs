@@ -1,6 +1,7 @@
 use std::future::Future;
 use std::pin::Pin;
 
+use http::{HeaderMap, StatusCode};
 use hyper::service::Service as HyperService;
 use hyper::{Request as HyperRequest, Response as HyperResponse};
 #[cfg(feature = "upgrade")]
@@ -10,6 +11,7 @@ use tracing::{Instrument, debug, info_span};
 use crate::core::remote_addr::RemoteAddr;
 use crate::core::res_body::ResBody;
 use crate::prelude::ReqBody;
+use crate::server::config::Http2Config;
 use crate::server::protocol::Protocol;
 use crate::server::protocol::hyper_http::HyperHttpProtocol;
 use crate::{Handler, Request, Response};
@@ -20,6 +22,10 @@ pub struct HyperServiceHandler<H: Handler> {
     pub(crate) remote_addr: RemoteAddr,
     pub(crate) routes: H,
     pub(crate) max_body_size: Option<usize>,
+    pub(crate) max_header_bytes: Option<usize>,
+    pub(crate) max_header_count: Option<usize>,
+    /// 接受 `Upgrade: h2c` 时升级后连接使用的 HTTP/2 参数，`None` 表示不接受升级
+    pub(crate) h2c_upgrade: Option<Http2Config>,
 }
 
 impl<H: Handler + Clone> HyperServiceHandler<H> {
@@ -29,6 +35,9 @@ impl<H: Handler + Clone> HyperServiceHandler<H> {
             remote_addr,
             routes,
             max_body_size: None,
+            max_header_bytes: None,
+            max_header_count: None,
+            h2c_upgrade: None,
         }
     }
 
@@ -38,8 +47,45 @@ impl<H: Handler + Clone> HyperServiceHandler<H> {
             remote_addr,
             routes,
             max_body_size,
+            max_header_bytes: None,
+            max_header_count: None,
+            h2c_upgrade: None,
         }
     }
+
+    /// 请求头总大小与数量上限，超出时返回 `431`
+    #[inline]
+    pub(crate) fn with_header_limits(
+        mut self,
+        max_header_bytes: Option<usize>,
+        max_header_count: Option<usize>,
+    ) -> Self {
+        self.max_header_bytes = max_header_bytes;
+        self.max_header_count = max_header_count;
+        self
+    }
+
+    /// 请求头是否超出限制
+    ///
+    /// HTTP/1 的限制与 HTTP/2 的头部数量均在此校验，HTTP/2 的头部大小另由 h2 在解码时限制。
+    fn headers_too_large(&self, headers: &HeaderMap) -> bool {
+        self.max_header_count.is_some_and(|max| headers.len() > max)
+            || self.max_header_bytes.is_some_and(|max| {
+                headers
+                    .iter()
+                    .map(|(name, value)| name.as_str().len() + value.len())
+                    .sum::<usize>()
+                    > max
+            })
+    }
+
+    /// 接受 HTTP/1.1 `Upgrade: h2c` 升级，升级后的连接按 `http2` 参数处理
+    #[inline]
+    pub(crate) fn with_h2c_upgrade(mut self, http2: Option<Http2Config>) -> Self {
+        self.h2c_upgrade = http2;
+        self
+    }
+
     #[inline]
     pub fn handle(&self, mut req: Request) -> impl Future<Output = Response> + use<H> {
         let remote_addr = self.remote_addr.clone();
@@ -59,6 +105,20 @@ where
 
     #[inline]
     fn call(&self, req: HyperRequest<B>) -> Self::Future {
+        if self.headers_too_large(req.headers()) {
+            debug!(peer = %self.remote_addr, "request header fields too large");
+            let mut res = Response::empty();
+            res.set_status(StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE);
+            return Box::pin(std::future::ready(Ok(HyperHttpProtocol::from_internal(
+                res,
+            ))));
+        }
+        if let Some(http2) = self.h2c_upgrade
+            && super::h2c::is_upgrade_request(&req)
+        {
+            let res = super::h2c::upgrade(req, self.clone(), &http2);
+            return Box::pin(std::future::ready(Ok(res)));
+        }
         #[cfg(feature = "upgrade")]
         let (mut parts, body) = req.into_parts();
         #[cfg(not(feature = "upgrade"))]
@@ -128,4 +188,37 @@ mod tests {
         let req = hyper::Request::builder().body(()).unwrap();
         let _ = svc.call(req).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_hyper_service_handler_header_limits() {
+        let remote_addr: RemoteAddr = "127.0.0.1:0"
+            .parse::<std::net::SocketAddr>()
+            .unwrap()
+            .into();
+        let routes =
+            Route::new_root().append(Route::new("").get(|_req: Request| async { Ok("ok") }));
+        let request = |count: usize| {
+            let mut builder = hyper::Request::builder().uri("/");
+            for i in 0..count {
+                builder = builder.header(format!("x-h{i}"), "value");
+            }
+            builder.body(()).unwrap()
+        };
+
+        let svc = HyperServiceHandler::new(remote_addr.clone(), routes.clone())
+            .with_header_limits(None, Some(3));
+        assert_eq!(svc.call(request(3)).await.unwrap().status(), StatusCode::OK);
+        assert_eq!(
+            svc.call(request(4)).await.unwrap().status(),
+            StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE
+        );
+
+        // 每个头部 "x-hN" + "value" 共 9 字节
+        let svc = HyperServiceHandler::new(remote_addr, routes).with_header_limits(Some(18), None);
+        assert_eq!(svc.call(request(2)).await.unwrap().status(), StatusCode::OK);
+        assert_eq!(
+            svc.call(request(3)).await.unwrap().status(),
+            StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE
+        );
+    }
 }
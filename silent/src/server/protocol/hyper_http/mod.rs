@@ -90,6 +90,59 @@ fn get_cookie(req: &HyperRequest<ReqBody>) -> Result<CookieJar, SilentError> {
     Ok(jar)
 }
 
+mod h2c;
+
+/// hyper HTTP/1 默认的头部数量上限
+const DEFAULT_HTTP1_MAX_HEADERS: usize = 100;
+/// hyper HTTP/1 默认的读缓冲区上限
+const DEFAULT_HTTP1_MAX_BUF_SIZE: usize = 8192 + 4096 * 100;
+
+/// 按 HTTP/2 参数与请求头限制创建连接构建器
+///
+/// 开启 pipeline flush 后，hyper 的 HTTP/1 解析器超出上限时直接断开连接而不返回错误响应，
+/// 因此解析上限取配置值的两倍（不低于 hyper 默认值），超出配置值的请求由服务层返回 `431`，
+/// 远超配置值的请求在解析阶段即被断开；HTTP/2 的头部大小在 h2 层限制，超出时同样返回 `431`。
+pub(crate) fn connection_builder(
+    http2: &crate::server::config::Http2Config,
+    limits: &crate::server::config::ConnectionLimits,
+) -> hyper_util::server::conn::auto::Builder<hyper_util::rt::TokioExecutor> {
+    use hyper_util::rt::{TokioExecutor, TokioTimer};
+
+    let mut builder = hyper_util::server::conn::auto::Builder::new(TokioExecutor::new());
+    // HTTP/1.1 调优：开启 pipeline flush，减少响应延迟
+    let mut h1 = builder.http1();
+    h1.pipeline_flush(true);
+    if let Some(max) = limits.max_header_count {
+        h1.max_headers(max.saturating_mul(2).max(DEFAULT_HTTP1_MAX_HEADERS));
+    }
+    if let Some(max) = limits.max_header_bytes {
+        h1.max_buf_size(max.saturating_mul(2).max(DEFAULT_HTTP1_MAX_BUF_SIZE));
+    }
+    let mut h2 = builder.http2();
+    h2.timer(TokioTimer::new())
+        .adaptive_window(http2.adaptive_window)
+        .keep_alive_interval(http2.keep_alive_interval);
+    // 未设置的项不调用 hyper 的设置方法，保留其默认值（向 max_concurrent_streams 传入 None 会取消限制）
+    if let Some(size) = http2.initial_stream_window_size {
+        h2.initial_stream_window_size(size);
+    }
+    if let Some(size) = http2.initial_connection_window_size {
+        h2.initial_connection_window_size(size);
+    }
+    if let Some(max) = http2.max_concurrent_streams {
+        h2.max_concurrent_streams(max);
+    }
+    if let Some(size) = http2.max_frame_size {
+        h2.max_frame_size(size);
+    }
+    if let Some(timeout) = http2.keep_alive_timeout {
+        h2.keep_alive_timeout(timeout);
+    }
+    if let Some(max) = limits.max_header_bytes {
+        h2.max_header_list_size(u32::try_from(max).unwrap_or(u32::MAX));
+    }
+    builder
+}
 // 对 hyper 服务适配器进行子模块归档，并在此 re-export 方便上层引用
 pub mod hyper_service;
 pub use hyper_service::HyperServiceHandler;
@@ -159,7 +159,7 @@ impl Listen for QuicEndpointListener {
         Box::pin(async move {
             match self.endpoint.accept().await {
                 Some(incoming) => {
-                    let remote = crate::SocketAddr::from(incoming.remote_address());
+                    let remote = crate::SocketAddr::from(incoming.remote_address()).tls()?;
                     let connection: BoxedConnection =
                         Box::new(super::connection::QuicConnection::new(incoming));
                     Ok((connection, remote))
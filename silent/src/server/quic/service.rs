@@ -8,6 +8,7 @@ use crate::server::metrics::{
     record_http3_response_size, record_webtransport_accept, record_webtransport_error,
     record_webtransport_handshake_duration, record_webtransport_session_duration,
 };
+use crate::server::net_server::drain_signal;
 use crate::server::protocol::Protocol as _;
 use crate::server::protocol::hyper_http::HyperHttpProtocol;
 use crate::{Handler, Request};
@@ -56,8 +57,28 @@ pub(crate) async fn handle_quic_connection(
         .await
         .context("构建 HTTP/3 连接失败")?;
 
+    // 服务优雅关停时发送 GOAWAY，已接受的请求继续处理，客户端在新连接上发起后续请求
+    let drain = drain_signal();
+    let mut draining = false;
     loop {
-        match h3_conn.accept().await {
+        let accepted = tokio::select! {
+            accepted = h3_conn.accept() => Some(accepted),
+            _ = async {
+                match &drain {
+                    Some(drain) => drain.cancelled().await,
+                    None => std::future::pending().await,
+                }
+            }, if !draining => None,
+        };
+        let Some(accepted) = accepted else {
+            draining = true;
+            if let Err(err) = h3_conn.shutdown(0).await {
+                warn!(%remote, error = ?err, "发送 HTTP/3 GOAWAY 失败");
+                break;
+            }
+            continue;
+        };
+        match accepted {
             Ok(Some(resolver)) => {
                 let routes = Arc::clone(&routes);
                 let handler = Arc::clone(&handler);
@@ -273,7 +294,7 @@ async fn handle_http3_request_impl<T: H3RequestIo + Send + 'static>(
     let (parts, _) = request.into_parts();
     let mut silent_req =
         Request::from_parts(parts, crate::prelude::ReqBody::from_stream(body_stream));
-    silent_req.set_remote(remote.into());
+    silent_req.set_remote(crate::core::socket_addr::SocketAddr::TlsTcp(remote).into());
 
     #[cfg(feature = "metrics")]
     let handle_started = Instant::now();
@@ -0,0 +1,204 @@
+//! 可热替换的路由表
+
+use std::sync::{Arc, Mutex};
+
+use arc_swap::ArcSwapOption;
+use async_trait::async_trait;
+
+use crate::core::socket_addr::SocketAddr as CoreSocketAddr;
+use crate::error::SilentResult;
+use crate::route::{Route, RouteTree};
+use crate::server::config::global_server_config;
+use crate::server::connection::BoxedConnection;
+use crate::server::connection_service::{ConnectionFuture, ConnectionService};
+use crate::server::route_connection::RouteConnectionService;
+use crate::{Handler, Request, Response};
+
+/// 可在运行时替换的路由表句柄
+///
+/// 每个请求开始时读取当前路由树，[`try_replace`](Self::try_replace) 之后到达的请求使用新路由，
+/// 已在处理中的请求不受影响，适合插件化或由配置驱动路由的部署。句柄可任意克隆，所有克隆共享同一份路由表。
+///
+/// 首次处理请求时才构建路由树，因此 `Server::with_state` 注入的状态同样生效；
+/// HTTP/3 连接在建立时读取路由树，替换后需新建连接才会生效。
+///
+/// ```rust,no_run
+/// use silent::prelude::*;
+/// use silent::ReloadableRoute;
+///
+/// let routes = ReloadableRoute::new(Route::new("").get(|_req: Request| async { Ok("v1") }));
+/// let handle = routes.clone();
+/// std::thread::spawn(move || {
+///     std::thread::sleep(std::time::Duration::from_secs(60));
+///     if let Err(e) = handle.try_replace(Route::new("").get(|_req: Request| async { Ok("v2") })) {
+///         eprintln!("路由表未替换：{e}");
+///     }
+/// });
+/// Server::new().run(routes);
+/// ```
+#[derive(Clone)]
+pub struct ReloadableRoute {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    route: Mutex<Route>,
+    tree: ArcSwapOption<RouteTree>,
+}
+
+impl ReloadableRoute {
+    /// 以初始路由创建句柄
+    pub fn new(route: Route) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                route: Mutex::new(route),
+                tree: ArcSwapOption::empty(),
+            }),
+        }
+    }
+
+    /// 替换路由表
+    ///
+    /// 立即构建新的路由树校验路由冲突，服务运行中时原子替换当前路由树。
+    /// 构建失败时返回错误，旧路由表保持不变。
+    pub fn try_replace(&self, route: Route) -> SilentResult<()> {
+        let mut current = self.inner.route.lock().unwrap_or_else(|e| e.into_inner());
+        let tree = RouteConnectionService::try_build_route_tree(&route)?;
+        if self.inner.tree.load().is_some() {
+            self.inner.tree.store(Some(Arc::new(tree)));
+        }
+        *current = route;
+        Ok(())
+    }
+
+    /// 替换路由表，构建失败时 panic，旧路由表保持不变
+    ///
+    /// 运行中的服务应使用 [`try_replace`](Self::try_replace) 处理路由冲突。
+    pub fn replace(&self, route: Route) {
+        if let Err(e) = self.try_replace(route) {
+            panic!("{}", e.message());
+        }
+    }
+
+    /// 当前生效的路由表
+    pub fn route(&self) -> Route {
+        self.inner
+            .route
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 当前生效的路由树，尚未构建时立即构建
+    pub(crate) fn current(&self) -> Arc<RouteTree> {
+        if let Some(tree) = self.inner.tree.load_full() {
+            return tree;
+        }
+        let route = self.inner.route.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(tree) = self.inner.tree.load_full() {
+            return tree;
+        }
+        let tree = RouteConnectionService::try_build_route_tree(&route)
+            .unwrap_or_else(|e| panic!("{}", e.message()));
+        let tree = Arc::new(tree);
+        self.inner.tree.store(Some(Arc::clone(&tree)));
+        tree
+    }
+}
+
+impl From<Route> for ReloadableRoute {
+    fn from(route: Route) -> Self {
+        Self::new(route)
+    }
+}
+
+#[async_trait]
+impl Handler for ReloadableRoute {
+    async fn call(&self, req: Request) -> crate::error::SilentResult<Response> {
+        self.current().call(req).await
+    }
+}
+
+impl ConnectionService for ReloadableRoute {
+    fn call(&self, stream: BoxedConnection, peer: CoreSocketAddr) -> ConnectionFuture {
+        // 直接使用句柄中的路由树快照，无需复制路由表
+        let (limits, http2) = {
+            let config = global_server_config();
+            (config.connection_limits.clone(), config.http2)
+        };
+        #[cfg(feature = "quic")]
+        let webtransport_handler: Arc<dyn crate::server::quic::WebTransportHandler> =
+            Arc::new(crate::server::quic::EchoHandler);
+        RouteConnectionService::dispatch(
+            self,
+            &limits,
+            http2,
+            #[cfg(feature = "quic")]
+            &webtransport_handler,
+            stream,
+            peer,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+
+    async fn body(routes: &ReloadableRoute, path: &str) -> (http::StatusCode, String) {
+        let mut req = Request::empty();
+        *req.uri_mut() = path.parse().unwrap();
+        let res = Handler::call(routes, req).await.unwrap_or_else(Into::into);
+        let status = res.status();
+        let body = res.body.collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_reloadable_route_replace() {
+        let routes = ReloadableRoute::new(Route::new("v1").get(|_req: Request| async { Ok("v1") }));
+        let handle = routes.clone();
+
+        // 首次请求前替换，仅替换待构建的路由
+        handle.replace(Route::new("v1").get(|_req: Request| async { Ok("first") }));
+        assert_eq!(body(&routes, "/v1").await.1, "first");
+
+        handle.replace(Route::new("v2").get(|_req: Request| async { Ok("second") }));
+        assert_eq!(body(&routes, "/v1").await.0, http::StatusCode::NOT_FOUND);
+        assert_eq!(body(&routes, "/v2").await.1, "second");
+        assert_eq!(routes.route().path, "v2");
+    }
+
+    #[tokio::test]
+    async fn test_reloadable_route_keeps_old_route_on_conflict() {
+        async fn ok(_req: Request) -> crate::Result<&'static str> {
+            Ok("ok")
+        }
+
+        let routes = ReloadableRoute::new(Route::new("").append(Route::new("users").get(ok)));
+        assert_eq!(body(&routes, "/users").await.1, "ok");
+
+        let err = routes
+            .try_replace(
+                Route::new("")
+                    .append(Route::new("<id:int>").get(ok))
+                    .append(Route::new("<id:u64>").get(ok)),
+            )
+            .unwrap_err();
+        assert!(err.message().contains("路由冲突"));
+        assert_eq!(body(&routes, "/users").await.1, "ok");
+
+        let handle = routes.clone();
+        let replaced = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            handle.replace(
+                Route::new("")
+                    .append(Route::new("<id:int>").get(ok))
+                    .append(Route::new("<id:u64>").get(ok)),
+            )
+        }));
+        assert!(replaced.is_err());
+        assert_eq!(body(&routes, "/users").await.1, "ok");
+        assert_eq!(routes.route().children[0].path, "users");
+    }
+}
@@ -5,15 +5,19 @@
 //! 而网络连接处理通过适配器模式实现。
 
 use crate::core::socket_addr::SocketAddr as CoreSocketAddr;
+use crate::error::SilentResult;
 use crate::route::{Route, RouteTree};
 #[cfg(feature = "scheduler")]
 use crate::scheduler::middleware::SchedulerMiddleware;
-use crate::server::config::{ConnectionLimits, global_server_config};
-use crate::server::connection::BoxedConnection;
+use crate::server::config::{ConnectionLimits, Http2Config, global_server_config};
+use crate::server::connection::{BoxedConnection, HttpConnection};
 use crate::server::connection_service::{ConnectionFuture, ConnectionService};
-use crate::server::protocol::hyper_http::HyperServiceHandler;
-use hyper_util::rt::{TokioExecutor, TokioIo};
-use hyper_util::server::conn::auto::Builder;
+use crate::server::listener::HttpProtocols;
+use crate::server::net_server::drain_signal;
+use crate::server::protocol::hyper_http::{HyperServiceHandler, connection_builder};
+use crate::server::reloadable_route::ReloadableRoute;
+use hyper_util::rt::TokioIo;
+#[cfg(feature = "quic")]
 use std::sync::Arc;
 
 /// RouteConnectionService 适配器
@@ -38,9 +42,10 @@ use std::sync::Arc;
 pub struct RouteConnectionService {
     #[allow(dead_code)] // 保留用于测试和调试
     route: Route,
-    /// 预构建的冻结路由树，所有连接共享同一份，避免每连接重建
-    frozen_tree: Arc<RouteTree>,
+    /// 预构建的冻结路由树，所有连接共享同一份，避免每连接重建；每个请求读取当前版本以支持热替换
+    routes: ReloadableRoute,
     limits: ConnectionLimits,
+    http2: Http2Config,
     #[cfg(feature = "quic")]
     webtransport_handler: Arc<dyn crate::server::quic::WebTransportHandler>,
 }
@@ -49,32 +54,49 @@ impl RouteConnectionService {
     /// 创建新的 RouteConnectionService 实例
     #[inline]
     pub fn new(route: Route) -> Self {
-        let limits = global_server_config().connection_limits.clone();
         // 启动时一次性构建冻结路由树，后续所有连接共享
-        let frozen_tree = Arc::new(Self::build_route_tree(&route));
+        let routes = ReloadableRoute::new(route.clone());
+        routes.current();
+        let (limits, http2) = {
+            let config = global_server_config();
+            (config.connection_limits.clone(), config.http2)
+        };
         #[cfg(feature = "quic")]
         let webtransport_handler: Arc<dyn crate::server::quic::WebTransportHandler> =
             Arc::new(crate::server::quic::EchoHandler);
         Self {
             route,
-            frozen_tree,
+            routes,
             limits,
+            http2,
             #[cfg(feature = "quic")]
             webtransport_handler,
         }
     }
 
-    /// 构建冻结路由树（包含 session/cookie/scheduler 检查）
-    fn build_route_tree(route: &Route) -> RouteTree {
-        #[allow(unused_mut)]
+    /// 构建冻结路由树（包含 server 状态注入与 session/cookie/scheduler 检查），路由冲突时返回错误
+    pub(crate) fn try_build_route_tree(route: &Route) -> SilentResult<RouteTree> {
         let mut route = route.clone();
+        Self::inject_server_state(&mut route, &global_server_config().state);
         #[cfg(feature = "session")]
         route.check_session();
         #[cfg(feature = "cookie")]
         route.check_cookie();
         #[cfg(feature = "scheduler")]
         route.hook_first(SchedulerMiddleware::new());
-        route.convert_to_route_tree()
+        route.try_convert_to_route_tree()
+    }
+
+    /// 将 `Server::with_state` 注入的共享状态合并到根路由，根路由自身的同类型状态优先。
+    fn inject_server_state(route: &mut Route, server_state: &crate::State) {
+        if server_state.is_empty() {
+            return;
+        }
+        let mut state = server_state.clone();
+        if let Some(route_state) = route.get_state() {
+            state.extend_from(route_state);
+        }
+        route.set_state(Some(state));
     }
 
     /// 为 WebTransport 提供自定义处理器，替代默认的 EchoHandler。
@@ -87,64 +109,39 @@ impl RouteConnectionService {
         self
     }
 
-    /// 处理 HTTP 连接（HTTP/1.1 或 HTTP/2）
-    ///
-    /// 直接使用 hyper 的 auto builder 处理连接，无需额外的 Serve 中间层。
-    /// 使用预构建的冻结路由树，避免每连接重建。
-    fn handle_http_connection(
-        frozen_tree: Arc<RouteTree>,
+    /// 按连接类型分发到 HTTP/3 或 HTTP/1.1、HTTP/2 处理
+    pub(crate) fn dispatch(
+        routes: &ReloadableRoute,
+        limits: &ConnectionLimits,
+        http2: Http2Config,
+        #[cfg(feature = "quic")] webtransport_handler: &Arc<
+            dyn crate::server::quic::WebTransportHandler,
+        >,
         stream: BoxedConnection,
         peer: CoreSocketAddr,
-        limits: ConnectionLimits,
     ) -> ConnectionFuture {
-        let max_body_size = limits.max_body_size;
-        Box::pin(async move {
-            let io = TokioIo::new(stream);
-            let mut builder = Builder::new(TokioExecutor::new());
-            // HTTP/1.1 调优：开启 pipeline flush，减少响应延迟
-            builder.http1().pipeline_flush(true);
-            // HTTP/2 调优：增大窗口和并发流以提升吞吐
-            builder
-                .http2()
-                .initial_stream_window_size(1024 * 1024) // 1MB 流窗口
-                .initial_connection_window_size(2 * 1024 * 1024) // 2MB 连接窗口
-                .adaptive_window(true)
-                .max_concurrent_streams(256);
-            builder
-                .serve_connection_with_upgrades(
-                    io,
-                    // 直接传 Arc<RouteTree>，clone 仅增加引用计数
-                    HyperServiceHandler::with_limits(peer.into(), frozen_tree, max_body_size),
-                )
-                .await
-        })
-    }
-}
-
-impl ConnectionService for RouteConnectionService {
-    fn call(&self, stream: BoxedConnection, peer: CoreSocketAddr) -> ConnectionFuture {
         // 尝试将连接转换为 QuicConnection
         #[cfg(feature = "quic")]
         {
             use crate::quic::connection::QuicConnection;
             match stream.downcast::<QuicConnection>() {
                 Ok(quic) => {
-                    // QUIC 连接处理：共享冻结路由树
-                    let routes = Arc::clone(&self.frozen_tree);
-                    let read_timeout = self.limits.h3_read_timeout;
-                    let max_body_size = self.limits.max_body_size;
-                    let max_wt_frame = self.limits.max_webtransport_frame_size;
-                    let wt_read_timeout = self.limits.webtransport_read_timeout;
-                    let max_wt_sessions = self.limits.max_webtransport_sessions;
+                    // QUIC 连接处理：使用连接建立时的路由树
+                    let routes = routes.current();
+                    let read_timeout = limits.h3_read_timeout;
+                    let max_body_size = limits.max_body_size;
+                    let max_wt_frame = limits.max_webtransport_frame_size;
+                    let wt_read_timeout = limits.webtransport_read_timeout;
+                    let max_wt_sessions = limits.max_webtransport_sessions;
                     let enable_datagram = global_server_config()
                         .quic_transport
                         .as_ref()
                         .map(|c| c.enable_datagram)
                         .unwrap_or(true);
-                    let max_datagram_size = self.limits.webtransport_datagram_max_size;
-                    let datagram_rate = self.limits.webtransport_datagram_rate;
-                    let datagram_drop_metric = self.limits.webtransport_datagram_drop_metric;
-                    let webtransport_handler = self.webtransport_handler.clone();
+                    let max_datagram_size = limits.webtransport_datagram_max_size;
+                    let datagram_rate = limits.webtransport_datagram_rate;
+                    let datagram_drop_metric = limits.webtransport_datagram_drop_metric;
+                    let webtransport_handler = webtransport_handler.clone();
                     Box::pin(async move {
                         let incoming = quic.into_incoming();
                         crate::quic::service::handle_quic_connection(
@@ -168,10 +165,11 @@ impl ConnectionService for RouteConnectionService {
                 Err(stream) => {
                     // 不是 QUIC 连接，继续处理为 HTTP/1.1 或 HTTP/2
                     Self::handle_http_connection(
-                        Arc::clone(&self.frozen_tree),
+                        routes.clone(),
                         stream,
                         peer,
-                        self.limits.clone(),
+                        limits.clone(),
+                        http2,
                     )
                 }
             }
@@ -179,11 +177,69 @@ impl ConnectionService for RouteConnectionService {
 
         // 没有 QUIC feature 时的 HTTP/1.1 或 HTTP/2 连接处理
         #[cfg(not(feature = "quic"))]
-        Self::handle_http_connection(
-            Arc::clone(&self.frozen_tree),
+        Self::handle_http_connection(routes.clone(), stream, peer, limits.clone(), http2)
+    }
+
+    /// 处理 HTTP 连接（HTTP/1.1 或 HTTP/2）
+    ///
+    /// 经 [`HttpListener`](crate::HttpListener) 接受的连接按其指定的协议处理。
+    ///
+    /// 直接使用 hyper 的 auto builder 处理连接，无需额外的 Serve 中间层，HTTP/2 参数取自 [`Http2Config`]。
+    /// 使用预构建的冻结路由树，避免每连接重建。服务优雅关停时，HTTP/1.1 在当前响应中携带
+    /// `Connection: close` 后关闭连接，HTTP/2 发送 GOAWAY，客户端无需等到关停超时即可迁移。
+    fn handle_http_connection(
+        routes: ReloadableRoute,
+        stream: BoxedConnection,
+        peer: CoreSocketAddr,
+        limits: ConnectionLimits,
+        http2: Http2Config,
+    ) -> ConnectionFuture {
+        let max_body_size = limits.max_body_size;
+        let (stream, protocols) = match stream.downcast::<HttpConnection>() {
+            Ok(conn) => (conn.inner, conn.protocols),
+            Err(stream) => (stream, HttpProtocols::Auto),
+        };
+        Box::pin(async move {
+            let io = TokioIo::new(stream);
+            let builder = connection_builder(&http2, &limits);
+            let builder = match protocols {
+                HttpProtocols::Http1Only => builder.http1_only(),
+                HttpProtocols::Http2Only => builder.http2_only(),
+                HttpProtocols::Auto | HttpProtocols::H2c => builder,
+            };
+            let h2c_upgrade = (protocols == HttpProtocols::H2c).then_some(http2);
+            let conn = builder.serve_connection_with_upgrades(
+                io,
+                // 每个请求读取当前路由树，clone 仅增加引用计数
+                HyperServiceHandler::with_limits(peer.into(), routes, max_body_size)
+                    .with_header_limits(limits.max_header_bytes, limits.max_header_count)
+                    .with_h2c_upgrade(h2c_upgrade),
+            );
+            let Some(drain) = drain_signal() else {
+                return conn.await;
+            };
+            let mut conn = std::pin::pin!(conn);
+            tokio::select! {
+                res = conn.as_mut() => res,
+                _ = drain.cancelled() => {
+                    conn.as_mut().graceful_shutdown();
+                    conn.await
+                }
+            }
+        })
+    }
+}
+
+impl ConnectionService for RouteConnectionService {
+    fn call(&self, stream: BoxedConnection, peer: CoreSocketAddr) -> ConnectionFuture {
+        Self::dispatch(
+            &self.routes,
+            &self.limits,
+            self.http2,
+            #[cfg(feature = "quic")]
+            &self.webtransport_handler,
             stream,
             peer,
-            self.limits.clone(),
         )
     }
 }
@@ -220,6 +276,236 @@ mod tests {
         assert_eq!(service.route.path, route.path);
     }
 
+    // ==================== Server 状态注入测试 ====================
+
+    #[test]
+    fn test_inject_server_state_empty() {
+        let mut route = Route::new("");
+        RouteConnectionService::inject_server_state(&mut route, &crate::State::new());
+        assert!(route.get_state().is_none());
+    }
+
+    #[test]
+    fn test_inject_server_state_route_priority() {
+        let mut server_state = crate::State::new();
+        server_state.insert(1i32);
+        server_state.insert("server".to_string());
+
+        let mut route = Route::new("").with_state(2i32);
+        RouteConnectionService::inject_server_state(&mut route, &server_state);
+
+        let state = route.get_state().unwrap();
+        assert_eq!(state.get::<i32>(), Some(&2));
+        assert_eq!(state.get::<String>().map(String::as_str), Some("server"));
+    }
+
+    #[tokio::test]
+    async fn test_server_state_reaches_handler() {
+        use crate::extractor::State;
+        use crate::{Handler, Request};
+
+        let mut server_state = crate::State::new();
+        server_state.insert(7u64);
+
+        let mut route =
+            Route::new("").get(|State(num): State<u64>| async move { Ok(num.to_string()) });
+        RouteConnectionService::inject_server_state(&mut route, &server_state);
+        let tree = route.convert_to_route_tree();
+
+        let mut req = Request::empty();
+        *req.uri_mut() = "/".parse().unwrap();
+        let res = tree.call(req).await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_http2_config_applied() {
+        use crate::Request;
+        use http_body_util::{BodyExt, Empty};
+        use hyper_util::rt::TokioExecutor;
+
+        let route = Route::new("").get(|_req: Request| async { Ok("h2") });
+        let routes = ReloadableRoute::new(route);
+        let http2 = Http2Config::new()
+            .with_max_concurrent_streams(8)
+            .with_max_frame_size(32 * 1024)
+            .with_keep_alive_interval(std::time::Duration::from_secs(5))
+            .with_keep_alive_timeout(std::time::Duration::from_secs(1));
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let peer: CoreSocketAddr = "127.0.0.1:1"
+            .parse::<std::net::SocketAddr>()
+            .unwrap()
+            .into();
+        tokio::spawn(RouteConnectionService::handle_http_connection(
+            routes,
+            Box::new(server),
+            peer,
+            ConnectionLimits::default(),
+            http2,
+        ));
+
+        let (mut sender, conn) =
+            hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(client))
+                .await
+                .unwrap();
+        tokio::spawn(conn);
+        let req = http::Request::builder()
+            .uri("http://localhost/")
+            .body(Empty::<bytes::Bytes>::new())
+            .unwrap();
+        let res = sender.send_request(req).await.unwrap();
+        assert_eq!(res.version(), http::Version::HTTP_2);
+        let body = res.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"h2");
+    }
+
+    #[tokio::test]
+    async fn test_header_limits_http1() {
+        use crate::Request;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        async fn status(limits: ConnectionLimits, headers: usize) -> String {
+            let route = Route::new("").get(|_req: Request| async { Ok("ok") });
+            let (mut client, server) = tokio::io::duplex(64 * 1024);
+            let peer: CoreSocketAddr = "127.0.0.1:1"
+                .parse::<std::net::SocketAddr>()
+                .unwrap()
+                .into();
+            tokio::spawn(RouteConnectionService::handle_http_connection(
+                ReloadableRoute::new(route),
+                Box::new(server),
+                peer,
+                limits,
+                Http2Config::default(),
+            ));
+            let mut req = "GET / HTTP/1.1\r\nHost: localhost\r\n".to_string();
+            for i in 0..headers {
+                req.push_str(&format!("x-h{i}: value\r\n"));
+            }
+            req.push_str("\r\n");
+            client.write_all(req.as_bytes()).await.unwrap();
+            let mut line = [0u8; 12];
+            client.read_exact(&mut line).await.unwrap();
+            String::from_utf8_lossy(&line).into_owned()
+        }
+
+        let limits = ConnectionLimits {
+            max_header_count: Some(4),
+            ..Default::default()
+        };
+        assert_eq!(status(limits.clone(), 3).await, "HTTP/1.1 200");
+        assert_eq!(status(limits, 8).await, "HTTP/1.1 431");
+        // 限制超过 hyper 默认的 100 个头部时同样返回 431
+        let limits = ConnectionLimits {
+            max_header_count: Some(150),
+            ..Default::default()
+        };
+        assert_eq!(status(limits.clone(), 120).await, "HTTP/1.1 200");
+        assert_eq!(status(limits, 160).await, "HTTP/1.1 431");
+
+        let limits = ConnectionLimits {
+            max_header_bytes: Some(64),
+            ..Default::default()
+        };
+        assert_eq!(status(limits.clone(), 1).await, "HTTP/1.1 200");
+        assert_eq!(status(limits, 10).await, "HTTP/1.1 431");
+    }
+
+    #[tokio::test]
+    async fn test_drain_http1_closes_keep_alive() {
+        use crate::Request;
+        use crate::server::net_server::with_drain;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio_util::sync::CancellationToken;
+
+        let route = Route::new("").get(|_req: Request| async {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            Ok("ok")
+        });
+        let (mut client, server) = tokio::io::duplex(64 * 1024);
+        let peer: CoreSocketAddr = "127.0.0.1:1"
+            .parse::<std::net::SocketAddr>()
+            .unwrap()
+            .into();
+        let drain = CancellationToken::new();
+        let conn = tokio::spawn(with_drain(
+            drain.clone(),
+            RouteConnectionService::handle_http_connection(
+                ReloadableRoute::new(route),
+                Box::new(server),
+                peer,
+                ConnectionLimits::default(),
+                Http2Config::default(),
+            ),
+        ));
+        client
+            .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        // 请求处理中开始排空：响应照常返回并携带 Connection: close，随后连接关闭
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        drain.cancel();
+        let mut response = Vec::new();
+        tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            client.read_to_end(&mut response),
+        )
+        .await
+        .expect("connection should close after draining")
+        .unwrap();
+        let response = String::from_utf8_lossy(&response).to_lowercase();
+        assert!(response.starts_with("http/1.1 200"));
+        assert!(response.contains("connection: close"));
+        assert!(response.ends_with("ok"));
+        conn.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_drain_http2_sends_goaway() {
+        use crate::Request;
+        use crate::server::net_server::with_drain;
+        use hyper_util::rt::TokioExecutor;
+        use tokio_util::sync::CancellationToken;
+
+        let route = Route::new("").get(|_req: Request| async { Ok("ok") });
+        let (client, server) = tokio::io::duplex(64 * 1024);
+        let peer: CoreSocketAddr = "127.0.0.1:1"
+            .parse::<std::net::SocketAddr>()
+            .unwrap()
+            .into();
+        let drain = CancellationToken::new();
+        tokio::spawn(with_drain(
+            drain.clone(),
+            RouteConnectionService::handle_http_connection(
+                ReloadableRoute::new(route),
+                Box::new(server),
+                peer,
+                ConnectionLimits::default(),
+                Http2Config::default(),
+            ),
+        ));
+        let (mut sender, conn) =
+            hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(client))
+                .await
+                .unwrap();
+        let conn = tokio::spawn(conn);
+        let req = http::Request::builder()
+            .uri("http://localhost/")
+            .body(http_body_util::Empty::<bytes::Bytes>::new())
+            .unwrap();
+        let res = sender.send_request(req).await.unwrap();
+        assert_eq!(res.status(), http::StatusCode::OK);
+
+        // 空闲连接收到 GOAWAY 后结束
+        drain.cancel();
+        tokio::time::timeout(std::time::Duration::from_secs(5), conn)
+            .await
+            .expect("client connection should end after GOAWAY")
+            .unwrap()
+            .unwrap();
+        assert!(sender.is_closed());
+    }
+
     #[test]
     fn test_route_connection_service_clone() {
         let route = Route::new("/test");
@@ -0,0 +1,187 @@
+//! systemd 套接字激活与就绪通知
+//!
+//! - 套接字激活：systemd 预先创建监听套接字，以文件描述符 3 起依次传给服务进程，
+//!   并通过 `LISTEN_PID`/`LISTEN_FDS` 环境变量告知数量；服务重启期间由 systemd 持有套接字，新连接在内核队列中等待，不会被拒绝。
+//! - 就绪通知：`Type=notify` 的服务通过 `NOTIFY_SOCKET` 上报 `READY=1`、`STOPPING=1` 等状态。
+
+use std::ffi::OsStr;
+use std::io::{Error, ErrorKind, Result};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::net::UnixDatagram;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use super::listener::Listener;
+
+/// systemd 传递的第一个文件描述符
+pub(crate) const SD_LISTEN_FDS_START: RawFd = 3;
+
+/// 继承的文件描述符只能被接管一次，否则会被重复关闭
+static LISTEN_FDS_TAKEN: AtomicBool = AtomicBool::new(false);
+
+/// 接管 systemd 传入的监听套接字
+///
+/// 未设置 `LISTEN_PID`、其值不是当前进程，或已接管过时返回空列表。
+pub(crate) fn take_listen_fds() -> Result<Vec<OwnedFd>> {
+    // 热重启时旧进程无法预知新进程 PID，改由父进程 PID 校验
+    let pid = std::env::var("LISTEN_PID")
+        .ok()
+        .or_else(|| super::hot_restart::is_upgraded().then(|| std::process::id().to_string()));
+    let fds = std::env::var("LISTEN_FDS").ok();
+    let Some(count) = listen_fds_count(pid.as_deref(), fds.as_deref(), std::process::id())? else {
+        return Ok(vec![]);
+    };
+    if LISTEN_FDS_TAKEN.swap(true, Ordering::SeqCst) {
+        return Ok(vec![]);
+    }
+    (SD_LISTEN_FDS_START..SD_LISTEN_FDS_START + count)
+        .map(|fd| {
+            // SAFETY: systemd 将 3..3+LISTEN_FDS 的文件描述符交由当前进程（LISTEN_PID 已校验）持有，
+            // 且 LISTEN_FDS_TAKEN 保证只接管一次，不会出现重复所有权。
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+            set_cloexec(&fd)?;
+            Ok(fd)
+        })
+        .collect()
+}
+
+/// 与 `sd_listen_fds` 一致，接管后设置 CLOEXEC，避免泄漏给子进程
+fn set_cloexec(fd: &OwnedFd) -> Result<()> {
+    // SAFETY: fd 为当前进程持有的有效描述符，F_SETFD 只修改描述符标志。
+    if unsafe { libc::fcntl(fd.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC) } < 0 {
+        return Err(Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// 解析环境变量，返回传给当前进程的套接字数量
+fn listen_fds_count(pid: Option<&str>, fds: Option<&str>, current: u32) -> Result<Option<RawFd>> {
+    let (Some(pid), Some(fds)) = (pid, fds) else {
+        return Ok(None);
+    };
+    if pid.trim().parse::<u32>().ok() != Some(current) {
+        return Ok(None);
+    }
+    let count = fds
+        .trim()
+        .parse::<RawFd>()
+        .ok()
+        .filter(|count| *count >= 0)
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid LISTEN_FDS: {fds}"),
+            )
+        })?;
+    Ok((count > 0).then_some(count))
+}
+
+/// 将继承的套接字转换为监听器，支持 TCP 与 Unix 流式套接字
+pub(crate) fn listener_from_fd(fd: OwnedFd) -> Result<Listener> {
+    let tcp = std::net::TcpListener::from(fd);
+    // 非 IP 协议族的套接字无法取得 TCP 地址
+    if tcp.local_addr().is_ok() {
+        return Listener::try_from(tcp);
+    }
+    let unix = std::os::unix::net::UnixListener::from(OwnedFd::from(tcp));
+    unix.local_addr()?;
+    Listener::try_from(unix)
+}
+
+/// 向 systemd 上报服务状态（如 `READY=1`、`STOPPING=1`、`STATUS=...`）
+///
+/// 未设置 `NOTIFY_SOCKET`（不是由 systemd 以 `Type=notify` 启动）时不做任何事并返回 `Ok(false)`。
+/// [`NetServer`](crate::NetServer) 会在完成绑定后自动上报 `READY=1`，开始关停时上报 `STOPPING=1`。
+///
+/// ```no_run
+/// silent::sd_notify("STATUS=warming up caches").unwrap();
+/// ```
+pub fn sd_notify(state: &str) -> Result<bool> {
+    match std::env::var_os("NOTIFY_SOCKET") {
+        Some(path) => notify_to(&path, state).map(|_| true),
+        None => Ok(false),
+    }
+}
+
+pub(crate) fn notify_to(path: &OsStr, state: &str) -> Result<()> {
+    let socket = UnixDatagram::unbound()?;
+    match path.as_bytes().first() {
+        Some(b'/') => {
+            socket.send_to(state.as_bytes(), path)?;
+        }
+        // 以 @ 开头表示 Linux 抽象命名空间
+        #[cfg(target_os = "linux")]
+        Some(b'@') => {
+            use std::os::linux::net::SocketAddrExt;
+            let addr = std::os::unix::net::SocketAddr::from_abstract_name(&path.as_bytes()[1..])?;
+            socket.send_to_addr(state.as_bytes(), &addr)?;
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("unsupported NOTIFY_SOCKET: {}", path.to_string_lossy()),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 上报状态，失败时仅记录日志
+pub(crate) fn notify_quietly(state: &str) {
+    if let Err(e) = sd_notify(state) {
+        tracing::warn!(state, error = ?e, "sd_notify failed");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::socket_addr::SocketAddr;
+    use crate::server::listener::Listen;
+
+    #[test]
+    fn test_listen_fds_count() {
+        assert_eq!(listen_fds_count(None, Some("2"), 42).unwrap(), None);
+        assert_eq!(listen_fds_count(Some("41"), Some("2"), 42).unwrap(), None);
+        assert_eq!(listen_fds_count(Some("42"), Some("0"), 42).unwrap(), None);
+        assert_eq!(
+            listen_fds_count(Some("42"), Some("2"), 42).unwrap(),
+            Some(2)
+        );
+        assert!(listen_fds_count(Some("42"), Some("x"), 42).is_err());
+        assert!(listen_fds_count(Some("42"), Some("-1"), 42).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_listener_from_fd() {
+        let tcp = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = tcp.local_addr().unwrap();
+        let listener = listener_from_fd(OwnedFd::from(tcp)).unwrap();
+        assert!(matches!(listener.local_addr().unwrap(), SocketAddr::Tcp(a) if a == addr));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("activated.sock");
+        let unix = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        let listener = listener_from_fd(OwnedFd::from(unix)).unwrap();
+        assert!(matches!(
+            listener.local_addr().unwrap(),
+            SocketAddr::Unix(_)
+        ));
+        let client = tokio::spawn(tokio::net::UnixStream::connect(path));
+        listener.accept().await.unwrap();
+        client.await.unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_notify_to() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notify.sock");
+        let receiver = UnixDatagram::bind(&path).unwrap();
+        notify_to(path.as_os_str(), "READY=1").unwrap();
+        let mut buf = [0u8; 16];
+        let n = receiver.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"READY=1");
+
+        assert!(notify_to(OsStr::new("relative.sock"), "READY=1").is_err());
+    }
+}
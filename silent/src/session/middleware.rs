@@ -89,6 +89,10 @@ where
             );
         }
         let mut res = next.call(req).await?;
+        // 处理函数修改了会话数据时写回存储
+        if session_copied.data_changed() {
+            session_store.store_session(session_copied.clone()).await?;
+        }
         if res.extensions().get::<Session>().is_none() {
             res.extensions_mut().insert(session_copied);
         }